@@ -0,0 +1,2 @@
+DROP TABLE category;
+DROP FUNCTION update_category_updated_at;
\ No newline at end of file
@@ -0,0 +1,29 @@
+CREATE TABLE category (
+    id SERIAL PRIMARY KEY,
+
+    name VARCHAR(50) NOT NULL UNIQUE,
+
+    updated_at TIMESTAMP,
+    created_at TIMESTAMP DEFAULT NOW() NOT NULL,
+
+    updated_by INT,
+    FOREIGN KEY (updated_by) REFERENCES user_account(id),
+    created_by INT NOT NULL,
+    FOREIGN KEY (created_by) REFERENCES user_account(id)
+);
+
+ALTER SEQUENCE category_id_seq RESTART WITH 1000;
+
+CREATE  FUNCTION update_category_updated_at()
+RETURNS TRIGGER AS $$
+BEGIN
+    NEW.updated_at = now();
+    RETURN NEW;
+END;
+$$ language 'plpgsql';
+
+CREATE TRIGGER category_on_update
+    BEFORE UPDATE
+    ON category
+    FOR EACH ROW
+EXECUTE PROCEDURE update_category_updated_at();
@@ -0,0 +1 @@
+DROP TABLE article_category;
\ No newline at end of file
@@ -0,0 +1,18 @@
+CREATE TABLE article_category (
+    id SERIAL PRIMARY KEY,
+
+    article_id INT NOT NULL,
+    FOREIGN KEY (article_id) REFERENCES article(id) ON DELETE CASCADE,
+
+    category_id INT NOT NULL,
+    FOREIGN KEY (category_id) REFERENCES category(id) ON DELETE CASCADE,
+
+    created_at TIMESTAMP DEFAULT NOW() NOT NULL,
+
+    created_by INT NOT NULL,
+    FOREIGN KEY (created_by) REFERENCES user_account(id),
+
+    CONSTRAINT category_per_article UNIQUE (article_id, category_id)
+);
+
+CREATE INDEX idx_article_category_category_id ON article_category(category_id);
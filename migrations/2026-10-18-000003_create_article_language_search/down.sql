@@ -0,0 +1,2 @@
+DROP TABLE article_language_search;
+DROP FUNCTION language_search_config;
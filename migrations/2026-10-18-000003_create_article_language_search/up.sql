@@ -0,0 +1,39 @@
+-- PostgreSQL has no built-in Ukrainian config, so `ua` (like any unmapped language) isn't stemmed
+CREATE FUNCTION language_search_config(language_code VARCHAR)
+RETURNS regconfig AS $$
+    SELECT CASE language_code
+        WHEN 'en' THEN 'english'::regconfig
+        ELSE 'simple'::regconfig
+    END;
+$$ LANGUAGE SQL IMMUTABLE;
+
+CREATE TABLE article_language_search (
+    article_language_id INT PRIMARY KEY,
+    FOREIGN KEY (article_language_id) REFERENCES article_language(id) ON DELETE CASCADE,
+
+    search_config regconfig NOT NULL,
+    content TEXT NOT NULL,
+    search_vector TSVECTOR GENERATED ALWAYS AS (to_tsvector(search_config, content)) STORED,
+
+    updated_at TIMESTAMP DEFAULT NOW() NOT NULL
+);
+
+CREATE INDEX idx_article_language_search_vector ON article_language_search USING GIN (search_vector);
+
+INSERT INTO article_language_search (article_language_id, search_config, content)
+SELECT
+    article_version.article_language_id,
+    language_search_config(language.code),
+    convert_from(version_content.content, 'UTF8')
+FROM article_version
+INNER JOIN version_content ON version_content.id = article_version.content_id
+INNER JOIN article_language ON article_language.id = article_version.article_language_id
+INNER JOIN language ON language.id = article_language.language_id
+-- only the latest version keeps full content here, so a disabled one is indexed
+-- after the next change of the language's versions
+WHERE article_version.enabled = true
+AND article_version.version = (
+    SELECT MAX(latest_version.version)
+    FROM article_version latest_version
+    WHERE latest_version.article_language_id = article_version.article_language_id
+);
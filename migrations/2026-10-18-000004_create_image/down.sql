@@ -0,0 +1,2 @@
+DROP TABLE image_reference;
+DROP TABLE image;
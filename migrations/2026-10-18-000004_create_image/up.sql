@@ -0,0 +1,27 @@
+CREATE TABLE image (
+    id SERIAL PRIMARY KEY,
+
+    storage_key VARCHAR(255) NOT NULL UNIQUE,
+    mime_type VARCHAR(100) NOT NULL,
+    size INT NOT NULL,
+    width INT,
+    height INT,
+
+    created_at TIMESTAMP DEFAULT NOW() NOT NULL,
+
+    created_by INT NOT NULL,
+    FOREIGN KEY (created_by) REFERENCES user_account(id)
+);
+
+ALTER SEQUENCE image_id_seq RESTART WITH 1000;
+
+CREATE TABLE image_reference (
+    image_id INT NOT NULL,
+    FOREIGN KEY (image_id) REFERENCES image(id) ON DELETE CASCADE,
+    article_version_id INT NOT NULL,
+    FOREIGN KEY (article_version_id) REFERENCES article_version(id) ON DELETE CASCADE,
+
+    PRIMARY KEY (image_id, article_version_id)
+);
+
+CREATE INDEX image_reference_article_version_id ON image_reference (article_version_id);
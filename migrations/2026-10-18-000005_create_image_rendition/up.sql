@@ -0,0 +1,21 @@
+CREATE TABLE image_rendition (
+    id SERIAL PRIMARY KEY,
+
+    image_id INT NOT NULL,
+    FOREIGN KEY (image_id) REFERENCES image(id) ON DELETE CASCADE,
+
+    name VARCHAR(20) NOT NULL,
+    storage_key VARCHAR(255) NOT NULL UNIQUE,
+    mime_type VARCHAR(100) NOT NULL,
+    size INT NOT NULL,
+    width INT,
+    height INT,
+
+    CONSTRAINT image_rendition_per_image UNIQUE (image_id, name)
+);
+
+ALTER SEQUENCE image_rendition_id_seq RESTART WITH 1000;
+
+INSERT INTO image_rendition (image_id, name, storage_key, mime_type, size, width, height)
+SELECT id, 'original', storage_key, mime_type, size, width, height
+FROM image;
@@ -0,0 +1,21 @@
+CREATE TABLE user_session (
+    id SERIAL PRIMARY KEY,
+
+    user_id INT NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES user_account(id) ON DELETE CASCADE,
+
+    refresh_token_hash VARCHAR(64) NOT NULL UNIQUE,
+
+    replaced_by INT,
+    FOREIGN KEY (replaced_by) REFERENCES user_session(id) ON DELETE SET NULL,
+
+    expires_at TIMESTAMP NOT NULL,
+    revoked_at TIMESTAMP,
+
+    updated_at TIMESTAMP,
+    created_at TIMESTAMP DEFAULT NOW() NOT NULL
+);
+
+CREATE INDEX idx_user_session_user_id ON user_session(user_id);
+
+ALTER SEQUENCE user_session_id_seq RESTART WITH 1000;
@@ -0,0 +1,8 @@
+-- hashed codes can't be restored, pending codes have to be requested again
+DELETE FROM user_otp;
+
+ALTER TABLE user_otp
+    DROP COLUMN attempts,
+    DROP COLUMN expires_at;
+
+ALTER TABLE user_otp RENAME COLUMN otp_hash TO otp;
@@ -0,0 +1,11 @@
+ALTER TABLE user_otp RENAME COLUMN otp TO otp_hash;
+
+UPDATE user_otp SET otp_hash = encode(sha256(otp_hash::bytea), 'hex');
+
+ALTER TABLE user_otp
+    ADD COLUMN attempts INT DEFAULT 0 NOT NULL,
+    ADD COLUMN expires_at TIMESTAMP;
+
+UPDATE user_otp SET expires_at = created_at + INTERVAL '1 day';
+
+ALTER TABLE user_otp ALTER COLUMN expires_at SET NOT NULL;
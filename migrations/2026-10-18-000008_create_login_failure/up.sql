@@ -0,0 +1,13 @@
+-- subject is either "user:<user_account.id>" or "ip:<client address>"
+CREATE TABLE login_failure (
+    id SERIAL PRIMARY KEY,
+
+    subject VARCHAR(255) NOT NULL UNIQUE,
+    failed_count INT DEFAULT 1 NOT NULL,
+    locked_until TIMESTAMP,
+
+    updated_at TIMESTAMP DEFAULT NOW() NOT NULL,
+    created_at TIMESTAMP DEFAULT NOW() NOT NULL
+);
+
+ALTER SEQUENCE login_failure_id_seq RESTART WITH 1000;
@@ -0,0 +1,31 @@
+CREATE TABLE user_totp (
+    id SERIAL PRIMARY KEY,
+
+    user_id INT NOT NULL UNIQUE,
+    FOREIGN KEY (user_id) REFERENCES user_account(id) ON DELETE CASCADE,
+
+    secret VARCHAR(64) NOT NULL,
+    confirmed BOOLEAN DEFAULT false NOT NULL,
+    last_used_step BIGINT,
+
+    updated_at TIMESTAMP,
+    created_at TIMESTAMP DEFAULT NOW() NOT NULL
+);
+
+ALTER SEQUENCE user_totp_id_seq RESTART WITH 1000;
+
+CREATE TABLE user_recovery_code (
+    id SERIAL PRIMARY KEY,
+
+    user_id INT NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES user_account(id) ON DELETE CASCADE,
+
+    code_hash VARCHAR(64) NOT NULL,
+    used_at TIMESTAMP,
+
+    created_at TIMESTAMP DEFAULT NOW() NOT NULL
+);
+
+CREATE INDEX idx_user_recovery_code_user_id ON user_recovery_code(user_id);
+
+ALTER SEQUENCE user_recovery_code_id_seq RESTART WITH 1000;
@@ -0,0 +1,19 @@
+CREATE TABLE user_identity (
+    id SERIAL PRIMARY KEY,
+
+    user_id INT NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES user_account(id) ON DELETE CASCADE,
+
+    provider VARCHAR(50) NOT NULL,
+    subject VARCHAR(255) NOT NULL,
+    email VARCHAR(255),
+
+    updated_at TIMESTAMP,
+    created_at TIMESTAMP DEFAULT NOW() NOT NULL,
+
+    CONSTRAINT user_identity_per_provider UNIQUE (provider, subject)
+);
+
+CREATE INDEX idx_user_identity_user_id ON user_identity(user_id);
+
+ALTER SEQUENCE user_identity_id_seq RESTART WITH 1000;
@@ -0,0 +1 @@
+DROP TABLE user_api_token;
\ No newline at end of file
@@ -0,0 +1,7 @@
+DROP TABLE email_template;
+
+DROP FUNCTION update_email_template_updated_at;
+
+DROP TYPE EMAIL_TEMPLATE_TYPE;
+
+ALTER TABLE user_account DROP COLUMN language_id;
@@ -0,0 +1,70 @@
+ALTER TABLE user_account ADD COLUMN language_id INT;
+ALTER TABLE user_account ADD FOREIGN KEY (language_id) REFERENCES language(id);
+
+CREATE TYPE EMAIL_TEMPLATE_TYPE AS ENUM ('confirmation', 'reset');
+
+CREATE TABLE email_template (
+    id SERIAL PRIMARY KEY,
+
+    template_type EMAIL_TEMPLATE_TYPE NOT NULL,
+
+    language_id INT NOT NULL,
+    FOREIGN KEY (language_id) REFERENCES language(id),
+
+    subject VARCHAR(255) NOT NULL,
+    html TEXT NOT NULL,
+    text TEXT NOT NULL,
+
+    updated_at TIMESTAMP,
+    created_at TIMESTAMP DEFAULT NOW() NOT NULL,
+
+    updated_by INT,
+    FOREIGN KEY (updated_by) REFERENCES user_account(id),
+
+    CONSTRAINT email_template_per_language UNIQUE (template_type, language_id)
+);
+
+ALTER SEQUENCE email_template_id_seq RESTART WITH 1000;
+
+CREATE  FUNCTION update_email_template_updated_at()
+RETURNS TRIGGER AS $$
+BEGIN
+    NEW.updated_at = now();
+    RETURN NEW;
+END;
+$$ language 'plpgsql';
+
+CREATE TRIGGER email_template_on_update
+    BEFORE UPDATE
+    ON email_template
+    FOR EACH ROW
+EXECUTE PROCEDURE update_email_template_updated_at();
+
+-- {{name}}, {{email}} and {{url}} placeholders are substituted on sending
+INSERT INTO email_template (template_type, language_id, subject, html, text)
+SELECT 'confirmation', id,
+    'Підтвердіть вашу пошту',
+    '<p>Вітаємо, {{name}}!</p><p>Щоб завершити реєстрацію, підтвердіть вашу пошту:</p><p><a href="{{url}}">{{url}}</a></p>',
+    E'Вітаємо, {{name}}!\n\nЩоб завершити реєстрацію, підтвердіть вашу пошту:\n{{url}}'
+FROM language WHERE code = 'ua';
+
+INSERT INTO email_template (template_type, language_id, subject, html, text)
+SELECT 'reset', id,
+    'Відновлення пароля',
+    '<p>Вітаємо, {{name}}!</p><p>Щоб встановити новий пароль, перейдіть за посиланням:</p><p><a href="{{url}}">{{url}}</a></p>',
+    E'Вітаємо, {{name}}!\n\nЩоб встановити новий пароль, перейдіть за посиланням:\n{{url}}'
+FROM language WHERE code = 'ua';
+
+INSERT INTO email_template (template_type, language_id, subject, html, text)
+SELECT 'confirmation', id,
+    'Confirm your email',
+    '<p>Hi, {{name}}!</p><p>To finish the sign up, confirm your email:</p><p><a href="{{url}}">{{url}}</a></p>',
+    E'Hi, {{name}}!\n\nTo finish the sign up, confirm your email:\n{{url}}'
+FROM language WHERE code = 'en';
+
+INSERT INTO email_template (template_type, language_id, subject, html, text)
+SELECT 'reset', id,
+    'Reset your password',
+    '<p>Hi, {{name}}!</p><p>To set a new password, follow the link:</p><p><a href="{{url}}">{{url}}</a></p>',
+    E'Hi, {{name}}!\n\nTo set a new password, follow the link:\n{{url}}'
+FROM language WHERE code = 'en';
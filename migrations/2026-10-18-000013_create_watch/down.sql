@@ -0,0 +1,13 @@
+DELETE FROM email_template WHERE template_type IN ('watch', 'digest');
+
+ALTER TABLE email_template ALTER COLUMN template_type TYPE VARCHAR(50);
+DROP TYPE EMAIL_TEMPLATE_TYPE;
+CREATE TYPE EMAIL_TEMPLATE_TYPE AS ENUM ('confirmation', 'reset');
+ALTER TABLE email_template
+    ALTER COLUMN template_type TYPE EMAIL_TEMPLATE_TYPE USING template_type::EMAIL_TEMPLATE_TYPE;
+
+DROP TABLE watch_notification;
+DROP TABLE watch;
+
+DROP TYPE WATCH_EVENT_TYPE;
+DROP TYPE WATCH_DELIVERY;
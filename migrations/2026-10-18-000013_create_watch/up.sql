@@ -0,0 +1,82 @@
+CREATE TYPE WATCH_DELIVERY AS ENUM ('immediate', 'digest');
+CREATE TYPE WATCH_EVENT_TYPE AS ENUM ('new_version', 'archived', 'restored', 'renamed');
+
+CREATE TABLE watch (
+    id SERIAL PRIMARY KEY,
+
+    user_id INT NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES user_account(id) ON DELETE CASCADE,
+
+    article_id INT NOT NULL,
+    FOREIGN KEY (article_id) REFERENCES article(id) ON DELETE CASCADE,
+
+    -- NULL watches all languages of the article
+    article_language_id INT,
+    FOREIGN KEY (article_language_id) REFERENCES article_language(id) ON DELETE CASCADE,
+
+    delivery WATCH_DELIVERY DEFAULT 'immediate' NOT NULL,
+    unsubscribe_key VARCHAR(64) NOT NULL UNIQUE,
+
+    created_at TIMESTAMP DEFAULT NOW() NOT NULL
+);
+
+CREATE UNIQUE INDEX watch_per_target ON watch(user_id, article_id, COALESCE(article_language_id, 0));
+CREATE INDEX idx_watch_article_id ON watch(article_id);
+
+ALTER SEQUENCE watch_id_seq RESTART WITH 1000;
+
+-- events waiting for the digest of their watch
+CREATE TABLE watch_notification (
+    id SERIAL PRIMARY KEY,
+
+    watch_id INT NOT NULL,
+    FOREIGN KEY (watch_id) REFERENCES watch(id) ON DELETE CASCADE,
+
+    event_type WATCH_EVENT_TYPE NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    language_code VARCHAR(10),
+    version INT,
+
+    created_at TIMESTAMP DEFAULT NOW() NOT NULL,
+    sent_at TIMESTAMP
+);
+
+CREATE INDEX idx_watch_notification_pending ON watch_notification(watch_id) WHERE sent_at IS NULL;
+
+ALTER SEQUENCE watch_notification_id_seq RESTART WITH 1000;
+
+-- new enum values can't be used in the migration transaction they are added in, so the type is recreated
+ALTER TABLE email_template ALTER COLUMN template_type TYPE VARCHAR(50);
+DROP TYPE EMAIL_TEMPLATE_TYPE;
+CREATE TYPE EMAIL_TEMPLATE_TYPE AS ENUM ('confirmation', 'reset', 'watch', 'digest');
+ALTER TABLE email_template
+    ALTER COLUMN template_type TYPE EMAIL_TEMPLATE_TYPE USING template_type::EMAIL_TEMPLATE_TYPE;
+
+-- {{details}} lists the events, {{unsubscribe_url}} is set for watch emails only
+INSERT INTO email_template (template_type, language_id, subject, html, text)
+SELECT 'watch', id,
+    'Зміни у статті, яку ви відстежуєте',
+    '<p>Вітаємо, {{name}}!</p><p>{{details}}</p><p><a href="{{unsubscribe_url}}">Відписатися</a></p>',
+    E'Вітаємо, {{name}}!\n\n{{details}}\n\nВідписатися: {{unsubscribe_url}}'
+FROM language WHERE code = 'ua';
+
+INSERT INTO email_template (template_type, language_id, subject, html, text)
+SELECT 'digest', id,
+    'Щоденний підсумок змін',
+    '<p>Вітаємо, {{name}}!</p><p>Зміни у статтях, які ви відстежуєте:</p><p>{{details}}</p>',
+    E'Вітаємо, {{name}}!\n\nЗміни у статтях, які ви відстежуєте:\n{{details}}'
+FROM language WHERE code = 'ua';
+
+INSERT INTO email_template (template_type, language_id, subject, html, text)
+SELECT 'watch', id,
+    'Changes in a watched article',
+    '<p>Hi, {{name}}!</p><p>{{details}}</p><p><a href="{{unsubscribe_url}}">Unsubscribe</a></p>',
+    E'Hi, {{name}}!\n\n{{details}}\n\nUnsubscribe: {{unsubscribe_url}}'
+FROM language WHERE code = 'en';
+
+INSERT INTO email_template (template_type, language_id, subject, html, text)
+SELECT 'digest', id,
+    'Daily digest of changes',
+    '<p>Hi, {{name}}!</p><p>Changes in the articles you watch:</p><p>{{details}}</p>',
+    E'Hi, {{name}}!\n\nChanges in the articles you watch:\n{{details}}'
+FROM language WHERE code = 'en';
@@ -0,0 +1,19 @@
+DROP TRIGGER language_on_update ON language;
+DROP FUNCTION update_language_updated_at;
+
+ALTER TABLE language
+    DROP COLUMN name,
+    DROP COLUMN native_name,
+    DROP COLUMN direction,
+    DROP COLUMN bcp47,
+    DROP COLUMN fallback_language_id,
+    DROP COLUMN enabled,
+    DROP COLUMN updated_at,
+    DROP COLUMN created_at,
+    DROP COLUMN updated_by,
+    DROP COLUMN created_by;
+
+DROP TYPE TEXT_DIRECTION;
+
+ALTER TABLE language DROP CONSTRAINT language_code_key;
+CREATE INDEX idx_language_code ON language(code);
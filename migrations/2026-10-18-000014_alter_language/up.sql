@@ -0,0 +1,48 @@
+CREATE TYPE TEXT_DIRECTION AS ENUM ('ltr', 'rtl');
+
+DROP INDEX idx_language_code;
+ALTER TABLE language ADD CONSTRAINT language_code_key UNIQUE (code);
+
+ALTER TABLE language
+    ADD COLUMN name VARCHAR(100),
+    ADD COLUMN native_name VARCHAR(100),
+    ADD COLUMN direction TEXT_DIRECTION DEFAULT 'ltr' NOT NULL,
+    ADD COLUMN bcp47 VARCHAR(35),
+
+    -- served when an article has no version in the requested language
+    ADD COLUMN fallback_language_id INT,
+    ADD FOREIGN KEY (fallback_language_id) REFERENCES language(id) ON DELETE SET NULL,
+
+    ADD COLUMN enabled BOOLEAN DEFAULT TRUE NOT NULL,
+
+    ADD COLUMN updated_at TIMESTAMP,
+    ADD COLUMN created_at TIMESTAMP DEFAULT NOW() NOT NULL,
+
+    ADD COLUMN updated_by INT,
+    ADD FOREIGN KEY (updated_by) REFERENCES user_account(id),
+    -- NULL for the seeded languages
+    ADD COLUMN created_by INT,
+    ADD FOREIGN KEY (created_by) REFERENCES user_account(id);
+
+UPDATE language SET name = 'Ukrainian', native_name = 'Українська', bcp47 = 'uk' WHERE code = 'ua';
+UPDATE language SET name = 'English', native_name = 'English', bcp47 = 'en' WHERE code = 'en';
+UPDATE language SET name = code, native_name = code, bcp47 = code WHERE name IS NULL;
+
+ALTER TABLE language
+    ALTER COLUMN name SET NOT NULL,
+    ALTER COLUMN native_name SET NOT NULL,
+    ALTER COLUMN bcp47 SET NOT NULL;
+
+CREATE FUNCTION update_language_updated_at()
+RETURNS TRIGGER AS $$
+BEGIN
+    NEW.updated_at = now();
+    RETURN NEW;
+END;
+$$ language 'plpgsql';
+
+CREATE TRIGGER language_on_update
+    BEFORE UPDATE
+    ON language
+    FOR EACH ROW
+EXECUTE PROCEDURE update_language_updated_at();
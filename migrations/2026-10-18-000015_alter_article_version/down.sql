@@ -0,0 +1,3 @@
+DROP INDEX idx_article_version_source_version_id;
+
+ALTER TABLE article_version DROP COLUMN source_version_id;
@@ -0,0 +1,7 @@
+-- version of another language of the same article this version was translated from
+ALTER TABLE article_version ADD COLUMN source_version_id INT;
+ALTER TABLE article_version
+    ADD CONSTRAINT article_version_source_version_id_fkey
+    FOREIGN KEY (source_version_id) REFERENCES article_version(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_article_version_source_version_id ON article_version(source_version_id);
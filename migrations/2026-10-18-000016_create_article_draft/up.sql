@@ -0,0 +1,23 @@
+-- private working copy of an article language per user, not a part of the versions delta chain
+CREATE TABLE article_draft (
+    id SERIAL PRIMARY KEY,
+
+    article_language_id INT NOT NULL,
+    FOREIGN KEY (article_language_id) REFERENCES article_language(id) ON DELETE CASCADE,
+
+    user_id INT NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES user_account(id) ON DELETE CASCADE,
+
+    name VARCHAR,
+    content TEXT NOT NULL,
+
+    -- actual version the draft was started from (or rebased on)
+    base_version INT NOT NULL,
+
+    updated_at TIMESTAMP,
+    created_at TIMESTAMP DEFAULT NOW() NOT NULL,
+
+    CONSTRAINT article_draft_per_user UNIQUE (article_language_id, user_id)
+);
+
+ALTER SEQUENCE article_draft_id_seq RESTART WITH 1000;
@@ -0,0 +1,44 @@
+use chrono::NaiveDateTime;
+use rocket::serde::{Deserialize, Serialize};
+use rocket_okapi::okapi::schemars::JsonSchema;
+
+use super::dtm_common::ApiTokenScope;
+
+use super::repository::entity::auth::UserApiToken;
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct ApiTokenAggregation {
+    pub id: i32,
+
+    pub name: String,
+    pub scopes: Vec<ApiTokenScope>,
+
+    pub expires_at: Option<NaiveDateTime>,
+    pub last_used_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+impl ApiTokenAggregation {
+    pub fn from_model(api_token: UserApiToken) -> Self {
+        Self {
+            id: api_token.id,
+            name: api_token.name,
+            scopes: api_token
+                .scopes
+                .iter()
+                .filter_map(|scope| ApiTokenScope::from_value(scope))
+                .collect(),
+
+            expires_at: api_token.expires_at,
+            last_used_at: api_token.last_used_at,
+            created_at: api_token.created_at,
+        }
+    }
+}
+
+// the raw token is returned only once, on creation
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct ApiTokenCreatedAggregation {
+    pub token: String,
+    pub api_token: ApiTokenAggregation,
+}
@@ -13,6 +13,7 @@ use super::repository::entity::{
 
 use super::article_language::ArticleLanguageAggregation;
 use super::article_version::ArticleVersionAggregation;
+use super::category::CategoryAggregation;
 use super::language::LanguageAggregation;
 
 #[derive(Serialize, Deserialize, JsonSchema)]
@@ -26,12 +27,14 @@ pub struct ArticleAggregation {
     pub created_at: NaiveDateTime,
 
     pub languages: Vec<ArticleLanguageAggregation>,
+    pub categories: Vec<CategoryAggregation>,
 }
 
 impl ArticleAggregation {
     pub fn from_model(
         article: Article,
         article_language_aggregations: Vec<ArticleLanguageAggregation>,
+        category_aggregations: Vec<CategoryAggregation>,
     ) -> Self {
         Self {
             id: article.id,
@@ -42,12 +45,14 @@ impl ArticleAggregation {
             created_at: article.created_at,
 
             languages: article_language_aggregations,
+            categories: category_aggregations,
         }
     }
 
     pub fn from_languages_map(
         articles: Vec<Article>,
         mut languages_aggregations_map: HashMap<i32, Vec<ArticleLanguageAggregation>>,
+        mut categories_aggregations_map: HashMap<i32, Vec<CategoryAggregation>>,
     ) -> Vec<Self> {
         articles
             .into_iter()
@@ -55,8 +60,15 @@ impl ArticleAggregation {
                 let article_languages_aggregations = languages_aggregations_map
                     .remove(&article.id)
                     .unwrap_or(vec![]);
+                let category_aggregations = categories_aggregations_map
+                    .remove(&article.id)
+                    .unwrap_or(vec![]);
 
-                Self::from_model(article, article_languages_aggregations)
+                Self::from_model(
+                    article,
+                    article_languages_aggregations,
+                    category_aggregations,
+                )
             })
             .collect()
     }
@@ -80,6 +92,6 @@ impl ArticleAggregation {
             vec![languages_aggregation],
         );
 
-        Self::from_model(article, article_language_aggregations)
+        Self::from_model(article, article_language_aggregations, vec![])
     }
 }
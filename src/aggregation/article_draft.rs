@@ -0,0 +1,41 @@
+use chrono::NaiveDateTime;
+use rocket::serde::{Deserialize, Serialize};
+use rocket_okapi::okapi::schemars::JsonSchema;
+
+use super::repository::entity::article_draft::ArticleDraft;
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct ArticleDraftAggregation {
+    pub id: i32,
+    pub article_language_id: i32,
+
+    pub name: Option<String>,
+    pub content: String,
+
+    pub base_version: i32,
+    pub actual_version: i32,
+    // the article language got new versions since the draft was started
+    pub is_outdated: bool,
+
+    pub updated_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+impl ArticleDraftAggregation {
+    pub fn from_model(article_draft: ArticleDraft, actual_version: i32) -> Self {
+        Self {
+            id: article_draft.id,
+            article_language_id: article_draft.article_language_id,
+
+            name: article_draft.name,
+            content: article_draft.content,
+
+            is_outdated: article_draft.base_version != actual_version,
+            base_version: article_draft.base_version,
+            actual_version,
+
+            updated_at: article_draft.updated_at,
+            created_at: article_draft.created_at,
+        }
+    }
+}
@@ -140,3 +140,30 @@ impl ArticleLanguageAggregation {
         }
     }
 }
+
+// article language served for a requested language, possibly from the fallback chain
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct ArticleLanguageFallbackAggregation {
+    pub requested_language: String,
+    pub served_language: String,
+    pub is_fallback: bool,
+
+    #[serde(flatten)]
+    pub article_language: ArticleLanguageAggregation,
+}
+
+impl ArticleLanguageFallbackAggregation {
+    pub fn from_aggregation(
+        requested_language: String,
+        article_language: ArticleLanguageAggregation,
+    ) -> Self {
+        let served_language = article_language.language.code.clone();
+
+        Self {
+            is_fallback: served_language != requested_language,
+            requested_language,
+            served_language,
+            article_language,
+        }
+    }
+}
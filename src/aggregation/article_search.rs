@@ -0,0 +1,40 @@
+use rocket::serde::{Deserialize, Serialize};
+use rocket_okapi::okapi::schemars::JsonSchema;
+
+use super::repository::entity::article_language::ArticleLanguageSearch;
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug)]
+pub struct ArticleSearchAggregation {
+    pub article_id: i32,
+    pub article_language_id: i32,
+
+    pub name: String,
+    pub name_key: String,
+    pub language_code: String,
+
+    pub rank: f32,
+    pub snippet: String,
+}
+
+impl ArticleSearchAggregation {
+    pub fn from_model(article_language_search: ArticleLanguageSearch) -> Self {
+        Self {
+            article_id: article_language_search.article_id,
+            article_language_id: article_language_search.article_language_id,
+
+            name: article_language_search.name,
+            name_key: article_language_search.name_key,
+            language_code: article_language_search.language_code,
+
+            rank: article_language_search.rank,
+            snippet: article_language_search.snippet,
+        }
+    }
+
+    pub fn from_model_list(article_languages_search: Vec<ArticleLanguageSearch>) -> Vec<Self> {
+        article_languages_search
+            .into_iter()
+            .map(|article_language_search| Self::from_model(article_language_search))
+            .collect()
+    }
+}
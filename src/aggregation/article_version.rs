@@ -12,8 +12,9 @@ use super::repository::entity::{
 
 use super::user_account::UserAccountPartialAggregation;
 use super::version_content::VersionContentAggregation;
+use super::version_diff::VersionDiffHunkAggregation;
 
-#[derive(Deserialize, Serialize, JsonSchema)]
+#[derive(Deserialize, Serialize, JsonSchema, Clone)]
 pub struct ArticleVersionAggregation {
     pub id: i32,
     pub version: i32,
@@ -28,6 +29,7 @@ pub struct ArticleVersionAggregation {
     pub created_by: Option<UserAccountPartialAggregation>,
 
     pub article_language_id: i32,
+    pub source_version_id: Option<i32>,
 }
 
 impl ArticleVersionAggregation {
@@ -54,6 +56,7 @@ impl ArticleVersionAggregation {
                     created_at: article_version.created_at,
 
                     article_language_id: article_version.article_language_id,
+                    source_version_id: article_version.source_version_id,
                     content: VersionContentAggregation::from_model(content_version, None),
 
                     created_by: None,
@@ -79,6 +82,7 @@ impl ArticleVersionAggregation {
                     created_at: article_version.created_at,
 
                     article_language_id: article_version.article_language_id,
+                    source_version_id: article_version.source_version_id,
                     content: VersionContentAggregation::from_model(
                         version_content,
                         Some(&contents_map),
@@ -90,3 +94,51 @@ impl ArticleVersionAggregation {
             .collect()
     }
 }
+
+#[derive(Deserialize, Serialize, JsonSchema)]
+pub struct ArticleVersionCompareAggregation {
+    pub actual: ArticleVersionAggregation,
+    pub compared: ArticleVersionAggregation,
+
+    pub diff: Vec<VersionDiffHunkAggregation>,
+}
+
+impl ArticleVersionCompareAggregation {
+    pub fn from_aggregations(
+        actual: ArticleVersionAggregation,
+        compared: ArticleVersionAggregation,
+        diff: Vec<VersionDiffHunkAggregation>,
+    ) -> Self {
+        Self {
+            actual,
+            compared,
+            diff,
+        }
+    }
+}
+
+// translation whose source language received newer versions than the one it was translated from
+#[derive(Deserialize, Serialize, JsonSchema)]
+pub struct ArticleVersionOutdatedAggregation {
+    pub language: String,
+    pub version: i32,
+
+    pub source_language: String,
+    pub source_version: i32,
+    pub source_actual_version: i32,
+
+    pub diff: Vec<VersionDiffHunkAggregation>,
+}
+
+// body of the 409 response when the version an edit was based on is not actual anymore
+#[derive(Deserialize, Serialize, JsonSchema)]
+pub struct ArticleVersionConflictAggregation {
+    pub message: String,
+
+    pub base_version: i32,
+    pub actual_version: i32,
+
+    // versions created after the base one, the newest first
+    pub versions: Vec<ArticleVersionAggregation>,
+    pub diff: Vec<VersionDiffHunkAggregation>,
+}
@@ -0,0 +1,56 @@
+use chrono::NaiveDateTime;
+use rocket::serde::{Deserialize, Serialize};
+use rocket_okapi::okapi::schemars::JsonSchema;
+use std::collections::{hash_map::Entry, HashMap};
+
+use super::repository::entity::category::{ArticleCategory, Category};
+
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
+pub struct CategoryAggregation {
+    pub id: i32,
+    pub name: String,
+
+    pub updated_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+impl CategoryAggregation {
+    pub fn from_model(category: Category) -> Self {
+        Self {
+            id: category.id,
+            name: category.name,
+
+            updated_at: category.updated_at,
+            created_at: category.created_at,
+        }
+    }
+
+    pub fn from_model_list(categories: Vec<Category>) -> Vec<Self> {
+        categories
+            .into_iter()
+            .map(|category| Self::from_model(category))
+            .collect()
+    }
+
+    pub fn get_aggregations_map(
+        article_categories_relations: Vec<(ArticleCategory, Category)>,
+    ) -> HashMap<i32, Vec<Self>> {
+        article_categories_relations.into_iter().fold(
+            HashMap::new(),
+            |mut acc, (article_category, category)| {
+                let category_aggregation = Self::from_model(category);
+
+                match acc.entry(article_category.article_id) {
+                    Entry::Vacant(acc) => {
+                        acc.insert(vec![category_aggregation]);
+                    }
+                    Entry::Occupied(mut acc) => {
+                        acc.get_mut().push(category_aggregation);
+                    }
+                };
+
+                acc
+            },
+        )
+    }
+}
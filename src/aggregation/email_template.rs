@@ -0,0 +1,48 @@
+use chrono::NaiveDateTime;
+use rocket::serde::{Deserialize, Serialize};
+use rocket_okapi::okapi::schemars::JsonSchema;
+
+use super::repository::entity::email_template::{EmailTemplate, EmailTemplateType};
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct EmailTemplateAggregation {
+    pub id: i32,
+
+    pub template_type: EmailTemplateType,
+    pub language: String,
+
+    pub subject: String,
+    pub html: String,
+    pub text: String,
+
+    pub updated_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+impl EmailTemplateAggregation {
+    pub fn from_model(email_template: EmailTemplate, language: String) -> Self {
+        Self {
+            language,
+            id: email_template.id,
+            template_type: email_template.template_type,
+
+            subject: email_template.subject,
+            html: email_template.html,
+            text: email_template.text,
+
+            updated_at: email_template.updated_at,
+            created_at: email_template.created_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct EmailPreviewAggregation {
+    pub template_type: EmailTemplateType,
+    pub language: String,
+
+    pub to: String,
+    pub subject: String,
+    pub html: String,
+    pub text: String,
+}
@@ -1,8 +1,94 @@
-use rocket::serde::Serialize;
+use chrono::NaiveDateTime;
+use rocket::serde::{Deserialize, Serialize};
 use rocket_okapi::okapi::schemars::JsonSchema;
 
-#[derive(Serialize, JsonSchema)]
+use super::repository::entity::image::{Image, ImageRendition};
+
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub struct ImageAggregation {
     pub id: i32,
     pub uri: String,
+
+    pub mime_type: String,
+    pub size: i32,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+
+    pub created_at: NaiveDateTime,
+    pub created_by: i32,
+
+    pub renditions: Vec<ImageRenditionAggregation>,
+}
+
+impl ImageAggregation {
+    pub fn from_model(
+        image: Image,
+        uri: String,
+        renditions: Vec<ImageRenditionAggregation>,
+    ) -> Self {
+        Self {
+            id: image.id,
+            uri,
+
+            mime_type: image.mime_type,
+            size: image.size,
+            width: image.width,
+            height: image.height,
+
+            created_at: image.created_at,
+            created_by: image.created_by,
+
+            renditions,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct ImageRenditionAggregation {
+    pub name: String,
+    pub uri: String,
+
+    pub mime_type: String,
+    pub size: i32,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+}
+
+impl ImageRenditionAggregation {
+    pub fn from_model(image_rendition: ImageRendition, uri: String) -> Self {
+        Self {
+            name: image_rendition.name,
+            uri,
+
+            mime_type: image_rendition.mime_type,
+            size: image_rendition.size,
+            width: image_rendition.width,
+            height: image_rendition.height,
+        }
+    }
+}
+
+// id is the one sent by the client to match uploaded files
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct ImageUploadAggregation {
+    pub id: i32,
+    pub uri: String,
+    pub image: ImageAggregation,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct ImageUploadErrorAggregation {
+    pub id: i32,
+    pub error: String,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct ImagesUploadAggregation {
+    pub images: Vec<ImageUploadAggregation>,
+    pub errors: Vec<ImageUploadErrorAggregation>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct ImageCleanupAggregation {
+    pub deleted_count: usize,
 }
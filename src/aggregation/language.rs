@@ -1,12 +1,20 @@
 use rocket::serde::{Deserialize, Serialize};
 use rocket_okapi::okapi::schemars::JsonSchema;
 
-use super::repository::entity::language::Language;
+use super::repository::entity::language::{Language, TextDirection};
 
 #[derive(Clone, Serialize, Deserialize, JsonSchema)]
 pub struct LanguageAggregation {
     pub id: i32,
     pub code: String,
+
+    pub name: String,
+    pub native_name: String,
+    pub direction: TextDirection,
+    pub bcp47: String,
+
+    pub fallback_language_id: Option<i32>,
+    pub enabled: bool,
 }
 
 impl LanguageAggregation {
@@ -14,6 +22,14 @@ impl LanguageAggregation {
         LanguageAggregation {
             id: language.id,
             code: language.code,
+
+            name: language.name,
+            native_name: language.native_name,
+            direction: language.direction,
+            bcp47: language.bcp47,
+
+            fallback_language_id: language.fallback_language_id,
+            enabled: language.enabled,
         }
     }
 
@@ -5,11 +5,19 @@ use super::error;
 use super::mapper;
 use super::repository;
 
+pub mod api_token;
 pub mod article;
+pub mod article_draft;
 pub mod article_language;
+pub mod article_search;
 pub mod article_version;
+pub mod category;
+pub mod email_template;
 pub mod image;
 pub mod language;
+pub mod page;
 pub mod user_account;
 pub mod user_account_auth;
 pub mod version_content;
+pub mod version_diff;
+pub mod watch;
@@ -0,0 +1,14 @@
+use rocket::serde::{Deserialize, Serialize};
+use rocket_okapi::okapi::schemars::JsonSchema;
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct PageAggregation<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<String>,
+}
+
+impl<T> PageAggregation<T> {
+    pub fn from_items(items: Vec<T>, next_cursor: Option<String>) -> Self {
+        Self { items, next_cursor }
+    }
+}
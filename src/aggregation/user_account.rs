@@ -4,12 +4,13 @@ use rocket_okapi::okapi::schemars::JsonSchema;
 
 use super::repository::entity::auth::UserAccount;
 
-#[derive(Deserialize, Serialize, JsonSchema)]
+#[derive(Deserialize, Serialize, JsonSchema, Clone)]
 pub struct UserAccountPartialAggregation {
     pub email: String,
     pub name: String,
 
     pub role_id: i32,
+    pub language_id: Option<i32>,
 }
 
 impl UserAccountPartialAggregation {
@@ -18,6 +19,7 @@ impl UserAccountPartialAggregation {
             email: user_account.email,
             name: user_account.name,
             role_id: user_account.role_id,
+            language_id: user_account.language_id,
         }
     }
 
@@ -26,6 +28,7 @@ impl UserAccountPartialAggregation {
             email: user_account_aggregation.email,
             name: user_account_aggregation.name,
             role_id: user_account_aggregation.role_id,
+            language_id: user_account_aggregation.language_id,
         }
     }
 }
@@ -45,6 +48,8 @@ pub struct UserAccountAggregation {
     pub created_at: NaiveDateTime,
 
     pub updated_by: Option<i32>,
+
+    pub language_id: Option<i32>,
 }
 
 impl UserAccountAggregation {
@@ -61,6 +66,8 @@ impl UserAccountAggregation {
             created_at: user_account.created_at,
 
             updated_by: user_account.updated_by,
+
+            language_id: user_account.language_id,
         }
     }
 }
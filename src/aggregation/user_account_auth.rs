@@ -1,14 +1,14 @@
-use rocket::serde::Serialize;
+use rocket::serde::{Deserialize, Serialize};
 use rocket_okapi::okapi::schemars::JsonSchema;
 
 use super::authorization::ArticlePermission;
-use super::dtm_common::TokenDto;
+use super::dtm_common::{ChallengePurpose, TokenDto};
 
 use super::repository::entity::auth::UserAccount;
 
 use super::user_account::{UserAccountAggregation, UserAccountPartialAggregation};
 
-#[derive(Serialize, JsonSchema)]
+#[derive(Serialize, Deserialize, JsonSchema)]
 pub struct UserAccountAuthAggregation {
     pub user: UserAccountPartialAggregation,
     pub token: TokenDto,
@@ -23,6 +23,75 @@ impl UserAccountAuthAggregation {
     }
 }
 
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct LoginChallengeAggregation {
+    pub challenge_token: String,
+    pub purpose: ChallengePurpose,
+}
+
+// either session tokens, or a challenge to pass for accounts with 2fa
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct UserAccountLoginAggregation {
+    pub user: UserAccountPartialAggregation,
+    pub token: Option<TokenDto>,
+    pub challenge: Option<LoginChallengeAggregation>,
+}
+
+impl UserAccountLoginAggregation {
+    pub fn from_token(user_account: UserAccount, token: TokenDto) -> Self {
+        Self {
+            token: Some(token),
+            challenge: None,
+            user: UserAccountPartialAggregation::from_model(user_account),
+        }
+    }
+
+    pub fn from_challenge(user_account: UserAccount, challenge: LoginChallengeAggregation) -> Self {
+        Self {
+            token: None,
+            challenge: Some(challenge),
+            user: UserAccountPartialAggregation::from_model(user_account),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct UserAccountEnrollmentAggregation {
+    pub user: UserAccountPartialAggregation,
+    pub token: TokenDto,
+    pub recovery_codes: Vec<String>,
+}
+
+impl UserAccountEnrollmentAggregation {
+    pub fn from_model(
+        user_account: UserAccount,
+        token: TokenDto,
+        recovery_codes: Vec<String>,
+    ) -> Self {
+        Self {
+            token,
+            recovery_codes,
+            user: UserAccountPartialAggregation::from_model(user_account),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct TotpEnrollmentAggregation {
+    pub secret: String,
+    pub provisioning_uri: String,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct TotpRecoveryCodesAggregation {
+    pub recovery_codes: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct OidcAuthorizationAggregation {
+    pub authorization_url: String,
+}
+
 #[derive(Serialize, JsonSchema)]
 pub struct UserAccountPermissionsAggregation {
     pub user: UserAccountPartialAggregation,
@@ -7,10 +7,12 @@ use super::error::FmtError;
 
 use super::repository::entity::version_content::{ContentType, VersionContent};
 
-#[derive(Serialize, Deserialize, JsonSchema)]
+#[derive(Serialize, Deserialize, JsonSchema, Clone)]
 pub struct VersionContentAggregation {
     pub id: i32,
     pub content: String,
+    // full for the actual version and keyframes, diff for the others
+    pub content_type: ContentType,
 }
 
 impl VersionContentAggregation {
@@ -22,6 +24,7 @@ impl VersionContentAggregation {
             id: version_content.id,
 
             content: Self::get_content(&version_content, contents_map),
+            content_type: version_content.content_type,
         };
     }
 
@@ -46,3 +49,8 @@ impl VersionContentAggregation {
         }
     }
 }
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct VersionKeyframesAggregation {
+    pub keyframes_count: i32,
+}
@@ -0,0 +1,19 @@
+use rocket::serde::{Deserialize, Serialize};
+use rocket_okapi::okapi::schemars::JsonSchema;
+
+use super::dtm_common::DiffOperation;
+
+#[derive(Deserialize, Serialize, JsonSchema)]
+pub struct VersionDiffHunkAggregation {
+    pub operation: DiffOperation,
+    pub content: String,
+}
+
+impl VersionDiffHunkAggregation {
+    pub fn from_hunks(hunks: Vec<(DiffOperation, String)>) -> Vec<Self> {
+        hunks
+            .into_iter()
+            .map(|(operation, content)| Self { operation, content })
+            .collect()
+    }
+}
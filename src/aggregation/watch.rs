@@ -0,0 +1,36 @@
+use chrono::NaiveDateTime;
+use rocket::serde::{Deserialize, Serialize};
+use rocket_okapi::okapi::schemars::JsonSchema;
+
+use super::repository::entity::watch::{Watch, WatchDelivery};
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct WatchAggregation {
+    pub id: i32,
+    pub article_id: i32,
+    // None watches all languages of the article
+    pub language: Option<String>,
+
+    pub delivery: WatchDelivery,
+
+    pub created_at: NaiveDateTime,
+}
+
+impl WatchAggregation {
+    pub fn from_model(watch: Watch, language: Option<String>) -> Self {
+        Self {
+            id: watch.id,
+            article_id: watch.article_id,
+            language,
+
+            delivery: watch.delivery,
+
+            created_at: watch.created_at,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct WatchDigestAggregation {
+    pub sent_count: usize,
+}
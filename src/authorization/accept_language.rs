@@ -0,0 +1,65 @@
+use rocket::request::{FromRequest, Outcome, Request};
+use rocket_okapi::{
+    gen::OpenApiGenerator,
+    request::{OpenApiFromRequest, RequestHeaderInput},
+};
+
+use super::error::ErrorWrapper;
+
+// language tags of the `Accept-Language` header, lowercased and ordered by quality
+#[derive(Debug)]
+pub struct AcceptLanguage {
+    pub tags: Vec<String>,
+}
+
+impl AcceptLanguage {
+    fn parse(header: &str) -> Vec<String> {
+        let mut weighted_tags = header
+            .split(',')
+            .filter_map(|item| {
+                let mut parts = item.split(';').map(|part| part.trim());
+
+                let tag = parts.next()?.to_lowercase();
+                let quality = parts
+                    .find_map(|part| part.strip_prefix("q="))
+                    .map(|quality| quality.parse::<f32>().unwrap_or(0.0))
+                    .unwrap_or(1.0);
+
+                match tag.is_empty() || tag == "*" || quality <= 0.0 {
+                    true => None,
+                    false => Some((tag, quality)),
+                }
+            })
+            .collect::<Vec<(String, f32)>>();
+
+        // stable sort keeps the header order for equal qualities
+        weighted_tags.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+
+        weighted_tags.into_iter().map(|(tag, _)| tag).collect()
+    }
+}
+
+impl<'a, 'r> OpenApiFromRequest<'a> for AcceptLanguage {
+    fn from_request_input(
+        _gen: &mut OpenApiGenerator,
+        _name: String,
+        _required: bool,
+    ) -> rocket_okapi::Result<RequestHeaderInput> {
+        Ok(RequestHeaderInput::None)
+    }
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for AcceptLanguage {
+    type Error = ErrorWrapper;
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, ErrorWrapper> {
+        return Outcome::Success(Self {
+            tags: req
+                .headers()
+                .get_one("Accept-Language")
+                .map(Self::parse)
+                .unwrap_or(vec![]),
+        });
+    }
+}
@@ -6,10 +6,11 @@ use rocket_okapi::{
 };
 
 use super::error::{ErrorWrapper, FmtError};
+use super::hasher::Hasher;
 use super::jwt_handler::JwtHandler;
 
 use super::aggregation::user_account::UserAccountAggregation;
-use super::dtm_common::{JwtDto, UserRoleId};
+use super::dtm_common::{ApiTokenScope, JwtDto, UserRoleId, API_TOKEN_PREFIX};
 
 use super::repository::{entity::auth::AuthRepository, PgConnection};
 
@@ -19,17 +20,48 @@ pub struct Authorization {
 }
 
 impl Authorization {
+    // accepts both session jwts and personal api tokens,
+    // api_token_scope - scope a token needs for the route, none - session only route
     pub async fn verify(
         self,
         allowed_roles: Vec<UserRoleId>,
+        api_token_scope: Option<ApiTokenScope>,
         connection: &PgConnection,
     ) -> Result<UserAccountAggregation, status::Custom<String>> {
+        if let Some(api_token) = self.get_api_token() {
+            let user_id = Self::check_api_token(connection, api_token, api_token_scope).await?;
+
+            return Self::check_user(connection, user_id, allowed_roles).await;
+        }
+
+        let (user, _) = self.verify_with_session(allowed_roles, connection).await?;
+
+        Ok(user)
+    }
+
+    pub async fn verify_with_session(
+        self,
+        allowed_roles: Vec<UserRoleId>,
+        connection: &PgConnection,
+    ) -> Result<(UserAccountAggregation, i32), status::Custom<String>> {
         let claims = match Self::get_claims(self) {
             Err(e) => return Err(e.custom()),
             Ok(claims) => claims,
         };
 
-        let user = Self::get_user(connection, claims.user_id).await?;
+        Self::check_session(connection, &claims).await?;
+
+        let user = Self::check_user(connection, claims.user_id, allowed_roles).await?;
+
+        Ok((user, claims.session_id))
+    }
+
+    async fn check_user(
+        connection: &PgConnection,
+        user_id: i32,
+        allowed_roles: Vec<UserRoleId>,
+    ) -> Result<UserAccountAggregation, status::Custom<String>> {
+        let user = Self::get_user(connection, user_id).await?;
 
         if !user.active || user.blocked {
             return Err(FmtError::PermissionDenied("not enough rights")
@@ -54,6 +86,55 @@ impl Authorization {
         }
     }
 
+    async fn check_api_token(
+        connection: &PgConnection,
+        api_token: String,
+        required_scope: Option<ApiTokenScope>,
+    ) -> Result<i32, status::Custom<String>> {
+        let user_api_token =
+            match AuthRepository::get_active_api_token(connection, Hasher::hash_token(&api_token))
+                .await
+            {
+                Some(user_api_token) => user_api_token,
+                None => {
+                    return Err(FmtError::Unauthorized("invalid token")
+                        .error_wrapper()
+                        .custom())
+                }
+            };
+
+        let is_allowed = match required_scope {
+            Some(required_scope) => {
+                ApiTokenScope::is_allowed(&user_api_token.scopes, required_scope)
+            }
+            None => false,
+        };
+
+        if !is_allowed {
+            return Err(FmtError::PermissionDenied("token scope")
+                .error_wrapper()
+                .custom());
+        }
+
+        AuthRepository::touch_api_token(connection, user_api_token.id).await;
+
+        Ok(user_api_token.user_id)
+    }
+
+    async fn check_session(
+        connection: &PgConnection,
+        claims: &JwtDto,
+    ) -> Result<(), status::Custom<String>> {
+        match AuthRepository::get_one_session(connection, claims.session_id).await {
+            Some(session) if session.user_id == claims.user_id && session.revoked_at.is_none() => {
+                Ok(())
+            }
+            _ => Err(FmtError::Unauthorized("revoked token")
+                .error_wrapper()
+                .custom()),
+        }
+    }
+
     async fn get_user(
         connection: &PgConnection,
         user_id: i32,
@@ -66,6 +147,15 @@ impl Authorization {
         }
     }
 
+    fn get_api_token(&self) -> Option<String> {
+        let token = self.token.as_ref()?.trim_start_matches("Bearer").trim();
+
+        match token.starts_with(API_TOKEN_PREFIX) {
+            true => Some(token.to_owned()),
+            false => None,
+        }
+    }
+
     fn get_claims(self) -> Result<JwtDto, ErrorWrapper> {
         match self.token {
             None => FmtError::Unauthorized("empty authorization").error(),
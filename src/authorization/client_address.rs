@@ -0,0 +1,84 @@
+use rocket::request::{FromRequest, Outcome, Request};
+use rocket_okapi::{
+    gen::OpenApiGenerator,
+    request::{OpenApiFromRequest, RequestHeaderInput},
+};
+use std::env;
+use std::net::IpAddr;
+
+use super::error::{ErrorWrapper, FmtError};
+
+const TRUSTED_PROXIES_ENV: &str = "TRUSTED_PROXIES";
+
+// proxies allowed to pass the client ip in the configured `ip_header` (X-Real-IP by default)
+#[derive(Default)]
+pub struct TrustedProxies(Vec<IpAddr>);
+
+impl TrustedProxies {
+    pub fn new(proxies: Vec<IpAddr>) -> Self {
+        Self(proxies)
+    }
+
+    pub fn from_env() -> Self {
+        let proxies = match env::var(TRUSTED_PROXIES_ENV) {
+            Err(_) => return Self::default(),
+            Ok(proxies) => proxies,
+        };
+
+        Self::new(
+            proxies
+                .split(',')
+                .map(|proxy| proxy.trim())
+                .filter(|proxy| !proxy.is_empty())
+                .map(|proxy| {
+                    proxy
+                        .parse::<IpAddr>()
+                        .expect(&FmtError::FailedToProcess(TRUSTED_PROXIES_ENV).fmt())
+                })
+                .collect(),
+        )
+    }
+
+    pub fn is_trusted(&self, ip: &IpAddr) -> bool {
+        self.0.contains(ip)
+    }
+}
+
+// client ip, taken from the connection, the forwarded header is honoured for trusted proxies only
+#[derive(Debug)]
+pub struct ClientAddress {
+    pub ip: Option<String>,
+}
+
+impl<'a, 'r> OpenApiFromRequest<'a> for ClientAddress {
+    fn from_request_input(
+        _gen: &mut OpenApiGenerator,
+        _name: String,
+        _required: bool,
+    ) -> rocket_okapi::Result<RequestHeaderInput> {
+        Ok(RequestHeaderInput::None)
+    }
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for ClientAddress {
+    type Error = ErrorWrapper;
+
+    async fn from_request(req: &'r Request<'_>) -> Outcome<Self, ErrorWrapper> {
+        let remote_ip = req.remote().map(|remote| remote.ip());
+
+        let is_proxied = match (remote_ip, req.rocket().state::<TrustedProxies>()) {
+            (Some(remote_ip), Some(trusted_proxies)) => trusted_proxies.is_trusted(&remote_ip),
+            _ => false,
+        };
+
+        let ip = match is_proxied {
+            true => req.real_ip().or(remote_ip),
+            false => remote_ip,
+        };
+
+        return Outcome::Success(Self {
+            ip: ip.map(|ip| ip.to_string()),
+        });
+    }
+}
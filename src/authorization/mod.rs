@@ -1,11 +1,16 @@
 use super::aggregation;
 use super::dtm_common;
 use super::error;
+use super::hasher;
 use super::jwt_handler;
 use super::repository;
 
+mod accept_language;
 mod authorization;
+mod client_address;
 mod permissions;
 
+pub use accept_language::AcceptLanguage;
 pub use authorization::Authorization;
+pub use client_address::{ClientAddress, TrustedProxies};
 pub use permissions::{ArticlePermission, PermissionsHandler};
@@ -2,6 +2,7 @@ use rocket::serde::Serialize;
 use rocket_okapi::okapi::schemars::JsonSchema;
 
 use super::repository::entity::article::{Article, ArticleType};
+use super::repository::entity::image::Image;
 
 use super::aggregation::user_account::UserAccountAggregation;
 use super::dtm_common::UserRoleId;
@@ -52,4 +53,12 @@ impl PermissionsHandler {
             .find(|permission| matches!(*permission, ArticlePermission::Edit))
             .is_some()
     }
+
+    pub fn can_delete_image(image: &Image, current_user: &UserAccountAggregation) -> bool {
+        let user_role = UserRoleId::from_i32(current_user.role_id).unwrap_or(UserRoleId::Common);
+
+        user_role == UserRoleId::Admin
+            || user_role == UserRoleId::Moderator
+            || current_user.id == image.created_by
+    }
 }
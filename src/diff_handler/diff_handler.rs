@@ -1,7 +1,10 @@
 use bsdiff::{diff, patch};
 use flate2::{read::ZlibDecoder, write::ZlibEncoder, Compression};
+use similar::{ChangeTag, DiffTag, TextDiff};
 use std::io::{Cursor, Read, Write};
+use std::ops::Range;
 
+use super::dtm_common::{DiffGranularity, DiffOperation};
 use super::error::FmtError;
 
 pub struct DiffHandler;
@@ -34,11 +37,107 @@ impl DiffHandler {
         patched_string
     }
 
+    // adjacent changes of the same kind are merged into a single hunk
+    pub fn get_text_diff(
+        old_version: &String,
+        new_version: &String,
+        granularity: &DiffGranularity,
+    ) -> Vec<(DiffOperation, String)> {
+        let text_diff = match granularity {
+            DiffGranularity::Line => {
+                TextDiff::from_lines(old_version.as_str(), new_version.as_str())
+            }
+            DiffGranularity::Word => {
+                TextDiff::from_words(old_version.as_str(), new_version.as_str())
+            }
+        };
+
+        text_diff.iter_all_changes().fold(
+            vec![],
+            |mut hunks: Vec<(DiffOperation, String)>, change| {
+                let operation = match change.tag() {
+                    ChangeTag::Equal => DiffOperation::Equal,
+                    ChangeTag::Insert => DiffOperation::Insert,
+                    ChangeTag::Delete => DiffOperation::Delete,
+                };
+
+                match hunks.last_mut() {
+                    Some((last_operation, content)) if *last_operation == operation => {
+                        content.push_str(change.value());
+                    }
+                    _ => hunks.push((operation, String::from(change.value()))),
+                };
+
+                hunks
+            },
+        )
+    }
+
+    // line based three-way merge, None if both sides changed the same or adjacent base lines
+    pub fn merge_three_way(base: &String, ours: &String, theirs: &String) -> Option<String> {
+        let our_hunks = Self::get_line_hunks(base, ours);
+        let their_hunks = Self::get_line_hunks(base, theirs);
+
+        for (our_range, our_lines) in our_hunks.iter() {
+            for (their_range, their_lines) in their_hunks.iter() {
+                let is_same = our_range == their_range && our_lines == their_lines;
+                let is_overlapping =
+                    our_range.start <= their_range.end && their_range.start <= our_range.end;
+
+                if is_overlapping && !is_same {
+                    return None;
+                }
+            }
+        }
+
+        let mut hunks = their_hunks
+            .into_iter()
+            .filter(|hunk| !our_hunks.contains(hunk))
+            .collect::<Vec<(Range<usize>, String)>>();
+
+        hunks.extend(our_hunks);
+        hunks.sort_by_key(|(range, _)| (range.start, range.end));
+
+        let base_lines = TextDiff::from_lines(base.as_str(), base.as_str())
+            .old_slices()
+            .to_vec();
+
+        let mut merged = String::new();
+        let mut position = 0;
+
+        for (range, lines) in hunks {
+            merged.push_str(&base_lines[position..range.start].concat());
+            merged.push_str(&lines);
+            position = range.end;
+        }
+
+        merged.push_str(&base_lines[position..].concat());
+
+        Some(merged)
+    }
+
     pub fn get_string_from_bytes(input: &Vec<u8>) -> String {
         String::from_utf8(input.to_vec())
             .expect(&FmtError::FailedToProcess("parse from utf8").fmt())
     }
 
+    // changed base lines ranges with their replacement
+    fn get_line_hunks(base: &String, changed: &String) -> Vec<(Range<usize>, String)> {
+        let text_diff = TextDiff::from_lines(base.as_str(), changed.as_str());
+        let changed_lines = text_diff.new_slices();
+
+        text_diff
+            .ops()
+            .iter()
+            .filter_map(|op| match op.as_tag_tuple() {
+                (DiffTag::Equal, _, _) => None,
+                (_, base_range, changed_range) => {
+                    Some((base_range, changed_lines[changed_range].concat()))
+                }
+            })
+            .collect()
+    }
+
     fn compress_bytes(input: Vec<u8>) -> Result<Vec<u8>, std::io::Error> {
         let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
 
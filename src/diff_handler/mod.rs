@@ -1,3 +1,4 @@
+use super::dtm_common;
 use super::error;
 
 mod diff_handler;
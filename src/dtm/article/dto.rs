@@ -20,3 +20,7 @@ pub struct ArticleCreateRelationsDto {
     pub article_type: ArticleType,
     pub user_id: i32,
 }
+
+pub struct ArticlesSearchDto {
+    pub category_id: Option<i32>,
+}
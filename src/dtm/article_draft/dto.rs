@@ -0,0 +1,15 @@
+pub struct ArticleDraftSaveDto {
+    pub user_id: i32,
+    pub name: Option<String>,
+    pub content: String,
+    pub rebase: bool,
+}
+
+pub struct ArticleDraftUpsertDto {
+    pub article_language_id: i32,
+    pub user_id: i32,
+    pub name: Option<String>,
+    pub content: String,
+    pub base_version: i32,
+    pub is_rebase: bool,
+}
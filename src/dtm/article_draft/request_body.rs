@@ -0,0 +1,27 @@
+use rocket::serde::{Deserialize, Serialize};
+use rocket_okapi::okapi::schemars::JsonSchema;
+
+use super::trait_common::DtoConvert;
+
+use super::dto::ArticleDraftSaveDto;
+
+#[derive(Deserialize, Serialize, JsonSchema)]
+pub struct ArticleDraftSaveBody {
+    pub name: Option<String>,
+    pub content: String,
+    // moves the draft base to the actual version, once its changes are taken into account
+    pub rebase: Option<bool>,
+}
+
+impl DtoConvert<ArticleDraftSaveDto> for ArticleDraftSaveBody {
+    type TParams = i32;
+
+    fn into_dto(self, user_id: Self::TParams) -> ArticleDraftSaveDto {
+        ArticleDraftSaveDto {
+            user_id,
+            name: self.name,
+            content: self.content,
+            rebase: self.rebase.unwrap_or(false),
+        }
+    }
+}
@@ -19,3 +19,9 @@ pub struct ArticleLanguagePatchDto {
     pub name: Option<String>,
     pub user_id: i32,
 }
+
+pub struct ArticleLanguageContentSearchDto {
+    pub query: String,
+    pub language_code: Option<String>,
+    pub limit: i64,
+}
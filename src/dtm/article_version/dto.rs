@@ -1,9 +1,20 @@
 use super::repository::entity::article_language::ArticleLanguage;
 
+#[derive(Clone)]
 pub struct ArticleVersionCreateRelationsDto {
     pub content: String,
     pub user_id: i32,
     pub name: Option<String>,
+    pub source: Option<ArticleVersionSourceDto>,
+    pub base_version: Option<i32>,
+    pub merge: bool,
+}
+
+// version of another article language a translation is based on
+#[derive(Clone)]
+pub struct ArticleVersionSourceDto {
+    pub language: String,
+    pub version: i32,
 }
 
 pub struct ArticleVersionCreateDto {
@@ -12,6 +23,7 @@ pub struct ArticleVersionCreateDto {
     pub article_language_id: i32,
     pub user_id: i32,
     pub name: String,
+    pub source_version_id: Option<i32>,
 }
 
 pub struct ArticleVersionPatchDto {
@@ -22,6 +34,7 @@ pub struct ArticleVersionPatchDto {
 pub struct ArticleVersionsJoinSearchDto {
     pub article_languages_ids: Vec<i32>,
     pub version_ge: i32,
+    pub version_le: Option<i32>,
 }
 
 pub struct LanguageSearchDto {
@@ -3,12 +3,25 @@ use rocket_okapi::okapi::schemars::JsonSchema;
 
 use super::trait_common::DtoConvert;
 
-use super::dto::{ArticleVersionCreateRelationsDto, ArticleVersionPatchDto};
+use super::dto::{
+    ArticleVersionCreateRelationsDto, ArticleVersionPatchDto, ArticleVersionSourceDto,
+};
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct ArticleVersionSourceBody {
+    pub language: String,
+    pub version: i32,
+}
 
 #[derive(Serialize, Deserialize, JsonSchema)]
 pub struct ArticleVersionCreateRelationsBody {
     pub content: String,
     pub name: Option<String>,
+    pub source: Option<ArticleVersionSourceBody>,
+    // actual version the edit was started from, creation fails with 409 if it is not actual anymore
+    pub base_version: Option<i32>,
+    // tries a three-way merge with the intervening versions instead of failing
+    pub merge: Option<bool>,
 }
 
 impl DtoConvert<ArticleVersionCreateRelationsDto> for ArticleVersionCreateRelationsBody {
@@ -19,6 +32,12 @@ impl DtoConvert<ArticleVersionCreateRelationsDto> for ArticleVersionCreateRelati
             user_id,
             content: self.content,
             name: self.name,
+            source: self.source.map(|source| ArticleVersionSourceDto {
+                language: source.language,
+                version: source.version,
+            }),
+            base_version: self.base_version,
+            merge: self.merge.unwrap_or(false),
         }
     }
 }
@@ -1,9 +1,11 @@
+use super::dtm_common::ApiTokenScope;
 use super::repository::entity::auth::OTPType;
 
 pub struct UserAccountCreateDto {
     pub email: String,
     pub name: String,
     pub role_id: i32,
+    pub language_id: Option<i32>,
 }
 
 pub struct UserPasswordCreateDto {
@@ -13,8 +15,30 @@ pub struct UserPasswordCreateDto {
 
 pub struct UserOtpCreateDto {
     pub user_id: i32,
-    pub otp: String,
+    pub otp_hash: String,
     pub otp_type: OTPType,
+    pub live_sec: i32,
+}
+
+pub struct UserSessionCreateDto {
+    pub user_id: i32,
+    pub refresh_token_hash: String,
+    pub live_sec: i32,
+}
+
+pub struct UserIdentityCreateDto {
+    pub user_id: i32,
+    pub provider: String,
+    pub subject: String,
+    pub email: Option<String>,
+}
+
+pub struct UserApiTokenCreateDto {
+    pub user_id: i32,
+    pub name: String,
+    pub token_hash: String,
+    pub scopes: Vec<String>,
+    pub live_days: Option<i32>,
 }
 
 pub struct UserPatchDto {
@@ -29,20 +53,28 @@ pub struct UserCreateRelationsDto {
     pub name: String,
     pub password_hash: String,
     pub role_id: i32,
-    pub otp: Option<String>,
+    pub otp_hash: Option<String>,
+    pub language_id: Option<i32>,
 }
 
 #[derive(Debug)]
 pub struct UserResetOTPsDto {
     pub user_id: i32,
     pub existing_otp_ids: Vec<i32>,
-    pub otp: String,
+    pub otp_hash: String,
+    pub otp_type: OTPType,
 }
 
 pub struct UserSignupDto {
     pub email: String,
     pub name: String,
     pub password: String,
+    pub language: Option<String>,
+}
+
+pub struct UserLanguageDto {
+    pub user_id: i32,
+    pub language: String,
 }
 
 #[derive(Debug)]
@@ -50,6 +82,10 @@ pub struct UserResetDto {
     pub email: String,
 }
 
+pub struct UserResendDto {
+    pub email: String,
+}
+
 pub struct UserConfirmDto {
     pub email: String,
     pub otp: String,
@@ -65,3 +101,34 @@ pub struct UserLoginDto {
     pub email: String,
     pub password: String,
 }
+
+pub struct UserRefreshDto {
+    pub refresh_token: String,
+}
+
+pub struct UserTotpCodeDto {
+    pub user_id: i32,
+    pub code: String,
+}
+
+pub struct UserChallengeDto {
+    pub challenge_token: String,
+    pub code: String,
+}
+
+pub struct UserChallengeEnrollDto {
+    pub challenge_token: String,
+}
+
+pub struct UserOidcCallbackDto {
+    pub provider: String,
+    pub code: String,
+    pub state: String,
+}
+
+pub struct ApiTokenCreateDto {
+    pub user_id: i32,
+    pub name: String,
+    pub scopes: Vec<ApiTokenScope>,
+    pub live_days: Option<i32>,
+}
@@ -1,3 +1,4 @@
+use super::dtm_common;
 use super::repository;
 use super::trait_common;
 
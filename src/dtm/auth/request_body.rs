@@ -1,11 +1,13 @@
 use rocket::serde::{Deserialize, Serialize};
 use rocket_okapi::okapi::schemars::JsonSchema;
 
+use super::dtm_common::ApiTokenScope;
 use super::trait_common::DtoConvert;
 
 use super::dto::{
-    UserConfirmDto, UserConfirmPasswordResetDto, UserLoginDto, UserPatchDto, UserResetDto,
-    UserSignupDto,
+    ApiTokenCreateDto, UserChallengeDto, UserChallengeEnrollDto, UserConfirmDto,
+    UserConfirmPasswordResetDto, UserLanguageDto, UserLoginDto, UserOidcCallbackDto, UserPatchDto,
+    UserRefreshDto, UserResendDto, UserResetDto, UserSignupDto, UserTotpCodeDto,
 };
 
 #[derive(Deserialize, JsonSchema)]
@@ -34,10 +36,11 @@ pub struct UserSignupBody {
 }
 
 impl DtoConvert<UserSignupDto> for UserSignupBody {
-    type TParams = ();
+    type TParams = Option<String>;
 
-    fn into_dto(self, _params: Self::TParams) -> UserSignupDto {
+    fn into_dto(self, language: Self::TParams) -> UserSignupDto {
         UserSignupDto {
+            language,
             email: self.email,
             name: self.name,
             password: self.password,
@@ -45,6 +48,22 @@ impl DtoConvert<UserSignupDto> for UserSignupBody {
     }
 }
 
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct UserLanguageBody {
+    pub language: String,
+}
+
+impl DtoConvert<UserLanguageDto> for UserLanguageBody {
+    type TParams = i32;
+
+    fn into_dto(self, user_id: Self::TParams) -> UserLanguageDto {
+        UserLanguageDto {
+            user_id,
+            language: self.language,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, JsonSchema)]
 pub struct UserResetBody {
     pub email: String,
@@ -58,6 +77,19 @@ impl DtoConvert<UserResetDto> for UserResetBody {
     }
 }
 
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct UserResendBody {
+    pub email: String,
+}
+
+impl DtoConvert<UserResendDto> for UserResendBody {
+    type TParams = ();
+
+    fn into_dto(self, _params: Self::TParams) -> UserResendDto {
+        UserResendDto { email: self.email }
+    }
+}
+
 #[derive(Deserialize, JsonSchema, Debug)]
 pub struct UserLoginBody {
     pub email: String,
@@ -110,3 +142,106 @@ impl DtoConvert<UserConfirmPasswordResetDto> for UserConfirmPasswordResetBody {
         }
     }
 }
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct UserRefreshBody {
+    pub refresh_token: String,
+}
+
+impl DtoConvert<UserRefreshDto> for UserRefreshBody {
+    type TParams = ();
+
+    fn into_dto(self, _params: Self::TParams) -> UserRefreshDto {
+        UserRefreshDto {
+            refresh_token: self.refresh_token,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct UserTotpCodeBody {
+    pub code: String,
+}
+
+impl DtoConvert<UserTotpCodeDto> for UserTotpCodeBody {
+    type TParams = i32;
+
+    fn into_dto(self, user_id: Self::TParams) -> UserTotpCodeDto {
+        UserTotpCodeDto {
+            user_id,
+            code: self.code,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct UserChallengeBody {
+    pub challenge_token: String,
+    // current TOTP code or one of the recovery codes
+    pub code: String,
+}
+
+impl DtoConvert<UserChallengeDto> for UserChallengeBody {
+    type TParams = ();
+
+    fn into_dto(self, _params: Self::TParams) -> UserChallengeDto {
+        UserChallengeDto {
+            challenge_token: self.challenge_token,
+            code: self.code,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct UserChallengeEnrollBody {
+    pub challenge_token: String,
+}
+
+impl DtoConvert<UserChallengeEnrollDto> for UserChallengeEnrollBody {
+    type TParams = ();
+
+    fn into_dto(self, _params: Self::TParams) -> UserChallengeEnrollDto {
+        UserChallengeEnrollDto {
+            challenge_token: self.challenge_token,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct UserOidcCallbackBody {
+    pub code: String,
+    pub state: String,
+}
+
+impl DtoConvert<UserOidcCallbackDto> for UserOidcCallbackBody {
+    type TParams = String;
+
+    fn into_dto(self, provider: Self::TParams) -> UserOidcCallbackDto {
+        UserOidcCallbackDto {
+            provider,
+            code: self.code,
+            state: self.state,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct ApiTokenCreateBody {
+    pub name: String,
+    pub scopes: Vec<ApiTokenScope>,
+    // token never expires if empty
+    pub live_days: Option<i32>,
+}
+
+impl DtoConvert<ApiTokenCreateDto> for ApiTokenCreateBody {
+    type TParams = i32;
+
+    fn into_dto(self, user_id: Self::TParams) -> ApiTokenCreateDto {
+        ApiTokenCreateDto {
+            user_id,
+            name: self.name,
+            scopes: self.scopes,
+            live_days: self.live_days,
+        }
+    }
+}
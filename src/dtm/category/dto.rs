@@ -0,0 +1,16 @@
+pub struct CategoryCreateDto {
+    pub name: String,
+    pub user_id: i32,
+}
+
+pub struct CategoryPatchDto {
+    pub id: i32,
+    pub name: Option<String>,
+    pub user_id: i32,
+}
+
+pub struct ArticleCategoryCreateDto {
+    pub article_id: i32,
+    pub category_id: i32,
+    pub user_id: i32,
+}
@@ -0,0 +1,4 @@
+use super::trait_common;
+
+pub mod dto;
+pub mod request_body;
@@ -0,0 +1,39 @@
+use rocket::serde::{Deserialize, Serialize};
+use rocket_okapi::okapi::schemars::JsonSchema;
+
+use super::trait_common::DtoConvert;
+
+use super::dto::{CategoryCreateDto, CategoryPatchDto};
+
+#[derive(Deserialize, Serialize, JsonSchema)]
+pub struct CategoryCreateBody {
+    pub name: String,
+}
+
+impl DtoConvert<CategoryCreateDto> for CategoryCreateBody {
+    type TParams = i32;
+
+    fn into_dto(self, user_id: Self::TParams) -> CategoryCreateDto {
+        CategoryCreateDto {
+            user_id,
+            name: self.name,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, JsonSchema)]
+pub struct CategoryPatchBody {
+    pub name: Option<String>,
+}
+
+impl DtoConvert<CategoryPatchDto> for CategoryPatchBody {
+    type TParams = (i32, i32);
+
+    fn into_dto(self, (id, user_id): Self::TParams) -> CategoryPatchDto {
+        CategoryPatchDto {
+            id,
+            user_id,
+            name: self.name,
+        }
+    }
+}
@@ -0,0 +1,8 @@
+pub struct EmailTemplatePatchDto {
+    pub template_type: String,
+    pub language: String,
+    pub subject: Option<String>,
+    pub html: Option<String>,
+    pub text: Option<String>,
+    pub user_id: i32,
+}
@@ -0,0 +1,28 @@
+use rocket::serde::{Deserialize, Serialize};
+use rocket_okapi::okapi::schemars::JsonSchema;
+
+use super::trait_common::DtoConvert;
+
+use super::dto::EmailTemplatePatchDto;
+
+#[derive(Deserialize, Serialize, JsonSchema)]
+pub struct EmailTemplatePatchBody {
+    pub subject: Option<String>,
+    pub html: Option<String>,
+    pub text: Option<String>,
+}
+
+impl DtoConvert<EmailTemplatePatchDto> for EmailTemplatePatchBody {
+    type TParams = (String, String, i32);
+
+    fn into_dto(self, (template_type, language, user_id): Self::TParams) -> EmailTemplatePatchDto {
+        EmailTemplatePatchDto {
+            template_type,
+            language,
+            user_id,
+            subject: self.subject,
+            html: self.html,
+            text: self.text,
+        }
+    }
+}
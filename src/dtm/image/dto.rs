@@ -1,5 +1,29 @@
+use rocket::fs::TempFile;
+
 pub struct ImageCreateDto {
     pub id: i32,
     pub base64: String,
-    pub format: String,
+}
+
+pub struct ImageFileDto<'r> {
+    pub id: i32,
+    pub file: TempFile<'r>,
+}
+
+pub struct ImageMetadataCreateDto {
+    pub storage_key: String,
+    pub mime_type: String,
+    pub size: i32,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+    pub user_id: i32,
+}
+
+pub struct ImageRenditionCreateDto {
+    pub name: String,
+    pub storage_key: String,
+    pub mime_type: String,
+    pub size: i32,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
 }
@@ -1,15 +1,39 @@
-use rocket::serde::Deserialize;
-use rocket_okapi::okapi::schemars::JsonSchema;
+use rocket::data::{self, Data, FromData, Limits};
+use rocket::form::{Errors, Form, FromForm};
+use rocket::fs::TempFile;
+use rocket::http::Status;
+use rocket::request::local_cache;
+use rocket::serde::{
+    json::{serde_json, Error as JsonError, Json},
+    Deserialize, Serialize,
+};
+use rocket::Request;
+use rocket_okapi::{
+    gen::OpenApiGenerator,
+    okapi::{
+        openapi3::{MediaType, RequestBody},
+        schemars::{
+            schema::{ArrayValidation, InstanceType, Schema, SchemaObject},
+            JsonSchema,
+        },
+        Map,
+    },
+    request::OpenApiFromData,
+};
+use std::io;
 
 use super::trait_common::DtoConvert;
 
-use super::dto::ImageCreateDto;
+use super::dto::{ImageCreateDto, ImageFileDto};
 
-#[derive(Deserialize, JsonSchema, Clone)]
+const IMAGES_JSON_LIMIT: &str = "images-json";
+
+#[derive(Deserialize, Serialize, JsonSchema, Clone)]
 pub struct ImageCreateBody {
-    id: i32,
-    base64: String,
-    format: String,
+    pub id: i32,
+    pub base64: String,
+    // ignored, image type is sniffed from the content
+    pub format: Option<String>,
 }
 
 impl DtoConvert<ImageCreateDto> for ImageCreateBody {
@@ -19,7 +43,119 @@ impl DtoConvert<ImageCreateDto> for ImageCreateBody {
         ImageCreateDto {
             id: self.id,
             base64: self.base64,
-            format: self.format,
         }
     }
 }
+
+// base64 images are read with their own `images-json` limit, other json bodies keep the default one
+pub struct ImagesCreateBody(pub Vec<ImageCreateBody>);
+
+#[rocket::async_trait]
+impl<'r> FromData<'r> for ImagesCreateBody {
+    type Error = JsonError<'r>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> data::Outcome<'r, Self> {
+        let limit = req.limits().get(IMAGES_JSON_LIMIT).unwrap_or(Limits::JSON);
+
+        let string = match data.open(limit).into_string().await {
+            Ok(string) if string.is_complete() => string.into_inner(),
+            Ok(_) => {
+                let eof = io::Error::new(io::ErrorKind::UnexpectedEof, "data limit exceeded");
+
+                return data::Outcome::Error((Status::PayloadTooLarge, JsonError::Io(eof)));
+            }
+            Err(e) => return data::Outcome::Error((Status::BadRequest, JsonError::Io(e))),
+        };
+
+        let string = local_cache!(req, string);
+
+        match serde_json::from_str(string) {
+            Ok(bodies) => data::Outcome::Success(Self(bodies)),
+            Err(e) => {
+                data::Outcome::Error((Status::UnprocessableEntity, JsonError::Parse(string, e)))
+            }
+        }
+    }
+}
+
+impl<'r> OpenApiFromData<'r> for ImagesCreateBody {
+    fn request_body(gen: &mut OpenApiGenerator) -> rocket_okapi::Result<RequestBody> {
+        Json::<Vec<ImageCreateBody>>::request_body(gen)
+    }
+}
+
+#[derive(FromForm)]
+pub struct ImageUploadForm<'r> {
+    pub files: Vec<TempFile<'r>>,
+}
+
+#[rocket::async_trait]
+impl<'r> FromData<'r> for ImageUploadForm<'r> {
+    type Error = Errors<'r>;
+
+    async fn from_data(req: &'r Request<'_>, data: Data<'r>) -> data::Outcome<'r, Self> {
+        Form::<Self>::from_data(req, data)
+            .await
+            .map(Form::into_inner)
+    }
+}
+
+// temp files have no json schema, the form is documented as a list of binary strings
+impl<'r> OpenApiFromData<'r> for ImageUploadForm<'r> {
+    fn request_body(_gen: &mut OpenApiGenerator) -> rocket_okapi::Result<RequestBody> {
+        let file_schema = SchemaObject {
+            instance_type: Some(InstanceType::String.into()),
+            format: Some(String::from("binary")),
+            ..SchemaObject::default()
+        };
+
+        let files_schema = SchemaObject {
+            instance_type: Some(InstanceType::Array.into()),
+            array: Some(Box::new(ArrayValidation {
+                items: Some(Schema::Object(file_schema).into()),
+                ..ArrayValidation::default()
+            })),
+            ..SchemaObject::default()
+        };
+
+        let mut form_schema = SchemaObject {
+            instance_type: Some(InstanceType::Object.into()),
+            ..SchemaObject::default()
+        };
+        form_schema
+            .object()
+            .properties
+            .insert(String::from("files"), Schema::Object(files_schema));
+
+        let mut content = Map::new();
+        content.insert(
+            String::from("multipart/form-data"),
+            MediaType {
+                schema: Some(form_schema),
+                ..MediaType::default()
+            },
+        );
+
+        Ok(RequestBody {
+            content,
+            required: true,
+            ..RequestBody::default()
+        })
+    }
+}
+
+// files are identified by their position in the form
+impl<'r> DtoConvert<Vec<ImageFileDto<'r>>> for ImageUploadForm<'r> {
+    type TParams = ();
+
+    fn into_dto(self, _params: Self::TParams) -> Vec<ImageFileDto<'r>> {
+        self.files
+            .into_iter()
+            .enumerate()
+            .map(|(index, file)| ImageFileDto {
+                id: index as i32,
+                file,
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,22 @@
+use super::repository::entity::language::TextDirection;
+
+pub struct LanguageCreateDto {
+    pub code: String,
+    pub name: String,
+    pub native_name: String,
+    pub direction: TextDirection,
+    pub bcp47: String,
+    pub fallback: Option<String>,
+    pub user_id: i32,
+}
+
+pub struct LanguagePatchDto {
+    pub code: String,
+    pub name: Option<String>,
+    pub native_name: Option<String>,
+    pub direction: Option<TextDirection>,
+    pub bcp47: Option<String>,
+    pub fallback: Option<String>,
+    pub enabled: Option<bool>,
+    pub user_id: i32,
+}
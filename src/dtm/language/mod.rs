@@ -0,0 +1,5 @@
+use super::repository;
+use super::trait_common;
+
+pub mod dto;
+pub mod request_body;
@@ -0,0 +1,61 @@
+use rocket::serde::{Deserialize, Serialize};
+use rocket_okapi::okapi::schemars::JsonSchema;
+
+use super::repository::entity::language::TextDirection;
+use super::trait_common::DtoConvert;
+
+use super::dto::{LanguageCreateDto, LanguagePatchDto};
+
+#[derive(Deserialize, Serialize, JsonSchema)]
+pub struct LanguageCreateBody {
+    pub code: String,
+    pub name: String,
+    pub native_name: String,
+    pub direction: Option<TextDirection>,
+    pub bcp47: String,
+    pub fallback: Option<String>,
+}
+
+impl DtoConvert<LanguageCreateDto> for LanguageCreateBody {
+    type TParams = i32;
+
+    fn into_dto(self, user_id: Self::TParams) -> LanguageCreateDto {
+        LanguageCreateDto {
+            user_id,
+            code: self.code,
+            name: self.name,
+            native_name: self.native_name,
+            direction: self.direction.unwrap_or(TextDirection::Ltr),
+            bcp47: self.bcp47,
+            fallback: self.fallback,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, JsonSchema)]
+pub struct LanguagePatchBody {
+    pub name: Option<String>,
+    pub native_name: Option<String>,
+    pub direction: Option<TextDirection>,
+    pub bcp47: Option<String>,
+    // empty string removes the fallback
+    pub fallback: Option<String>,
+    pub enabled: Option<bool>,
+}
+
+impl DtoConvert<LanguagePatchDto> for LanguagePatchBody {
+    type TParams = (String, i32);
+
+    fn into_dto(self, (code, user_id): Self::TParams) -> LanguagePatchDto {
+        LanguagePatchDto {
+            code,
+            user_id,
+            name: self.name,
+            native_name: self.native_name,
+            direction: self.direction,
+            bcp47: self.bcp47,
+            fallback: self.fallback,
+            enabled: self.enabled,
+        }
+    }
+}
@@ -1,9 +1,15 @@
+use super::dtm_common;
 use super::repository;
 use super::trait_common;
 
 pub mod article;
+pub mod article_draft;
 pub mod article_language;
 pub mod article_version;
 pub mod auth;
+pub mod category;
+pub mod email_template;
 pub mod image;
+pub mod language;
 pub mod version_content;
+pub mod watch;
@@ -0,0 +1,37 @@
+use super::repository::entity::watch::{WatchDelivery, WatchEventType};
+
+pub struct WatchCreateDto {
+    pub user_id: i32,
+    pub article_id: i32,
+    pub language: Option<String>,
+    pub delivery: WatchDelivery,
+}
+
+pub struct UserWatchCreateDto {
+    pub user_id: i32,
+    pub article_id: i32,
+    pub article_language_id: Option<i32>,
+    pub delivery: WatchDelivery,
+    pub unsubscribe_key: String,
+}
+
+pub struct WatchPatchDto {
+    pub id: i32,
+    pub user_id: i32,
+    pub delivery: WatchDelivery,
+}
+
+#[derive(Clone)]
+pub struct WatchEventDto {
+    pub article_id: i32,
+    pub article_language_id: Option<i32>,
+    pub event_type: WatchEventType,
+    pub name: String,
+    pub language_code: Option<String>,
+    pub version: Option<i32>,
+}
+
+pub struct WatchNotificationCreateDto {
+    pub watch_id: i32,
+    pub event: WatchEventDto,
+}
@@ -0,0 +1,49 @@
+use rocket::serde::{Deserialize, Serialize};
+use rocket_okapi::okapi::schemars::JsonSchema;
+
+use super::repository::entity::watch::WatchDelivery;
+use super::trait_common::DtoConvert;
+
+use super::dto::{WatchCreateDto, WatchPatchDto};
+
+#[derive(Deserialize, Serialize, JsonSchema)]
+pub struct WatchCreateBody {
+    pub article_id: i32,
+    pub language: Option<String>,
+    pub delivery: Option<WatchDelivery>,
+}
+
+impl DtoConvert<WatchCreateDto> for WatchCreateBody {
+    type TParams = i32;
+
+    fn into_dto(self, user_id: Self::TParams) -> WatchCreateDto {
+        WatchCreateDto {
+            user_id,
+            article_id: self.article_id,
+            language: self.language,
+            delivery: self.delivery.unwrap_or(WatchDelivery::Immediate),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, JsonSchema)]
+pub struct WatchPatchBody {
+    pub delivery: WatchDelivery,
+}
+
+impl DtoConvert<WatchPatchDto> for WatchPatchBody {
+    type TParams = (i32, i32);
+
+    fn into_dto(self, (id, user_id): Self::TParams) -> WatchPatchDto {
+        WatchPatchDto {
+            id,
+            user_id,
+            delivery: self.delivery,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, JsonSchema)]
+pub struct WatchUnsubscribeBody {
+    pub key: String,
+}
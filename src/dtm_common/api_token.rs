@@ -0,0 +1,40 @@
+use rocket::serde::{Deserialize, Serialize};
+use rocket_okapi::okapi::schemars::JsonSchema;
+
+// distinguishes personal api tokens from jwts in the authorization header
+pub const API_TOKEN_PREFIX: &str = "wkp_";
+
+#[derive(Serialize, Deserialize, JsonSchema, Debug, PartialEq, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum ApiTokenScope {
+    Read,
+    EditArticles,
+    UploadImages,
+}
+
+impl ApiTokenScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ApiTokenScope::Read => "read",
+            ApiTokenScope::EditArticles => "edit_articles",
+            ApiTokenScope::UploadImages => "upload_images",
+        }
+    }
+
+    pub fn from_value(value: &str) -> Option<Self> {
+        match value {
+            "read" => Some(ApiTokenScope::Read),
+            "edit_articles" => Some(ApiTokenScope::EditArticles),
+            "upload_images" => Some(ApiTokenScope::UploadImages),
+            _ => None,
+        }
+    }
+
+    // any write scope includes reading
+    pub fn is_allowed(scopes: &Vec<String>, required_scope: ApiTokenScope) -> bool {
+        match required_scope {
+            ApiTokenScope::Read => !scopes.is_empty(),
+            _ => scopes.iter().any(|scope| scope == required_scope.as_str()),
+        }
+    }
+}
@@ -4,10 +4,33 @@ use rocket_okapi::okapi::schemars::JsonSchema;
 #[derive(Serialize, JsonSchema, Deserialize, Debug)]
 pub struct TokenDto {
     pub token: String,
+    pub refresh_token: String,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct JwtDto {
     pub user_id: i32,
+    pub session_id: i32,
+    pub exp: usize,
+}
+
+#[derive(Serialize, JsonSchema, Deserialize, Debug, PartialEq, Clone, Copy)]
+pub enum ChallengePurpose {
+    // second login step for accounts with enabled 2fa
+    Totp,
+    // 2fa enrollment is required before the first session is issued
+    Enrollment,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ChallengeJwtDto {
+    pub user_id: i32,
+    pub purpose: ChallengePurpose,
+    pub exp: usize,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct OidcStateJwtDto {
+    pub provider: String,
     pub exp: usize,
 }
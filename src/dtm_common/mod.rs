@@ -1,9 +1,17 @@
+use super::error;
+
+mod api_token;
 mod jwt;
+mod pagination;
 mod request_query;
 mod response;
 mod user_role;
+mod version_diff;
 
-pub use jwt::{JwtDto, TokenDto};
+pub use api_token::{ApiTokenScope, API_TOKEN_PREFIX};
+pub use jwt::{ChallengeJwtDto, ChallengePurpose, JwtDto, OidcStateJwtDto, TokenDto};
+pub use pagination::{PageCursor, PaginationOptions};
 pub use request_query::QueryOptions;
 pub use response::ResponseString;
 pub use user_role::UserRoleId;
+pub use version_diff::{DiffGranularity, DiffOperation};
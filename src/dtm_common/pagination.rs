@@ -0,0 +1,87 @@
+use base64::{engine::general_purpose, Engine as _};
+use chrono::NaiveDateTime;
+
+use super::error::{ErrorWrapper, FmtError};
+
+pub const DEFAULT_PAGE_LIMIT: i64 = 20;
+pub const MAX_PAGE_LIMIT: i64 = 100;
+
+const CURSOR_DATE_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+const CURSOR_SEPARATOR: char = '|';
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageCursor {
+    pub created_at: NaiveDateTime,
+    pub id: i32,
+}
+
+impl PageCursor {
+    pub fn encode(&self) -> String {
+        let raw_cursor = format!(
+            "{}{}{}",
+            self.created_at.format(CURSOR_DATE_FORMAT),
+            CURSOR_SEPARATOR,
+            self.id
+        );
+
+        general_purpose::URL_SAFE_NO_PAD.encode(raw_cursor)
+    }
+
+    pub fn decode(cursor: &String) -> Option<Self> {
+        let decoded = general_purpose::URL_SAFE_NO_PAD.decode(cursor).ok()?;
+        let raw_cursor = String::from_utf8(decoded).ok()?;
+
+        let (created_at, id) = raw_cursor.split_once(CURSOR_SEPARATOR)?;
+
+        Some(Self {
+            created_at: NaiveDateTime::parse_from_str(created_at, CURSOR_DATE_FORMAT).ok()?,
+            id: id.parse::<i32>().ok()?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PaginationOptions {
+    pub limit: i64,
+    pub cursor: Option<PageCursor>,
+}
+
+impl PaginationOptions {
+    pub fn new(limit: Option<i64>, cursor: Option<String>) -> Result<Self, ErrorWrapper> {
+        let limit = limit.unwrap_or(DEFAULT_PAGE_LIMIT);
+
+        if limit < 1 || limit > MAX_PAGE_LIMIT {
+            return FmtError::FailedToProcess("limit").error();
+        }
+
+        let cursor = match cursor {
+            None => None,
+            Some(cursor) => match PageCursor::decode(&cursor) {
+                None => return FmtError::FailedToProcess("cursor").error(),
+                Some(cursor) => Some(cursor),
+            },
+        };
+
+        Ok(Self { limit, cursor })
+    }
+
+    // rows are fetched with one extra item to detect the next page
+    pub fn get_fetch_limit(&self) -> i64 {
+        self.limit + 1
+    }
+
+    pub fn split_page<T, F>(&self, mut items: Vec<T>, get_cursor: F) -> (Vec<T>, Option<String>)
+    where
+        F: Fn(&T) -> PageCursor,
+    {
+        if items.len() as i64 <= self.limit {
+            return (items, None);
+        }
+
+        items.truncate(self.limit as usize);
+
+        let next_cursor = items.last().map(|item| get_cursor(item).encode());
+
+        (items, next_cursor)
+    }
+}
@@ -0,0 +1,16 @@
+use rocket::serde::{Deserialize, Serialize};
+use rocket::FromFormField;
+use rocket_okapi::okapi::schemars::JsonSchema;
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, JsonSchema, FromFormField)]
+pub enum DiffGranularity {
+    Line,
+    Word,
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, PartialEq, Clone, Copy)]
+pub enum DiffOperation {
+    Equal,
+    Insert,
+    Delete,
+}
@@ -0,0 +1,82 @@
+use reqwest::header::{HeaderMap, HeaderValue};
+use rocket::serde::json::json;
+use std::env;
+
+use super::error::{ErrorWrapper, FmtError};
+
+use super::email_transport::{EmailMessage, EmailTransport, EmailTransportHandler};
+
+const BREVO_URL: &str = "https://api.brevo.com/v3/smtp/email";
+const BREVO_SECRET_ENV: &str = "BREVO_SECRET";
+
+pub struct BrevoEmailTransport {
+    secret: String,
+}
+
+impl BrevoEmailTransport {
+    pub fn new() -> Self {
+        Self {
+            secret: env::var(BREVO_SECRET_ENV)
+                .expect(&FmtError::EmptyValue(BREVO_SECRET_ENV).fmt()),
+        }
+    }
+
+    fn get_headers(&self) -> HeaderMap {
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("accept", HeaderValue::from_static("application/json"));
+        headers.insert("content-type", HeaderValue::from_static("application/json"));
+        headers.insert(
+            "api-key",
+            HeaderValue::from_str(&self.secret)
+                .expect(&FmtError::FailedToProcess(BREVO_SECRET_ENV).fmt()),
+        );
+
+        headers
+    }
+}
+
+#[rocket::async_trait]
+impl EmailTransport for BrevoEmailTransport {
+    async fn send(&self, message: &EmailMessage) -> Result<(), ErrorWrapper> {
+        let client = reqwest::Client::builder()
+            .build()
+            .expect(&FmtError::FailedToProcess("emailer client").fmt());
+
+        let sender = EmailTransportHandler::get_sender();
+
+        let json = json!({
+            "sender": {
+                "email": sender.email,
+                "name": sender.name
+            },
+            "to": [
+                {
+                    "email": message.to
+                }
+            ],
+            "subject": message.subject,
+            "htmlContent": message.html,
+            "textContent": message.text
+        });
+
+        match client
+            .request(reqwest::Method::POST, BREVO_URL)
+            .headers(self.get_headers())
+            .json(&json)
+            .send()
+            .await
+        {
+            Ok(response) if response.status().is_success() => Ok(()),
+            Ok(response) => {
+                eprintln!("{}", response.status());
+
+                FmtError::FailedToSendRequest("emailer").error()
+            }
+            Err(err) => {
+                eprintln!("{}", err);
+
+                FmtError::FailedToSendRequest("emailer").error()
+            }
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use std::env;
+use std::sync::Arc;
+
+use super::error::{ErrorWrapper, FmtError};
+
+use super::brevo_transport::BrevoEmailTransport;
+use super::outbox_transport::OutboxEmailTransport;
+use super::smtp_transport::SmtpEmailTransport;
+
+const EMAIL_TRANSPORT_ENV: &str = "EMAIL_TRANSPORT";
+const EMAIL_FROM_ENV: &str = "EMAIL_FROM";
+const EMAIL_FROM_NAME_ENV: &str = "EMAIL_FROM_NAME";
+
+const DEFAULT_EMAIL_FROM_NAME: &str = "Wiki";
+
+#[derive(Debug, PartialEq)]
+pub enum EmailTransportType {
+    Brevo,
+    Smtp,
+    Outbox,
+}
+
+#[derive(Debug, Clone)]
+pub struct EmailMessage {
+    pub to: String,
+    pub subject: String,
+    pub html: String,
+    pub text: String,
+}
+
+pub struct EmailSender {
+    pub email: String,
+    pub name: String,
+}
+
+#[rocket::async_trait]
+pub trait EmailTransport: Send + Sync {
+    async fn send(&self, message: &EmailMessage) -> Result<(), ErrorWrapper>;
+}
+
+pub struct EmailTransportHandler;
+
+impl EmailTransportHandler {
+    pub fn get_transport_type() -> EmailTransportType {
+        match env::var(EMAIL_TRANSPORT_ENV) {
+            Err(_) => EmailTransportType::Brevo,
+            Ok(transport_type) => match transport_type.to_lowercase().as_str() {
+                "brevo" => EmailTransportType::Brevo,
+                "smtp" => EmailTransportType::Smtp,
+                "outbox" => EmailTransportType::Outbox,
+                _ => panic!("{}", &FmtError::FailedToProcess(EMAIL_TRANSPORT_ENV).fmt()),
+            },
+        }
+    }
+
+    // built once on launch and shared as managed state, so smtp keeps its connection pool
+    pub fn get_transport() -> Arc<dyn EmailTransport> {
+        match Self::get_transport_type() {
+            EmailTransportType::Brevo => Arc::new(BrevoEmailTransport::new()),
+            EmailTransportType::Smtp => Arc::new(SmtpEmailTransport::new()),
+            EmailTransportType::Outbox => Arc::new(OutboxEmailTransport::new()),
+        }
+    }
+
+    pub fn get_sender() -> EmailSender {
+        EmailSender {
+            email: env::var(EMAIL_FROM_ENV).expect(&FmtError::EmptyValue(EMAIL_FROM_ENV).fmt()),
+            name: env::var(EMAIL_FROM_NAME_ENV).unwrap_or(String::from(DEFAULT_EMAIL_FROM_NAME)),
+        }
+    }
+}
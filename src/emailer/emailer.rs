@@ -1,52 +1,27 @@
-use reqwest::header::{HeaderMap, HeaderValue};
 use std::env;
 
-use rocket::serde;
+use super::error::FmtError;
 
-use super::error::{ErrorWrapper, FmtError};
+use super::email_transport::EmailMessage;
 
-const BREVO_URL: &str = "https://api.brevo.com/v3/smtp/email";
-const BREVO_SECRET_ENV: &str = "BREVO_SECRET";
 const CLIENT_URL_ENV: &str = "CLIENT_URL";
 
+pub struct EmailParams {
+    pub name: String,
+    pub email: String,
+    pub url: String,
+    pub details: String,
+    pub unsubscribe_url: String,
+}
+
 pub struct Emailer;
 
 impl Emailer {
-    fn get_headers() -> HeaderMap {
-        let secret =
-            env::var(BREVO_SECRET_ENV).expect(&FmtError::EmptyValue(BREVO_SECRET_ENV).fmt());
-
-        let mut headers = reqwest::header::HeaderMap::new();
-        headers.insert("accept", HeaderValue::from_static("application/json"));
-        headers.insert("content-type", HeaderValue::from_static("application/json"));
-        headers.insert(
-            "api-key",
-            HeaderValue::from_str(&secret)
-                .expect(&FmtError::FailedToProcess(BREVO_SECRET_ENV).fmt()),
-        );
-
-        return headers;
-    }
-
-    fn get_confirmation_body(url: String, email: &String) -> serde::json::Value {
-        return serde::json::json!({
-            "to": [
-                {
-                    "email": email,
-                    "name": "User"
-                }
-            ],
-            "templateId": 1,
-            "params": {
-                "url": url
-            },
-            "headers": {
-                "charset": "iso-8859-1"
-            }
-        });
-    }
-
-    fn get_confirmation_url(otp: &String, email: &String, redirect_to: &Option<String>) -> String {
+    pub fn get_confirmation_url(
+        otp: &String,
+        email: &String,
+        redirect_to: &Option<String>,
+    ) -> String {
         let client_urn =
             env::var(CLIENT_URL_ENV).expect(&FmtError::EmptyValue(CLIENT_URL_ENV).fmt());
 
@@ -58,25 +33,7 @@ impl Emailer {
         format!("{client_urn}/api/confirm?key={otp}&email={email}{from_query}")
     }
 
-    fn get_reset_body(url: String, email: &String) -> serde::json::Value {
-        return serde::json::json!({
-            "to": [
-                {
-                    "email": email,
-                    "name": "User"
-                }
-            ],
-            "templateId": 2,
-            "params": {
-                "url": url
-            },
-            "headers": {
-                "charset": "iso-8859-1"
-            }
-        });
-    }
-
-    fn get_reset_url(otp: &String, email: &String, redirect_to: &Option<String>) -> String {
+    pub fn get_reset_url(otp: &String, email: &String, redirect_to: &Option<String>) -> String {
         let client_urn =
             env::var(CLIENT_URL_ENV).expect(&FmtError::EmptyValue(CLIENT_URL_ENV).fmt());
 
@@ -88,66 +45,75 @@ impl Emailer {
         format!("{client_urn}/reset/confirm?key={otp}&email={email}{from_query}")
     }
 
-    pub async fn send_reset_email(
-        otp: &String,
-        email: &String,
-        redirect_to: &Option<String>,
-    ) -> Result<(), ErrorWrapper> {
-        let client = reqwest::Client::builder()
-            .build()
-            .expect(&FmtError::FailedToProcess("emailer client").fmt());
-
-        let url = Self::get_reset_url(otp, email, redirect_to);
-
-        let json = Self::get_reset_body(url, email);
-
-        match client
-            .request(reqwest::Method::POST, BREVO_URL)
-            .headers(Self::get_headers())
-            .json(&json)
-            .send()
-            .await
-        {
-            Ok(_) => (),
+    pub fn get_unsubscribe_url(unsubscribe_key: &String) -> String {
+        let client_urn =
+            env::var(CLIENT_URL_ENV).expect(&FmtError::EmptyValue(CLIENT_URL_ENV).fmt());
 
-            Err(err) => {
-                eprintln!("{}", err);
+        format!("{client_urn}/api/unsubscribe?key={unsubscribe_key}")
+    }
 
-                return Err(FmtError::FailedToSendRequest("emailer").error_wrapper());
-            }
+    // substitutes {{name}}, {{email}}, {{url}}, {{details}} and {{unsubscribe_url}} placeholders,
+    // values are escaped in html part and multiline details are split by <br>
+    pub fn render(
+        subject: &String,
+        html: &String,
+        text: &String,
+        params: &EmailParams,
+    ) -> EmailMessage {
+        EmailMessage {
+            to: params.email.clone(),
+            subject: Self::substitute(subject, params, |value| value.to_owned()),
+            html: Self::substitute(html, params, |value| {
+                Self::escape_html(value).replace('\n', "<br>")
+            }),
+            text: Self::substitute(text, params, |value| value.to_owned()),
         }
-
-        Ok(())
     }
 
-    pub async fn send_confirmation_email(
-        otp: &String,
-        email: &String,
-        redirect_to: &Option<String>,
-    ) -> Result<(), ErrorWrapper> {
-        let client = reqwest::Client::builder()
-            .build()
-            .expect(&FmtError::FailedToProcess("emailer client").fmt());
-
-        let url = Self::get_confirmation_url(otp, email, redirect_to);
-
-        let json = Self::get_confirmation_body(url, email);
-
-        match client
-            .request(reqwest::Method::POST, BREVO_URL)
-            .headers(Self::get_headers())
-            .json(&json)
-            .send()
-            .await
-        {
-            Ok(_) => (),
-            Err(err) => {
-                eprintln!("{}", err);
-
-                return Err(FmtError::FailedToSendRequest("emailer").error_wrapper());
+    // single pass, so placeholders inside substituted values are kept as is
+    fn substitute(template: &String, params: &EmailParams, escape: fn(&str) -> String) -> String {
+        let mut result = String::with_capacity(template.len());
+        let mut rest = template.as_str();
+
+        while let Some(start) = rest.find("{{") {
+            result.push_str(&rest[..start]);
+            rest = &rest[start..];
+
+            let value = match rest.find("}}") {
+                Some(end) => match &rest[2..end] {
+                    "name" => Some((&params.name, end)),
+                    "email" => Some((&params.email, end)),
+                    "url" => Some((&params.url, end)),
+                    "details" => Some((&params.details, end)),
+                    "unsubscribe_url" => Some((&params.unsubscribe_url, end)),
+                    _ => None,
+                },
+                None => None,
+            };
+
+            match value {
+                Some((value, end)) => {
+                    result.push_str(&escape(value));
+                    rest = &rest[end + 2..];
+                }
+                None => {
+                    result.push_str("{{");
+                    rest = &rest[2..];
+                }
             }
         }
 
-        Ok(())
+        result.push_str(rest);
+
+        result
+    }
+
+    fn escape_html(value: &str) -> String {
+        value
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+            .replace('\'', "&#39;")
     }
 }
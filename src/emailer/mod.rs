@@ -1,5 +1,13 @@
 use super::error;
 
+mod brevo_transport;
+mod email_transport;
 mod emailer;
+mod outbox_transport;
+mod smtp_transport;
 
-pub use emailer::Emailer;
+pub use email_transport::{
+    EmailMessage, EmailTransport, EmailTransportHandler, EmailTransportType,
+};
+pub use emailer::{EmailParams, Emailer};
+pub use outbox_transport::OutboxEmailTransport;
@@ -0,0 +1,76 @@
+#[cfg(test)]
+use parking_lot::{const_mutex, Mutex};
+use rocket::tokio::fs;
+use std::env;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::error::{ErrorWrapper, FmtError};
+
+use super::email_transport::{EmailMessage, EmailTransport};
+
+const EMAIL_OUTBOX_PATH_ENV: &str = "EMAIL_OUTBOX_PATH";
+
+// sent messages are kept in memory for tests only
+#[cfg(test)]
+static OUTBOX: Mutex<Vec<EmailMessage>> = const_mutex(Vec::new());
+
+// writes sent messages to a directory (if configured) instead of delivering
+pub struct OutboxEmailTransport {
+    path: Option<PathBuf>,
+}
+
+impl OutboxEmailTransport {
+    pub fn new() -> Self {
+        Self {
+            path: env::var(EMAIL_OUTBOX_PATH_ENV).ok().map(PathBuf::from),
+        }
+    }
+
+    #[cfg(test)]
+    pub fn get_messages(to: &String) -> Vec<EmailMessage> {
+        OUTBOX
+            .lock()
+            .iter()
+            .filter(|message| &message.to == to)
+            .cloned()
+            .collect()
+    }
+
+    fn get_file_content(message: &EmailMessage) -> String {
+        format!(
+            "To: {}\nSubject: {}\n\n{}\n\n{}\n",
+            message.to, message.subject, message.text, message.html
+        )
+    }
+}
+
+#[rocket::async_trait]
+impl EmailTransport for OutboxEmailTransport {
+    async fn send(&self, message: &EmailMessage) -> Result<(), ErrorWrapper> {
+        if let Some(path) = &self.path {
+            let timestamp = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|duration| duration.as_nanos())
+                .unwrap_or_default();
+
+            if fs::create_dir_all(path).await.is_err() {
+                return FmtError::FailedToInsert("email outbox").error();
+            }
+
+            let file_path = path.join(format!("{}_{}.eml", timestamp, message.to));
+
+            if fs::write(file_path, Self::get_file_content(message))
+                .await
+                .is_err()
+            {
+                return FmtError::FailedToInsert("email outbox").error();
+            }
+        }
+
+        #[cfg(test)]
+        OUTBOX.lock().push(message.clone());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+use lettre::{
+    message::{Mailbox, MultiPart},
+    transport::smtp::authentication::Credentials,
+    AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor,
+};
+use std::env;
+
+use super::error::{ErrorWrapper, FmtError};
+
+use super::email_transport::{EmailMessage, EmailTransport, EmailTransportHandler};
+
+const SMTP_HOST_ENV: &str = "SMTP_HOST";
+const SMTP_PORT_ENV: &str = "SMTP_PORT";
+const SMTP_USERNAME_ENV: &str = "SMTP_USERNAME";
+const SMTP_PASSWORD_ENV: &str = "SMTP_PASSWORD";
+const SMTP_SECURITY_ENV: &str = "SMTP_SECURITY";
+
+const DEFAULT_SMTP_PORT: u16 = 587;
+
+pub struct SmtpEmailTransport {
+    mailer: AsyncSmtpTransport<Tokio1Executor>,
+}
+
+impl SmtpEmailTransport {
+    pub fn new() -> Self {
+        let host = env::var(SMTP_HOST_ENV).expect(&FmtError::EmptyValue(SMTP_HOST_ENV).fmt());
+
+        let port = match env::var(SMTP_PORT_ENV) {
+            Ok(port) => port
+                .parse::<u16>()
+                .expect(&FmtError::FailedToProcess(SMTP_PORT_ENV).fmt()),
+            Err(_) => DEFAULT_SMTP_PORT,
+        };
+
+        // "starttls" (default), "tls" for implicit tls or "none" for local relays
+        let builder = match env::var(SMTP_SECURITY_ENV)
+            .unwrap_or(String::from("starttls"))
+            .to_lowercase()
+            .as_str()
+        {
+            "starttls" => AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&host)
+                .expect(&FmtError::FailedToProcess(SMTP_HOST_ENV).fmt()),
+            "tls" => AsyncSmtpTransport::<Tokio1Executor>::relay(&host)
+                .expect(&FmtError::FailedToProcess(SMTP_HOST_ENV).fmt()),
+            "none" => AsyncSmtpTransport::<Tokio1Executor>::builder_dangerous(&host),
+            _ => panic!("{}", &FmtError::FailedToProcess(SMTP_SECURITY_ENV).fmt()),
+        }
+        .port(port);
+
+        let builder = match (env::var(SMTP_USERNAME_ENV), env::var(SMTP_PASSWORD_ENV)) {
+            (Ok(username), Ok(password)) => {
+                builder.credentials(Credentials::new(username, password))
+            }
+            _ => builder,
+        };
+
+        Self {
+            mailer: builder.build(),
+        }
+    }
+
+    fn build_message(message: &EmailMessage) -> Result<Message, ErrorWrapper> {
+        let sender = EmailTransportHandler::get_sender();
+
+        let from = match sender.email.parse() {
+            Ok(address) => Mailbox::new(Some(sender.name), address),
+            Err(_) => return FmtError::FailedToProcess("email sender").error(),
+        };
+
+        let to = match message.to.parse::<Mailbox>() {
+            Ok(to) => to,
+            Err(_) => return FmtError::FailedToProcess("email recipient").error(),
+        };
+
+        match Message::builder()
+            .from(from)
+            .to(to)
+            .subject(message.subject.clone())
+            .multipart(MultiPart::alternative_plain_html(
+                message.text.clone(),
+                message.html.clone(),
+            )) {
+            Ok(message) => Ok(message),
+            Err(_) => FmtError::FailedToProcess("email message").error(),
+        }
+    }
+}
+
+#[rocket::async_trait]
+impl EmailTransport for SmtpEmailTransport {
+    async fn send(&self, message: &EmailMessage) -> Result<(), ErrorWrapper> {
+        let message = Self::build_message(message)?;
+
+        match self.mailer.send(message).await {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                eprintln!("{}", err);
+
+                FmtError::FailedToSendRequest("emailer").error()
+            }
+        }
+    }
+}
@@ -15,11 +15,14 @@ impl ErrorWrapper {
         let status = match fmt_error {
             FmtError::NotFound(_) => Status::NotFound,
             FmtError::AlreadyExists(_) => Status::BadRequest,
+            FmtError::Conflict(_) => Status::Conflict,
             FmtError::FailedToProcess(_) => Status::NotAcceptable,
             FmtError::EmptyValue(_) => Status::NotAcceptable,
 
             FmtError::Unauthorized(_) => Status::Unauthorized,
             FmtError::PermissionDenied(_) => Status::Forbidden,
+            FmtError::TooManyRequests(_) => Status::TooManyRequests,
+            FmtError::AlreadyLocked(_) => Status::Locked,
 
             FmtError::DatabaseError(_) => Status::InternalServerError,
 
@@ -52,6 +55,15 @@ impl ErrorWrapper {
             Some(duplicated_key) => match duplicated_key {
                 "\"user_account_email_key\"" => Self::new(&FmtError::AlreadyExists("email"), None),
                 "\"user_account_name_key\"" => Self::new(&FmtError::AlreadyExists("name"), None),
+                "\"category_name_key\"" => Self::new(&FmtError::AlreadyExists("category"), None),
+                "\"language_code_key\"" => Self::new(&FmtError::AlreadyExists("language"), None),
+                "\"user_identity_per_provider\"" => {
+                    Self::new(&FmtError::AlreadyExists("user_identity"), None)
+                }
+                "\"category_per_article\"" => {
+                    Self::new(&FmtError::AlreadyExists("article_category"), None)
+                }
+                "\"watch_per_target\"" => Self::new(&FmtError::AlreadyExists("watch"), None),
                 _ => Self::new(&FmtError::DatabaseError(error.to_string().as_str()), None),
             },
             None => Self::new(&FmtError::DatabaseError(error.to_string().as_str()), None),
@@ -61,6 +73,14 @@ impl ErrorWrapper {
     pub fn custom(&self) -> status::Custom<String> {
         status::Custom(self.status, self.message.clone())
     }
+
+    // responds with the extra message (e.g. serialized conflict details) when there is one
+    pub fn custom_detailed(&self) -> status::Custom<String> {
+        match &self.extra_message {
+            Some(extra_message) => status::Custom(self.status, extra_message.clone()),
+            None => self.custom(),
+        }
+    }
 }
 
 impl From<result::Error> for ErrorWrapper {
@@ -5,6 +5,7 @@ pub enum FmtError<'input> {
     EmptyValue(&'input str),
     NotFound(&'input str),
     AlreadyExists(&'input str),
+    Conflict(&'input str),
 
     FailedToFetch(&'input str),
     FailedToInsert(&'input str),
@@ -12,6 +13,8 @@ pub enum FmtError<'input> {
 
     Unauthorized(&'input str),
     PermissionDenied(&'input str),
+    TooManyRequests(&'input str),
+    AlreadyLocked(&'input str),
 
     DatabaseError(&'input str),
 
@@ -25,6 +28,7 @@ impl<'input> FmtError<'input> {
             FmtError::EmptyValue(s) => format!("Empty: {}", s),
             FmtError::NotFound(s) => format!("Entity not found: {}", s),
             FmtError::AlreadyExists(s) => format!("Entity already exists: {}", s),
+            FmtError::Conflict(s) => format!("Conflict: {}", s),
 
             FmtError::FailedToFetch(s) => format!("Failed to fetch: {}", s),
             FmtError::FailedToInsert(s) => format!("Failed to insert: {}", s),
@@ -32,6 +36,8 @@ impl<'input> FmtError<'input> {
 
             FmtError::Unauthorized(s) => format!("Not authorized: {}", s),
             FmtError::PermissionDenied(s) => format!("Permission denied: {}", s),
+            FmtError::TooManyRequests(s) => format!("Too many requests: {}", s),
+            FmtError::AlreadyLocked(s) => format!("Temporarily locked: {}", s),
 
             FmtError::DatabaseError(s) => format!("Database error: {}", s),
 
@@ -1,13 +1,44 @@
-use argon2::{self, Config};
+use argon2::{self, Config, Variant, Version};
+use rand::RngCore;
+use sha2::{Digest, Sha256};
 use std::env;
+use std::sync::OnceLock;
 
 use super::error::{ErrorWrapper, FmtError};
 
-const PWD_SECRET_ENV: &str = "PWD_SECRET";
+const PWD_MEMORY_COST_ENV: &str = "PWD_MEMORY_COST";
+const PWD_TIME_COST_ENV: &str = "PWD_TIME_COST";
+
+const DEFAULT_PWD_MEMORY_COST: u32 = 19456;
+const DEFAULT_PWD_TIME_COST: u32 = 2;
+const SALT_LENGTH: usize = 16;
+const DUMMY_PASSWORD: &str = "dummy_password";
+
+static DUMMY_PASSWORD_HASH: OnceLock<String> = OnceLock::new();
 
 pub struct Hasher;
 
 impl Hasher {
+    fn get_cost(env_name: &str, default: u32) -> u32 {
+        match env::var(env_name) {
+            Ok(cost) => cost
+                .parse::<u32>()
+                .expect(&FmtError::FailedToProcess(env_name).fmt()),
+            Err(_) => default,
+        }
+    }
+
+    fn get_config<'a>() -> Config<'a> {
+        Config {
+            variant: Variant::Argon2id,
+            version: Version::Version13,
+            mem_cost: Self::get_cost(PWD_MEMORY_COST_ENV, DEFAULT_PWD_MEMORY_COST),
+            time_cost: Self::get_cost(PWD_TIME_COST_ENV, DEFAULT_PWD_TIME_COST),
+            ..Config::default()
+        }
+    }
+
+    // parameters and salt are encoded into the hash, so legacy (argon2i + global salt) hashes are verified as well
     pub fn verify_encoded(password: String, stored_password: String) -> Result<bool, ErrorWrapper> {
         match argon2::verify_encoded(&stored_password, password.as_bytes()) {
             Ok(is_correct) => Ok(is_correct),
@@ -15,11 +46,55 @@ impl Hasher {
         }
     }
 
+    // costs the same as checking a real password, so failed logins don't reveal unknown accounts
+    pub fn verify_dummy(password: String) {
+        let dummy_hash = DUMMY_PASSWORD_HASH.get_or_init(|| {
+            argon2::hash_encoded(
+                DUMMY_PASSWORD.as_bytes(),
+                &[0u8; SALT_LENGTH],
+                &Self::get_config(),
+            )
+            .expect(&FmtError::FailedToProcess("password").fmt())
+        });
+
+        let _ = argon2::verify_encoded(dummy_hash, password.as_bytes());
+    }
+
+    pub fn needs_rehash(stored_password: &String) -> bool {
+        let config = Self::get_config();
+        let prefix = format!(
+            "$argon2id$v=19$m={},t={},p={}$",
+            config.mem_cost, config.time_cost, config.lanes
+        );
+
+        !stored_password.starts_with(&prefix)
+    }
+
+    // refresh tokens and otps are random, so a fast unsalted digest is enough
+    pub fn hash_token(token: &String) -> String {
+        format!("{:x}", Sha256::digest(token.as_bytes()))
+    }
+
+    pub fn verify_token(token: &String, token_hash: &String) -> bool {
+        Self::constant_time_eq(Self::hash_token(token).as_bytes(), token_hash.as_bytes())
+    }
+
+    // doesn't stop on the first mismatched byte
+    pub fn constant_time_eq(left: &[u8], right: &[u8]) -> bool {
+        left.len() == right.len()
+            && left
+                .iter()
+                .zip(right.iter())
+                .fold(0, |acc, (a, b)| acc | (a ^ b))
+                == 0
+    }
+
     pub fn hash_password(password: String) -> Result<String, ErrorWrapper> {
-        let salt = env::var(PWD_SECRET_ENV).expect(&FmtError::EmptyValue(PWD_SECRET_ENV).fmt());
+        let mut salt = [0u8; SALT_LENGTH];
+        rand::thread_rng().fill_bytes(&mut salt);
 
-        let config = Config::default();
-        match argon2::hash_encoded(password.as_bytes(), salt.as_bytes(), &config) {
+        let config = Self::get_config();
+        match argon2::hash_encoded(password.as_bytes(), &salt, &config) {
             Ok(hash) => Ok(hash),
             Err(_) => FmtError::FailedToProcess("password").error(),
         }
@@ -0,0 +1,208 @@
+use image::{imageops::FilterType, DynamicImage, GenericImageView, ImageFormat, ImageOutputFormat};
+use std::env;
+use std::io::Cursor;
+
+use super::error::{ErrorWrapper, FmtError};
+
+const IMAGE_RENDITION_FORMAT_ENV: &str = "IMAGE_RENDITION_FORMAT";
+const IMAGE_THUMBNAIL_SIZE_ENV: &str = "IMAGE_THUMBNAIL_SIZE";
+const IMAGE_MEDIUM_SIZE_ENV: &str = "IMAGE_MEDIUM_SIZE";
+const IMAGE_JPEG_QUALITY_ENV: &str = "IMAGE_JPEG_QUALITY";
+
+const DEFAULT_IMAGE_THUMBNAIL_SIZE: u32 = 320;
+const DEFAULT_IMAGE_MEDIUM_SIZE: u32 = 1280;
+const DEFAULT_IMAGE_JPEG_QUALITY: u8 = 85;
+
+const ORIGINAL_RENDITION: &str = "original";
+const THUMBNAIL_RENDITION: &str = "thumbnail";
+const MEDIUM_RENDITION: &str = "medium";
+
+#[derive(Debug, PartialEq)]
+enum RenditionFormat {
+    // jpeg for opaque images, webp for images with alpha channel
+    Auto,
+    Jpeg,
+    WebP,
+}
+
+pub struct ImageRendition {
+    pub name: String,
+    pub content: Vec<u8>,
+    pub mime_type: String,
+    pub extension: String,
+    pub width: i32,
+    pub height: i32,
+}
+
+pub struct ImageProcessor;
+
+impl ImageProcessor {
+    // client-provided format is not trusted, type is detected by content signature
+    pub fn get_image_type(content: &Vec<u8>) -> Option<(&'static str, &'static str)> {
+        Self::get_mime_type(image::guess_format(content).ok()?)
+    }
+
+    // decoding and re-encoding drops all metadata (EXIF, GPS, etc.) of the upload
+    pub fn get_renditions(content: Vec<u8>) -> Result<Vec<ImageRendition>, ErrorWrapper> {
+        let format = match image::guess_format(&content) {
+            Ok(format) if Self::get_mime_type(format).is_some() => format,
+            _ => return FmtError::FailedToProcess("image type").error(),
+        };
+
+        let decoded_image = match image::load_from_memory_with_format(&content, format) {
+            Ok(decoded_image) => decoded_image,
+            Err(_) => return FmtError::FailedToProcess("image content").error(),
+        };
+
+        let decoded_image = Self::apply_orientation(decoded_image, Self::get_orientation(&content));
+
+        let rendition_format = Self::get_rendition_format();
+        let (width, height) = GenericImageView::dimensions(&decoded_image);
+
+        let original_rendition = match format {
+            // animation would be lost on re-encoding, gif carries no EXIF anyway
+            ImageFormat::Gif => ImageRendition {
+                name: String::from(ORIGINAL_RENDITION),
+                mime_type: String::from("image/gif"),
+                extension: String::from("gif"),
+                width: width as i32,
+                height: height as i32,
+                content,
+            },
+            _ => Self::encode(ORIGINAL_RENDITION, &decoded_image, &rendition_format)?,
+        };
+
+        let mut renditions = vec![original_rendition];
+
+        for (name, size) in [
+            (
+                THUMBNAIL_RENDITION,
+                Self::get_size_env(IMAGE_THUMBNAIL_SIZE_ENV, DEFAULT_IMAGE_THUMBNAIL_SIZE),
+            ),
+            (
+                MEDIUM_RENDITION,
+                Self::get_size_env(IMAGE_MEDIUM_SIZE_ENV, DEFAULT_IMAGE_MEDIUM_SIZE),
+            ),
+        ] {
+            let resized_image = match width > size || height > size {
+                true => decoded_image.resize(size, size, FilterType::Lanczos3),
+                false => decoded_image.clone(),
+            };
+
+            renditions.push(Self::encode(name, &resized_image, &rendition_format)?);
+        }
+
+        Ok(renditions)
+    }
+
+    fn encode(
+        name: &str,
+        image: &DynamicImage,
+        rendition_format: &RenditionFormat,
+    ) -> Result<ImageRendition, ErrorWrapper> {
+        let is_webp = match rendition_format {
+            RenditionFormat::Auto => image.color().has_alpha(),
+            RenditionFormat::Jpeg => false,
+            RenditionFormat::WebP => true,
+        };
+
+        let (width, height) = GenericImageView::dimensions(image);
+
+        let mut content = Cursor::new(vec![]);
+
+        let (encode_result, mime_type, extension) = match is_webp {
+            true => (
+                DynamicImage::ImageRgba8(image.to_rgba8())
+                    .write_to(&mut content, ImageOutputFormat::WebP),
+                "image/webp",
+                "webp",
+            ),
+            false => (
+                DynamicImage::ImageRgb8(image.to_rgb8()).write_to(
+                    &mut content,
+                    ImageOutputFormat::Jpeg(Self::get_jpeg_quality()),
+                ),
+                "image/jpeg",
+                "jpg",
+            ),
+        };
+
+        if encode_result.is_err() {
+            return FmtError::FailedToProcess("image rendition").error();
+        }
+
+        Ok(ImageRendition {
+            name: String::from(name),
+            content: content.into_inner(),
+            mime_type: String::from(mime_type),
+            extension: String::from(extension),
+            width: width as i32,
+            height: height as i32,
+        })
+    }
+
+    fn get_mime_type(format: ImageFormat) -> Option<(&'static str, &'static str)> {
+        match format {
+            ImageFormat::Png => Some(("image/png", "png")),
+            ImageFormat::Jpeg => Some(("image/jpeg", "jpg")),
+            ImageFormat::Gif => Some(("image/gif", "gif")),
+            ImageFormat::WebP => Some(("image/webp", "webp")),
+            _ => None,
+        }
+    }
+
+    fn get_orientation(content: &Vec<u8>) -> u32 {
+        let exif = match exif::Reader::new().read_from_container(&mut Cursor::new(content)) {
+            Ok(exif) => exif,
+            Err(_) => return 1,
+        };
+
+        match exif.get_field(exif::Tag::Orientation, exif::In::PRIMARY) {
+            Some(field) => field.value.get_uint(0).unwrap_or(1),
+            None => 1,
+        }
+    }
+
+    // pixels are rotated, since the orientation tag is stripped together with EXIF
+    fn apply_orientation(image: DynamicImage, orientation: u32) -> DynamicImage {
+        match orientation {
+            2 => image.fliph(),
+            3 => image.rotate180(),
+            4 => image.flipv(),
+            5 => image.rotate90().fliph(),
+            6 => image.rotate90(),
+            7 => image.rotate270().fliph(),
+            8 => image.rotate270(),
+            _ => image,
+        }
+    }
+
+    fn get_rendition_format() -> RenditionFormat {
+        match env::var(IMAGE_RENDITION_FORMAT_ENV) {
+            Err(_) => RenditionFormat::Auto,
+            Ok(rendition_format) => match rendition_format.to_lowercase().as_str() {
+                "auto" => RenditionFormat::Auto,
+                "jpeg" => RenditionFormat::Jpeg,
+                "webp" => RenditionFormat::WebP,
+                _ => panic!(
+                    "{}",
+                    &FmtError::FailedToProcess(IMAGE_RENDITION_FORMAT_ENV).fmt()
+                ),
+            },
+        }
+    }
+
+    fn get_size_env(env_name: &str, default_size: u32) -> u32 {
+        env::var(env_name)
+            .ok()
+            .and_then(|size| size.parse::<u32>().ok())
+            .unwrap_or(default_size)
+    }
+
+    fn get_jpeg_quality() -> u8 {
+        env::var(IMAGE_JPEG_QUALITY_ENV)
+            .ok()
+            .and_then(|quality| quality.parse::<u8>().ok())
+            .unwrap_or(DEFAULT_IMAGE_JPEG_QUALITY)
+    }
+}
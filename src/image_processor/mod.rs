@@ -0,0 +1,5 @@
+use super::error;
+
+mod image_processor;
+
+pub use image_processor::{ImageProcessor, ImageRendition};
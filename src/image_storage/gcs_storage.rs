@@ -0,0 +1,74 @@
+use cloud_storage::Object;
+use std::env;
+use std::path::Path;
+
+use super::error::{ErrorWrapper, FmtError};
+
+use super::image_storage::ImageStorage;
+
+const IMAGE_BUCKET_ENV: &str = "IMAGE_BUCKET";
+const GOOGLE_API_URL_ENV: &str = "GOOGLE_API_URL";
+
+const SERVICE_ACCOUNT_JSON_ENV: &str = "SERVICE_ACCOUNT_JSON";
+const SERVICE_ACCOUNT_PATH_ENVS: [&str; 2] = ["SERVICE_ACCOUNT", "GOOGLE_APPLICATION_CREDENTIALS"];
+
+pub struct GcsImageStorage {
+    bucket: String,
+    api_url: String,
+}
+
+impl GcsImageStorage {
+    pub fn new() -> Self {
+        Self {
+            bucket: env::var(IMAGE_BUCKET_ENV)
+                .expect(&FmtError::EmptyValue(IMAGE_BUCKET_ENV).fmt()),
+            api_url: env::var(GOOGLE_API_URL_ENV)
+                .expect(&FmtError::EmptyValue(GOOGLE_API_URL_ENV).fmt()),
+        }
+    }
+
+    // cloud-storage client panics on the first request without service account
+    fn has_service_account() -> bool {
+        env::var(SERVICE_ACCOUNT_JSON_ENV).is_ok()
+            || SERVICE_ACCOUNT_PATH_ENVS
+                .iter()
+                .any(|path_env| match env::var(path_env) {
+                    Ok(path) => Path::new(&path).exists(),
+                    Err(_) => false,
+                })
+    }
+}
+
+#[rocket::async_trait]
+impl ImageStorage for GcsImageStorage {
+    async fn upload(
+        &self,
+        key: &String,
+        content: Vec<u8>,
+        mime_type: &String,
+    ) -> Result<String, ErrorWrapper> {
+        if !Self::has_service_account() {
+            return FmtError::EmptyValue("SA").error();
+        }
+
+        match Object::create(&self.bucket, content, key.as_str(), mime_type.as_str()).await {
+            Ok(object) => Ok(self.get_uri(&object.name)),
+            Err(_) => FmtError::FailedToSendRequest("image storage").error(),
+        }
+    }
+
+    async fn delete(&self, key: &String) -> Result<(), ErrorWrapper> {
+        if !Self::has_service_account() {
+            return FmtError::EmptyValue("SA").error();
+        }
+
+        match Object::delete(&self.bucket, key.as_str()).await {
+            Ok(_) => Ok(()),
+            Err(_) => FmtError::FailedToSendRequest("image storage").error(),
+        }
+    }
+
+    fn get_uri(&self, key: &String) -> String {
+        format!("{}/{}", self.api_url, key)
+    }
+}
@@ -0,0 +1,82 @@
+use std::collections::HashSet;
+use std::env;
+use std::sync::Arc;
+
+use super::error::{ErrorWrapper, FmtError};
+
+use super::gcs_storage::GcsImageStorage;
+use super::local_storage::LocalImageStorage;
+
+const IMAGE_STORAGE_ENV: &str = "IMAGE_STORAGE";
+
+#[derive(Debug, PartialEq)]
+pub enum ImageStorageType {
+    Gcs,
+    Local,
+}
+
+#[rocket::async_trait]
+pub trait ImageStorage: Send + Sync {
+    // stores content under the key and returns its public uri
+    async fn upload(
+        &self,
+        key: &String,
+        content: Vec<u8>,
+        mime_type: &String,
+    ) -> Result<String, ErrorWrapper>;
+
+    async fn delete(&self, key: &String) -> Result<(), ErrorWrapper>;
+
+    fn get_uri(&self, key: &String) -> String;
+}
+
+pub struct ImageStorageHandler;
+
+impl ImageStorageHandler {
+    pub fn get_storage_type() -> ImageStorageType {
+        match env::var(IMAGE_STORAGE_ENV) {
+            Err(_) => ImageStorageType::Gcs,
+            Ok(storage_type) => match storage_type.to_lowercase().as_str() {
+                "gcs" => ImageStorageType::Gcs,
+                "local" => ImageStorageType::Local,
+                _ => panic!("{}", &FmtError::FailedToProcess(IMAGE_STORAGE_ENV).fmt()),
+            },
+        }
+    }
+
+    pub fn get_storage() -> Arc<dyn ImageStorage> {
+        match Self::get_storage_type() {
+            ImageStorageType::Gcs => Arc::new(GcsImageStorage::new()),
+            ImageStorageType::Local => Arc::new(LocalImageStorage::new()),
+        }
+    }
+
+    // keys are generated on upload, anything else (e.g. path segments) is rejected
+    pub fn is_valid_key(key: &String) -> bool {
+        !key.is_empty() && !key.starts_with('.') && key.chars().all(|char| Self::is_key_char(char))
+    }
+
+    // content references images by uri, so a key is what follows the storage uri prefix;
+    // links elsewhere are skipped even if they end with a similar file name
+    pub fn get_content_keys(content: &str) -> Vec<String> {
+        let uri_prefix = Self::get_storage().get_uri(&String::new());
+        let mut keys: HashSet<String> = HashSet::new();
+
+        for (index, _) in content.match_indices(&uri_prefix) {
+            let key = content[index + uri_prefix.len()..]
+                .chars()
+                .take_while(|char| Self::is_key_char(*char))
+                .collect::<String>();
+
+            if Self::is_valid_key(&key) {
+                keys.insert(key);
+            }
+        }
+
+        keys.into_iter().collect()
+    }
+
+    fn is_key_char(char: char) -> bool {
+        char.is_ascii_alphanumeric() || matches!(char, '.' | '_' | '-')
+    }
+}
@@ -0,0 +1,79 @@
+use rocket::tokio::fs;
+use std::env;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+use super::error::{ErrorWrapper, FmtError};
+
+use super::image_storage::{ImageStorage, ImageStorageHandler};
+
+const IMAGE_STORAGE_PATH_ENV: &str = "IMAGE_STORAGE_PATH";
+const IMAGE_STORAGE_URL_ENV: &str = "IMAGE_STORAGE_URL";
+
+const DEFAULT_IMAGE_STORAGE_PATH: &str = "images";
+const DEFAULT_IMAGE_STORAGE_URL: &str = "/image/file";
+
+pub struct LocalImageStorage {
+    path: PathBuf,
+    url: String,
+}
+
+impl LocalImageStorage {
+    pub fn new() -> Self {
+        Self {
+            path: PathBuf::from(
+                env::var(IMAGE_STORAGE_PATH_ENV)
+                    .unwrap_or(String::from(DEFAULT_IMAGE_STORAGE_PATH)),
+            ),
+            url: env::var(IMAGE_STORAGE_URL_ENV).unwrap_or(String::from(DEFAULT_IMAGE_STORAGE_URL)),
+        }
+    }
+
+    pub fn get_path(&self, key: &String) -> Option<PathBuf> {
+        match ImageStorageHandler::is_valid_key(key) {
+            true => Some(self.path.join(key)),
+            false => None,
+        }
+    }
+}
+
+#[rocket::async_trait]
+impl ImageStorage for LocalImageStorage {
+    async fn upload(
+        &self,
+        key: &String,
+        content: Vec<u8>,
+        _mime_type: &String,
+    ) -> Result<String, ErrorWrapper> {
+        let path = match self.get_path(key) {
+            Some(path) => path,
+            None => return FmtError::FailedToProcess("image key").error(),
+        };
+
+        if fs::create_dir_all(&self.path).await.is_err() {
+            return FmtError::FailedToInsert("image").error();
+        }
+
+        match fs::write(path, content).await {
+            Ok(_) => Ok(self.get_uri(key)),
+            Err(_) => FmtError::FailedToInsert("image").error(),
+        }
+    }
+
+    async fn delete(&self, key: &String) -> Result<(), ErrorWrapper> {
+        let path = match self.get_path(key) {
+            Some(path) => path,
+            None => return FmtError::FailedToProcess("image key").error(),
+        };
+
+        match fs::remove_file(path).await {
+            Ok(_) => Ok(()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+            Err(_) => FmtError::FailedToUpdate("image").error(),
+        }
+    }
+
+    fn get_uri(&self, key: &String) -> String {
+        format!("{}/{}", self.url, key)
+    }
+}
@@ -0,0 +1,8 @@
+use super::error;
+
+mod gcs_storage;
+mod image_storage;
+mod local_storage;
+
+pub use image_storage::{ImageStorage, ImageStorageHandler, ImageStorageType};
+pub use local_storage::LocalImageStorage;
@@ -2,13 +2,20 @@ use chrono::Utc;
 use jsonwebtoken::{
     decode, encode, errors::ErrorKind, Algorithm, DecodingKey, EncodingKey, Header, Validation,
 };
+use serde::{de::DeserializeOwned, Serialize};
 use std::env;
+use uuid::Uuid;
 
-use super::dtm_common::JwtDto;
+use super::dtm_common::{ChallengeJwtDto, ChallengePurpose, JwtDto, OidcStateJwtDto};
 use super::error::{ErrorWrapper, FmtError};
 
 const JWT_SECRET_ENV: &str = "JWT_SECRET";
 const JWT_LIVE_SEC_ENV: &str = "JWT_LIVE_SEC";
+const REFRESH_TOKEN_LIVE_SEC_ENV: &str = "REFRESH_TOKEN_LIVE_SEC";
+const CHALLENGE_LIVE_SEC_ENV: &str = "CHALLENGE_LIVE_SEC";
+
+const DEFAULT_REFRESH_TOKEN_LIVE_SEC: i32 = 60 * 60 * 24 * 30;
+const DEFAULT_CHALLENGE_LIVE_SEC: i64 = 60 * 5;
 
 pub struct JwtHandler;
 
@@ -23,26 +30,93 @@ impl JwtHandler {
             .parse::<i64>()
             .expect(&FmtError::FailedToProcess(JWT_LIVE_SEC_ENV).fmt());
 
+        Self::get_expiration_after(live_sec)
+    }
+
+    fn get_challenge_expiration() -> i64 {
+        let live_sec = match env::var(CHALLENGE_LIVE_SEC_ENV) {
+            Ok(live_sec) => live_sec
+                .parse::<i64>()
+                .expect(&FmtError::FailedToProcess(CHALLENGE_LIVE_SEC_ENV).fmt()),
+            Err(_) => DEFAULT_CHALLENGE_LIVE_SEC,
+        };
+
+        Self::get_expiration_after(live_sec)
+    }
+
+    fn get_expiration_after(live_sec: i64) -> i64 {
         Utc::now()
             .checked_add_signed(chrono::Duration::seconds(live_sec))
             .expect(&FmtError::FailedToProcess("timestamp").fmt())
             .timestamp()
     }
 
-    pub fn encode_jwt(user_id: i32) -> Result<String, ErrorWrapper> {
-        let secret = Self::get_secret();
+    pub fn get_refresh_live_sec() -> i32 {
+        match env::var(REFRESH_TOKEN_LIVE_SEC_ENV) {
+            Ok(live_sec) => live_sec
+                .parse::<i32>()
+                .expect(&FmtError::FailedToProcess(REFRESH_TOKEN_LIVE_SEC_ENV).fmt()),
+            Err(_) => DEFAULT_REFRESH_TOKEN_LIVE_SEC,
+        }
+    }
+
+    pub fn generate_refresh_token() -> String {
+        format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple())
+    }
+
+    pub fn encode_jwt(user_id: i32, session_id: i32) -> Result<String, ErrorWrapper> {
         let expiration = Self::get_expiration();
 
-        let claims = JwtDto {
+        Self::encode_claims(&JwtDto {
             user_id,
+            session_id,
             exp: expiration as usize,
-        };
+        })
+    }
 
+    // challenge tokens have no session, so they can't be used as access tokens (and vice versa)
+    pub fn encode_challenge_jwt(
+        user_id: i32,
+        purpose: ChallengePurpose,
+    ) -> Result<String, ErrorWrapper> {
+        let expiration = Self::get_challenge_expiration();
+
+        Self::encode_claims(&ChallengeJwtDto {
+            user_id,
+            purpose,
+            exp: expiration as usize,
+        })
+    }
+
+    // signed state keeps the oidc flow stateless on our side
+    pub fn encode_oidc_state_jwt(provider: String) -> Result<String, ErrorWrapper> {
+        let expiration = Self::get_challenge_expiration();
+
+        Self::encode_claims(&OidcStateJwtDto {
+            provider,
+            exp: expiration as usize,
+        })
+    }
+
+    pub fn decode_jwt(token: String) -> Result<JwtDto, ErrorKind> {
+        Self::decode_claims::<JwtDto>(token)
+    }
+
+    pub fn decode_challenge_jwt(token: String) -> Result<ChallengeJwtDto, ErrorKind> {
+        Self::decode_claims::<ChallengeJwtDto>(token)
+    }
+
+    pub fn decode_oidc_state_jwt(token: String) -> Result<OidcStateJwtDto, ErrorKind> {
+        Self::decode_claims::<OidcStateJwtDto>(token)
+    }
+
+    fn encode_claims<T: Serialize>(claims: &T) -> Result<String, ErrorWrapper> {
+        let secret = Self::get_secret();
         let header = Header::new(Algorithm::HS512);
 
         match encode(
             &header,
-            &claims,
+            claims,
             &EncodingKey::from_secret(secret.as_bytes()),
         ) {
             Ok(jwt_string) => Ok(jwt_string),
@@ -50,11 +124,11 @@ impl JwtHandler {
         }
     }
 
-    pub fn decode_jwt(token: String) -> Result<JwtDto, ErrorKind> {
+    fn decode_claims<T: DeserializeOwned>(token: String) -> Result<T, ErrorKind> {
         let secret = Self::get_secret();
         let token = token.trim_start_matches("Bearer").trim();
 
-        match decode::<JwtDto>(
+        match decode::<T>(
             &token,
             &DecodingKey::from_secret(secret.as_bytes()),
             &Validation::new(Algorithm::HS512),
@@ -4,16 +4,22 @@ use rocket_okapi::{
     settings::UrlObject,
     swagger_ui::{make_swagger_ui, SwaggerUIConfig},
 };
+use std::sync::Arc;
 use std::{env, fs};
 
 use rocket::{
-    fairing::{Fairing, Info, Kind},
-    options,
+    fairing::{AdHoc, Fairing, Info, Kind},
+    options, tokio,
 };
 use rocket::{http::Header, routes};
 use rocket::{Request, Response};
 
+use super::authorization::TrustedProxies;
+use super::emailer::{EmailTransport, EmailTransportHandler};
 use super::error::FmtError;
+use super::image_storage::{ImageStorageHandler, ImageStorageType};
+use super::service::version_content::VersionKeyframeInterval;
+use super::service::watch::{WatchDigestInterval, WatchService};
 use super::{repository, router};
 
 const SA_ENV: &str = "SA";
@@ -25,18 +31,42 @@ fn get_docs() -> SwaggerUIConfig {
                 name: "article version".to_string(),
                 url: "/articles/article_version.json".to_string(),
             },
+            UrlObject {
+                name: "article draft".to_string(),
+                url: "/articles/article_draft.json".to_string(),
+            },
             UrlObject {
                 name: "article language".to_string(),
                 url: "/articles/article_language.json".to_string(),
             },
+            UrlObject {
+                name: "language".to_string(),
+                url: "/languages/languages.json".to_string(),
+            },
             UrlObject {
                 name: "article".to_string(),
                 url: "/articles/article.json".to_string(),
             },
+            UrlObject {
+                name: "category".to_string(),
+                url: "/categories/category.json".to_string(),
+            },
+            UrlObject {
+                name: "image".to_string(),
+                url: "/image/image.json".to_string(),
+            },
             UrlObject {
                 name: "auth".to_string(),
                 url: "/auth/auth.json".to_string(),
             },
+            UrlObject {
+                name: "email template".to_string(),
+                url: "/email-templates/email_template.json".to_string(),
+            },
+            UrlObject {
+                name: "watch".to_string(),
+                url: "/watches/watch.json".to_string(),
+            },
         ],
 
         display_operation_id: true,
@@ -75,7 +105,12 @@ impl Fairing for CORS {
 #[options("/<_..>")]
 fn all_options() {}
 
+// service account is required by gcs image storage only
 fn create_sa_file() {
+    if ImageStorageHandler::get_storage_type() != ImageStorageType::Gcs {
+        return;
+    }
+
     let sa = env::var(SA_ENV).expect(&FmtError::EmptyValue(SA_ENV).fmt());
     let decoded = general_purpose::STANDARD_NO_PAD
         .decode(sa)
@@ -86,6 +121,45 @@ fn create_sa_file() {
     fs::write("service-account.json", str).expect("Unable to write file");
 }
 
+// sends pending watch digests every interval while the app is running
+fn schedule_watch_digests(digest_interval: WatchDigestInterval) -> AdHoc {
+    AdHoc::on_liftoff("Watch digests", move |rocket| {
+        Box::pin(async move {
+            let duration = match digest_interval.get_duration() {
+                Some(duration) => duration,
+                None => return,
+            };
+
+            let pool = repository::PgConnection::pool(rocket)
+                .expect(&FmtError::NotFound("database pool").fmt())
+                .clone();
+            let email_transport = Arc::clone(
+                rocket
+                    .state::<Arc<dyn EmailTransport>>()
+                    .expect(&FmtError::NotFound("email transport").fmt()),
+            );
+
+            tokio::spawn(async move {
+                let mut interval = tokio::time::interval(duration);
+
+                // the first tick completes immediately, digests are sent after a full interval
+                interval.tick().await;
+
+                loop {
+                    interval.tick().await;
+
+                    match repository::PgConnection::get_from_pool(&pool).await {
+                        Some(connection) => {
+                            WatchService::send_digests(&connection, &email_transport).await;
+                        }
+                        None => eprintln!("{}", FmtError::FailedToFetch("connection").fmt()),
+                    }
+                }
+            });
+        })
+    })
+}
+
 pub fn launch() -> rocket::Rocket<rocket::Build> {
     dotenv().ok();
 
@@ -94,11 +168,19 @@ pub fn launch() -> rocket::Rocket<rocket::Build> {
     rocket::build()
         .attach(repository::PgConnection::fairing())
         .attach(CORS)
+        .attach(schedule_watch_digests(WatchDigestInterval::from_env()))
+        .manage(VersionKeyframeInterval::from_env())
+        .manage(TrustedProxies::from_env())
+        .manage(EmailTransportHandler::get_transport())
         .mount("/articles", router::article::routes())
         .mount("/articles", router::article_language::routes())
         .mount("/articles", router::article_version::routes())
+        .mount("/articles", router::article_draft::routes())
         .mount("/languages", router::language::routes())
+        .mount("/categories", router::category::routes())
         .mount("/auth", router::auth::routes())
+        .mount("/email-templates", router::email_template::routes())
+        .mount("/watches", router::watch::routes())
         .mount("/image", router::image::routes())
         .mount("/swagger", make_swagger_ui(&get_docs()))
         .mount("/", routes![all_options,])
@@ -6,12 +6,16 @@ mod dtm_common;
 mod emailer;
 mod error;
 mod hasher;
+mod image_processor;
+mod image_storage;
 mod jwt_handler;
 mod launch;
 mod mapper;
+mod oidc_handler;
 mod repository;
 mod router;
 mod service;
+mod totp_handler;
 mod trait_common;
 
 #[cfg(test)]
@@ -0,0 +1,5 @@
+use super::error;
+
+mod oidc_handler;
+
+pub use oidc_handler::{OidcHandler, OidcProvider, OidcUserInfo};
@@ -0,0 +1,160 @@
+use reqwest::{RequestBuilder, Url};
+use serde::{de::DeserializeOwned, Deserialize};
+use std::env;
+
+use super::error::{ErrorWrapper, FmtError};
+
+const OIDC_PROVIDERS_ENV: &str = "OIDC_PROVIDERS";
+
+const OIDC_SCOPES: &str = "openid email profile";
+
+pub struct OidcProvider {
+    pub name: String,
+    pub issuer: String,
+    pub client_id: String,
+    pub client_secret: String,
+    pub redirect_uri: String,
+}
+
+#[derive(Deserialize)]
+struct OidcDiscovery {
+    issuer: String,
+    authorization_endpoint: String,
+    token_endpoint: String,
+    userinfo_endpoint: String,
+}
+
+#[derive(Deserialize)]
+struct OidcTokenResponse {
+    access_token: String,
+}
+
+#[derive(Deserialize)]
+pub struct OidcUserInfo {
+    pub sub: String,
+    pub email: Option<String>,
+    pub email_verified: Option<bool>,
+    pub name: Option<String>,
+    pub preferred_username: Option<String>,
+}
+
+pub struct OidcHandler;
+
+impl OidcHandler {
+    // OIDC_PROVIDERS is a comma separated list, each provider is configured by OIDC_<NAME>_* envs
+    pub fn get_provider(name: &String) -> Result<OidcProvider, ErrorWrapper> {
+        let is_configured = env::var(OIDC_PROVIDERS_ENV)
+            .map(|providers| providers.split(',').any(|provider| provider.trim() == name))
+            .unwrap_or(false);
+
+        if !is_configured {
+            return FmtError::NotFound("oidc provider").error();
+        }
+
+        Ok(OidcProvider {
+            name: name.clone(),
+            issuer: Self::get_provider_env(name, "ISSUER")?
+                .trim_end_matches('/')
+                .to_owned(),
+            client_id: Self::get_provider_env(name, "CLIENT_ID")?,
+            client_secret: Self::get_provider_env(name, "CLIENT_SECRET")?,
+            redirect_uri: Self::get_provider_env(name, "REDIRECT_URI")?,
+        })
+    }
+
+    pub async fn get_authorization_url(
+        provider: &OidcProvider,
+        state: String,
+    ) -> Result<String, ErrorWrapper> {
+        let discovery = Self::discover(provider).await?;
+
+        let mut url = match Url::parse(&discovery.authorization_endpoint) {
+            Ok(url) => url,
+            Err(_) => return FmtError::FailedToProcess("oidc authorization_endpoint").error(),
+        };
+
+        url.query_pairs_mut()
+            .append_pair("response_type", "code")
+            .append_pair("client_id", &provider.client_id)
+            .append_pair("redirect_uri", &provider.redirect_uri)
+            .append_pair("scope", OIDC_SCOPES)
+            .append_pair("state", &state);
+
+        Ok(url.to_string())
+    }
+
+    // exchanges authorization code and fetches the user claims with the issued access token
+    pub async fn get_user_info(
+        provider: &OidcProvider,
+        code: String,
+    ) -> Result<OidcUserInfo, ErrorWrapper> {
+        let discovery = Self::discover(provider).await?;
+        let client = Self::get_client();
+
+        let token_response =
+            Self::fetch_json::<OidcTokenResponse>(client.post(&discovery.token_endpoint).form(&[
+                ("grant_type", "authorization_code"),
+                ("code", code.as_str()),
+                ("redirect_uri", provider.redirect_uri.as_str()),
+                ("client_id", provider.client_id.as_str()),
+                ("client_secret", provider.client_secret.as_str()),
+            ]))
+            .await?;
+
+        Self::fetch_json::<OidcUserInfo>(
+            client
+                .get(&discovery.userinfo_endpoint)
+                .bearer_auth(token_response.access_token),
+        )
+        .await
+    }
+
+    fn get_provider_env(name: &str, key: &str) -> Result<String, ErrorWrapper> {
+        let env_name = format!("OIDC_{}_{}", name.to_uppercase(), key);
+
+        match env::var(&env_name) {
+            Ok(value) => Ok(value),
+            Err(_) => FmtError::EmptyValue(&env_name).error(),
+        }
+    }
+
+    fn get_client() -> reqwest::Client {
+        reqwest::Client::builder()
+            .build()
+            .expect(&FmtError::FailedToProcess("oidc client").fmt())
+    }
+
+    async fn discover(provider: &OidcProvider) -> Result<OidcDiscovery, ErrorWrapper> {
+        let discovery = Self::fetch_json::<OidcDiscovery>(Self::get_client().get(format!(
+            "{}/.well-known/openid-configuration",
+            provider.issuer
+        )))
+        .await?;
+
+        if discovery.issuer.trim_end_matches('/') != provider.issuer {
+            return FmtError::FailedToProcess("oidc issuer").error();
+        }
+
+        Ok(discovery)
+    }
+
+    async fn fetch_json<T: DeserializeOwned>(request: RequestBuilder) -> Result<T, ErrorWrapper> {
+        let response = match request.header("accept", "application/json").send().await {
+            Ok(response) => response,
+            Err(err) => {
+                eprintln!("{}", err);
+
+                return FmtError::FailedToSendRequest("oidc provider").error();
+            }
+        };
+
+        if !response.status().is_success() {
+            return FmtError::Unauthorized("rejected by oidc provider").error();
+        }
+
+        match response.json::<T>().await {
+            Ok(body) => Ok(body),
+            Err(_) => FmtError::FailedToProcess("oidc provider response").error(),
+        }
+    }
+}
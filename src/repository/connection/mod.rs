@@ -1,4 +1,4 @@
-use rocket_sync_db_pools::{database, diesel};
+use rocket_sync_db_pools::{database, diesel, ConnectionPool};
 
 use rocket_okapi::{
     gen::OpenApiGenerator,
@@ -9,6 +9,15 @@ use rocket_okapi::{
 #[database("diesel")]
 pub struct PgConnection(diesel::pg::PgConnection);
 
+impl PgConnection {
+    // for work outside of requests (scheduled tasks), the pool is taken from rocket on liftoff
+    pub async fn get_from_pool(
+        pool: &ConnectionPool<Self, diesel::pg::PgConnection>,
+    ) -> Option<Self> {
+        pool.get().await.map(Self)
+    }
+}
+
 impl<'a, 'r> OpenApiFromRequest<'a> for PgConnection {
     fn from_request_input(
         _gen: &mut OpenApiGenerator,
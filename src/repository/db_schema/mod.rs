@@ -10,6 +10,22 @@ pub mod sql_types {
     #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
     #[diesel(postgres_type(name = "otp_type"))]
     pub struct OTPType;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "email_template_type"))]
+    pub struct EmailTemplateType;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "watch_delivery"))]
+    pub struct WatchDelivery;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "watch_event_type"))]
+    pub struct WatchEventType;
+
+    #[derive(diesel::sql_types::SqlType, diesel::query_builder::QueryId)]
+    #[diesel(postgres_type(name = "text_direction"))]
+    pub struct TextDirection;
 }
 
 diesel::table! {
@@ -56,6 +72,74 @@ diesel::table! {
         created_at -> Timestamp,
         updated_by -> Nullable<Int4>,
         created_by -> Int4,
+        source_version_id -> Nullable<Int4>,
+    }
+}
+
+diesel::table! {
+    article_draft (id) {
+        id -> Int4,
+        article_language_id -> Int4,
+        user_id -> Int4,
+        name -> Nullable<Varchar>,
+        content -> Text,
+        base_version -> Int4,
+        updated_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    category (id) {
+        id -> Int4,
+        name -> Varchar,
+        updated_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        updated_by -> Nullable<Int4>,
+        created_by -> Int4,
+    }
+}
+
+diesel::table! {
+    article_category (id) {
+        id -> Int4,
+        article_id -> Int4,
+        category_id -> Int4,
+        created_at -> Timestamp,
+        created_by -> Int4,
+    }
+}
+
+diesel::table! {
+    image (id) {
+        id -> Int4,
+        storage_key -> Varchar,
+        mime_type -> Varchar,
+        size -> Int4,
+        width -> Nullable<Int4>,
+        height -> Nullable<Int4>,
+        created_at -> Timestamp,
+        created_by -> Int4,
+    }
+}
+
+diesel::table! {
+    image_rendition (id) {
+        id -> Int4,
+        image_id -> Int4,
+        name -> Varchar,
+        storage_key -> Varchar,
+        mime_type -> Varchar,
+        size -> Int4,
+        width -> Nullable<Int4>,
+        height -> Nullable<Int4>,
+    }
+}
+
+diesel::table! {
+    image_reference (image_id, article_version_id) {
+        image_id -> Int4,
+        article_version_id -> Int4,
     }
 }
 
@@ -75,10 +159,12 @@ diesel::table! {
 
     user_otp (id) {
         id -> Int4,
-        otp -> Varchar,
+        otp_hash -> Varchar,
         user_id -> Int4,
         otp_type -> OTPType,
         created_at -> Timestamp,
+        attempts -> Int4,
+        expires_at -> Timestamp,
     }
 }
 
@@ -93,6 +179,127 @@ diesel::table! {
         updated_at -> Nullable<Timestamp>,
         created_at -> Timestamp,
         updated_by -> Nullable<Int4>,
+        language_id -> Nullable<Int4>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::WatchDelivery;
+
+    watch (id) {
+        id -> Int4,
+        user_id -> Int4,
+        article_id -> Int4,
+        article_language_id -> Nullable<Int4>,
+        delivery -> WatchDelivery,
+        unsubscribe_key -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::WatchEventType;
+
+    watch_notification (id) {
+        id -> Int4,
+        watch_id -> Int4,
+        event_type -> WatchEventType,
+        name -> Varchar,
+        language_code -> Nullable<Varchar>,
+        version -> Nullable<Int4>,
+        created_at -> Timestamp,
+        sent_at -> Nullable<Timestamp>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::EmailTemplateType;
+
+    email_template (id) {
+        id -> Int4,
+        template_type -> EmailTemplateType,
+        language_id -> Int4,
+        subject -> Varchar,
+        html -> Text,
+        text -> Text,
+        updated_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        updated_by -> Nullable<Int4>,
+    }
+}
+
+diesel::table! {
+    user_session (id) {
+        id -> Int4,
+        user_id -> Int4,
+        refresh_token_hash -> Varchar,
+        replaced_by -> Nullable<Int4>,
+        expires_at -> Timestamp,
+        revoked_at -> Nullable<Timestamp>,
+        updated_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    user_totp (id) {
+        id -> Int4,
+        user_id -> Int4,
+        secret -> Varchar,
+        confirmed -> Bool,
+        last_used_step -> Nullable<Int8>,
+        updated_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    user_recovery_code (id) {
+        id -> Int4,
+        user_id -> Int4,
+        code_hash -> Varchar,
+        used_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    user_api_token (id) {
+        id -> Int4,
+        user_id -> Int4,
+        name -> Varchar,
+        token_hash -> Varchar,
+        scopes -> Array<Text>,
+        expires_at -> Nullable<Timestamp>,
+        last_used_at -> Nullable<Timestamp>,
+        revoked_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    user_identity (id) {
+        id -> Int4,
+        user_id -> Int4,
+        provider -> Varchar,
+        subject -> Varchar,
+        email -> Nullable<Varchar>,
+        updated_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    login_failure (id) {
+        id -> Int4,
+        subject -> Varchar,
+        failed_count -> Int4,
+        locked_until -> Nullable<Timestamp>,
+        updated_at -> Timestamp,
+        created_at -> Timestamp,
     }
 }
 
@@ -104,9 +311,22 @@ diesel::table! {
 }
 
 diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::TextDirection;
+
     language (id) {
         id -> Int4,
         code -> Varchar,
+        name -> Varchar,
+        native_name -> Varchar,
+        direction -> TextDirection,
+        bcp47 -> Varchar,
+        fallback_language_id -> Nullable<Int4>,
+        enabled -> Bool,
+        updated_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        updated_by -> Nullable<Int4>,
+        created_by -> Nullable<Int4>,
     }
 }
 
@@ -129,20 +349,66 @@ diesel::joinable!(article_version -> article_language (article_language_id));
 diesel::joinable!(article_version -> version_content (content_id));
 diesel::joinable!(article_version -> user_account (created_by));
 
+diesel::joinable!(article_draft -> article_language (article_language_id));
+diesel::joinable!(article_draft -> user_account (user_id));
+
+diesel::joinable!(article_category -> article (article_id));
+diesel::joinable!(article_category -> category (category_id));
+
+diesel::joinable!(image -> user_account (created_by));
+
+diesel::joinable!(image_rendition -> image (image_id));
+
+diesel::joinable!(image_reference -> image (image_id));
+diesel::joinable!(image_reference -> article_version (article_version_id));
+
 diesel::joinable!(user_otp -> user_account (user_id));
 
 diesel::joinable!(user_password -> user_account (user_id));
 
+diesel::joinable!(user_session -> user_account (user_id));
+
+diesel::joinable!(user_totp -> user_account (user_id));
+
+diesel::joinable!(user_recovery_code -> user_account (user_id));
+
+diesel::joinable!(user_identity -> user_account (user_id));
+
+diesel::joinable!(user_api_token -> user_account (user_id));
+
 diesel::joinable!(user_account -> user_role (role_id));
 
+diesel::joinable!(email_template -> language (language_id));
+
+diesel::joinable!(watch -> user_account (user_id));
+diesel::joinable!(watch -> article (article_id));
+diesel::joinable!(watch -> article_language (article_language_id));
+
+diesel::joinable!(watch_notification -> watch (watch_id));
+
 diesel::allow_tables_to_appear_in_same_query!(
     article,
     article_language,
     article_version,
+    article_draft,
     version_content,
     language,
+    category,
+    article_category,
+    image,
+    image_rendition,
+    image_reference,
     user_password,
     user_otp,
+    user_session,
+    user_totp,
+    user_recovery_code,
+    user_identity,
+    user_api_token,
+    login_failure,
+    email_template,
+    watch,
+    watch_notification,
     user_account,
     user_role,
 );
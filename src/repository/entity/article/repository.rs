@@ -1,6 +1,6 @@
 use diesel::prelude::*;
 
-use super::dtm_common::QueryOptions;
+use super::dtm_common::{PaginationOptions, QueryOptions};
 use super::error::FmtError;
 
 use super::connection::PgConnection;
@@ -9,7 +9,7 @@ use super::model;
 
 use super::article_language::ArticleLanguage;
 
-use super::dtm::article::dto::{ArticleCreateDto, ArticlePatchDto};
+use super::dtm::article::dto::{ArticleCreateDto, ArticlePatchDto, ArticlesSearchDto};
 
 pub struct ArticleRepository;
 
@@ -68,23 +68,48 @@ impl ArticleRepository {
 
     pub async fn get_many(
         connection: &PgConnection,
+        search_dto: ArticlesSearchDto,
+        pagination: PaginationOptions,
         query_options: &QueryOptions,
     ) -> Vec<model::Article> {
         let is_actual = query_options.is_actual;
 
         connection
             .run(move |connection| {
-                let query = db_schema::article::table;
+                let mut query = db_schema::article::table.into_boxed();
+
+                if let Some(category_id) = search_dto.category_id {
+                    query = query.filter(
+                        db_schema::article::id.eq_any(
+                            db_schema::article_category::table
+                                .filter(db_schema::article_category::category_id.eq(category_id))
+                                .select(db_schema::article_category::article_id),
+                        ),
+                    );
+                }
+
+                if let Some(cursor) = &pagination.cursor {
+                    query = query.filter(
+                        db_schema::article::created_at.lt(cursor.created_at).or(
+                            db_schema::article::created_at
+                                .eq(cursor.created_at)
+                                .and(db_schema::article::id.lt(cursor.id)),
+                        ),
+                    );
+                }
 
                 if is_actual {
-                    return query
+                    query = query
                         .filter(db_schema::article::enabled.eq(true))
-                        .filter(db_schema::article::archived.eq(false))
-                        .load(connection);
+                        .filter(db_schema::article::archived.eq(false));
                 }
 
                 query
-                    .order(db_schema::article::created_at.desc())
+                    .order((
+                        db_schema::article::created_at.desc(),
+                        db_schema::article::id.desc(),
+                    ))
+                    .limit(pagination.get_fetch_limit())
                     .load(connection)
             })
             .await
@@ -0,0 +1,10 @@
+use super::connection;
+use super::db_schema;
+use super::dtm;
+use super::error;
+
+mod model;
+mod repository;
+
+pub use self::model::ArticleDraft;
+pub use self::repository::ArticleDraftRepository;
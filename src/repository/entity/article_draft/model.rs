@@ -0,0 +1,35 @@
+use chrono::NaiveDateTime;
+use diesel::{Insertable, Queryable, Selectable};
+use rocket::serde::{Deserialize, Serialize};
+use rocket_sync_db_pools::diesel;
+
+use super::db_schema::article_draft;
+
+#[derive(Queryable, Debug, Serialize, Deserialize, Selectable)]
+#[diesel(table_name = article_draft)]
+pub struct ArticleDraft {
+    pub id: i32,
+
+    pub article_language_id: i32,
+    pub user_id: i32,
+
+    pub name: Option<String>,
+    pub content: String,
+
+    pub base_version: i32,
+
+    pub updated_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = article_draft)]
+pub struct ArticleDraftInsertable {
+    pub article_language_id: i32,
+    pub user_id: i32,
+
+    pub name: Option<String>,
+    pub content: String,
+
+    pub base_version: i32,
+}
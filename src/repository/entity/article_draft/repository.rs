@@ -0,0 +1,89 @@
+use diesel::{dsl::now, prelude::*};
+
+use super::connection::PgConnection;
+use super::error::FmtError;
+
+use super::db_schema;
+use super::model;
+
+use super::dtm::article_draft::dto::ArticleDraftUpsertDto;
+
+pub struct ArticleDraftRepository;
+
+impl ArticleDraftRepository {
+    pub async fn get_one(
+        connection: &PgConnection,
+        article_language_id: i32,
+        user_id: i32,
+    ) -> Option<model::ArticleDraft> {
+        connection
+            .run(move |connection| {
+                db_schema::article_draft::table
+                    .filter(db_schema::article_draft::article_language_id.eq(article_language_id))
+                    .filter(db_schema::article_draft::user_id.eq(user_id))
+                    .first(connection)
+                    .optional()
+            })
+            .await
+            .expect(&FmtError::FailedToFetch("article_draft").fmt())
+    }
+
+    // autosave: the base version is set once, when the draft is started, unless it is rebased
+    pub async fn upsert(
+        connection: &PgConnection,
+        upsert_dto: ArticleDraftUpsertDto,
+    ) -> model::ArticleDraft {
+        connection
+            .run(move |connection| {
+                let insert_query = diesel::insert_into(db_schema::article_draft::table)
+                    .values(model::ArticleDraftInsertable {
+                        article_language_id: upsert_dto.article_language_id,
+                        user_id: upsert_dto.user_id,
+                        name: upsert_dto.name.clone(),
+                        content: upsert_dto.content.clone(),
+                        base_version: upsert_dto.base_version,
+                    })
+                    .on_conflict((
+                        db_schema::article_draft::article_language_id,
+                        db_schema::article_draft::user_id,
+                    ))
+                    .do_update();
+
+                match upsert_dto.is_rebase {
+                    true => insert_query
+                        .set((
+                            db_schema::article_draft::name.eq(upsert_dto.name),
+                            db_schema::article_draft::content.eq(upsert_dto.content),
+                            db_schema::article_draft::base_version.eq(upsert_dto.base_version),
+                            db_schema::article_draft::updated_at.eq(now.nullable()),
+                        ))
+                        .get_result::<model::ArticleDraft>(connection),
+                    false => insert_query
+                        .set((
+                            db_schema::article_draft::name.eq(upsert_dto.name),
+                            db_schema::article_draft::content.eq(upsert_dto.content),
+                            db_schema::article_draft::updated_at.eq(now.nullable()),
+                        ))
+                        .get_result::<model::ArticleDraft>(connection),
+                }
+            })
+            .await
+            .expect(&FmtError::FailedToInsert("article_draft").fmt())
+    }
+
+    pub async fn delete(
+        connection: &PgConnection,
+        article_language_id: i32,
+        user_id: i32,
+    ) -> usize {
+        connection
+            .run(move |connection| {
+                diesel::delete(db_schema::article_draft::table)
+                    .filter(db_schema::article_draft::article_language_id.eq(article_language_id))
+                    .filter(db_schema::article_draft::user_id.eq(user_id))
+                    .execute(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToUpdate("article_draft").fmt())
+    }
+}
@@ -9,5 +9,5 @@ use super::language;
 mod model;
 mod repository;
 
-pub use self::model::ArticleLanguage;
+pub use self::model::{ArticleLanguage, ArticleLanguageSearch};
 pub use self::repository::ArticleLanguageRepository;
@@ -1,5 +1,5 @@
 use chrono::NaiveDateTime;
-use diesel::{AsChangeset, Insertable, Queryable, Selectable};
+use diesel::{sql_types, AsChangeset, Insertable, Queryable, QueryableByName, Selectable};
 use rocket::serde::{Deserialize, Serialize};
 use rocket_sync_db_pools::diesel;
 
@@ -64,3 +64,24 @@ pub struct ArticleLanguage {
     pub updated_by: Option<i32>,
     pub created_by: i32,
 }
+
+// article_language_search is managed by raw queries only (tsvector isn't mapped in db_schema)
+#[derive(Debug, Serialize, Deserialize, QueryableByName)]
+pub struct ArticleLanguageSearch {
+    #[diesel(sql_type = sql_types::Integer)]
+    pub article_language_id: i32,
+    #[diesel(sql_type = sql_types::Integer)]
+    pub article_id: i32,
+
+    #[diesel(sql_type = sql_types::Varchar)]
+    pub name: String,
+    #[diesel(sql_type = sql_types::Varchar)]
+    pub name_key: String,
+    #[diesel(sql_type = sql_types::Varchar)]
+    pub language_code: String,
+
+    #[diesel(sql_type = sql_types::Float4)]
+    pub rank: f32,
+    #[diesel(sql_type = sql_types::Text)]
+    pub snippet: String,
+}
@@ -1,4 +1,4 @@
-use diesel::prelude::*;
+use diesel::{prelude::*, sql_query, sql_types};
 
 use super::connection::PgConnection;
 use super::error::FmtError;
@@ -6,12 +6,14 @@ use super::error::FmtError;
 use super::db_schema;
 use super::model;
 
-use super::dtm_common::QueryOptions;
+use super::dtm_common::{PaginationOptions, QueryOptions};
 
 use super::article::Article;
 use super::language::Language;
 
-use super::dtm::article_language::dto::{ArticleLanguageCreateDto, ArticleLanguagePatchDto};
+use super::dtm::article_language::dto::{
+    ArticleLanguageContentSearchDto, ArticleLanguageCreateDto, ArticleLanguagePatchDto,
+};
 
 pub struct ArticleLanguageRepository;
 
@@ -118,6 +120,50 @@ impl ArticleLanguageRepository {
             .expect(&FmtError::FailedToFetch("article_languages").fmt())
     }
 
+    pub async fn get_page(
+        connection: &PgConnection,
+        article_id: i32,
+        pagination: PaginationOptions,
+        query_options: &QueryOptions,
+    ) -> Vec<model::ArticleLanguage> {
+        let is_actual = query_options.is_actual;
+
+        connection
+            .run(move |connection| {
+                let mut query = db_schema::article_language::table
+                    .filter(db_schema::article_language::article_id.eq(article_id))
+                    .into_boxed();
+
+                if let Some(cursor) = &pagination.cursor {
+                    query = query.filter(
+                        db_schema::article_language::created_at
+                            .lt(cursor.created_at)
+                            .or(db_schema::article_language::created_at
+                                .eq(cursor.created_at)
+                                .and(db_schema::article_language::id.lt(cursor.id))),
+                    );
+                }
+
+                if is_actual {
+                    query = query.filter(
+                        db_schema::article_language::enabled
+                            .eq(true)
+                            .and(db_schema::article_language::archived.eq(false)),
+                    );
+                }
+
+                query
+                    .order((
+                        db_schema::article_language::created_at.desc(),
+                        db_schema::article_language::id.desc(),
+                    ))
+                    .limit(pagination.get_fetch_limit())
+                    .load(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToFetch("article_languages").fmt())
+    }
+
     pub async fn get_many_by_query(
         connection: &PgConnection,
         article_language_query: String,
@@ -152,6 +198,121 @@ impl ArticleLanguageRepository {
             .expect(&FmtError::FailedToFetch("article_languages").fmt())
     }
 
+    pub async fn get_many_by_content(
+        connection: &PgConnection,
+        search_dto: ArticleLanguageContentSearchDto,
+        query_options: &QueryOptions,
+    ) -> Vec<model::ArticleLanguageSearch> {
+        let is_actual = query_options.is_actual;
+
+        connection
+            .run(move |connection| {
+                sql_query(
+                    r#"
+                    SELECT
+                        article_language.id AS article_language_id,
+                        article_language.article_id,
+                        article_language.name,
+                        article_language.name_key,
+                        language.code AS language_code,
+                        ts_rank_cd(article_search.search_vector, search_query) AS rank,
+                        ts_headline(
+                            article_search.search_config,
+                            article_search.content,
+                            search_query,
+                            'MaxFragments=2, MaxWords=20, MinWords=5'
+                        ) AS snippet
+                    FROM article_language_search article_search
+                    INNER JOIN article_language ON article_language.id = article_search.article_language_id
+                    INNER JOIN language ON language.id = article_language.language_id
+                    INNER JOIN article ON article.id = article_language.article_id
+                    CROSS JOIN LATERAL websearch_to_tsquery(article_search.search_config, $1) search_query
+                    WHERE article_search.search_vector @@ search_query
+                        AND ($2::varchar IS NULL OR language.code = $2)
+                        AND (
+                            $3 = false
+                            OR (
+                                article_language.enabled = true
+                                AND article_language.archived = false
+                                AND article.enabled = true
+                                AND article.archived = false
+                            )
+                        )
+                    ORDER BY rank DESC, article_language.id DESC
+                    LIMIT $4
+                    ;"#,
+                )
+                .bind::<sql_types::Text, _>(search_dto.query)
+                .bind::<sql_types::Nullable<sql_types::Varchar>, _>(search_dto.language_code)
+                .bind::<sql_types::Bool, _>(is_actual)
+                .bind::<sql_types::BigInt, _>(search_dto.limit)
+                .load::<model::ArticleLanguageSearch>(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToFetch("article_languages_search").fmt())
+    }
+
+    // locks the row until the end of the transaction, so versions of a language are created one by one
+    pub fn get_one_for_update_raw(
+        connection: &mut diesel::PgConnection,
+        id: i32,
+    ) -> Result<model::ArticleLanguage, diesel::result::Error> {
+        db_schema::article_language::table
+            .filter(db_schema::article_language::id.eq(id))
+            .for_update()
+            .first(connection)
+    }
+
+    pub async fn upsert_search_content(
+        connection: &PgConnection,
+        article_language_id: i32,
+        content: String,
+    ) -> usize {
+        connection
+            .run(move |connection| {
+                Self::upsert_search_content_raw(connection, article_language_id, content)
+            })
+            .await
+            .expect(&FmtError::FailedToUpdate("article_language_search").fmt())
+    }
+
+    pub async fn delete_search_content(
+        connection: &PgConnection,
+        article_language_id: i32,
+    ) -> usize {
+        connection
+            .run(move |connection| {
+                sql_query("DELETE FROM article_language_search WHERE article_language_id = $1;")
+                    .bind::<sql_types::Integer, _>(article_language_id)
+                    .execute(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToUpdate("article_language_search").fmt())
+    }
+
+    pub fn upsert_search_content_raw(
+        connection: &mut diesel::PgConnection,
+        article_language_id: i32,
+        content: String,
+    ) -> Result<usize, diesel::result::Error> {
+        sql_query(
+            r#"
+            INSERT INTO article_language_search (article_language_id, search_config, content)
+            SELECT article_language.id, language_search_config(language.code), $2
+            FROM article_language
+            INNER JOIN language ON language.id = article_language.language_id
+            WHERE article_language.id = $1
+            ON CONFLICT (article_language_id) DO UPDATE
+            SET search_config = EXCLUDED.search_config,
+                content = EXCLUDED.content,
+                updated_at = NOW()
+            ;"#,
+        )
+        .bind::<sql_types::Integer, _>(article_language_id)
+        .bind::<sql_types::Text, _>(content)
+        .execute(connection)
+    }
+
     pub fn insert_raw(
         connection: &mut diesel::PgConnection,
         creation_dto: ArticleLanguageCreateDto,
@@ -2,6 +2,7 @@ use super::auth;
 use super::connection;
 use super::db_schema;
 use super::dtm;
+use super::dtm_common;
 use super::error;
 use super::version_content;
 
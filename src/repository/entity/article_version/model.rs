@@ -23,6 +23,8 @@ pub struct ArticleVersionInsertable {
 
     pub updated_by: Option<i32>,
     pub created_by: i32,
+
+    pub source_version_id: Option<i32>,
 }
 
 #[derive(Queryable, Debug, Insertable, Serialize, Deserialize, AsChangeset)]
@@ -63,4 +65,6 @@ pub struct ArticleVersion {
 
     pub updated_by: Option<i32>,
     pub created_by: i32,
+
+    pub source_version_id: Option<i32>,
 }
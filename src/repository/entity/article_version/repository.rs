@@ -1,13 +1,14 @@
 use diesel::{prelude::*, sql_query};
 
 use super::connection::PgConnection;
+use super::dtm_common::{PaginationOptions, QueryOptions};
 use super::error::FmtError;
 
 use super::db_schema;
 use super::model;
 
 use super::auth::UserAccount;
-use super::version_content::VersionContent;
+use super::version_content::{ContentType, VersionContent};
 
 use super::dtm::article_version::dto::{
     ArticleVersionCreateDto, ArticleVersionPatchDto, ArticleVersionsJoinSearchDto,
@@ -16,18 +17,107 @@ use super::dtm::article_version::dto::{
 pub struct ArticleVersionRepository;
 
 impl ArticleVersionRepository {
-    pub async fn get_count(connection: &PgConnection, article_language_id: i32) -> i32 {
-        let count: i64 = connection
+    pub async fn get_last_version(connection: &PgConnection, article_language_id: i32) -> i32 {
+        connection
+            .run(move |connection| Self::get_last_version_raw(connection, article_language_id))
+            .await
+            .expect(&FmtError::FailedToFetch("article_versions").fmt())
+    }
+
+    pub fn get_last_version_raw(
+        connection: &mut diesel::PgConnection,
+        article_language_id: i32,
+    ) -> Result<i32, diesel::result::Error> {
+        let last_version = db_schema::article_version::table
+            .filter(db_schema::article_version::article_language_id.eq(article_language_id))
+            .select(diesel::dsl::max(db_schema::article_version::version))
+            .first::<Option<i32>>(connection)?;
+
+        Ok(last_version.unwrap_or(0))
+    }
+
+    pub async fn get_article_languages_ids(
+        connection: &PgConnection,
+        article_id: Option<i32>,
+    ) -> Vec<i32> {
+        connection
+            .run(move |connection| {
+                let mut query = db_schema::article_version::table
+                    .inner_join(db_schema::article_language::table)
+                    .select(db_schema::article_version::article_language_id)
+                    .distinct()
+                    .into_boxed();
+
+                if let Some(article_id) = article_id {
+                    query = query.filter(db_schema::article_language::article_id.eq(article_id));
+                }
+
+                query.load::<i32>(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToFetch("article_versions").fmt())
+    }
+
+    pub async fn get_nearest_keyframe_version(
+        connection: &PgConnection,
+        article_language_id: i32,
+        version: i32,
+    ) -> Option<i32> {
+        connection
             .run(move |connection| {
-                return db_schema::article_version::table
+                db_schema::article_version::table
+                    .inner_join(db_schema::version_content::table)
                     .filter(db_schema::article_version::article_language_id.eq(article_language_id))
-                    .count()
-                    .get_result::<i64>(connection);
+                    .filter(db_schema::article_version::version.ge(version))
+                    .filter(db_schema::version_content::content_type.eq(ContentType::Full))
+                    .select(diesel::dsl::min(db_schema::article_version::version))
+                    .first::<Option<i32>>(connection)
             })
             .await
-            .expect(&FmtError::FailedToFetch("article_versions").fmt());
+            .expect(&FmtError::FailedToFetch("article_version").fmt())
+    }
+
+    pub async fn get_page(
+        connection: &PgConnection,
+        article_languages_ids: Vec<i32>,
+        pagination: PaginationOptions,
+        query_options: &QueryOptions,
+    ) -> Vec<model::ArticleVersion> {
+        let is_actual = query_options.is_actual;
+
+        connection
+            .run(move |connection| {
+                let mut query = db_schema::article_version::table
+                    .filter(
+                        db_schema::article_version::article_language_id
+                            .eq_any(article_languages_ids),
+                    )
+                    .into_boxed();
+
+                if let Some(cursor) = &pagination.cursor {
+                    query = query.filter(
+                        db_schema::article_version::created_at
+                            .lt(cursor.created_at)
+                            .or(db_schema::article_version::created_at
+                                .eq(cursor.created_at)
+                                .and(db_schema::article_version::id.lt(cursor.id))),
+                    );
+                }
 
-        return count as i32;
+                if is_actual {
+                    query = query.filter(db_schema::article_version::enabled.eq(true));
+                }
+
+                query
+                    .order((
+                        db_schema::article_version::created_at.desc(),
+                        db_schema::article_version::id.desc(),
+                    ))
+                    .limit(pagination.get_fetch_limit())
+                    .load(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToFetch("article_versions").fmt())
     }
 
     pub async fn get_many_actuals_with_content(
@@ -89,6 +179,10 @@ impl ArticleVersionRepository {
                     );
                 }
 
+                if let Some(version_le) = query_dto.version_le {
+                    query = query.filter(db_schema::article_version::version.le(version_le));
+                }
+
                 return query
                     .filter(db_schema::article_version::version.ge(query_dto.version_ge))
                     .order(db_schema::article_version::version.desc())
@@ -98,6 +192,44 @@ impl ArticleVersionRepository {
             .expect(&FmtError::FailedToFetch("article_versions").fmt())
     }
 
+    pub fn get_many_with_content_raw(
+        connection: &mut diesel::PgConnection,
+        article_language_id: i32,
+    ) -> Result<Vec<(model::ArticleVersion, VersionContent)>, diesel::result::Error> {
+        db_schema::article_version::table
+            .inner_join(db_schema::version_content::table)
+            .filter(db_schema::article_version::article_language_id.eq(article_language_id))
+            .order(db_schema::article_version::version.desc())
+            .load::<(model::ArticleVersion, VersionContent)>(connection)
+    }
+
+    pub async fn get_one_by_version(
+        connection: &PgConnection,
+        article_language_id: i32,
+        version: i32,
+    ) -> Option<model::ArticleVersion> {
+        connection
+            .run(move |connection| {
+                Self::get_by_version_raw(connection, article_language_id, version)
+            })
+            .await
+            .expect(&FmtError::FailedToFetch("article_version").fmt())
+    }
+
+    pub async fn get_many_by_ids(
+        connection: &PgConnection,
+        ids: Vec<i32>,
+    ) -> Vec<model::ArticleVersion> {
+        connection
+            .run(move |connection| {
+                db_schema::article_version::table
+                    .filter(db_schema::article_version::id.eq_any(ids))
+                    .load(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToFetch("article_versions").fmt())
+    }
+
     pub fn get_by_version_raw(
         connection: &mut diesel::PgConnection,
         article_language_id: i32,
@@ -133,10 +265,21 @@ impl ArticleVersionRepository {
 
                 updated_by: None,
                 created_by: creation_dto.user_id,
+
+                source_version_id: creation_dto.source_version_id,
             })
             .get_result::<model::ArticleVersion>(connection)
     }
 
+    pub fn delete_raw(
+        connection: &mut diesel::PgConnection,
+        id: i32,
+    ) -> Result<usize, diesel::result::Error> {
+        diesel::delete(db_schema::article_version::table)
+            .filter(db_schema::article_version::id.eq(id))
+            .execute(connection)
+    }
+
     pub async fn patch(
         connection: &PgConnection,
         version: i32,
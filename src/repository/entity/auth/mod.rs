@@ -6,5 +6,8 @@ use super::error;
 mod model;
 mod repository;
 
-pub use self::model::{OTPType, UserAccount, UserOtp, UserPassword};
+pub use self::model::{
+    LoginFailure, OTPType, UserAccount, UserApiToken, UserIdentity, UserOtp, UserPassword,
+    UserSession,
+};
 pub use self::repository::AuthRepository;
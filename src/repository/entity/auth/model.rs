@@ -4,7 +4,10 @@ use diesel_derive_enum;
 use rocket::serde::{Deserialize, Serialize};
 use rocket_sync_db_pools::diesel;
 
-use super::db_schema::{sql_types, user_account, user_otp, user_password};
+use super::db_schema::{
+    login_failure, sql_types, user_account, user_api_token, user_identity, user_otp, user_password,
+    user_recovery_code, user_session, user_totp,
+};
 
 #[derive(Queryable, Debug, Serialize, Deserialize)]
 #[diesel(table_name = user_role)]
@@ -29,6 +32,8 @@ pub struct UserAccount {
     pub created_at: NaiveDateTime,
 
     pub updated_by: Option<i32>,
+
+    pub language_id: Option<i32>,
 }
 
 #[derive(Queryable, Debug, Insertable, Serialize, Deserialize, AsChangeset)]
@@ -47,6 +52,8 @@ pub struct UserAccountInsertable {
     pub created_at: Option<NaiveDateTime>,
 
     pub updated_by: Option<i32>,
+
+    pub language_id: Option<i32>,
 }
 
 #[derive(Queryable, Debug, Insertable, Serialize, Deserialize, AsChangeset)]
@@ -65,6 +72,8 @@ pub struct UserAccountPatch {
     pub created_at: Option<NaiveDateTime>,
 
     pub updated_by: Option<i32>,
+
+    pub language_id: Option<i32>,
 }
 
 #[derive(Queryable, Debug, Serialize, Deserialize, Selectable)]
@@ -115,21 +124,103 @@ pub enum OTPType {
 pub struct UserOtp {
     pub id: i32,
 
-    pub otp: String,
+    pub otp_hash: String,
     pub user_id: i32,
     pub otp_type: OTPType,
 
     pub created_at: NaiveDateTime,
+
+    pub attempts: i32,
+    pub expires_at: NaiveDateTime,
 }
 
-#[derive(Queryable, Debug, Insertable, Serialize, Deserialize, AsChangeset)]
-#[diesel(table_name = user_otp)]
-pub struct UserOtpInsertable {
-    pub id: Option<i32>,
+#[derive(Queryable, Debug, Serialize, Deserialize, Selectable)]
+#[diesel(table_name = user_session)]
+pub struct UserSession {
+    pub id: i32,
 
-    pub otp: String,
     pub user_id: i32,
-    pub otp_type: OTPType,
+    pub refresh_token_hash: String,
+    pub replaced_by: Option<i32>,
 
-    pub created_at: Option<NaiveDateTime>,
+    pub expires_at: NaiveDateTime,
+    pub revoked_at: Option<NaiveDateTime>,
+
+    pub updated_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Queryable, Debug, Serialize, Deserialize, Selectable, QueryableByName)]
+#[diesel(table_name = login_failure)]
+pub struct LoginFailure {
+    pub id: i32,
+
+    pub subject: String,
+    pub failed_count: i32,
+    pub locked_until: Option<NaiveDateTime>,
+
+    pub updated_at: NaiveDateTime,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, QueryableByName)]
+pub struct LoginLock {
+    #[diesel(sql_type = diesel::sql_types::Integer)]
+    pub retry_after_sec: i32,
+}
+
+#[derive(Queryable, Debug, Serialize, Deserialize, Selectable)]
+#[diesel(table_name = user_totp)]
+pub struct UserTotp {
+    pub id: i32,
+
+    pub user_id: i32,
+    pub secret: String,
+    pub confirmed: bool,
+    pub last_used_step: Option<i64>,
+
+    pub updated_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Queryable, Debug, Serialize, Deserialize, Selectable)]
+#[diesel(table_name = user_recovery_code)]
+pub struct UserRecoveryCode {
+    pub id: i32,
+
+    pub user_id: i32,
+    pub code_hash: String,
+    pub used_at: Option<NaiveDateTime>,
+
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Queryable, Debug, Serialize, Deserialize, Selectable)]
+#[diesel(table_name = user_identity)]
+pub struct UserIdentity {
+    pub id: i32,
+
+    pub user_id: i32,
+    pub provider: String,
+    pub subject: String,
+    pub email: Option<String>,
+
+    pub updated_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Queryable, Debug, Serialize, Deserialize, Selectable)]
+#[diesel(table_name = user_api_token)]
+pub struct UserApiToken {
+    pub id: i32,
+
+    pub user_id: i32,
+    pub name: String,
+    pub token_hash: String,
+    pub scopes: Vec<String>,
+
+    pub expires_at: Option<NaiveDateTime>,
+    pub last_used_at: Option<NaiveDateTime>,
+    pub revoked_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
 }
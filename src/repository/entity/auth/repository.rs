@@ -1,4 +1,6 @@
+use diesel::dsl::{now, IntervalDsl};
 use diesel::prelude::*;
+use diesel::{sql_query, sql_types};
 
 use super::connection::PgConnection;
 use super::error::FmtError;
@@ -8,7 +10,8 @@ use super::db_schema;
 use super::model;
 
 use super::dtm::auth::dto::{
-    UserAccountCreateDto, UserOtpCreateDto, UserPasswordCreateDto, UserPatchDto,
+    UserAccountCreateDto, UserApiTokenCreateDto, UserIdentityCreateDto, UserOtpCreateDto,
+    UserPasswordCreateDto, UserPatchDto, UserSessionCreateDto,
 };
 
 pub struct AuthRepository;
@@ -52,6 +55,7 @@ impl AuthRepository {
                 let mut query = db_schema::user_account::table
                     .filter(db_schema::user_account::email.eq(email))
                     .inner_join(db_schema::user_otp::table)
+                    .filter(db_schema::user_otp::expires_at.gt(now))
                     .into_boxed();
 
                 if let Some(otp_type) = otp_type {
@@ -103,6 +107,8 @@ impl AuthRepository {
                 updated_at: None,
                 created_at: None,
                 updated_by: None,
+
+                language_id: creation_dto.language_id,
             })
             .get_result::<model::UserAccount>(connection)
     }
@@ -140,21 +146,66 @@ impl AuthRepository {
             .get_result::<model::UserPassword>(connection)
     }
 
+    pub async fn update_user_password(
+        connection: &PgConnection,
+        creation_dto: UserPasswordCreateDto,
+    ) -> model::UserPassword {
+        connection
+            .run(move |connection| Self::update_user_password_raw(connection, creation_dto))
+            .await
+            .expect(&FmtError::FailedToUpdate("user_password").fmt())
+    }
+
     pub fn insert_user_otp_raw(
         connection: &mut diesel::PgConnection,
         creation_dto: UserOtpCreateDto,
     ) -> Result<model::UserOtp, diesel::result::Error> {
         diesel::insert_into(db_schema::user_otp::table)
-            .values(model::UserOtpInsertable {
-                id: None,
+            .values((
+                db_schema::user_otp::user_id.eq(creation_dto.user_id),
+                db_schema::user_otp::otp_hash.eq(creation_dto.otp_hash),
+                db_schema::user_otp::otp_type.eq(creation_dto.otp_type),
+                db_schema::user_otp::expires_at.eq(now + creation_dto.live_sec.seconds()),
+            ))
+            .get_result::<model::UserOtp>(connection)
+    }
 
-                user_id: creation_dto.user_id,
-                otp: creation_dto.otp,
-                otp_type: creation_dto.otp_type,
+    pub async fn increment_otp_attempts(connection: &PgConnection, id: i32) -> i32 {
+        connection
+            .run(move |connection| {
+                diesel::update(db_schema::user_otp::table)
+                    .filter(db_schema::user_otp::id.eq(id))
+                    .set(db_schema::user_otp::attempts.eq(db_schema::user_otp::attempts + 1))
+                    .returning(db_schema::user_otp::attempts)
+                    .get_result::<i32>(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToUpdate("user_otp").fmt())
+    }
 
-                created_at: None,
+    pub async fn has_recent_otp(
+        connection: &PgConnection,
+        user_id: i32,
+        cooldown_sec: i32,
+    ) -> bool {
+        connection
+            .run(move |connection| {
+                diesel::select(diesel::dsl::exists(
+                    db_schema::user_otp::table
+                        .filter(db_schema::user_otp::user_id.eq(user_id))
+                        .filter(db_schema::user_otp::created_at.gt(now - cooldown_sec.seconds())),
+                ))
+                .get_result::<bool>(connection)
             })
-            .get_result::<model::UserOtp>(connection)
+            .await
+            .expect(&FmtError::FailedToFetch("user_otp").fmt())
+    }
+
+    pub async fn delete_user_otps(connection: &PgConnection, ids: Vec<i32>) -> usize {
+        connection
+            .run(move |connection| Self::delete_user_otps_raw(connection, ids))
+            .await
+            .expect(&FmtError::FailedToUpdate("user_otp").fmt())
     }
 
     pub fn delete_user_otps_raw(
@@ -183,6 +234,7 @@ impl AuthRepository {
                 role_id: None,
                 created_at: None,
                 updated_at: None,
+                language_id: None,
             })
             .get_result::<model::UserAccount>(connection)
     }
@@ -203,10 +255,503 @@ impl AuthRepository {
                         role_id: None,
                         created_at: None,
                         updated_at: None,
+                        language_id: None,
                     })
                     .execute(connection)
             })
             .await
             .expect(&FmtError::FailedToUpdate("user_account").fmt())
     }
+
+    pub async fn patch_language(
+        connection: &PgConnection,
+        user_id: i32,
+        language_id: i32,
+    ) -> usize {
+        connection
+            .run(move |connection| {
+                diesel::update(db_schema::user_account::table)
+                    .filter(db_schema::user_account::id.eq(user_id))
+                    .set(db_schema::user_account::language_id.eq(language_id))
+                    .execute(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToUpdate("user_account").fmt())
+    }
+
+    pub async fn get_one_session(connection: &PgConnection, id: i32) -> Option<model::UserSession> {
+        connection
+            .run(move |connection| {
+                db_schema::user_session::table
+                    .filter(db_schema::user_session::id.eq(id))
+                    .first(connection)
+                    .optional()
+            })
+            .await
+            .expect(&FmtError::FailedToFetch("user_session").fmt())
+    }
+
+    pub async fn get_one_session_with_user(
+        connection: &PgConnection,
+        refresh_token_hash: String,
+    ) -> Option<(model::UserSession, model::UserAccount)> {
+        connection
+            .run(move |connection| {
+                db_schema::user_session::table
+                    .inner_join(db_schema::user_account::table)
+                    .filter(db_schema::user_session::refresh_token_hash.eq(refresh_token_hash))
+                    .filter(db_schema::user_session::expires_at.gt(now))
+                    .first(connection)
+                    .optional()
+            })
+            .await
+            .expect(&FmtError::FailedToFetch("user_session__user_account").fmt())
+    }
+
+    pub fn insert_user_session_raw(
+        connection: &mut diesel::PgConnection,
+        creation_dto: UserSessionCreateDto,
+    ) -> Result<model::UserSession, diesel::result::Error> {
+        diesel::insert_into(db_schema::user_session::table)
+            .values((
+                db_schema::user_session::user_id.eq(creation_dto.user_id),
+                db_schema::user_session::refresh_token_hash.eq(creation_dto.refresh_token_hash),
+                db_schema::user_session::expires_at.eq(now + creation_dto.live_sec.seconds()),
+            ))
+            .get_result::<model::UserSession>(connection)
+    }
+
+    pub fn replace_user_session_raw(
+        connection: &mut diesel::PgConnection,
+        id: i32,
+        replaced_by: i32,
+    ) -> Result<usize, diesel::result::Error> {
+        diesel::update(db_schema::user_session::table)
+            .filter(db_schema::user_session::id.eq(id))
+            .filter(db_schema::user_session::revoked_at.is_null())
+            .set((
+                db_schema::user_session::replaced_by.eq(Some(replaced_by)),
+                db_schema::user_session::revoked_at.eq(now.nullable()),
+                db_schema::user_session::updated_at.eq(now.nullable()),
+            ))
+            .execute(connection)
+    }
+
+    pub async fn revoke_user_session(connection: &PgConnection, id: i32) -> usize {
+        connection
+            .run(move |connection| {
+                diesel::update(db_schema::user_session::table)
+                    .filter(db_schema::user_session::id.eq(id))
+                    .filter(db_schema::user_session::revoked_at.is_null())
+                    .set((
+                        db_schema::user_session::revoked_at.eq(now.nullable()),
+                        db_schema::user_session::updated_at.eq(now.nullable()),
+                    ))
+                    .execute(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToUpdate("user_session").fmt())
+    }
+
+    pub fn revoke_user_sessions_raw(
+        connection: &mut diesel::PgConnection,
+        user_id: i32,
+    ) -> Result<usize, diesel::result::Error> {
+        diesel::update(db_schema::user_session::table)
+            .filter(db_schema::user_session::user_id.eq(user_id))
+            .filter(db_schema::user_session::revoked_at.is_null())
+            .set((
+                db_schema::user_session::revoked_at.eq(now.nullable()),
+                db_schema::user_session::updated_at.eq(now.nullable()),
+            ))
+            .execute(connection)
+    }
+
+    pub async fn revoke_user_sessions(connection: &PgConnection, user_id: i32) -> usize {
+        connection
+            .run(move |connection| Self::revoke_user_sessions_raw(connection, user_id))
+            .await
+            .expect(&FmtError::FailedToUpdate("user_session").fmt())
+    }
+
+    pub async fn get_login_lock(
+        connection: &PgConnection,
+        subjects: Vec<String>,
+    ) -> Option<model::LoginLock> {
+        connection
+            .run(move |connection| {
+                sql_query(
+                    r#"
+                    SELECT CEIL(EXTRACT(EPOCH FROM MAX(locked_until) - NOW()))::INT AS retry_after_sec
+                    FROM login_failure
+                    WHERE subject = ANY($1) AND locked_until > NOW()
+                    HAVING COUNT(*) > 0
+                    ;"#,
+                )
+                .bind::<sql_types::Array<sql_types::Varchar>, _>(subjects)
+                .get_result::<model::LoginLock>(connection)
+                .optional()
+            })
+            .await
+            .expect(&FmtError::FailedToFetch("login_failure").fmt())
+    }
+
+    // failures older than the window start counting from scratch
+    pub async fn insert_login_failure(
+        connection: &PgConnection,
+        subject: String,
+        window_sec: i32,
+    ) -> model::LoginFailure {
+        connection
+            .run(move |connection| {
+                sql_query(
+                    r#"
+                    INSERT INTO login_failure (subject)
+                    VALUES ($1)
+                    ON CONFLICT (subject) DO UPDATE
+                    SET failed_count = CASE
+                            WHEN login_failure.updated_at < NOW() - $2 * INTERVAL '1 second' THEN 1
+                            ELSE login_failure.failed_count + 1
+                        END,
+                        updated_at = NOW()
+                    RETURNING *
+                    ;"#,
+                )
+                .bind::<sql_types::Varchar, _>(subject)
+                .bind::<sql_types::Integer, _>(window_sec)
+                .get_result::<model::LoginFailure>(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToInsert("login_failure").fmt())
+    }
+
+    pub async fn lock_login_subject(connection: &PgConnection, id: i32, lock_sec: i32) -> usize {
+        connection
+            .run(move |connection| {
+                diesel::update(db_schema::login_failure::table)
+                    .filter(db_schema::login_failure::id.eq(id))
+                    .set(
+                        db_schema::login_failure::locked_until
+                            .eq((now + lock_sec.seconds()).nullable()),
+                    )
+                    .execute(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToUpdate("login_failure").fmt())
+    }
+
+    pub async fn delete_login_failure(connection: &PgConnection, subject: String) -> usize {
+        connection
+            .run(move |connection| {
+                diesel::delete(db_schema::login_failure::table)
+                    .filter(db_schema::login_failure::subject.eq(subject))
+                    .execute(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToUpdate("login_failure").fmt())
+    }
+
+    pub async fn get_user_totp(connection: &PgConnection, user_id: i32) -> Option<model::UserTotp> {
+        connection
+            .run(move |connection| {
+                db_schema::user_totp::table
+                    .filter(db_schema::user_totp::user_id.eq(user_id))
+                    .first(connection)
+                    .optional()
+            })
+            .await
+            .expect(&FmtError::FailedToFetch("user_totp").fmt())
+    }
+
+    // restarting enrollment replaces the pending secret
+    pub async fn upsert_user_totp(
+        connection: &PgConnection,
+        user_id: i32,
+        secret: String,
+    ) -> model::UserTotp {
+        connection
+            .run(move |connection| {
+                diesel::insert_into(db_schema::user_totp::table)
+                    .values((
+                        db_schema::user_totp::user_id.eq(user_id),
+                        db_schema::user_totp::secret.eq(secret.clone()),
+                    ))
+                    .on_conflict(db_schema::user_totp::user_id)
+                    .do_update()
+                    .set((
+                        db_schema::user_totp::secret.eq(secret),
+                        db_schema::user_totp::confirmed.eq(false),
+                        db_schema::user_totp::last_used_step.eq(None::<i64>),
+                        db_schema::user_totp::updated_at.eq(now.nullable()),
+                    ))
+                    .get_result::<model::UserTotp>(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToInsert("user_totp").fmt())
+    }
+
+    pub fn confirm_user_totp_raw(
+        connection: &mut diesel::PgConnection,
+        id: i32,
+        step: i64,
+    ) -> Result<usize, diesel::result::Error> {
+        diesel::update(db_schema::user_totp::table)
+            .filter(db_schema::user_totp::id.eq(id))
+            .filter(db_schema::user_totp::confirmed.eq(false))
+            .set((
+                db_schema::user_totp::confirmed.eq(true),
+                db_schema::user_totp::last_used_step.eq(Some(step)),
+                db_schema::user_totp::updated_at.eq(now.nullable()),
+            ))
+            .execute(connection)
+    }
+
+    // returns 0 if the step (or a later one) was already used
+    pub async fn use_totp_step(connection: &PgConnection, id: i32, step: i64) -> usize {
+        connection
+            .run(move |connection| {
+                diesel::update(db_schema::user_totp::table)
+                    .filter(db_schema::user_totp::id.eq(id))
+                    .filter(
+                        db_schema::user_totp::last_used_step
+                            .is_null()
+                            .or(db_schema::user_totp::last_used_step.lt(step)),
+                    )
+                    .set((
+                        db_schema::user_totp::last_used_step.eq(Some(step)),
+                        db_schema::user_totp::updated_at.eq(now.nullable()),
+                    ))
+                    .execute(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToUpdate("user_totp").fmt())
+    }
+
+    pub fn delete_user_totp_raw(
+        connection: &mut diesel::PgConnection,
+        user_id: i32,
+    ) -> Result<usize, diesel::result::Error> {
+        diesel::delete(db_schema::user_totp::table)
+            .filter(db_schema::user_totp::user_id.eq(user_id))
+            .execute(connection)
+    }
+
+    pub fn delete_recovery_codes_raw(
+        connection: &mut diesel::PgConnection,
+        user_id: i32,
+    ) -> Result<usize, diesel::result::Error> {
+        diesel::delete(db_schema::user_recovery_code::table)
+            .filter(db_schema::user_recovery_code::user_id.eq(user_id))
+            .execute(connection)
+    }
+
+    pub fn insert_recovery_codes_raw(
+        connection: &mut diesel::PgConnection,
+        user_id: i32,
+        code_hashes: Vec<String>,
+    ) -> Result<usize, diesel::result::Error> {
+        diesel::insert_into(db_schema::user_recovery_code::table)
+            .values(
+                code_hashes
+                    .into_iter()
+                    .map(|code_hash| {
+                        (
+                            db_schema::user_recovery_code::user_id.eq(user_id),
+                            db_schema::user_recovery_code::code_hash.eq(code_hash),
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+            )
+            .execute(connection)
+    }
+
+    pub async fn use_recovery_code(
+        connection: &PgConnection,
+        user_id: i32,
+        code_hash: String,
+    ) -> usize {
+        connection
+            .run(move |connection| {
+                diesel::update(db_schema::user_recovery_code::table)
+                    .filter(db_schema::user_recovery_code::user_id.eq(user_id))
+                    .filter(db_schema::user_recovery_code::code_hash.eq(code_hash))
+                    .filter(db_schema::user_recovery_code::used_at.is_null())
+                    .set(db_schema::user_recovery_code::used_at.eq(now.nullable()))
+                    .execute(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToUpdate("user_recovery_code").fmt())
+    }
+
+    pub async fn get_one_user_by_identity(
+        connection: &PgConnection,
+        provider: String,
+        subject: String,
+    ) -> Option<(model::UserIdentity, model::UserAccount)> {
+        connection
+            .run(move |connection| {
+                db_schema::user_identity::table
+                    .inner_join(db_schema::user_account::table)
+                    .filter(db_schema::user_identity::provider.eq(provider))
+                    .filter(db_schema::user_identity::subject.eq(subject))
+                    .first(connection)
+                    .optional()
+            })
+            .await
+            .expect(&FmtError::FailedToFetch("user_identity__user_account").fmt())
+    }
+
+    pub fn get_one_user_by_email_raw(
+        connection: &mut diesel::PgConnection,
+        email: String,
+    ) -> Result<Option<model::UserAccount>, diesel::result::Error> {
+        db_schema::user_account::table
+            .filter(db_schema::user_account::email.eq(email))
+            .first(connection)
+            .optional()
+    }
+
+    pub async fn exists_user_name(connection: &PgConnection, name: String) -> bool {
+        connection
+            .run(move |connection| {
+                diesel::select(diesel::dsl::exists(
+                    db_schema::user_account::table.filter(db_schema::user_account::name.eq(name)),
+                ))
+                .get_result::<bool>(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToFetch("user_account").fmt())
+    }
+
+    pub fn insert_user_identity_raw(
+        connection: &mut diesel::PgConnection,
+        creation_dto: UserIdentityCreateDto,
+    ) -> Result<model::UserIdentity, diesel::result::Error> {
+        diesel::insert_into(db_schema::user_identity::table)
+            .values((
+                db_schema::user_identity::user_id.eq(creation_dto.user_id),
+                db_schema::user_identity::provider.eq(creation_dto.provider),
+                db_schema::user_identity::subject.eq(creation_dto.subject),
+                db_schema::user_identity::email.eq(creation_dto.email),
+            ))
+            .get_result::<model::UserIdentity>(connection)
+    }
+
+    pub async fn update_user_identity_email(
+        connection: &PgConnection,
+        id: i32,
+        email: Option<String>,
+    ) -> usize {
+        connection
+            .run(move |connection| {
+                diesel::update(db_schema::user_identity::table)
+                    .filter(db_schema::user_identity::id.eq(id))
+                    .set((
+                        db_schema::user_identity::email.eq(email),
+                        db_schema::user_identity::updated_at.eq(now.nullable()),
+                    ))
+                    .execute(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToUpdate("user_identity").fmt())
+    }
+
+    pub async fn insert_api_token(
+        connection: &PgConnection,
+        creation_dto: UserApiTokenCreateDto,
+    ) -> model::UserApiToken {
+        connection
+            .run(move |connection| {
+                let values = (
+                    db_schema::user_api_token::user_id.eq(creation_dto.user_id),
+                    db_schema::user_api_token::name.eq(creation_dto.name),
+                    db_schema::user_api_token::token_hash.eq(creation_dto.token_hash),
+                    db_schema::user_api_token::scopes.eq(creation_dto.scopes),
+                );
+
+                match creation_dto.live_days {
+                    Some(live_days) => diesel::insert_into(db_schema::user_api_token::table)
+                        .values((
+                            values,
+                            db_schema::user_api_token::expires_at
+                                .eq((now + live_days.days()).nullable()),
+                        ))
+                        .get_result::<model::UserApiToken>(connection),
+                    None => diesel::insert_into(db_schema::user_api_token::table)
+                        .values(values)
+                        .get_result::<model::UserApiToken>(connection),
+                }
+            })
+            .await
+            .expect(&FmtError::FailedToInsert("user_api_token").fmt())
+    }
+
+    pub async fn get_active_api_token(
+        connection: &PgConnection,
+        token_hash: String,
+    ) -> Option<model::UserApiToken> {
+        connection
+            .run(move |connection| {
+                db_schema::user_api_token::table
+                    .filter(db_schema::user_api_token::token_hash.eq(token_hash))
+                    .filter(db_schema::user_api_token::revoked_at.is_null())
+                    .filter(
+                        db_schema::user_api_token::expires_at
+                            .is_null()
+                            .or(db_schema::user_api_token::expires_at.gt(now)),
+                    )
+                    .first(connection)
+                    .optional()
+            })
+            .await
+            .expect(&FmtError::FailedToFetch("user_api_token").fmt())
+    }
+
+    pub async fn get_api_tokens(
+        connection: &PgConnection,
+        user_id: i32,
+    ) -> Vec<model::UserApiToken> {
+        connection
+            .run(move |connection| {
+                db_schema::user_api_token::table
+                    .filter(db_schema::user_api_token::user_id.eq(user_id))
+                    .filter(db_schema::user_api_token::revoked_at.is_null())
+                    .order(db_schema::user_api_token::id.desc())
+                    .load(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToFetch("user_api_token").fmt())
+    }
+
+    // last usage is updated at most once a minute to spare writes on busy tokens
+    pub async fn touch_api_token(connection: &PgConnection, id: i32) -> usize {
+        connection
+            .run(move |connection| {
+                diesel::update(db_schema::user_api_token::table)
+                    .filter(db_schema::user_api_token::id.eq(id))
+                    .filter(
+                        db_schema::user_api_token::last_used_at
+                            .is_null()
+                            .or(db_schema::user_api_token::last_used_at.lt(now - 1.minutes())),
+                    )
+                    .set(db_schema::user_api_token::last_used_at.eq(now.nullable()))
+                    .execute(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToUpdate("user_api_token").fmt())
+    }
+
+    pub async fn revoke_api_token(connection: &PgConnection, id: i32, user_id: i32) -> usize {
+        connection
+            .run(move |connection| {
+                diesel::update(db_schema::user_api_token::table)
+                    .filter(db_schema::user_api_token::id.eq(id))
+                    .filter(db_schema::user_api_token::user_id.eq(user_id))
+                    .filter(db_schema::user_api_token::revoked_at.is_null())
+                    .set(db_schema::user_api_token::revoked_at.eq(now.nullable()))
+                    .execute(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToUpdate("user_api_token").fmt())
+    }
 }
@@ -0,0 +1,69 @@
+use chrono::NaiveDateTime;
+use diesel::{AsChangeset, Insertable, Queryable, Selectable};
+use rocket::serde::{Deserialize, Serialize};
+use rocket_sync_db_pools::diesel;
+
+use super::db_schema::{article_category, category};
+
+#[derive(Queryable, Debug, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = category)]
+pub struct CategoryInsertable {
+    pub id: Option<i32>,
+    pub name: String,
+
+    pub updated_at: Option<NaiveDateTime>,
+    pub created_at: Option<NaiveDateTime>,
+
+    pub updated_by: Option<i32>,
+    pub created_by: i32,
+}
+
+#[derive(Queryable, Debug, Insertable, Serialize, Deserialize, AsChangeset)]
+#[diesel(table_name = category)]
+pub struct CategoryPatch {
+    pub id: Option<i32>,
+    pub name: Option<String>,
+
+    pub updated_at: Option<NaiveDateTime>,
+    pub created_at: Option<NaiveDateTime>,
+
+    pub updated_by: i32,
+    pub created_by: Option<i32>,
+}
+
+#[derive(Queryable, Debug, Serialize, Deserialize, Selectable)]
+#[diesel(table_name = category)]
+pub struct Category {
+    pub id: i32,
+    pub name: String,
+
+    pub updated_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+
+    pub updated_by: Option<i32>,
+    pub created_by: i32,
+}
+
+#[derive(Queryable, Debug, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = article_category)]
+pub struct ArticleCategoryInsertable {
+    pub id: Option<i32>,
+
+    pub article_id: i32,
+    pub category_id: i32,
+
+    pub created_at: Option<NaiveDateTime>,
+    pub created_by: i32,
+}
+
+#[derive(Queryable, Debug, Serialize, Deserialize, Selectable)]
+#[diesel(table_name = article_category)]
+pub struct ArticleCategory {
+    pub id: i32,
+
+    pub article_id: i32,
+    pub category_id: i32,
+
+    pub created_at: NaiveDateTime,
+    pub created_by: i32,
+}
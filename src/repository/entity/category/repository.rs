@@ -0,0 +1,147 @@
+use diesel::prelude::*;
+
+use super::connection::PgConnection;
+use super::error::FmtError;
+
+use super::db_schema;
+use super::model;
+
+use super::dtm::category::dto::{ArticleCategoryCreateDto, CategoryCreateDto, CategoryPatchDto};
+
+pub struct CategoryRepository;
+
+impl CategoryRepository {
+    pub async fn get_one(connection: &PgConnection, id: i32) -> Option<model::Category> {
+        connection
+            .run(move |connection| {
+                db_schema::category::table
+                    .filter(db_schema::category::id.eq(id))
+                    .first(connection)
+                    .optional()
+            })
+            .await
+            .expect(&FmtError::FailedToFetch("category").fmt())
+    }
+
+    pub async fn get_many(connection: &PgConnection) -> Vec<model::Category> {
+        connection
+            .run(|connection| {
+                db_schema::category::table
+                    .order(db_schema::category::name.asc())
+                    .load(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToFetch("categories").fmt())
+    }
+
+    pub async fn get_many_by_articles(
+        connection: &PgConnection,
+        article_ids: Vec<i32>,
+    ) -> Vec<(model::ArticleCategory, model::Category)> {
+        connection
+            .run(move |connection| {
+                db_schema::article_category::table
+                    .inner_join(db_schema::category::table)
+                    .filter(db_schema::article_category::article_id.eq_any(article_ids))
+                    .order(db_schema::category::name.asc())
+                    .load::<(model::ArticleCategory, model::Category)>(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToFetch("article_categories").fmt())
+    }
+
+    pub async fn insert(
+        connection: &PgConnection,
+        creation_dto: CategoryCreateDto,
+    ) -> Result<model::Category, diesel::result::Error> {
+        connection
+            .run(move |connection| {
+                diesel::insert_into(db_schema::category::table)
+                    .values(model::CategoryInsertable {
+                        id: None,
+                        name: creation_dto.name,
+
+                        updated_at: None,
+                        created_at: None,
+
+                        updated_by: None,
+                        created_by: creation_dto.user_id,
+                    })
+                    .get_result::<model::Category>(connection)
+            })
+            .await
+    }
+
+    pub async fn patch(
+        connection: &PgConnection,
+        patch_dto: CategoryPatchDto,
+    ) -> Result<usize, diesel::result::Error> {
+        connection
+            .run(move |connection| {
+                diesel::update(db_schema::category::table)
+                    .filter(db_schema::category::id.eq(patch_dto.id))
+                    .set(model::CategoryPatch {
+                        name: patch_dto.name,
+                        updated_by: patch_dto.user_id,
+
+                        id: None,
+                        updated_at: None,
+                        created_at: None,
+                        created_by: None,
+                    })
+                    .execute(connection)
+            })
+            .await
+    }
+
+    pub async fn delete(connection: &PgConnection, id: i32) -> usize {
+        connection
+            .run(move |connection| {
+                diesel::delete(db_schema::category::table)
+                    .filter(db_schema::category::id.eq(id))
+                    .execute(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToUpdate("category").fmt())
+    }
+
+    pub async fn insert_article_category(
+        connection: &PgConnection,
+        creation_dto: ArticleCategoryCreateDto,
+    ) -> Result<model::ArticleCategory, diesel::result::Error> {
+        connection
+            .run(move |connection| {
+                diesel::insert_into(db_schema::article_category::table)
+                    .values(model::ArticleCategoryInsertable {
+                        id: None,
+
+                        article_id: creation_dto.article_id,
+                        category_id: creation_dto.category_id,
+
+                        created_at: None,
+                        created_by: creation_dto.user_id,
+                    })
+                    .get_result::<model::ArticleCategory>(connection)
+            })
+            .await
+    }
+
+    pub async fn delete_article_category(
+        connection: &PgConnection,
+        article_id: i32,
+        category_id: i32,
+    ) -> usize {
+        connection
+            .run(move |connection| {
+                diesel::delete(db_schema::article_category::table)
+                    .filter(
+                        db_schema::article_category::article_id
+                            .eq(article_id)
+                            .and(db_schema::article_category::category_id.eq(category_id)),
+                    )
+                    .execute(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToUpdate("article_category").fmt())
+    }
+}
@@ -0,0 +1,69 @@
+use chrono::NaiveDateTime;
+use diesel::{AsChangeset, Queryable, Selectable};
+use diesel_derive_enum;
+
+use rocket::serde::{Deserialize, Serialize};
+use rocket_okapi::okapi::schemars::JsonSchema;
+use rocket_sync_db_pools::diesel;
+
+use super::db_schema::{email_template, sql_types};
+
+#[derive(
+    Serialize,
+    Deserialize,
+    Debug,
+    diesel_derive_enum::DbEnum,
+    JsonSchema,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+)]
+#[ExistingTypePath = "sql_types::EmailTemplateType"]
+#[serde(rename_all = "snake_case")]
+pub enum EmailTemplateType {
+    Confirmation,
+    Reset,
+    Watch,
+    Digest,
+}
+
+impl EmailTemplateType {
+    pub fn from_value(value: &str) -> Option<Self> {
+        match value {
+            "confirmation" => Some(Self::Confirmation),
+            "reset" => Some(Self::Reset),
+            "watch" => Some(Self::Watch),
+            "digest" => Some(Self::Digest),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Queryable, Debug, Serialize, Deserialize, Selectable)]
+#[diesel(table_name = email_template)]
+pub struct EmailTemplate {
+    pub id: i32,
+
+    pub template_type: EmailTemplateType,
+    pub language_id: i32,
+
+    pub subject: String,
+    pub html: String,
+    pub text: String,
+
+    pub updated_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+
+    pub updated_by: Option<i32>,
+}
+
+#[derive(Debug, AsChangeset)]
+#[diesel(table_name = email_template)]
+pub struct EmailTemplatePatch {
+    pub subject: Option<String>,
+    pub html: Option<String>,
+    pub text: Option<String>,
+
+    pub updated_by: i32,
+}
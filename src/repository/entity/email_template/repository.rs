@@ -0,0 +1,66 @@
+use diesel::prelude::*;
+
+use super::connection::PgConnection;
+use super::error::FmtError;
+
+use super::db_schema;
+use super::model;
+
+use super::dtm::email_template::dto::EmailTemplatePatchDto;
+
+pub struct EmailTemplateRepository;
+
+impl EmailTemplateRepository {
+    pub async fn get_one(
+        connection: &PgConnection,
+        template_type: model::EmailTemplateType,
+        language_id: i32,
+    ) -> Option<model::EmailTemplate> {
+        connection
+            .run(move |connection| {
+                db_schema::email_template::table
+                    .filter(db_schema::email_template::template_type.eq(template_type))
+                    .filter(db_schema::email_template::language_id.eq(language_id))
+                    .first(connection)
+                    .optional()
+            })
+            .await
+            .expect(&FmtError::FailedToFetch("email_template").fmt())
+    }
+
+    pub async fn get_many(connection: &PgConnection) -> Vec<model::EmailTemplate> {
+        connection
+            .run(|connection| {
+                db_schema::email_template::table
+                    .order((
+                        db_schema::email_template::template_type.asc(),
+                        db_schema::email_template::language_id.asc(),
+                    ))
+                    .load(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToFetch("email_templates").fmt())
+    }
+
+    pub async fn patch(
+        connection: &PgConnection,
+        id: i32,
+        patch_dto: EmailTemplatePatchDto,
+    ) -> usize {
+        connection
+            .run(move |connection| {
+                diesel::update(db_schema::email_template::table)
+                    .filter(db_schema::email_template::id.eq(id))
+                    .set(model::EmailTemplatePatch {
+                        subject: patch_dto.subject,
+                        html: patch_dto.html,
+                        text: patch_dto.text,
+
+                        updated_by: patch_dto.user_id,
+                    })
+                    .execute(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToUpdate("email_template").fmt())
+    }
+}
@@ -0,0 +1,11 @@
+use super::connection;
+use super::db_schema;
+use super::dtm;
+use super::dtm_common;
+use super::error;
+
+mod model;
+mod repository;
+
+pub use self::model::{Image, ImageRendition};
+pub use self::repository::ImageRepository;
@@ -0,0 +1,64 @@
+use chrono::NaiveDateTime;
+use diesel::{Insertable, Queryable, Selectable};
+use rocket::serde::{Deserialize, Serialize};
+use rocket_sync_db_pools::diesel;
+
+use super::db_schema::{image, image_rendition};
+
+#[derive(Queryable, Debug, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = image)]
+pub struct ImageInsertable {
+    pub id: Option<i32>,
+
+    pub storage_key: String,
+    pub mime_type: String,
+    pub size: i32,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+
+    pub created_at: Option<NaiveDateTime>,
+    pub created_by: i32,
+}
+
+#[derive(Queryable, Debug, Serialize, Deserialize, Selectable)]
+#[diesel(table_name = image)]
+pub struct Image {
+    pub id: i32,
+
+    pub storage_key: String,
+    pub mime_type: String,
+    pub size: i32,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+
+    pub created_at: NaiveDateTime,
+    pub created_by: i32,
+}
+
+#[derive(Queryable, Debug, Insertable, Serialize, Deserialize)]
+#[diesel(table_name = image_rendition)]
+pub struct ImageRenditionInsertable {
+    pub id: Option<i32>,
+    pub image_id: i32,
+
+    pub name: String,
+    pub storage_key: String,
+    pub mime_type: String,
+    pub size: i32,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+}
+
+#[derive(Queryable, Debug, Serialize, Deserialize, Selectable)]
+#[diesel(table_name = image_rendition)]
+pub struct ImageRendition {
+    pub id: i32,
+    pub image_id: i32,
+
+    pub name: String,
+    pub storage_key: String,
+    pub mime_type: String,
+    pub size: i32,
+    pub width: Option<i32>,
+    pub height: Option<i32>,
+}
@@ -0,0 +1,215 @@
+use diesel::dsl::{exists, not, now, IntervalDsl};
+use diesel::prelude::*;
+use diesel::{sql_query, sql_types};
+
+use super::connection::PgConnection;
+use super::dtm_common::PaginationOptions;
+use super::error::FmtError;
+
+use super::db_schema;
+use super::model;
+
+use super::dtm::image::dto::{ImageMetadataCreateDto, ImageRenditionCreateDto};
+
+pub struct ImageRepository;
+
+impl ImageRepository {
+    pub async fn get_one(connection: &PgConnection, id: i32) -> Option<model::Image> {
+        connection
+            .run(move |connection| {
+                db_schema::image::table
+                    .filter(db_schema::image::id.eq(id))
+                    .first(connection)
+                    .optional()
+            })
+            .await
+            .expect(&FmtError::FailedToFetch("image").fmt())
+    }
+
+    pub async fn get_page_by_user(
+        connection: &PgConnection,
+        user_id: i32,
+        pagination: PaginationOptions,
+    ) -> Vec<model::Image> {
+        connection
+            .run(move |connection| {
+                let mut query = db_schema::image::table
+                    .filter(db_schema::image::created_by.eq(user_id))
+                    .into_boxed();
+
+                if let Some(cursor) = &pagination.cursor {
+                    query = query.filter(
+                        db_schema::image::created_at.lt(cursor.created_at).or(
+                            db_schema::image::created_at
+                                .eq(cursor.created_at)
+                                .and(db_schema::image::id.lt(cursor.id)),
+                        ),
+                    );
+                }
+
+                query
+                    .order((
+                        db_schema::image::created_at.desc(),
+                        db_schema::image::id.desc(),
+                    ))
+                    .limit(pagination.get_fetch_limit())
+                    .load(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToFetch("images").fmt())
+    }
+
+    // images referenced by any version of any article language
+    pub async fn get_many_by_article(
+        connection: &PgConnection,
+        article_id: i32,
+    ) -> Vec<model::Image> {
+        connection
+            .run(move |connection| {
+                db_schema::image::table
+                    .filter(
+                        db_schema::image::id.eq_any(
+                            db_schema::image_reference::table
+                                .inner_join(
+                                    db_schema::article_version::table
+                                        .inner_join(db_schema::article_language::table),
+                                )
+                                .filter(db_schema::article_language::article_id.eq(article_id))
+                                .select(db_schema::image_reference::image_id),
+                        ),
+                    )
+                    .order((
+                        db_schema::image::created_at.desc(),
+                        db_schema::image::id.desc(),
+                    ))
+                    .load(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToFetch("article_images").fmt())
+    }
+
+    pub async fn get_many_unreferenced(
+        connection: &PgConnection,
+        ttl_hours: i32,
+    ) -> Vec<model::Image> {
+        connection
+            .run(move |connection| {
+                db_schema::image::table
+                    .filter(db_schema::image::created_at.lt(now - ttl_hours.hours()))
+                    .filter(not(exists(db_schema::image_reference::table.filter(
+                        db_schema::image_reference::image_id.eq(db_schema::image::id),
+                    ))))
+                    .load(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToFetch("unreferenced_images").fmt())
+    }
+
+    pub async fn is_referenced(connection: &PgConnection, id: i32) -> bool {
+        connection
+            .run(move |connection| {
+                diesel::select(exists(
+                    db_schema::image_reference::table
+                        .filter(db_schema::image_reference::image_id.eq(id)),
+                ))
+                .get_result::<bool>(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToFetch("image_reference").fmt())
+    }
+
+    pub async fn get_renditions_by_images(
+        connection: &PgConnection,
+        image_ids: Vec<i32>,
+    ) -> Vec<model::ImageRendition> {
+        connection
+            .run(move |connection| {
+                db_schema::image_rendition::table
+                    .filter(db_schema::image_rendition::image_id.eq_any(image_ids))
+                    .order(db_schema::image_rendition::id.asc())
+                    .load(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToFetch("image_renditions").fmt())
+    }
+
+    pub fn insert_raw(
+        connection: &mut diesel::PgConnection,
+        creation_dto: ImageMetadataCreateDto,
+    ) -> Result<model::Image, diesel::result::Error> {
+        diesel::insert_into(db_schema::image::table)
+            .values(model::ImageInsertable {
+                id: None,
+
+                storage_key: creation_dto.storage_key,
+                mime_type: creation_dto.mime_type,
+                size: creation_dto.size,
+                width: creation_dto.width,
+                height: creation_dto.height,
+
+                created_at: None,
+                created_by: creation_dto.user_id,
+            })
+            .get_result::<model::Image>(connection)
+    }
+
+    pub fn insert_renditions_raw(
+        connection: &mut diesel::PgConnection,
+        image_id: i32,
+        creation_dtos: Vec<ImageRenditionCreateDto>,
+    ) -> Result<Vec<model::ImageRendition>, diesel::result::Error> {
+        diesel::insert_into(db_schema::image_rendition::table)
+            .values(
+                creation_dtos
+                    .into_iter()
+                    .map(|creation_dto| model::ImageRenditionInsertable {
+                        id: None,
+                        image_id,
+
+                        name: creation_dto.name,
+                        storage_key: creation_dto.storage_key,
+                        mime_type: creation_dto.mime_type,
+                        size: creation_dto.size,
+                        width: creation_dto.width,
+                        height: creation_dto.height,
+                    })
+                    .collect::<Vec<model::ImageRenditionInsertable>>(),
+            )
+            .get_results::<model::ImageRendition>(connection)
+    }
+
+    pub async fn delete(connection: &PgConnection, ids: Vec<i32>) -> usize {
+        connection
+            .run(move |connection| {
+                diesel::delete(db_schema::image::table)
+                    .filter(db_schema::image::id.eq_any(ids))
+                    .execute(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToUpdate("image").fmt())
+    }
+
+    // storage keys are extracted from the content, any rendition references its image
+    pub fn insert_references_raw(
+        connection: &mut diesel::PgConnection,
+        article_version_id: i32,
+        storage_keys: Vec<String>,
+    ) -> Result<usize, diesel::result::Error> {
+        if storage_keys.is_empty() {
+            return Ok(0);
+        }
+
+        sql_query(
+            r#"
+            INSERT INTO image_reference (image_id, article_version_id)
+            SELECT DISTINCT image_rendition.image_id, $1
+            FROM image_rendition
+            WHERE image_rendition.storage_key = ANY($2)
+            ON CONFLICT DO NOTHING
+            ;"#,
+        )
+        .bind::<sql_types::Integer, _>(article_version_id)
+        .bind::<sql_types::Array<sql_types::Text>, _>(storage_keys)
+        .execute(connection)
+    }
+}
@@ -1,9 +1,10 @@
 use super::connection;
 use super::db_schema;
+use super::dtm;
 use super::error;
 
 mod model;
 mod repository;
 
-pub use self::model::Language;
+pub use self::model::{Language, TextDirection};
 pub use self::repository::LanguageRepository;
@@ -1,12 +1,77 @@
-use diesel::{Insertable, Queryable};
+use chrono::NaiveDateTime;
+use diesel::{AsChangeset, Insertable, Queryable, Selectable};
+use diesel_derive_enum;
+
 use rocket::serde::{Deserialize, Serialize};
+use rocket_okapi::okapi::schemars::JsonSchema;
 use rocket_sync_db_pools::diesel;
 
-use super::db_schema::language;
+use super::db_schema::{language, sql_types};
+
+#[derive(
+    Serialize,
+    Deserialize,
+    Debug,
+    diesel_derive_enum::DbEnum,
+    JsonSchema,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+)]
+#[ExistingTypePath = "sql_types::TextDirection"]
+#[serde(rename_all = "snake_case")]
+pub enum TextDirection {
+    Ltr,
+    Rtl,
+}
 
-#[derive(Queryable, Debug, Insertable, Serialize, Deserialize)]
+#[derive(Queryable, Debug, Serialize, Deserialize, Selectable)]
 #[diesel(table_name = language)]
 pub struct Language {
     pub id: i32,
     pub code: String,
+
+    pub name: String,
+    pub native_name: String,
+    pub direction: TextDirection,
+    pub bcp47: String,
+
+    pub fallback_language_id: Option<i32>,
+    pub enabled: bool,
+
+    pub updated_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+
+    pub updated_by: Option<i32>,
+    pub created_by: Option<i32>,
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = language)]
+pub struct LanguageInsertable {
+    pub code: String,
+
+    pub name: String,
+    pub native_name: String,
+    pub direction: TextDirection,
+    pub bcp47: String,
+
+    pub fallback_language_id: Option<i32>,
+
+    pub created_by: i32,
+}
+
+#[derive(Debug, AsChangeset)]
+#[diesel(table_name = language)]
+pub struct LanguagePatch {
+    pub name: Option<String>,
+    pub native_name: Option<String>,
+    pub direction: Option<TextDirection>,
+    pub bcp47: Option<String>,
+
+    pub fallback_language_id: Option<Option<i32>>,
+    pub enabled: Option<bool>,
+
+    pub updated_by: i32,
 }
@@ -6,6 +6,8 @@ use super::error::FmtError;
 use super::db_schema;
 use super::model;
 
+use super::dtm::language::dto::{LanguageCreateDto, LanguagePatchDto};
+
 pub struct LanguageRepository;
 
 impl LanguageRepository {
@@ -23,8 +25,60 @@ impl LanguageRepository {
 
     pub async fn get_many(connection: &PgConnection) -> Vec<model::Language> {
         connection
-            .run(|connection| db_schema::language::table.load(connection))
+            .run(|connection| {
+                db_schema::language::table
+                    .order(db_schema::language::id.asc())
+                    .load(connection)
+            })
             .await
             .expect(&FmtError::FailedToFetch("languages").fmt())
     }
+
+    pub async fn insert(
+        connection: &PgConnection,
+        creation_dto: LanguageCreateDto,
+        fallback_language_id: Option<i32>,
+    ) -> Result<model::Language, diesel::result::Error> {
+        connection
+            .run(move |connection| {
+                diesel::insert_into(db_schema::language::table)
+                    .values(model::LanguageInsertable {
+                        fallback_language_id,
+                        code: creation_dto.code,
+                        name: creation_dto.name,
+                        native_name: creation_dto.native_name,
+                        direction: creation_dto.direction,
+                        bcp47: creation_dto.bcp47,
+                        created_by: creation_dto.user_id,
+                    })
+                    .get_result::<model::Language>(connection)
+            })
+            .await
+    }
+
+    // fallback_language_id: None - unchanged, Some(None) - removed
+    pub async fn patch(
+        connection: &PgConnection,
+        id: i32,
+        patch_dto: LanguagePatchDto,
+        fallback_language_id: Option<Option<i32>>,
+    ) -> usize {
+        connection
+            .run(move |connection| {
+                diesel::update(db_schema::language::table)
+                    .filter(db_schema::language::id.eq(id))
+                    .set(model::LanguagePatch {
+                        fallback_language_id,
+                        name: patch_dto.name,
+                        native_name: patch_dto.native_name,
+                        direction: patch_dto.direction,
+                        bcp47: patch_dto.bcp47,
+                        enabled: patch_dto.enabled,
+                        updated_by: patch_dto.user_id,
+                    })
+                    .execute(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToUpdate("language").fmt())
+    }
 }
@@ -5,8 +5,13 @@ use super::dtm_common;
 use super::error;
 
 pub mod article;
+pub mod article_draft;
 pub mod article_language;
 pub mod article_version;
 pub mod auth;
+pub mod category;
+pub mod email_template;
+pub mod image;
 pub mod language;
 pub mod version_content;
+pub mod watch;
@@ -1,11 +1,22 @@
 use diesel::{AsChangeset, Insertable, Queryable, QueryableByName};
 use diesel_derive_enum;
 use rocket::serde::{Deserialize, Serialize};
+use rocket_okapi::okapi::schemars::JsonSchema;
 use rocket_sync_db_pools::diesel;
 
 use super::db_schema::{sql_types, version_content};
 
-#[derive(Serialize, Deserialize, Debug, diesel_derive_enum::DbEnum)]
+#[derive(
+    Serialize,
+    Deserialize,
+    Debug,
+    diesel_derive_enum::DbEnum,
+    JsonSchema,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+)]
 #[ExistingTypePath = "sql_types::ContentType"]
 pub enum ContentType {
     Full,
@@ -22,14 +22,14 @@ impl VersionContentRepository {
     pub fn patch_raw(
         connection: &mut diesel::PgConnection,
         id: i32,
-        content: Vec<u8>,
+        patch_dto: VersionContentDto,
     ) -> Result<model::VersionContent, diesel::result::Error> {
         diesel::update(db_schema::version_content::table)
             .filter(db_schema::version_content::id.eq(id))
             .set(model::VersionContentPatch {
                 id: None,
-                content: Some(content),
-                content_type: Some(model::ContentType::Diff),
+                content: Some(patch_dto.content),
+                content_type: Some(patch_dto.content_type),
                 content_length: None,
             })
             .get_result::<model::VersionContent>(connection)
@@ -51,4 +51,13 @@ impl VersionContentRepository {
             })
             .get_result::<model::VersionContent>(connection)
     }
+
+    pub fn delete_raw(
+        connection: &mut diesel::PgConnection,
+        id: i32,
+    ) -> Result<usize, diesel::result::Error> {
+        diesel::delete(db_schema::version_content::table)
+            .filter(db_schema::version_content::id.eq(id))
+            .execute(connection)
+    }
 }
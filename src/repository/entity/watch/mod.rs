@@ -0,0 +1,11 @@
+use super::auth;
+use super::connection;
+use super::db_schema;
+use super::dtm;
+use super::error;
+
+mod model;
+mod repository;
+
+pub use self::model::{Watch, WatchDelivery, WatchEventType, WatchNotification};
+pub use self::repository::WatchRepository;
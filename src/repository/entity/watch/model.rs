@@ -0,0 +1,98 @@
+use chrono::NaiveDateTime;
+use diesel::{Insertable, Queryable, Selectable};
+use diesel_derive_enum;
+
+use rocket::serde::{Deserialize, Serialize};
+use rocket_okapi::okapi::schemars::JsonSchema;
+use rocket_sync_db_pools::diesel;
+
+use super::db_schema::{sql_types, watch, watch_notification};
+
+#[derive(
+    Serialize,
+    Deserialize,
+    Debug,
+    diesel_derive_enum::DbEnum,
+    JsonSchema,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+)]
+#[ExistingTypePath = "sql_types::WatchDelivery"]
+#[serde(rename_all = "snake_case")]
+pub enum WatchDelivery {
+    Immediate,
+    Digest,
+}
+
+#[derive(
+    Serialize,
+    Deserialize,
+    Debug,
+    diesel_derive_enum::DbEnum,
+    JsonSchema,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+)]
+#[ExistingTypePath = "sql_types::WatchEventType"]
+#[serde(rename_all = "snake_case")]
+pub enum WatchEventType {
+    NewVersion,
+    Archived,
+    Restored,
+    Renamed,
+}
+
+#[derive(Queryable, Debug, Serialize, Deserialize, Selectable, Clone)]
+#[diesel(table_name = watch)]
+pub struct Watch {
+    pub id: i32,
+
+    pub user_id: i32,
+    pub article_id: i32,
+    pub article_language_id: Option<i32>,
+
+    pub delivery: WatchDelivery,
+    pub unsubscribe_key: String,
+
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = watch)]
+pub struct WatchInsertable {
+    pub user_id: i32,
+    pub article_id: i32,
+    pub article_language_id: Option<i32>,
+
+    pub delivery: WatchDelivery,
+    pub unsubscribe_key: String,
+}
+
+#[derive(Queryable, Debug, Serialize, Deserialize, Selectable)]
+#[diesel(table_name = watch_notification)]
+pub struct WatchNotification {
+    pub id: i32,
+
+    pub watch_id: i32,
+    pub event_type: WatchEventType,
+    pub name: String,
+    pub language_code: Option<String>,
+    pub version: Option<i32>,
+
+    pub created_at: NaiveDateTime,
+    pub sent_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = watch_notification)]
+pub struct WatchNotificationInsertable {
+    pub watch_id: i32,
+    pub event_type: WatchEventType,
+    pub name: String,
+    pub language_code: Option<String>,
+    pub version: Option<i32>,
+}
@@ -0,0 +1,183 @@
+use diesel::{dsl::now, prelude::*};
+
+use super::connection::PgConnection;
+use super::error::FmtError;
+
+use super::db_schema;
+use super::model;
+
+use super::auth::UserAccount;
+
+use super::dtm::watch::dto::{UserWatchCreateDto, WatchNotificationCreateDto, WatchPatchDto};
+
+pub struct WatchRepository;
+
+impl WatchRepository {
+    pub async fn get_many(
+        connection: &PgConnection,
+        user_id: i32,
+    ) -> Vec<(model::Watch, Option<String>)> {
+        connection
+            .run(move |connection| {
+                db_schema::watch::table
+                    .left_join(
+                        db_schema::article_language::table.inner_join(db_schema::language::table),
+                    )
+                    .filter(db_schema::watch::user_id.eq(user_id))
+                    .select((
+                        db_schema::watch::all_columns,
+                        db_schema::language::code.nullable(),
+                    ))
+                    .order(db_schema::watch::id.asc())
+                    .load::<(model::Watch, Option<String>)>(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToFetch("watches").fmt())
+    }
+
+    // watches of the article (or of the given language only) of active users, except the author
+    pub async fn get_many_by_target(
+        connection: &PgConnection,
+        article_id: i32,
+        article_language_id: Option<i32>,
+        except_user_id: i32,
+    ) -> Vec<(model::Watch, UserAccount)> {
+        connection
+            .run(move |connection| {
+                let mut query = db_schema::watch::table
+                    .inner_join(db_schema::user_account::table)
+                    .filter(db_schema::watch::article_id.eq(article_id))
+                    .filter(db_schema::watch::user_id.ne(except_user_id))
+                    .filter(db_schema::user_account::active.eq(true))
+                    .filter(db_schema::user_account::blocked.eq(false))
+                    .into_boxed();
+
+                if let Some(article_language_id) = article_language_id {
+                    query = query.filter(
+                        db_schema::watch::article_language_id
+                            .is_null()
+                            .or(db_schema::watch::article_language_id.eq(article_language_id)),
+                    );
+                }
+
+                query.load::<(model::Watch, UserAccount)>(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToFetch("watches").fmt())
+    }
+
+    pub async fn insert(
+        connection: &PgConnection,
+        creation_dto: UserWatchCreateDto,
+    ) -> Result<model::Watch, diesel::result::Error> {
+        connection
+            .run(move |connection| {
+                diesel::insert_into(db_schema::watch::table)
+                    .values(model::WatchInsertable {
+                        user_id: creation_dto.user_id,
+                        article_id: creation_dto.article_id,
+                        article_language_id: creation_dto.article_language_id,
+                        delivery: creation_dto.delivery,
+                        unsubscribe_key: creation_dto.unsubscribe_key,
+                    })
+                    .get_result::<model::Watch>(connection)
+            })
+            .await
+    }
+
+    pub async fn patch(connection: &PgConnection, patch_dto: WatchPatchDto) -> usize {
+        connection
+            .run(move |connection| {
+                diesel::update(db_schema::watch::table)
+                    .filter(db_schema::watch::id.eq(patch_dto.id))
+                    .filter(db_schema::watch::user_id.eq(patch_dto.user_id))
+                    .set(db_schema::watch::delivery.eq(patch_dto.delivery))
+                    .execute(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToUpdate("watch").fmt())
+    }
+
+    pub async fn delete(connection: &PgConnection, id: i32, user_id: i32) -> usize {
+        connection
+            .run(move |connection| {
+                diesel::delete(db_schema::watch::table)
+                    .filter(db_schema::watch::id.eq(id))
+                    .filter(db_schema::watch::user_id.eq(user_id))
+                    .execute(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToUpdate("watch").fmt())
+    }
+
+    pub async fn delete_by_key(connection: &PgConnection, unsubscribe_key: String) -> usize {
+        connection
+            .run(move |connection| {
+                diesel::delete(db_schema::watch::table)
+                    .filter(db_schema::watch::unsubscribe_key.eq(unsubscribe_key))
+                    .execute(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToUpdate("watch").fmt())
+    }
+
+    pub async fn insert_notifications(
+        connection: &PgConnection,
+        creation_dtos: Vec<WatchNotificationCreateDto>,
+    ) -> usize {
+        connection
+            .run(move |connection| {
+                diesel::insert_into(db_schema::watch_notification::table)
+                    .values(
+                        creation_dtos
+                            .into_iter()
+                            .map(|creation_dto| model::WatchNotificationInsertable {
+                                watch_id: creation_dto.watch_id,
+                                event_type: creation_dto.event.event_type,
+                                name: creation_dto.event.name,
+                                language_code: creation_dto.event.language_code,
+                                version: creation_dto.event.version,
+                            })
+                            .collect::<Vec<model::WatchNotificationInsertable>>(),
+                    )
+                    .execute(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToInsert("watch_notification").fmt())
+    }
+
+    pub async fn get_pending_notifications(
+        connection: &PgConnection,
+    ) -> Vec<(model::WatchNotification, model::Watch, UserAccount)> {
+        connection
+            .run(|connection| {
+                db_schema::watch_notification::table
+                    .inner_join(db_schema::watch::table.inner_join(db_schema::user_account::table))
+                    .filter(db_schema::watch_notification::sent_at.is_null())
+                    .select((
+                        db_schema::watch_notification::all_columns,
+                        db_schema::watch::all_columns,
+                        db_schema::user_account::all_columns,
+                    ))
+                    .order((
+                        db_schema::watch::user_id.asc(),
+                        db_schema::watch_notification::id.asc(),
+                    ))
+                    .load::<(model::WatchNotification, model::Watch, UserAccount)>(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToFetch("watch_notifications").fmt())
+    }
+
+    pub async fn mark_notifications_sent(connection: &PgConnection, ids: Vec<i32>) -> usize {
+        connection
+            .run(move |connection| {
+                diesel::update(db_schema::watch_notification::table)
+                    .filter(db_schema::watch_notification::id.eq_any(ids))
+                    .set(db_schema::watch_notification::sent_at.eq(now.nullable()))
+                    .execute(connection)
+            })
+            .await
+            .expect(&FmtError::FailedToUpdate("watch_notification").fmt())
+    }
+}
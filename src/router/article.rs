@@ -1,28 +1,47 @@
-use rocket::{delete, get, patch, post, response::status, serde::json::Json};
+use rocket::{delete, get, patch, post, response::status, serde::json::Json, State};
 use rocket_okapi::{
     okapi::schemars::gen::SchemaSettings, openapi, openapi_get_routes, settings::OpenApiSettings,
 };
+use std::sync::Arc;
 
 use super::authorization::Authorization;
-use super::dtm_common::{QueryOptions, UserRoleId};
+use super::dtm_common::{
+    ApiTokenScope, PaginationOptions, QueryOptions, ResponseString, UserRoleId,
+};
+use super::emailer::EmailTransport;
 use super::repository::PgConnection;
 use super::trait_common::DtoConvert;
 
-use super::aggregation::article::ArticleAggregation;
+use super::aggregation::{article::ArticleAggregation, page::PageAggregation};
 use super::dtm::article::{
-    dto::ArticlePatchDto,
+    dto::{ArticlePatchDto, ArticlesSearchDto},
     request_body::{ArticleCreateRelationsBody, ArticlePatchBody},
 };
 
-use super::service::article::ArticleService;
+use super::dtm::category::dto::ArticleCategoryCreateDto;
+
+use super::service::{article::ArticleService, category::CategoryService};
 
 #[openapi]
-#[get("/")]
+#[get("/?<category_id>&<limit>&<cursor>")]
 async fn get_articles(
     connection: PgConnection,
-) -> Result<Json<Vec<ArticleAggregation>>, status::Custom<String>> {
-    let article_aggregation =
-        ArticleService::get_aggregations(&connection, &QueryOptions { is_actual: true }).await;
+    category_id: Option<i32>,
+    limit: Option<i64>,
+    cursor: Option<String>,
+) -> Result<Json<PageAggregation<ArticleAggregation>>, status::Custom<String>> {
+    let pagination = match PaginationOptions::new(limit, cursor) {
+        Ok(pagination) => pagination,
+        Err(e) => return Err(e.custom()),
+    };
+
+    let article_aggregation = ArticleService::get_aggregations(
+        &connection,
+        ArticlesSearchDto { category_id },
+        pagination,
+        &QueryOptions { is_actual: true },
+    )
+    .await;
 
     return Ok(Json(article_aggregation));
 }
@@ -65,7 +84,9 @@ async fn create_article(
     authorization: Authorization,
     creation_body: Json<ArticleCreateRelationsBody>,
 ) -> Result<Json<ArticleAggregation>, status::Custom<String>> {
-    let user_aggregation = authorization.verify(vec![], &connection).await?;
+    let user_aggregation = authorization
+        .verify(vec![], Some(ApiTokenScope::EditArticles), &connection)
+        .await?;
 
     match ArticleService::insert(&connection, creation_body.0.into_dto(user_aggregation.id)).await {
         Ok(article_aggregation) => Ok(Json(article_aggregation)),
@@ -78,6 +99,7 @@ async fn create_article(
 async fn patch_article(
     connection: PgConnection,
     authorization: Authorization,
+    email_transport: &State<Arc<dyn EmailTransport>>,
     id: i32,
     patch_body: Json<ArticlePatchBody>,
 ) -> Result<Json<ArticleAggregation>, status::Custom<String>> {
@@ -90,11 +112,16 @@ async fn patch_article(
     };
 
     let user_aggregation = authorization
-        .verify(get_allowed_roles(), &connection)
+        .verify(
+            get_allowed_roles(),
+            Some(ApiTokenScope::EditArticles),
+            &connection,
+        )
         .await?;
 
     match ArticleService::patch(
         &connection,
+        email_transport.inner(),
         patch_body.0.into_dto((id, user_aggregation.id)),
         &user_aggregation,
     )
@@ -110,12 +137,16 @@ async fn patch_article(
 async fn delete_article(
     connection: PgConnection,
     authorization: Authorization,
+    email_transport: &State<Arc<dyn EmailTransport>>,
     id: i32,
 ) -> Result<Json<ArticleAggregation>, status::Custom<String>> {
-    let user_aggregation = authorization.verify(vec![], &connection).await?;
+    let user_aggregation = authorization
+        .verify(vec![], Some(ApiTokenScope::EditArticles), &connection)
+        .await?;
 
     match ArticleService::patch(
         &connection,
+        email_transport.inner(),
         ArticlePatchDto {
             id,
             archived: Some(true),
@@ -137,14 +168,20 @@ async fn delete_article(
 async fn restore_article(
     connection: PgConnection,
     authorization: Authorization,
+    email_transport: &State<Arc<dyn EmailTransport>>,
     id: i32,
 ) -> Result<Json<ArticleAggregation>, status::Custom<String>> {
     let user_aggregation = authorization
-        .verify(vec![UserRoleId::Moderator, UserRoleId::Admin], &connection)
+        .verify(
+            vec![UserRoleId::Moderator, UserRoleId::Admin],
+            Some(ApiTokenScope::EditArticles),
+            &connection,
+        )
         .await?;
 
     match ArticleService::patch(
         &connection,
+        email_transport.inner(),
         ArticlePatchDto {
             id,
             archived: Some(false),
@@ -161,6 +198,56 @@ async fn restore_article(
     }
 }
 
+#[openapi]
+#[post("/<id>/category/<category_id>")]
+async fn attach_article_category(
+    connection: PgConnection,
+    authorization: Authorization,
+    id: i32,
+    category_id: i32,
+) -> Result<Json<ResponseString>, status::Custom<String>> {
+    let user_aggregation = authorization
+        .verify(vec![], Some(ApiTokenScope::EditArticles), &connection)
+        .await?;
+
+    match CategoryService::attach(
+        &connection,
+        ArticleCategoryCreateDto {
+            article_id: id,
+            category_id,
+            user_id: user_aggregation.id,
+        },
+        &user_aggregation,
+    )
+    .await
+    {
+        Ok(_) => Ok(Json(ResponseString {
+            status: String::from("success"),
+        })),
+        Err(e) => Err(e.custom()),
+    }
+}
+
+#[openapi]
+#[delete("/<id>/category/<category_id>")]
+async fn detach_article_category(
+    connection: PgConnection,
+    authorization: Authorization,
+    id: i32,
+    category_id: i32,
+) -> Result<Json<ResponseString>, status::Custom<String>> {
+    let user_aggregation = authorization
+        .verify(vec![], Some(ApiTokenScope::EditArticles), &connection)
+        .await?;
+
+    match CategoryService::detach(&connection, id, category_id, &user_aggregation).await {
+        Ok(_) => Ok(Json(ResponseString {
+            status: String::from("success"),
+        })),
+        Err(e) => Err(e.custom()),
+    }
+}
+
 pub fn routes() -> Vec<rocket::Route> {
     let settings = OpenApiSettings {
         json_path: "/article.json".to_owned(),
@@ -175,5 +262,7 @@ pub fn routes() -> Vec<rocket::Route> {
         patch_article,
         delete_article,
         restore_article,
+        attach_article_category,
+        detach_article_category,
     ]
 }
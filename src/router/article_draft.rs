@@ -0,0 +1,138 @@
+use rocket::{delete, get, post, put, response::status, serde::json::Json, State};
+use rocket_okapi::{
+    okapi::schemars::gen::SchemaSettings, openapi, openapi_get_routes, settings::OpenApiSettings,
+};
+use std::sync::Arc;
+
+use super::authorization::Authorization;
+use super::dtm_common::{ApiTokenScope, ResponseString};
+use super::emailer::EmailTransport;
+use super::repository::PgConnection;
+use super::trait_common::DtoConvert;
+
+use super::aggregation::{
+    article_draft::ArticleDraftAggregation, article_version::ArticleVersionAggregation,
+};
+use super::dtm::article_draft::request_body::ArticleDraftSaveBody;
+
+use super::service::{
+    article_draft::ArticleDraftService, version_content::VersionKeyframeInterval,
+};
+
+// drafts are private, each user sees their own one only
+#[openapi]
+#[get("/<article_id>/language/<language_code>/draft")]
+async fn get_article_draft(
+    connection: PgConnection,
+    authorization: Authorization,
+    article_id: i32,
+    language_code: String,
+) -> Result<Json<ArticleDraftAggregation>, status::Custom<String>> {
+    let user_aggregation = authorization
+        .verify(vec![], Some(ApiTokenScope::Read), &connection)
+        .await?;
+
+    match ArticleDraftService::get_aggregation(
+        &connection,
+        article_id,
+        language_code,
+        user_aggregation.id,
+    )
+    .await
+    {
+        Ok(article_draft_aggregation) => Ok(Json(article_draft_aggregation)),
+        Err(e) => Err(e.custom()),
+    }
+}
+
+#[openapi]
+#[put("/<article_id>/language/<language_code>/draft", data = "<save_body>")]
+async fn save_article_draft(
+    connection: PgConnection,
+    authorization: Authorization,
+    article_id: i32,
+    language_code: String,
+    save_body: Json<ArticleDraftSaveBody>,
+) -> Result<Json<ArticleDraftAggregation>, status::Custom<String>> {
+    let user_aggregation = authorization
+        .verify(vec![], Some(ApiTokenScope::EditArticles), &connection)
+        .await?;
+
+    match ArticleDraftService::save(
+        &connection,
+        article_id,
+        language_code,
+        save_body.0.into_dto(user_aggregation.id),
+        &user_aggregation,
+    )
+    .await
+    {
+        Ok(article_draft_aggregation) => Ok(Json(article_draft_aggregation)),
+        Err(e) => Err(e.custom()),
+    }
+}
+
+#[openapi]
+#[delete("/<article_id>/language/<language_code>/draft")]
+async fn delete_article_draft(
+    connection: PgConnection,
+    authorization: Authorization,
+    article_id: i32,
+    language_code: String,
+) -> Result<Json<ResponseString>, status::Custom<String>> {
+    let user_aggregation = authorization
+        .verify(vec![], Some(ApiTokenScope::EditArticles), &connection)
+        .await?;
+
+    match ArticleDraftService::delete(&connection, article_id, language_code, user_aggregation.id)
+        .await
+    {
+        Ok(_) => Ok(Json(ResponseString {
+            status: String::from("success"),
+        })),
+        Err(e) => Err(e.custom()),
+    }
+}
+
+#[openapi]
+#[post("/<article_id>/language/<language_code>/draft/publish")]
+async fn publish_article_draft(
+    connection: PgConnection,
+    authorization: Authorization,
+    email_transport: &State<Arc<dyn EmailTransport>>,
+    keyframe_interval: &State<VersionKeyframeInterval>,
+    article_id: i32,
+    language_code: String,
+) -> Result<Json<ArticleVersionAggregation>, status::Custom<String>> {
+    let user_aggregation = authorization
+        .verify(vec![], Some(ApiTokenScope::EditArticles), &connection)
+        .await?;
+
+    match ArticleDraftService::publish(
+        &connection,
+        email_transport.inner(),
+        article_id,
+        language_code,
+        &user_aggregation,
+        *keyframe_interval.inner(),
+    )
+    .await
+    {
+        Ok(article_version_aggregation) => Ok(Json(article_version_aggregation)),
+        Err(e) => Err(e.custom_detailed()),
+    }
+}
+
+pub fn routes() -> Vec<rocket::Route> {
+    let settings = OpenApiSettings {
+        json_path: "/article_draft.json".to_owned(),
+        schema_settings: SchemaSettings::openapi3(),
+    };
+
+    openapi_get_routes![
+        settings: get_article_draft,
+        save_article_draft,
+        delete_article_draft,
+        publish_article_draft,
+    ]
+}
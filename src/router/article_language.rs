@@ -1,52 +1,91 @@
-use rocket::{delete, get, patch, post, response::status, serde::json::Json};
+use rocket::{delete, get, patch, post, response::status, serde::json::Json, State};
 use rocket_okapi::{
     okapi::schemars::gen::SchemaSettings, openapi, openapi_get_routes, settings::OpenApiSettings,
 };
+use std::sync::Arc;
 
-use super::authorization::Authorization;
-use super::dtm_common::{QueryOptions, UserRoleId};
+use super::authorization::{AcceptLanguage, Authorization};
+use super::dtm_common::{ApiTokenScope, PaginationOptions, QueryOptions, UserRoleId};
+use super::emailer::EmailTransport;
 use super::repository::PgConnection;
 use super::trait_common::DtoConvert;
 
-use super::aggregation::article_language::{
-    ArticleLanguageAggregation, ArticleLanguagePartialAggregation,
+use super::aggregation::{
+    article_language::{
+        ArticleLanguageAggregation, ArticleLanguageFallbackAggregation,
+        ArticleLanguagePartialAggregation,
+    },
+    article_search::ArticleSearchAggregation,
+    page::PageAggregation,
 };
 use super::dtm::article_language::{
-    dto::ArticleLanguagePatchDto,
+    dto::{ArticleLanguageContentSearchDto, ArticleLanguagePatchDto},
     request_body::{ArticleLanguageCreateRelationsBody, ArticleLanguagePatchBody},
 };
 
 use super::service::article_language::ArticleLanguageService;
 
+// missing translations are replaced by the best available one unless `fallback=false`
 #[openapi]
-#[get("/<article_id>/language/<language_code>")]
+#[get("/<article_id>/language/<language_code>?<fallback>")]
 async fn get_article_language(
     connection: PgConnection,
+    accept_language: AcceptLanguage,
     article_id: i32,
     language_code: String,
-) -> Result<Json<ArticleLanguageAggregation>, status::Custom<String>> {
-    match ArticleLanguageService::get_aggregation(
-        &connection,
-        article_id,
-        language_code,
-        QueryOptions { is_actual: true },
-    )
-    .await
-    {
+    fallback: Option<bool>,
+) -> Result<Json<ArticleLanguageFallbackAggregation>, status::Custom<String>> {
+    let query_options = QueryOptions { is_actual: true };
+
+    let article_language_aggregation = match fallback.unwrap_or(true) {
+        true => {
+            ArticleLanguageService::get_fallback_aggregation(
+                &connection,
+                article_id,
+                language_code,
+                accept_language.tags,
+                query_options,
+            )
+            .await
+        }
+        false => ArticleLanguageService::get_aggregation(
+            &connection,
+            article_id,
+            language_code.clone(),
+            query_options,
+        )
+        .await
+        .map(|article_language_aggregation| {
+            ArticleLanguageFallbackAggregation::from_aggregation(
+                language_code,
+                article_language_aggregation,
+            )
+        }),
+    };
+
+    match article_language_aggregation {
         Ok(article_language_aggregation) => Ok(Json(article_language_aggregation)),
         Err(e) => Err(e.custom()),
     }
 }
 
 #[openapi]
-#[get("/<article_id>/language")]
+#[get("/<article_id>/language?<limit>&<cursor>")]
 async fn get_article_languages(
     connection: PgConnection,
     article_id: i32,
-) -> Result<Json<Vec<ArticleLanguageAggregation>>, status::Custom<String>> {
-    let article_languages = ArticleLanguageService::get_aggregations(
+    limit: Option<i64>,
+    cursor: Option<String>,
+) -> Result<Json<PageAggregation<ArticleLanguageAggregation>>, status::Custom<String>> {
+    let pagination = match PaginationOptions::new(limit, cursor) {
+        Ok(pagination) => pagination,
+        Err(e) => return Err(e.custom()),
+    };
+
+    let article_languages = ArticleLanguageService::get_aggregations_page(
         &connection,
         article_id,
+        pagination,
         &QueryOptions { is_actual: true },
     )
     .await;
@@ -70,6 +109,33 @@ async fn get_article_languages_by_query(
     return Ok(Json(article_languages_partials));
 }
 
+#[openapi]
+#[get("/search/content?<query>&<language_code>&<limit>")]
+async fn search_article_languages_by_content(
+    connection: PgConnection,
+    query: String,
+    language_code: Option<String>,
+    limit: Option<i64>,
+) -> Result<Json<Vec<ArticleSearchAggregation>>, status::Custom<String>> {
+    let pagination = match PaginationOptions::new(limit, None) {
+        Ok(pagination) => pagination,
+        Err(e) => return Err(e.custom()),
+    };
+
+    let article_search_aggregations = ArticleLanguageService::get_search_aggregations(
+        &connection,
+        ArticleLanguageContentSearchDto {
+            query,
+            language_code,
+            limit: pagination.limit,
+        },
+        &QueryOptions { is_actual: true },
+    )
+    .await;
+
+    Ok(Json(article_search_aggregations))
+}
+
 #[openapi]
 #[post("/<article_id>/language/<language_code>", data = "<creation_body>")]
 async fn create_article_language(
@@ -79,7 +145,9 @@ async fn create_article_language(
     article_id: i32,
     language_code: String,
 ) -> Result<Json<ArticleLanguageAggregation>, status::Custom<String>> {
-    let user_aggregation = authorization.verify(vec![], &connection).await?;
+    let user_aggregation = authorization
+        .verify(vec![], Some(ApiTokenScope::EditArticles), &connection)
+        .await?;
 
     match ArticleLanguageService::insert(
         &connection,
@@ -99,6 +167,7 @@ async fn create_article_language(
 async fn patch_article_language(
     connection: PgConnection,
     authorization: Authorization,
+    email_transport: &State<Arc<dyn EmailTransport>>,
     patch_body: Json<ArticleLanguagePatchBody>,
     article_id: i32,
     language_code: String,
@@ -112,11 +181,16 @@ async fn patch_article_language(
     };
 
     let user_aggregation = authorization
-        .verify(get_allowed_roles(), &connection)
+        .verify(
+            get_allowed_roles(),
+            Some(ApiTokenScope::EditArticles),
+            &connection,
+        )
         .await?;
 
     match ArticleLanguageService::patch(
         &connection,
+        email_transport.inner(),
         language_code,
         article_id,
         patch_body.0.into_dto(user_aggregation.id),
@@ -133,13 +207,17 @@ async fn patch_article_language(
 async fn delete_article_language(
     connection: PgConnection,
     authorization: Authorization,
+    email_transport: &State<Arc<dyn EmailTransport>>,
     article_id: i32,
     language_code: String,
 ) -> Result<Json<ArticleLanguageAggregation>, status::Custom<String>> {
-    let user_aggregation = authorization.verify(vec![], &connection).await?;
+    let user_aggregation = authorization
+        .verify(vec![], Some(ApiTokenScope::EditArticles), &connection)
+        .await?;
 
     match ArticleLanguageService::patch(
         &connection,
+        email_transport.inner(),
         language_code,
         article_id,
         ArticleLanguagePatchDto {
@@ -161,15 +239,21 @@ async fn delete_article_language(
 async fn restore_article_language(
     connection: PgConnection,
     authorization: Authorization,
+    email_transport: &State<Arc<dyn EmailTransport>>,
     article_id: i32,
     language_code: String,
 ) -> Result<Json<ArticleLanguageAggregation>, status::Custom<String>> {
     let user_aggregation = authorization
-        .verify(vec![UserRoleId::Admin, UserRoleId::Moderator], &connection)
+        .verify(
+            vec![UserRoleId::Admin, UserRoleId::Moderator],
+            Some(ApiTokenScope::EditArticles),
+            &connection,
+        )
         .await?;
 
     match ArticleLanguageService::patch(
         &connection,
+        email_transport.inner(),
         language_code,
         article_id,
         ArticleLanguagePatchDto {
@@ -200,5 +284,6 @@ pub fn routes() -> Vec<rocket::Route> {
         restore_article_language,
         get_article_languages,
         get_article_languages_by_query,
+        search_article_languages_by_content,
     ]
 }
@@ -1,20 +1,33 @@
-use rocket::{get, patch, post, response::status, serde::json::Json};
+use rocket::{delete, get, patch, post, response::status, serde::json::Json, State};
 use rocket_okapi::{
     okapi::schemars::gen::SchemaSettings, openapi, openapi_get_routes, settings::OpenApiSettings,
 };
+use std::sync::Arc;
 
 use super::authorization::Authorization;
-use super::dtm_common::{QueryOptions, UserRoleId};
+use super::dtm_common::{
+    ApiTokenScope, DiffGranularity, PaginationOptions, QueryOptions, ResponseString, UserRoleId,
+};
+use super::emailer::EmailTransport;
 use super::repository::PgConnection;
 use super::trait_common::DtoConvert;
 
-use super::aggregation::article_version::ArticleVersionAggregation;
+use super::aggregation::{
+    article_version::{
+        ArticleVersionAggregation, ArticleVersionCompareAggregation,
+        ArticleVersionOutdatedAggregation,
+    },
+    page::PageAggregation,
+    version_content::VersionKeyframesAggregation,
+};
 use super::dtm::article_version::{
     dto::LanguageSearchDto,
     request_body::{ArticleVersionCreateRelationsBody, ArticleVersionPatchBody},
 };
 
-use super::service::article_version::ArticleVersionService;
+use super::service::{
+    article_version::ArticleVersionService, version_content::VersionKeyframeInterval,
+};
 
 #[openapi]
 #[get("/<article_id>/language/<language_code>/version/<version>")]
@@ -72,15 +85,74 @@ pub async fn get_actual_article_version(
 }
 
 #[openapi]
-#[get("/<article_id>/language/<language_code>/version")]
+#[get(
+    "/<article_id>/language/<language_code>/version/<version>/compare/<compared_version>?<granularity>"
+)]
+pub async fn compare_article_versions(
+    connection: PgConnection,
+    article_id: i32,
+    version: i32,
+    compared_version: i32,
+    language_code: String,
+    granularity: Option<DiffGranularity>,
+) -> Result<Json<ArticleVersionCompareAggregation>, status::Custom<String>> {
+    match ArticleVersionService::get_compare_aggregation(
+        &connection,
+        version,
+        compared_version,
+        granularity.unwrap_or(DiffGranularity::Line),
+        LanguageSearchDto {
+            language_code: Some(language_code),
+            article_id: Some(article_id),
+
+            article_language: None,
+            article_languages_ids: None,
+            article_language_key: None,
+        },
+        &QueryOptions { is_actual: true },
+    )
+    .await
+    {
+        Ok(article_version_compare_aggregation) => Ok(Json(article_version_compare_aggregation)),
+        Err(e) => Err(e.custom()),
+    }
+}
+
+#[openapi]
+#[get("/<article_id>/translation/outdated?<granularity>")]
+pub async fn get_outdated_translations(
+    connection: PgConnection,
+    article_id: i32,
+    granularity: Option<DiffGranularity>,
+) -> Result<Json<Vec<ArticleVersionOutdatedAggregation>>, status::Custom<String>> {
+    match ArticleVersionService::get_outdated_aggregations(
+        &connection,
+        article_id,
+        granularity.unwrap_or(DiffGranularity::Line),
+    )
+    .await
+    {
+        Ok(outdated_aggregations) => Ok(Json(outdated_aggregations)),
+        Err(e) => Err(e.custom()),
+    }
+}
+
+#[openapi]
+#[get("/<article_id>/language/<language_code>/version?<limit>&<cursor>")]
 async fn get_article_versions(
     connection: PgConnection,
     article_id: i32,
     language_code: String,
-) -> Result<Json<Vec<ArticleVersionAggregation>>, status::Custom<String>> {
-    match ArticleVersionService::get_aggregations(
+    limit: Option<i64>,
+    cursor: Option<String>,
+) -> Result<Json<PageAggregation<ArticleVersionAggregation>>, status::Custom<String>> {
+    let pagination = match PaginationOptions::new(limit, cursor) {
+        Ok(pagination) => pagination,
+        Err(e) => return Err(e.custom()),
+    };
+
+    match ArticleVersionService::get_aggregations_page(
         &connection,
-        false,
         LanguageSearchDto {
             language_code: Some(language_code),
             article_id: Some(article_id),
@@ -89,6 +161,7 @@ async fn get_article_versions(
             article_languages_ids: None,
             article_language_key: None,
         },
+        pagination,
         &QueryOptions { is_actual: true },
     )
     .await
@@ -99,14 +172,20 @@ async fn get_article_versions(
 }
 
 #[openapi]
-#[get("/key/<article_language_key>/version", rank = 1)]
+#[get("/key/<article_language_key>/version?<limit>&<cursor>", rank = 1)]
 async fn get_article_versions_by_key(
     connection: PgConnection,
     article_language_key: String,
-) -> Result<Json<Vec<ArticleVersionAggregation>>, status::Custom<String>> {
-    match ArticleVersionService::get_aggregations(
+    limit: Option<i64>,
+    cursor: Option<String>,
+) -> Result<Json<PageAggregation<ArticleVersionAggregation>>, status::Custom<String>> {
+    let pagination = match PaginationOptions::new(limit, cursor) {
+        Ok(pagination) => pagination,
+        Err(e) => return Err(e.custom()),
+    };
+
+    match ArticleVersionService::get_aggregations_page(
         &connection,
-        false,
         LanguageSearchDto {
             article_language_key: Some(article_language_key),
 
@@ -115,6 +194,7 @@ async fn get_article_versions_by_key(
             article_language: None,
             article_languages_ids: None,
         },
+        pagination,
         &QueryOptions { is_actual: true },
     )
     .await
@@ -132,23 +212,29 @@ async fn get_article_versions_by_key(
 async fn create_article_version(
     connection: PgConnection,
     authorization: Authorization,
+    email_transport: &State<Arc<dyn EmailTransport>>,
+    keyframe_interval: &State<VersionKeyframeInterval>,
     creation_body: Json<ArticleVersionCreateRelationsBody>,
     article_id: i32,
     language_code: String,
 ) -> Result<Json<ArticleVersionAggregation>, status::Custom<String>> {
-    let user_aggregation = authorization.verify(vec![], &connection).await?;
+    let user_aggregation = authorization
+        .verify(vec![], Some(ApiTokenScope::EditArticles), &connection)
+        .await?;
 
     match ArticleVersionService::insert(
         &connection,
+        email_transport.inner(),
         article_id,
         language_code,
         creation_body.0.into_dto(user_aggregation.id),
         &user_aggregation,
+        *keyframe_interval.inner(),
     )
     .await
     {
         Ok(article_version_aggregation) => Ok(Json(article_version_aggregation)),
-        Err(e) => Err(e.custom()),
+        Err(e) => Err(e.custom_detailed()),
     }
 }
 
@@ -166,7 +252,11 @@ async fn patch_article_version(
     patch_body: Json<ArticleVersionPatchBody>,
 ) -> Result<Json<ArticleVersionAggregation>, status::Custom<String>> {
     let user_aggregation = authorization
-        .verify(vec![UserRoleId::Admin, UserRoleId::Moderator], &connection)
+        .verify(
+            vec![UserRoleId::Admin, UserRoleId::Moderator],
+            Some(ApiTokenScope::EditArticles),
+            &connection,
+        )
         .await?;
 
     match ArticleVersionService::patch(
@@ -183,6 +273,57 @@ async fn patch_article_version(
     }
 }
 
+#[openapi]
+#[delete("/<article_id>/language/<language_code>/version/<version>")]
+async fn delete_article_version(
+    connection: PgConnection,
+    authorization: Authorization,
+    article_id: i32,
+    version: i32,
+    language_code: String,
+) -> Result<Json<ResponseString>, status::Custom<String>> {
+    let user_aggregation = authorization
+        .verify(vec![UserRoleId::Admin], None, &connection)
+        .await?;
+
+    match ArticleVersionService::delete(
+        &connection,
+        version,
+        article_id,
+        language_code,
+        user_aggregation.id,
+    )
+    .await
+    {
+        Ok(_) => Ok(Json(ResponseString {
+            status: String::from("success"),
+        })),
+        Err(e) => Err(e.custom()),
+    }
+}
+
+#[openapi]
+#[post("/version/keyframes?<article_id>")]
+async fn rebuild_version_keyframes(
+    connection: PgConnection,
+    authorization: Authorization,
+    keyframe_interval: &State<VersionKeyframeInterval>,
+    article_id: Option<i32>,
+) -> Result<Json<VersionKeyframesAggregation>, status::Custom<String>> {
+    authorization
+        .verify(vec![UserRoleId::Admin], None, &connection)
+        .await?;
+
+    let keyframes_count = ArticleVersionService::rebuild_keyframes(
+        &connection,
+        article_id,
+        *keyframe_interval.inner(),
+    )
+    .await;
+
+    Ok(Json(VersionKeyframesAggregation { keyframes_count }))
+}
+
 pub fn routes() -> Vec<rocket::Route> {
     let settings = OpenApiSettings {
         json_path: "/article_version.json".to_owned(),
@@ -194,7 +335,11 @@ pub fn routes() -> Vec<rocket::Route> {
         get_article_version,
         create_article_version,
         patch_article_version,
+        delete_article_version,
         get_actual_article_version,
-        get_article_versions_by_key
+        get_article_versions_by_key,
+        compare_article_versions,
+        get_outdated_translations,
+        rebuild_version_keyframes
     ]
 }
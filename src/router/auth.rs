@@ -1,33 +1,49 @@
-use rocket::{get, patch, post, response::status, serde::json::Json};
+use rocket::{delete, get, patch, post, response::status, serde::json::Json, State};
 use rocket_okapi::{
     okapi::schemars::gen::SchemaSettings, openapi, openapi_get_routes, settings::OpenApiSettings,
 };
+use std::sync::Arc;
 
-use super::authorization::Authorization;
+use super::authorization::{Authorization, ClientAddress};
 use super::dtm_common::{ResponseString, TokenDto, UserRoleId};
+use super::emailer::EmailTransport;
 use super::repository::PgConnection;
 use super::trait_common::DtoConvert;
 
+use super::aggregation::api_token::{ApiTokenAggregation, ApiTokenCreatedAggregation};
 use super::aggregation::user_account::UserAccountAggregation;
 use super::aggregation::user_account_auth::{
-    UserAccountAuthAggregation, UserAccountPermissionsAggregation,
+    OidcAuthorizationAggregation, TotpEnrollmentAggregation, TotpRecoveryCodesAggregation,
+    UserAccountAuthAggregation, UserAccountEnrollmentAggregation, UserAccountLoginAggregation,
+    UserAccountPermissionsAggregation,
 };
 
 use super::dtm::auth::request_body::{
-    UserConfirmBody, UserConfirmPasswordResetBody, UserLoginBody, UserPatchBody, UserResetBody,
-    UserSignupBody,
+    ApiTokenCreateBody, UserChallengeBody, UserChallengeEnrollBody, UserConfirmBody,
+    UserConfirmPasswordResetBody, UserLanguageBody, UserLoginBody, UserOidcCallbackBody,
+    UserPatchBody, UserRefreshBody, UserResendBody, UserResetBody, UserSignupBody,
+    UserTotpCodeBody,
 };
 
+use super::service::api_token::ApiTokenService;
 use super::service::auth::AuthService;
 
 #[openapi]
-#[post("/signup?<redirect_to>", data = "<user_signup_body>")]
+#[post("/signup?<redirect_to>&<language>", data = "<user_signup_body>")]
 async fn signup(
     connection: PgConnection,
+    email_transport: &State<Arc<dyn EmailTransport>>,
     user_signup_body: Json<UserSignupBody>,
     redirect_to: Option<String>,
+    language: Option<String>,
 ) -> Result<Json<ResponseString>, status::Custom<String>> {
-    match AuthService::create_user(&connection, user_signup_body.0.into_dto(()), redirect_to).await
+    match AuthService::create_user(
+        &connection,
+        email_transport.inner(),
+        user_signup_body.0.into_dto(language),
+        redirect_to,
+    )
+    .await
     {
         Ok(_) => Ok(Json(ResponseString {
             status: String::from("success"),
@@ -40,11 +56,40 @@ async fn signup(
 #[post("/reset?<redirect_to>", data = "<user_reset_body>")]
 async fn reset(
     connection: PgConnection,
+    email_transport: &State<Arc<dyn EmailTransport>>,
     user_reset_body: Json<UserResetBody>,
     redirect_to: Option<String>,
 ) -> Result<Json<ResponseString>, status::Custom<String>> {
-    match AuthService::reset_user_password(&connection, user_reset_body.0.into_dto(()), redirect_to)
-        .await
+    match AuthService::reset_user_password(
+        &connection,
+        email_transport.inner(),
+        user_reset_body.0.into_dto(()),
+        redirect_to,
+    )
+    .await
+    {
+        Ok(_) => Ok(Json(ResponseString {
+            status: String::from("success"),
+        })),
+        Err(e) => Err(e.custom()),
+    }
+}
+
+#[openapi]
+#[post("/resend?<redirect_to>", data = "<user_resend_body>")]
+async fn resend(
+    connection: PgConnection,
+    email_transport: &State<Arc<dyn EmailTransport>>,
+    user_resend_body: Json<UserResendBody>,
+    redirect_to: Option<String>,
+) -> Result<Json<ResponseString>, status::Custom<String>> {
+    match AuthService::resend_otp(
+        &connection,
+        email_transport.inner(),
+        user_resend_body.0.into_dto(()),
+        redirect_to,
+    )
+    .await
     {
         Ok(_) => Ok(Json(ResponseString {
             status: String::from("success"),
@@ -70,7 +115,7 @@ async fn confirm(
 async fn confirm_reset(
     connection: PgConnection,
     user_confirm_reset_body: Json<UserConfirmPasswordResetBody>,
-) -> Result<Json<UserAccountAuthAggregation>, status::Custom<String>> {
+) -> Result<Json<UserAccountLoginAggregation>, status::Custom<String>> {
     match AuthService::confirm_password_reset(&connection, user_confirm_reset_body.0.into_dto(()))
         .await
     {
@@ -88,8 +133,12 @@ async fn signup_with_role(
     user_signup_body: Json<UserSignupBody>,
     role_id: i32,
 ) -> Result<Json<TokenDto>, status::Custom<String>> {
-    match AuthService::create_user_with_role(&connection, user_signup_body.0.into_dto(()), role_id)
-        .await
+    match AuthService::create_user_with_role(
+        &connection,
+        user_signup_body.0.into_dto(None),
+        role_id,
+    )
+    .await
     {
         Ok(token_response) => Ok(Json(token_response)),
         Err(e) => Err(e.custom()),
@@ -100,14 +149,172 @@ async fn signup_with_role(
 #[post("/login", data = "<user_login_body>")]
 async fn login(
     connection: PgConnection,
+    client_address: ClientAddress,
     user_login_body: Json<UserLoginBody>,
+) -> Result<Json<UserAccountLoginAggregation>, status::Custom<String>> {
+    match AuthService::login(
+        &connection,
+        user_login_body.0.into_dto(()),
+        client_address.ip,
+    )
+    .await
+    {
+        Ok(aggregation) => Ok(Json(aggregation)),
+        Err(e) => Err(e.custom()),
+    }
+}
+
+#[openapi]
+#[get("/oidc/<provider>")]
+async fn oidc_authorize(
+    provider: String,
+) -> Result<Json<OidcAuthorizationAggregation>, status::Custom<String>> {
+    match AuthService::get_oidc_authorization(provider).await {
+        Ok(aggregation) => Ok(Json(aggregation)),
+        Err(e) => Err(e.custom()),
+    }
+}
+
+#[openapi]
+#[post("/oidc/<provider>/callback", data = "<callback_body>")]
+async fn oidc_callback(
+    connection: PgConnection,
+    provider: String,
+    callback_body: Json<UserOidcCallbackBody>,
+) -> Result<Json<UserAccountLoginAggregation>, status::Custom<String>> {
+    match AuthService::login_oidc(&connection, callback_body.0.into_dto(provider)).await {
+        Ok(aggregation) => Ok(Json(aggregation)),
+        Err(e) => Err(e.custom()),
+    }
+}
+
+#[openapi]
+#[post("/login/totp", data = "<challenge_body>")]
+async fn login_totp(
+    connection: PgConnection,
+    challenge_body: Json<UserChallengeBody>,
 ) -> Result<Json<UserAccountAuthAggregation>, status::Custom<String>> {
-    match AuthService::login(&connection, user_login_body.0.into_dto(())).await {
+    match AuthService::login_challenge(&connection, challenge_body.0.into_dto(())).await {
         Ok(aggregation) => Ok(Json(aggregation)),
         Err(e) => Err(e.custom()),
     }
 }
 
+#[openapi]
+#[post("/login/totp/enroll", data = "<enroll_body>")]
+async fn login_totp_enroll(
+    connection: PgConnection,
+    enroll_body: Json<UserChallengeEnrollBody>,
+) -> Result<Json<TotpEnrollmentAggregation>, status::Custom<String>> {
+    match AuthService::enroll_challenge(&connection, enroll_body.0.into_dto(())).await {
+        Ok(aggregation) => Ok(Json(aggregation)),
+        Err(e) => Err(e.custom()),
+    }
+}
+
+#[openapi]
+#[post("/login/totp/confirm", data = "<challenge_body>")]
+async fn login_totp_confirm(
+    connection: PgConnection,
+    challenge_body: Json<UserChallengeBody>,
+) -> Result<Json<UserAccountEnrollmentAggregation>, status::Custom<String>> {
+    match AuthService::confirm_enroll_challenge(&connection, challenge_body.0.into_dto(())).await {
+        Ok(aggregation) => Ok(Json(aggregation)),
+        Err(e) => Err(e.custom()),
+    }
+}
+
+#[openapi]
+#[post("/totp")]
+async fn start_totp(
+    connection: PgConnection,
+    authorization: Authorization,
+) -> Result<Json<TotpEnrollmentAggregation>, status::Custom<String>> {
+    let user_aggregation = authorization.verify(vec![], None, &connection).await?;
+
+    match AuthService::start_totp(&connection, user_aggregation.id).await {
+        Ok(aggregation) => Ok(Json(aggregation)),
+        Err(e) => Err(e.custom()),
+    }
+}
+
+#[openapi]
+#[post("/totp/confirm", data = "<code_body>")]
+async fn confirm_totp(
+    connection: PgConnection,
+    authorization: Authorization,
+    code_body: Json<UserTotpCodeBody>,
+) -> Result<Json<TotpRecoveryCodesAggregation>, status::Custom<String>> {
+    let user_aggregation = authorization.verify(vec![], None, &connection).await?;
+
+    match AuthService::confirm_totp(&connection, code_body.0.into_dto(user_aggregation.id)).await {
+        Ok(aggregation) => Ok(Json(aggregation)),
+        Err(e) => Err(e.custom()),
+    }
+}
+
+#[openapi]
+#[post("/totp/disable", data = "<code_body>")]
+async fn disable_totp(
+    connection: PgConnection,
+    authorization: Authorization,
+    code_body: Json<UserTotpCodeBody>,
+) -> Result<Json<ResponseString>, status::Custom<String>> {
+    let user_aggregation = authorization.verify(vec![], None, &connection).await?;
+
+    match AuthService::disable_totp(&connection, code_body.0.into_dto(user_aggregation.id)).await {
+        Ok(_) => Ok(Json(ResponseString {
+            status: String::from("success"),
+        })),
+        Err(e) => Err(e.custom()),
+    }
+}
+
+#[openapi]
+#[post("/refresh", data = "<user_refresh_body>")]
+async fn refresh(
+    connection: PgConnection,
+    user_refresh_body: Json<UserRefreshBody>,
+) -> Result<Json<UserAccountAuthAggregation>, status::Custom<String>> {
+    match AuthService::refresh(&connection, user_refresh_body.0.into_dto(())).await {
+        Ok(aggregation) => Ok(Json(aggregation)),
+        Err(e) => Err(e.custom()),
+    }
+}
+
+#[openapi]
+#[post("/logout")]
+async fn logout(
+    connection: PgConnection,
+    authorization: Authorization,
+) -> Result<Json<ResponseString>, status::Custom<String>> {
+    let (_, session_id) = authorization
+        .verify_with_session(vec![], &connection)
+        .await?;
+
+    match AuthService::logout(&connection, session_id).await {
+        Ok(_) => Ok(Json(ResponseString {
+            status: String::from("success"),
+        })),
+        Err(e) => Err(e.custom()),
+    }
+}
+
+#[openapi]
+#[post("/logout/all")]
+async fn logout_all(
+    connection: PgConnection,
+    authorization: Authorization,
+) -> Result<Json<ResponseString>, status::Custom<String>> {
+    let user_aggregation = authorization.verify(vec![], None, &connection).await?;
+
+    AuthService::logout_all(&connection, user_aggregation.id).await;
+
+    Ok(Json(ResponseString {
+        status: String::from("success"),
+    }))
+}
+
 #[openapi]
 #[get("/user?<article_code>")]
 async fn get_user(
@@ -115,7 +322,7 @@ async fn get_user(
     authorization: Authorization,
     article_code: Option<String>,
 ) -> Result<Json<UserAccountPermissionsAggregation>, status::Custom<String>> {
-    let user_aggregation = authorization.verify(vec![], &connection).await?;
+    let user_aggregation = authorization.verify(vec![], None, &connection).await?;
 
     let user_permission_aggregation =
         AuthService::get_user_with_permissions(&connection, user_aggregation, article_code).await;
@@ -130,12 +337,35 @@ async fn test_jwt(
     authorization: Authorization,
 ) -> Result<Json<String>, status::Custom<String>> {
     authorization
-        .verify(vec![UserRoleId::Admin, UserRoleId::Common], &connection)
+        .verify(
+            vec![UserRoleId::Admin, UserRoleId::Common],
+            None,
+            &connection,
+        )
         .await?;
 
     Ok(Json(String::from("ok")))
 }
 
+#[openapi]
+#[patch("/user/language", data = "<language_body>")]
+async fn patch_user_language(
+    connection: PgConnection,
+    authorization: Authorization,
+    language_body: Json<UserLanguageBody>,
+) -> Result<Json<ResponseString>, status::Custom<String>> {
+    let user_aggregation = authorization.verify(vec![], None, &connection).await?;
+
+    match AuthService::patch_language(&connection, language_body.0.into_dto(user_aggregation.id))
+        .await
+    {
+        Ok(_) => Ok(Json(ResponseString {
+            status: String::from("success"),
+        })),
+        Err(e) => Err(e.custom()),
+    }
+}
+
 #[openapi]
 #[patch("/user/<user_id>", data = "<patch_body>")]
 async fn patch_user(
@@ -145,7 +375,11 @@ async fn patch_user(
     patch_body: Json<UserPatchBody>,
 ) -> Result<Json<UserAccountAggregation>, status::Custom<String>> {
     let user_aggregation = authorization
-        .verify(vec![UserRoleId::Admin, UserRoleId::Moderator], &connection)
+        .verify(
+            vec![UserRoleId::Admin, UserRoleId::Moderator],
+            None,
+            &connection,
+        )
         .await?;
 
     match AuthService::patch(
@@ -159,6 +393,69 @@ async fn patch_user(
     }
 }
 
+#[openapi]
+#[post("/user/<user_id>/unlock")]
+async fn unlock_user(
+    connection: PgConnection,
+    authorization: Authorization,
+    user_id: i32,
+) -> Result<Json<UserAccountAggregation>, status::Custom<String>> {
+    authorization
+        .verify(vec![UserRoleId::Admin], None, &connection)
+        .await?;
+
+    match AuthService::unlock(&connection, user_id).await {
+        Ok(user_account_aggregation) => Ok(Json(user_account_aggregation)),
+        Err(e) => Err(e.custom()),
+    }
+}
+
+#[openapi]
+#[post("/tokens", data = "<api_token_body>")]
+async fn create_api_token(
+    connection: PgConnection,
+    authorization: Authorization,
+    api_token_body: Json<ApiTokenCreateBody>,
+) -> Result<Json<ApiTokenCreatedAggregation>, status::Custom<String>> {
+    let user_aggregation = authorization.verify(vec![], None, &connection).await?;
+
+    match ApiTokenService::create(&connection, api_token_body.0.into_dto(user_aggregation.id)).await
+    {
+        Ok(aggregation) => Ok(Json(aggregation)),
+        Err(e) => Err(e.custom()),
+    }
+}
+
+#[openapi]
+#[get("/tokens")]
+async fn get_api_tokens(
+    connection: PgConnection,
+    authorization: Authorization,
+) -> Result<Json<Vec<ApiTokenAggregation>>, status::Custom<String>> {
+    let user_aggregation = authorization.verify(vec![], None, &connection).await?;
+
+    Ok(Json(
+        ApiTokenService::get_all(&connection, user_aggregation.id).await,
+    ))
+}
+
+#[openapi]
+#[delete("/tokens/<id>")]
+async fn revoke_api_token(
+    connection: PgConnection,
+    authorization: Authorization,
+    id: i32,
+) -> Result<Json<ResponseString>, status::Custom<String>> {
+    let user_aggregation = authorization.verify(vec![], None, &connection).await?;
+
+    match ApiTokenService::revoke(&connection, id, user_aggregation.id).await {
+        Ok(_) => Ok(Json(ResponseString {
+            status: String::from("success"),
+        })),
+        Err(e) => Err(e.custom()),
+    }
+}
+
 pub fn routes() -> Vec<rocket::Route> {
     let settings = OpenApiSettings {
         json_path: "/auth.json".to_owned(),
@@ -168,9 +465,26 @@ pub fn routes() -> Vec<rocket::Route> {
     openapi_get_routes![
         settings: signup,
         login,
+        login_totp,
+        login_totp_enroll,
+        login_totp_confirm,
+        oidc_authorize,
+        oidc_callback,
+        start_totp,
+        confirm_totp,
+        disable_totp,
+        refresh,
+        logout,
+        logout_all,
         reset,
+        resend,
         test_jwt,
         patch_user,
+        patch_user_language,
+        unlock_user,
+        create_api_token,
+        get_api_tokens,
+        revoke_api_token,
         get_user,
         confirm,
         confirm_reset,
@@ -188,9 +502,26 @@ pub fn test_routes() -> Vec<rocket::Route> {
     openapi_get_routes![
         settings: signup,
         login,
+        login_totp,
+        login_totp_enroll,
+        login_totp_confirm,
+        oidc_authorize,
+        oidc_callback,
+        start_totp,
+        confirm_totp,
+        disable_totp,
+        refresh,
+        logout,
+        logout_all,
         reset,
+        resend,
         test_jwt,
         patch_user,
+        patch_user_language,
+        unlock_user,
+        create_api_token,
+        get_api_tokens,
+        revoke_api_token,
         signup_with_role,
         get_user,
         confirm,
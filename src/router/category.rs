@@ -0,0 +1,123 @@
+use rocket::{delete, get, patch, post, response::status, serde::json::Json};
+use rocket_okapi::{
+    okapi::schemars::gen::SchemaSettings, openapi, openapi_get_routes, settings::OpenApiSettings,
+};
+
+use super::authorization::Authorization;
+use super::dtm_common::{ResponseString, UserRoleId};
+use super::repository::PgConnection;
+use super::trait_common::DtoConvert;
+
+use super::aggregation::category::CategoryAggregation;
+use super::dtm::category::request_body::{CategoryCreateBody, CategoryPatchBody};
+
+use super::service::category::CategoryService;
+
+#[openapi]
+#[get("/")]
+async fn get_categories(
+    connection: PgConnection,
+) -> Result<Json<Vec<CategoryAggregation>>, status::Custom<String>> {
+    let aggregations = CategoryService::get_aggregations(&connection).await;
+
+    Ok(Json(aggregations))
+}
+
+#[openapi]
+#[get("/<id>")]
+async fn get_category(
+    connection: PgConnection,
+    id: i32,
+) -> Result<Json<CategoryAggregation>, status::Custom<String>> {
+    match CategoryService::get_aggregation(&connection, id).await {
+        Ok(category_aggregation) => Ok(Json(category_aggregation)),
+        Err(e) => Err(e.custom()),
+    }
+}
+
+#[openapi]
+#[post("/", data = "<creation_body>")]
+async fn create_category(
+    connection: PgConnection,
+    authorization: Authorization,
+    creation_body: Json<CategoryCreateBody>,
+) -> Result<Json<CategoryAggregation>, status::Custom<String>> {
+    let user_aggregation = authorization
+        .verify(
+            vec![UserRoleId::Moderator, UserRoleId::Admin],
+            None,
+            &connection,
+        )
+        .await?;
+
+    match CategoryService::insert(&connection, creation_body.0.into_dto(user_aggregation.id)).await
+    {
+        Ok(category_aggregation) => Ok(Json(category_aggregation)),
+        Err(e) => Err(e.custom()),
+    }
+}
+
+#[openapi]
+#[patch("/<id>", data = "<patch_body>")]
+async fn patch_category(
+    connection: PgConnection,
+    authorization: Authorization,
+    id: i32,
+    patch_body: Json<CategoryPatchBody>,
+) -> Result<Json<CategoryAggregation>, status::Custom<String>> {
+    let user_aggregation = authorization
+        .verify(
+            vec![UserRoleId::Moderator, UserRoleId::Admin],
+            None,
+            &connection,
+        )
+        .await?;
+
+    match CategoryService::patch(
+        &connection,
+        patch_body.0.into_dto((id, user_aggregation.id)),
+    )
+    .await
+    {
+        Ok(category_aggregation) => Ok(Json(category_aggregation)),
+        Err(e) => Err(e.custom()),
+    }
+}
+
+#[openapi]
+#[delete("/<id>")]
+async fn delete_category(
+    connection: PgConnection,
+    authorization: Authorization,
+    id: i32,
+) -> Result<Json<ResponseString>, status::Custom<String>> {
+    authorization
+        .verify(
+            vec![UserRoleId::Moderator, UserRoleId::Admin],
+            None,
+            &connection,
+        )
+        .await?;
+
+    match CategoryService::delete(&connection, id).await {
+        Ok(_) => Ok(Json(ResponseString {
+            status: String::from("success"),
+        })),
+        Err(e) => Err(e.custom()),
+    }
+}
+
+pub fn routes() -> Vec<rocket::Route> {
+    let settings = OpenApiSettings {
+        json_path: "/category.json".to_owned(),
+        schema_settings: SchemaSettings::openapi3(),
+    };
+
+    openapi_get_routes![
+        settings: get_categories,
+        get_category,
+        create_category,
+        patch_category,
+        delete_category,
+    ]
+}
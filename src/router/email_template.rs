@@ -0,0 +1,88 @@
+use rocket::{get, patch, response::status, serde::json::Json};
+use rocket_okapi::{
+    okapi::schemars::gen::SchemaSettings, openapi, openapi_get_routes, settings::OpenApiSettings,
+};
+
+use super::authorization::Authorization;
+use super::dtm_common::UserRoleId;
+use super::repository::PgConnection;
+use super::trait_common::DtoConvert;
+
+use super::aggregation::email_template::{EmailPreviewAggregation, EmailTemplateAggregation};
+use super::dtm::email_template::request_body::EmailTemplatePatchBody;
+
+use super::service::email_template::EmailTemplateService;
+
+#[openapi]
+#[get("/")]
+async fn get_email_templates(
+    connection: PgConnection,
+    authorization: Authorization,
+) -> Result<Json<Vec<EmailTemplateAggregation>>, status::Custom<String>> {
+    authorization
+        .verify(vec![UserRoleId::Admin], None, &connection)
+        .await?;
+
+    let aggregations = EmailTemplateService::get_aggregations(&connection).await;
+
+    Ok(Json(aggregations))
+}
+
+#[openapi]
+#[patch("/<template_type>/<language>", data = "<patch_body>")]
+async fn patch_email_template(
+    connection: PgConnection,
+    authorization: Authorization,
+    template_type: String,
+    language: String,
+    patch_body: Json<EmailTemplatePatchBody>,
+) -> Result<Json<EmailTemplateAggregation>, status::Custom<String>> {
+    let user_aggregation = authorization
+        .verify(vec![UserRoleId::Admin], None, &connection)
+        .await?;
+
+    match EmailTemplateService::patch(
+        &connection,
+        patch_body
+            .0
+            .into_dto((template_type, language, user_aggregation.id)),
+    )
+    .await
+    {
+        Ok(email_template_aggregation) => Ok(Json(email_template_aggregation)),
+        Err(e) => Err(e.custom()),
+    }
+}
+
+#[openapi]
+#[get("/<template_type>/<language>/preview")]
+async fn preview_email_template(
+    connection: PgConnection,
+    authorization: Authorization,
+    template_type: String,
+    language: String,
+) -> Result<Json<EmailPreviewAggregation>, status::Custom<String>> {
+    let user_aggregation = authorization
+        .verify(vec![UserRoleId::Admin], None, &connection)
+        .await?;
+
+    match EmailTemplateService::get_preview(&connection, template_type, language, &user_aggregation)
+        .await
+    {
+        Ok(preview_aggregation) => Ok(Json(preview_aggregation)),
+        Err(e) => Err(e.custom()),
+    }
+}
+
+pub fn routes() -> Vec<rocket::Route> {
+    let settings = OpenApiSettings {
+        json_path: "/email_template.json".to_owned(),
+        schema_settings: SchemaSettings::openapi3(),
+    };
+
+    openapi_get_routes![
+        settings: get_email_templates,
+        patch_email_template,
+        preview_email_template,
+    ]
+}
@@ -1,25 +1,67 @@
-use rocket::{post, response::status, serde::json::Json};
+use rocket::{delete, fs::NamedFile, get, post, response::status, serde::json::Json};
 use rocket_okapi::{
     okapi::schemars::gen::SchemaSettings, openapi, openapi_get_routes, settings::OpenApiSettings,
 };
 
 use super::authorization::Authorization;
+use super::dtm_common::{ApiTokenScope, PaginationOptions, ResponseString, UserRoleId};
 use super::repository::PgConnection;
 use super::trait_common::DtoConvert;
 
-use super::aggregation::image::ImageAggregation;
-use super::dtm::image::request_body::ImageCreateBody;
+use super::aggregation::{
+    image::{ImageAggregation, ImageCleanupAggregation, ImagesUploadAggregation},
+    page::PageAggregation,
+};
+use super::dtm::image::request_body::{ImageUploadForm, ImagesCreateBody};
 
 use super::service::image::ImageService;
 
+#[openapi]
+#[get("/?<limit>&<cursor>")]
+async fn get_images(
+    connection: PgConnection,
+    authorization: Authorization,
+    limit: Option<i64>,
+    cursor: Option<String>,
+) -> Result<Json<PageAggregation<ImageAggregation>>, status::Custom<String>> {
+    let user_aggregation = authorization
+        .verify(vec![], Some(ApiTokenScope::Read), &connection)
+        .await?;
+
+    let pagination = match PaginationOptions::new(limit, cursor) {
+        Ok(pagination) => pagination,
+        Err(e) => return Err(e.custom()),
+    };
+
+    let images_aggregation =
+        ImageService::get_user_aggregations_page(&connection, user_aggregation.id, pagination)
+            .await;
+
+    Ok(Json(images_aggregation))
+}
+
+#[openapi]
+#[get("/article/<article_id>")]
+async fn get_article_images(
+    connection: PgConnection,
+    article_id: i32,
+) -> Result<Json<Vec<ImageAggregation>>, status::Custom<String>> {
+    match ImageService::get_article_aggregations(&connection, article_id).await {
+        Ok(images_aggregation) => Ok(Json(images_aggregation)),
+        Err(e) => Err(e.custom()),
+    }
+}
+
 #[openapi]
 #[post("/", data = "<images_body>")]
 async fn create_image(
-    images_body: Json<Vec<ImageCreateBody>>,
+    images_body: ImagesCreateBody,
     connection: PgConnection,
     authorization: Authorization,
-) -> Result<Json<Vec<ImageAggregation>>, status::Custom<String>> {
-    authorization.verify(vec![], &connection).await?;
+) -> Result<Json<ImagesUploadAggregation>, status::Custom<String>> {
+    let user_aggregation = authorization
+        .verify(vec![], Some(ApiTokenScope::UploadImages), &connection)
+        .await?;
 
     let dtos = images_body
         .0
@@ -27,17 +69,85 @@ async fn create_image(
         .map(|body| body.into_dto(()))
         .collect();
 
-    match ImageService::upload_images(dtos).await {
-        Ok(created_images) => Ok(Json(created_images)),
+    let images_aggregation =
+        ImageService::upload_images(&connection, dtos, user_aggregation.id).await;
+
+    Ok(Json(images_aggregation))
+}
+
+#[openapi]
+#[post("/file", data = "<upload_form>")]
+async fn upload_image_files(
+    upload_form: ImageUploadForm<'_>,
+    connection: PgConnection,
+    authorization: Authorization,
+) -> Result<Json<ImagesUploadAggregation>, status::Custom<String>> {
+    let user_aggregation = authorization
+        .verify(vec![], Some(ApiTokenScope::UploadImages), &connection)
+        .await?;
+
+    let images_aggregation =
+        ImageService::upload_files(&connection, upload_form.into_dto(()), user_aggregation.id)
+            .await;
+
+    Ok(Json(images_aggregation))
+}
+
+#[openapi]
+#[delete("/<id>")]
+async fn delete_image(
+    connection: PgConnection,
+    authorization: Authorization,
+    id: i32,
+) -> Result<Json<ResponseString>, status::Custom<String>> {
+    let user_aggregation = authorization
+        .verify(vec![], Some(ApiTokenScope::UploadImages), &connection)
+        .await?;
+
+    match ImageService::delete(&connection, id, &user_aggregation).await {
+        Ok(_) => Ok(Json(ResponseString {
+            status: String::from("success"),
+        })),
         Err(e) => Err(e.custom()),
     }
 }
 
+#[openapi]
+#[post("/cleanup")]
+async fn cleanup_images(
+    connection: PgConnection,
+    authorization: Authorization,
+) -> Result<Json<ImageCleanupAggregation>, status::Custom<String>> {
+    authorization
+        .verify(vec![UserRoleId::Admin], None, &connection)
+        .await?;
+
+    let deleted_count = ImageService::delete_unreferenced(&connection).await;
+
+    Ok(Json(ImageCleanupAggregation { deleted_count }))
+}
+
+#[openapi]
+#[get("/file/<key>")]
+async fn get_image_file(key: String) -> Option<NamedFile> {
+    let path = ImageService::get_local_file_path(&key)?;
+
+    NamedFile::open(path).await.ok()
+}
+
 pub fn routes() -> Vec<rocket::Route> {
     let settings = OpenApiSettings {
         json_path: "/image.json".to_owned(),
         schema_settings: SchemaSettings::openapi3(),
     };
 
-    openapi_get_routes![settings: create_image]
+    openapi_get_routes![
+        settings: get_images,
+        get_article_images,
+        create_image,
+        upload_image_files,
+        delete_image,
+        cleanup_images,
+        get_image_file
+    ]
 }
@@ -1,29 +1,93 @@
-use rocket::{get, response::status, serde::json::Json};
+use rocket::{get, patch, post, response::status, serde::json::Json};
 use rocket_okapi::{
     okapi::schemars::gen::SchemaSettings, openapi, openapi_get_routes, settings::OpenApiSettings,
 };
 
+use super::authorization::Authorization;
+use super::dtm_common::UserRoleId;
 use super::repository::PgConnection;
+use super::trait_common::DtoConvert;
 
 use super::aggregation::language::LanguageAggregation;
+use super::dtm::language::request_body::{LanguageCreateBody, LanguagePatchBody};
 
 use super::service::language::LanguageService;
 
+// disabled languages are listed for admins only
 #[openapi]
-#[get("/")]
+#[get("/?<include_disabled>")]
 async fn get_languages(
     connection: PgConnection,
+    authorization: Authorization,
+    include_disabled: Option<bool>,
 ) -> Result<Json<Vec<LanguageAggregation>>, status::Custom<String>> {
-    let aggregations = LanguageService::get_aggregations(&connection).await;
+    let include_disabled = include_disabled.unwrap_or(false);
+
+    if include_disabled {
+        authorization
+            .verify(vec![UserRoleId::Admin], None, &connection)
+            .await?;
+    }
+
+    let aggregations = LanguageService::get_aggregations(&connection)
+        .await
+        .into_iter()
+        .filter(|language| include_disabled || language.enabled)
+        .collect();
 
     Ok(Json(aggregations))
 }
 
+#[openapi]
+#[post("/", data = "<creation_body>")]
+async fn create_language(
+    connection: PgConnection,
+    authorization: Authorization,
+    creation_body: Json<LanguageCreateBody>,
+) -> Result<Json<LanguageAggregation>, status::Custom<String>> {
+    let user_aggregation = authorization
+        .verify(vec![UserRoleId::Admin], None, &connection)
+        .await?;
+
+    match LanguageService::insert(&connection, creation_body.0.into_dto(user_aggregation.id)).await
+    {
+        Ok(language_aggregation) => Ok(Json(language_aggregation)),
+        Err(e) => Err(e.custom()),
+    }
+}
+
+#[openapi]
+#[patch("/<code>", data = "<patch_body>")]
+async fn patch_language(
+    connection: PgConnection,
+    authorization: Authorization,
+    code: String,
+    patch_body: Json<LanguagePatchBody>,
+) -> Result<Json<LanguageAggregation>, status::Custom<String>> {
+    let user_aggregation = authorization
+        .verify(vec![UserRoleId::Admin], None, &connection)
+        .await?;
+
+    match LanguageService::patch(
+        &connection,
+        patch_body.0.into_dto((code, user_aggregation.id)),
+    )
+    .await
+    {
+        Ok(language_aggregation) => Ok(Json(language_aggregation)),
+        Err(e) => Err(e.custom()),
+    }
+}
+
 pub fn routes() -> Vec<rocket::Route> {
     let settings = OpenApiSettings {
         json_path: "/languages.json".to_owned(),
         schema_settings: SchemaSettings::openapi3(),
     };
 
-    openapi_get_routes![settings: get_languages,]
+    openapi_get_routes![
+        settings: get_languages,
+        create_language,
+        patch_language,
+    ]
 }
@@ -2,14 +2,19 @@ use super::aggregation;
 use super::authorization;
 use super::dtm;
 use super::dtm_common;
+use super::emailer;
 use super::repository;
 use super::service;
 use super::trait_common;
 
 pub mod article;
+pub mod article_draft;
 pub mod article_language;
 pub mod article_version;
 pub mod auth;
 pub mod catchers;
+pub mod category;
+pub mod email_template;
 pub mod image;
 pub mod language;
+pub mod watch;
@@ -0,0 +1,148 @@
+use rocket::{delete, get, patch, post, response::status, serde::json::Json, State};
+use rocket_okapi::{
+    okapi::schemars::gen::SchemaSettings, openapi, openapi_get_routes, settings::OpenApiSettings,
+};
+use std::sync::Arc;
+
+use super::authorization::Authorization;
+use super::dtm_common::{ApiTokenScope, QueryOptions, ResponseString, UserRoleId};
+use super::emailer::EmailTransport;
+use super::repository::PgConnection;
+use super::trait_common::DtoConvert;
+
+use super::aggregation::watch::{WatchAggregation, WatchDigestAggregation};
+use super::dtm::watch::request_body::{WatchCreateBody, WatchPatchBody, WatchUnsubscribeBody};
+
+use super::service::watch::WatchService;
+
+#[openapi]
+#[get("/")]
+async fn get_watches(
+    connection: PgConnection,
+    authorization: Authorization,
+) -> Result<Json<Vec<WatchAggregation>>, status::Custom<String>> {
+    let user_aggregation = authorization
+        .verify(vec![], Some(ApiTokenScope::Read), &connection)
+        .await?;
+
+    let aggregations = WatchService::get_aggregations(&connection, user_aggregation.id).await;
+
+    Ok(Json(aggregations))
+}
+
+#[openapi]
+#[post("/", data = "<creation_body>")]
+async fn create_watch(
+    connection: PgConnection,
+    authorization: Authorization,
+    creation_body: Json<WatchCreateBody>,
+) -> Result<Json<WatchAggregation>, status::Custom<String>> {
+    let user_aggregation = authorization
+        .verify(vec![], Some(ApiTokenScope::EditArticles), &connection)
+        .await?;
+
+    // disabled articles and languages can be watched by admins only
+    let is_admin = UserRoleId::from_i32(user_aggregation.role_id) == Some(UserRoleId::Admin);
+
+    match WatchService::create(
+        &connection,
+        creation_body.0.into_dto(user_aggregation.id),
+        &QueryOptions {
+            is_actual: !is_admin,
+        },
+    )
+    .await
+    {
+        Ok(watch_aggregation) => Ok(Json(watch_aggregation)),
+        Err(e) => Err(e.custom()),
+    }
+}
+
+#[openapi]
+#[patch("/<id>", data = "<patch_body>")]
+async fn patch_watch(
+    connection: PgConnection,
+    authorization: Authorization,
+    id: i32,
+    patch_body: Json<WatchPatchBody>,
+) -> Result<Json<WatchAggregation>, status::Custom<String>> {
+    let user_aggregation = authorization
+        .verify(vec![], Some(ApiTokenScope::EditArticles), &connection)
+        .await?;
+
+    match WatchService::patch(
+        &connection,
+        patch_body.0.into_dto((id, user_aggregation.id)),
+    )
+    .await
+    {
+        Ok(watch_aggregation) => Ok(Json(watch_aggregation)),
+        Err(e) => Err(e.custom()),
+    }
+}
+
+#[openapi]
+#[delete("/<id>")]
+async fn delete_watch(
+    connection: PgConnection,
+    authorization: Authorization,
+    id: i32,
+) -> Result<Json<ResponseString>, status::Custom<String>> {
+    let user_aggregation = authorization
+        .verify(vec![], Some(ApiTokenScope::EditArticles), &connection)
+        .await?;
+
+    match WatchService::delete(&connection, id, user_aggregation.id).await {
+        Ok(_) => Ok(Json(ResponseString {
+            status: String::from("success"),
+        })),
+        Err(e) => Err(e.custom()),
+    }
+}
+
+// used by unsubscribe links, the key identifies the watch without logging in
+#[openapi]
+#[post("/unsubscribe", data = "<unsubscribe_body>")]
+async fn unsubscribe_watch(
+    connection: PgConnection,
+    unsubscribe_body: Json<WatchUnsubscribeBody>,
+) -> Result<Json<ResponseString>, status::Custom<String>> {
+    match WatchService::unsubscribe(&connection, unsubscribe_body.0.key).await {
+        Ok(_) => Ok(Json(ResponseString {
+            status: String::from("success"),
+        })),
+        Err(e) => Err(e.custom()),
+    }
+}
+
+#[openapi]
+#[post("/digest")]
+async fn send_watch_digests(
+    connection: PgConnection,
+    authorization: Authorization,
+    email_transport: &State<Arc<dyn EmailTransport>>,
+) -> Result<Json<WatchDigestAggregation>, status::Custom<String>> {
+    authorization
+        .verify(vec![UserRoleId::Admin], None, &connection)
+        .await?;
+
+    Ok(Json(
+        WatchService::send_digests(&connection, email_transport.inner()).await,
+    ))
+}
+
+pub fn routes() -> Vec<rocket::Route> {
+    let settings = OpenApiSettings {
+        json_path: "/watch.json".to_owned(),
+        schema_settings: SchemaSettings::openapi3(),
+    };
+
+    openapi_get_routes![
+        settings: get_watches,
+        create_watch,
+        patch_watch,
+        delete_watch,
+        unsubscribe_watch,
+        send_watch_digests,
+    ]
+}
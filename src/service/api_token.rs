@@ -0,0 +1,92 @@
+use uuid::Uuid;
+
+use super::dtm_common::API_TOKEN_PREFIX;
+use super::error::{ErrorWrapper, FmtError};
+use super::hasher::Hasher;
+
+use super::dtm::auth::dto::{ApiTokenCreateDto, UserApiTokenCreateDto};
+
+use super::aggregation::api_token::{ApiTokenAggregation, ApiTokenCreatedAggregation};
+
+use super::repository::{entity::auth::AuthRepository, PgConnection};
+
+const NAME_MAX_LENGTH: usize = 100;
+
+pub struct ApiTokenService;
+
+impl ApiTokenService {
+    pub async fn create(
+        connection: &PgConnection,
+        creation_dto: ApiTokenCreateDto,
+    ) -> Result<ApiTokenCreatedAggregation, ErrorWrapper> {
+        let name = creation_dto.name.trim().to_owned();
+
+        if name.is_empty() {
+            return FmtError::EmptyValue("name").error();
+        }
+
+        if name.chars().count() > NAME_MAX_LENGTH {
+            return FmtError::FailedToProcess("name").error();
+        }
+
+        if creation_dto.scopes.is_empty() {
+            return FmtError::EmptyValue("scopes").error();
+        }
+
+        if matches!(creation_dto.live_days, Some(live_days) if live_days <= 0) {
+            return FmtError::FailedToProcess("live_days").error();
+        }
+
+        let mut scopes = creation_dto
+            .scopes
+            .iter()
+            .map(|scope| scope.as_str().to_owned())
+            .collect::<Vec<String>>();
+
+        scopes.sort();
+        scopes.dedup();
+
+        let token = format!(
+            "{}{}{}",
+            API_TOKEN_PREFIX,
+            Uuid::new_v4().simple(),
+            Uuid::new_v4().simple()
+        );
+
+        let api_token = AuthRepository::insert_api_token(
+            connection,
+            UserApiTokenCreateDto {
+                name,
+                scopes,
+                user_id: creation_dto.user_id,
+                token_hash: Hasher::hash_token(&token),
+                live_days: creation_dto.live_days,
+            },
+        )
+        .await;
+
+        Ok(ApiTokenCreatedAggregation {
+            token,
+            api_token: ApiTokenAggregation::from_model(api_token),
+        })
+    }
+
+    pub async fn get_all(connection: &PgConnection, user_id: i32) -> Vec<ApiTokenAggregation> {
+        AuthRepository::get_api_tokens(connection, user_id)
+            .await
+            .into_iter()
+            .map(ApiTokenAggregation::from_model)
+            .collect()
+    }
+
+    pub async fn revoke(
+        connection: &PgConnection,
+        id: i32,
+        user_id: i32,
+    ) -> Result<(), ErrorWrapper> {
+        match AuthRepository::revoke_api_token(connection, id, user_id).await {
+            0 => FmtError::NotFound("user_api_token").error(),
+            _ => Ok(()),
+        }
+    }
+}
@@ -1,30 +1,42 @@
 use diesel::Connection;
+use std::sync::Arc;
 
-use super::dtm_common::QueryOptions;
+use super::dtm_common::{PageCursor, PaginationOptions, QueryOptions};
+use super::emailer::EmailTransport;
 use super::error::{ErrorWrapper, FmtError};
 
 use super::dtm::{
-    article::dto::{ArticleCreateDto, ArticleCreateRelationsDto, ArticlePatchDto},
+    article::dto::{
+        ArticleCreateDto, ArticleCreateRelationsDto, ArticlePatchDto, ArticlesSearchDto,
+    },
     article_language::dto::ArticleLanguageCreateDto,
     article_version::dto::ArticleVersionCreateDto,
     version_content::dto::VersionContentDto,
+    watch::dto::WatchEventDto,
 };
 
-use super::aggregation::{article::ArticleAggregation, user_account::UserAccountAggregation};
+use super::aggregation::{
+    article::ArticleAggregation, page::PageAggregation, user_account::UserAccountAggregation,
+};
 use super::authorization::PermissionsHandler;
+use super::image_storage::ImageStorageHandler;
 
 use super::repository::{
     entity::{
         article::{Article, ArticleRepository},
         article_language::{ArticleLanguage, ArticleLanguageRepository},
         article_version::{ArticleVersion, ArticleVersionRepository},
+        image::ImageRepository,
         version_content::{ContentType, VersionContent, VersionContentRepository},
+        watch::WatchEventType,
     },
     PgConnection,
 };
 
 use super::article_language::ArticleLanguageService;
+use super::category::CategoryService;
 use super::language::LanguageService;
+use super::watch::WatchService;
 
 pub struct ArticleService;
 
@@ -41,10 +53,13 @@ impl ArticleService {
 
         let article_language_aggregations =
             ArticleLanguageService::get_aggregations(&connection, article.id, query_options).await;
+        let category_aggregations =
+            CategoryService::get_article_aggregations(&connection, article.id).await;
 
         Ok(ArticleAggregation::from_model(
             article,
             article_language_aggregations,
+            category_aggregations,
         ))
     }
 
@@ -66,26 +81,50 @@ impl ArticleService {
 
         let article_language_aggregations =
             ArticleLanguageService::get_aggregations(&connection, article.id, query_options).await;
+        let category_aggregations =
+            CategoryService::get_article_aggregations(&connection, article.id).await;
 
         Ok(ArticleAggregation::from_model(
             article,
             article_language_aggregations,
+            category_aggregations,
         ))
     }
 
     pub async fn get_aggregations(
         connection: &PgConnection,
+        search_dto: ArticlesSearchDto,
+        pagination: PaginationOptions,
         query_options: &QueryOptions,
-    ) -> Vec<ArticleAggregation> {
-        let articles = ArticleRepository::get_many(connection, query_options).await;
+    ) -> PageAggregation<ArticleAggregation> {
+        let articles =
+            ArticleRepository::get_many(connection, search_dto, pagination.clone(), query_options)
+                .await;
 
-        let articles_ids = articles.iter().map(|article| article.id).collect();
+        let (articles, next_cursor) = pagination.split_page(articles, |article| PageCursor {
+            created_at: article.created_at,
+            id: article.id,
+        });
 
-        let article_language_aggregations_map =
-            ArticleLanguageService::get_aggregations_map(&connection, articles_ids, query_options)
-                .await;
+        let articles_ids: Vec<i32> = articles.iter().map(|article| article.id).collect();
 
-        ArticleAggregation::from_languages_map(articles, article_language_aggregations_map)
+        let article_language_aggregations_map = ArticleLanguageService::get_aggregations_map(
+            &connection,
+            articles_ids.clone(),
+            query_options,
+        )
+        .await;
+        let category_aggregations_map =
+            CategoryService::get_aggregations_map(&connection, articles_ids).await;
+
+        PageAggregation::from_items(
+            ArticleAggregation::from_languages_map(
+                articles,
+                article_language_aggregations_map,
+                category_aggregations_map,
+            ),
+            next_cursor,
+        )
     }
 
     pub async fn insert(
@@ -94,10 +133,7 @@ impl ArticleService {
     ) -> Result<ArticleAggregation, ErrorWrapper> {
         let language_code = String::from(&creation_dto.language);
 
-        let language = match LanguageService::get_one(connection, language_code).await {
-            None => return FmtError::NotFound("language").error(),
-            Some(language) => language,
-        };
+        let language = LanguageService::get_enabled_aggregation(connection, language_code).await?;
 
         let (article, article_language, version_content, article_version) =
             Self::create_relations_transaction(connection, creation_dto, language.id).await;
@@ -115,6 +151,7 @@ impl ArticleService {
 
     pub async fn patch(
         connection: &PgConnection,
+        email_transport: &Arc<dyn EmailTransport>,
         patch_dto: ArticlePatchDto,
         user_aggregation: &UserAccountAggregation,
     ) -> Result<ArticleAggregation, ErrorWrapper> {
@@ -136,13 +173,45 @@ impl ArticleService {
             _ => (),
         };
 
+        let archived_event_type = match patch_dto.archived {
+            Some(true) if !article.archived => Some(WatchEventType::Archived),
+            Some(false) if article.archived => Some(WatchEventType::Restored),
+            _ => None,
+        };
+
         let updated_count = ArticleRepository::patch(connection, patch_dto).await;
 
         if updated_count == 0 {
             return FmtError::NotFound("article").error();
         }
 
-        Self::get_aggregation(connection, article_id, &QueryOptions { is_actual: false }).await
+        let article_aggregation =
+            Self::get_aggregation(connection, article_id, &QueryOptions { is_actual: false })
+                .await?;
+
+        if let Some(event_type) = archived_event_type {
+            let name = match article_aggregation.languages.first() {
+                Some(article_language_aggregation) => article_language_aggregation.name.clone(),
+                None => format!("#{article_id}"),
+            };
+
+            WatchService::notify(
+                connection,
+                email_transport,
+                user_aggregation.id,
+                vec![WatchEventDto {
+                    article_id,
+                    event_type,
+                    name,
+                    article_language_id: None,
+                    language_code: None,
+                    version: None,
+                }],
+            )
+            .await;
+        }
+
+        Ok(article_aggregation)
     }
 
     async fn create_relations_transaction(
@@ -205,6 +274,13 @@ impl ArticleService {
         )
         .expect(&FmtError::FailedToInsert("version_content").fmt());
 
+        ArticleLanguageRepository::upsert_search_content_raw(
+            connection,
+            article_language.id,
+            creation_dto.content.clone(),
+        )
+        .expect(&FmtError::FailedToUpdate("article_language_search").fmt());
+
         let article_version = ArticleVersionRepository::insert_raw(
             connection,
             ArticleVersionCreateDto {
@@ -213,10 +289,18 @@ impl ArticleService {
                 content_id: version_content.id,
                 user_id: creation_dto.user_id,
                 name: creation_dto.name,
+                source_version_id: None,
             },
         )
         .expect(&FmtError::FailedToInsert("article_version").fmt());
 
+        ImageRepository::insert_references_raw(
+            connection,
+            article_version.id,
+            ImageStorageHandler::get_content_keys(&creation_dto.content),
+        )
+        .expect(&FmtError::FailedToInsert("image_reference").fmt());
+
         (article, article_language, version_content, article_version)
     }
 }
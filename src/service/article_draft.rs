@@ -0,0 +1,180 @@
+use std::sync::Arc;
+
+use super::authorization::PermissionsHandler;
+use super::dtm_common::QueryOptions;
+use super::emailer::EmailTransport;
+use super::error::{ErrorWrapper, FmtError};
+
+use super::dtm::{
+    article_draft::dto::{ArticleDraftSaveDto, ArticleDraftUpsertDto},
+    article_version::dto::ArticleVersionCreateRelationsDto,
+};
+
+use super::aggregation::{
+    article_draft::ArticleDraftAggregation, article_version::ArticleVersionAggregation,
+    user_account::UserAccountAggregation,
+};
+
+use super::repository::{
+    entity::{
+        article::ArticleRepository, article_draft::ArticleDraftRepository,
+        article_language::ArticleLanguage, article_version::ArticleVersionRepository,
+    },
+    PgConnection,
+};
+
+use super::article_language::ArticleLanguageService;
+use super::article_version::ArticleVersionService;
+use super::version_content::VersionKeyframeInterval;
+
+pub struct ArticleDraftService;
+
+impl ArticleDraftService {
+    pub async fn get_aggregation(
+        connection: &PgConnection,
+        article_id: i32,
+        language_code: String,
+        user_id: i32,
+    ) -> Result<ArticleDraftAggregation, ErrorWrapper> {
+        let article_language =
+            Self::get_article_language(connection, article_id, language_code).await?;
+
+        let article_draft =
+            match ArticleDraftRepository::get_one(connection, article_language.id, user_id).await {
+                Some(article_draft) => article_draft,
+                None => return FmtError::NotFound("article_draft").error(),
+            };
+
+        let actual_version =
+            ArticleVersionRepository::get_last_version(connection, article_language.id).await;
+
+        Ok(ArticleDraftAggregation::from_model(
+            article_draft,
+            actual_version,
+        ))
+    }
+
+    pub async fn save(
+        connection: &PgConnection,
+        article_id: i32,
+        language_code: String,
+        save_dto: ArticleDraftSaveDto,
+        user_aggregation: &UserAccountAggregation,
+    ) -> Result<ArticleDraftAggregation, ErrorWrapper> {
+        let article = match ArticleRepository::get_one(
+            connection,
+            article_id,
+            &QueryOptions { is_actual: false },
+        )
+        .await
+        {
+            Some(article) => article,
+            None => return FmtError::NotFound("article").error(),
+        };
+
+        if !PermissionsHandler::can_create_article_version(&article, user_aggregation) {
+            return FmtError::PermissionDenied("not enough rights").error();
+        }
+
+        let article_language =
+            Self::get_article_language(connection, article_id, language_code).await?;
+
+        let actual_version =
+            ArticleVersionRepository::get_last_version(connection, article_language.id).await;
+
+        let article_draft = ArticleDraftRepository::upsert(
+            connection,
+            ArticleDraftUpsertDto {
+                article_language_id: article_language.id,
+                user_id: save_dto.user_id,
+                name: save_dto.name,
+                content: save_dto.content,
+                base_version: actual_version,
+                is_rebase: save_dto.rebase,
+            },
+        )
+        .await;
+
+        Ok(ArticleDraftAggregation::from_model(
+            article_draft,
+            actual_version,
+        ))
+    }
+
+    pub async fn delete(
+        connection: &PgConnection,
+        article_id: i32,
+        language_code: String,
+        user_id: i32,
+    ) -> Result<(), ErrorWrapper> {
+        let article_language =
+            Self::get_article_language(connection, article_id, language_code).await?;
+
+        match ArticleDraftRepository::delete(connection, article_language.id, user_id).await {
+            0 => FmtError::NotFound("article_draft").error(),
+            _ => Ok(()),
+        }
+    }
+
+    // the draft becomes a regular version, unless someone published a version after it was started
+    pub async fn publish(
+        connection: &PgConnection,
+        email_transport: &Arc<dyn EmailTransport>,
+        article_id: i32,
+        language_code: String,
+        user_aggregation: &UserAccountAggregation,
+        keyframe_interval: VersionKeyframeInterval,
+    ) -> Result<ArticleVersionAggregation, ErrorWrapper> {
+        let article_language =
+            Self::get_article_language(connection, article_id, language_code.clone()).await?;
+
+        let article_draft = match ArticleDraftRepository::get_one(
+            connection,
+            article_language.id,
+            user_aggregation.id,
+        )
+        .await
+        {
+            Some(article_draft) => article_draft,
+            None => return FmtError::NotFound("article_draft").error(),
+        };
+
+        let article_version_aggregation = ArticleVersionService::insert(
+            connection,
+            email_transport,
+            article_id,
+            language_code,
+            ArticleVersionCreateRelationsDto {
+                content: article_draft.content,
+                user_id: user_aggregation.id,
+                name: article_draft.name,
+                source: None,
+                // publishing fails with a conflict once the draft is outdated
+                base_version: Some(article_draft.base_version),
+                merge: false,
+            },
+            user_aggregation,
+            keyframe_interval,
+        )
+        .await?;
+
+        ArticleDraftRepository::delete(connection, article_language.id, user_aggregation.id).await;
+
+        Ok(article_version_aggregation)
+    }
+
+    async fn get_article_language(
+        connection: &PgConnection,
+        article_id: i32,
+        language_code: String,
+    ) -> Result<ArticleLanguage, ErrorWrapper> {
+        ArticleLanguageService::get_one_with_language(
+            connection,
+            article_id,
+            language_code,
+            &QueryOptions { is_actual: true },
+        )
+        .await
+        .map(|(article_language, _)| article_language)
+    }
+}
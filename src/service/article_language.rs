@@ -1,34 +1,43 @@
 use diesel::Connection;
 use std::collections::HashMap;
+use std::sync::Arc;
 
-use super::dtm_common::QueryOptions;
+use super::dtm_common::{PageCursor, PaginationOptions, QueryOptions};
+use super::emailer::EmailTransport;
 use super::error::{ErrorWrapper, FmtError};
 
 use super::dtm::{
     article_language::dto::{
-        ArticleLanguageCreateDto, ArticleLanguageCreateRelationsDto, ArticleLanguagePatchDto,
+        ArticleLanguageContentSearchDto, ArticleLanguageCreateDto,
+        ArticleLanguageCreateRelationsDto, ArticleLanguagePatchDto,
     },
     article_version::dto::{ArticleVersionCreateDto, LanguageSearchDto},
     version_content::dto::VersionContentDto,
+    watch::dto::WatchEventDto,
 };
 
 use super::aggregation::{
     article_language::ArticleLanguageAggregation,
-    article_language::ArticleLanguagePartialAggregation,
+    article_language::ArticleLanguageFallbackAggregation,
+    article_language::ArticleLanguagePartialAggregation, article_search::ArticleSearchAggregation,
     article_version::ArticleVersionAggregation, language::LanguageAggregation,
+    page::PageAggregation,
 };
 
 use super::repository::{
     entity::{
         article_language::{ArticleLanguage, ArticleLanguageRepository},
         article_version::{ArticleVersion, ArticleVersionRepository},
+        image::ImageRepository,
         version_content::{ContentType, VersionContent, VersionContentRepository},
+        watch::WatchEventType,
     },
     PgConnection,
 };
 
 use super::article_version::ArticleVersionService;
 use super::language::LanguageService;
+use super::watch::WatchService;
 
 pub struct ArticleLanguageService {}
 
@@ -61,6 +70,82 @@ impl ArticleLanguageService {
         .await
     }
 
+    // requested language first, then the `Accept-Language` ones, the configured fallback chain
+    // and the article's original language; disabled languages are never served as a fallback
+    pub async fn get_fallback_aggregation(
+        connection: &PgConnection,
+        article_id: i32,
+        language_code: String,
+        accept_languages: Vec<String>,
+        query_options: QueryOptions,
+    ) -> Result<ArticleLanguageFallbackAggregation, ErrorWrapper> {
+        let languages = LanguageService::get_aggregations(connection).await;
+
+        let requested_language = match languages
+            .iter()
+            .find(|language| language.code == language_code)
+        {
+            Some(language) => language,
+            None => return FmtError::NotFound("language").error(),
+        };
+
+        let mut article_languages =
+            ArticleLanguageRepository::get_many(connection, vec![article_id], &query_options).await;
+
+        let mut language_ids = vec![requested_language.id];
+
+        language_ids.extend(
+            accept_languages
+                .iter()
+                .filter_map(|tag| LanguageService::find_by_tag(&languages, tag))
+                .map(|language| language.id),
+        );
+
+        language_ids.extend(LanguageService::get_fallback_chain(
+            &languages,
+            requested_language.id,
+        ));
+
+        // article languages are ordered from the newest one
+        if let Some(original_article_language) = article_languages.last() {
+            language_ids.push(original_article_language.language_id);
+        }
+
+        let served = language_ids.into_iter().find_map(|language_id| {
+            let language = languages
+                .iter()
+                .find(|language| language.id == language_id)?;
+
+            if language.id != requested_language.id && !language.enabled {
+                return None;
+            }
+
+            article_languages
+                .iter()
+                .position(|article_language| article_language.language_id == language_id)
+                .map(|position| (position, language.clone()))
+        });
+
+        let (position, language) = match served {
+            Some(served) => served,
+            None => return FmtError::NotFound("article_language").error(),
+        };
+
+        let article_language_aggregation = Self::get_aggregation_with_relations(
+            connection,
+            article_id,
+            &query_options,
+            language,
+            Some(article_languages.swap_remove(position)),
+        )
+        .await?;
+
+        Ok(ArticleLanguageFallbackAggregation::from_aggregation(
+            language_code,
+            article_language_aggregation,
+        ))
+    }
+
     pub async fn get_aggregations(
         connection: &PgConnection,
         article_id: i32,
@@ -75,6 +160,35 @@ impl ArticleLanguageService {
         }
     }
 
+    pub async fn get_aggregations_page(
+        connection: &PgConnection,
+        article_id: i32,
+        pagination: PaginationOptions,
+        query_options: &QueryOptions,
+    ) -> PageAggregation<ArticleLanguageAggregation> {
+        let article_languages = ArticleLanguageRepository::get_page(
+            connection,
+            article_id,
+            pagination.clone(),
+            query_options,
+        )
+        .await;
+
+        let (article_languages, next_cursor) =
+            pagination.split_page(article_languages, |article_language| PageCursor {
+                created_at: article_language.created_at,
+                id: article_language.id,
+            });
+
+        let article_language_aggregations =
+            Self::get_aggregations_map_by_models(connection, article_languages, query_options)
+                .await
+                .remove(&article_id)
+                .unwrap_or(vec![]);
+
+        PageAggregation::from_items(article_language_aggregations, next_cursor)
+    }
+
     pub async fn get_partial_aggregations_by_query(
         connection: &PgConnection,
         query: String,
@@ -86,6 +200,18 @@ impl ArticleLanguageService {
         ArticleLanguagePartialAggregation::from_related_models(article_languages_relations)
     }
 
+    pub async fn get_search_aggregations(
+        connection: &PgConnection,
+        search_dto: ArticleLanguageContentSearchDto,
+        query_options: &QueryOptions,
+    ) -> Vec<ArticleSearchAggregation> {
+        let article_languages_search =
+            ArticleLanguageRepository::get_many_by_content(connection, search_dto, query_options)
+                .await;
+
+        ArticleSearchAggregation::from_model_list(article_languages_search)
+    }
+
     pub async fn get_aggregations_map(
         connection: &PgConnection,
         article_ids: Vec<i32>,
@@ -94,6 +220,14 @@ impl ArticleLanguageService {
         let article_languages =
             ArticleLanguageRepository::get_many(connection, article_ids, query_options).await;
 
+        Self::get_aggregations_map_by_models(connection, article_languages, query_options).await
+    }
+
+    async fn get_aggregations_map_by_models(
+        connection: &PgConnection,
+        article_languages: Vec<ArticleLanguage>,
+        query_options: &QueryOptions,
+    ) -> HashMap<i32, Vec<ArticleLanguageAggregation>> {
         let article_languages_ids: Vec<i32> = article_languages
             .iter()
             .map(|article_language| article_language.id)
@@ -178,10 +312,7 @@ impl ArticleLanguageService {
     ) -> Result<ArticleLanguageAggregation, ErrorWrapper> {
         let language_code = String::from(&creation_dto.language_code);
 
-        let language = match LanguageService::get_aggregation(connection, language_code).await {
-            None => return FmtError::NotFound("language").error(),
-            Some(language) => language,
-        };
+        let language = LanguageService::get_enabled_aggregation(connection, language_code).await?;
 
         match ArticleLanguageRepository::get_one(
             connection,
@@ -215,6 +346,7 @@ impl ArticleLanguageService {
 
     pub async fn patch(
         connection: &PgConnection,
+        email_transport: &Arc<dyn EmailTransport>,
         language_code: String,
         article_id: i32,
         patch_dto: ArticleLanguagePatchDto,
@@ -224,6 +356,33 @@ impl ArticleLanguageService {
             Some(language) => language,
         };
 
+        let article_language = match ArticleLanguageRepository::get_one(
+            connection,
+            article_id,
+            language.id,
+            &QueryOptions { is_actual: false },
+        )
+        .await
+        {
+            None => return FmtError::NotFound("article_language").error(),
+            Some(article_language) => article_language,
+        };
+
+        let user_id = patch_dto.user_id;
+        let mut event_types = vec![];
+
+        match patch_dto.archived {
+            Some(true) if !article_language.archived => event_types.push(WatchEventType::Archived),
+            Some(false) if article_language.archived => event_types.push(WatchEventType::Restored),
+            _ => (),
+        };
+
+        if let Some(name) = &patch_dto.name {
+            if *name != article_language.name {
+                event_types.push(WatchEventType::Renamed);
+            }
+        }
+
         let updated_count =
             ArticleLanguageRepository::patch(connection, language.id, article_id, patch_dto).await;
 
@@ -231,14 +390,32 @@ impl ArticleLanguageService {
             return FmtError::NotFound("article_language").error();
         }
 
-        Self::get_aggregation_with_relations(
+        let article_language_aggregation = Self::get_aggregation_with_relations(
             connection,
             article_id,
             &QueryOptions { is_actual: false },
             language,
             None,
         )
-        .await
+        .await?;
+
+        if !event_types.is_empty() {
+            let events = event_types
+                .into_iter()
+                .map(|event_type| WatchEventDto {
+                    article_id,
+                    event_type,
+                    article_language_id: Some(article_language.id),
+                    name: article_language_aggregation.name.clone(),
+                    language_code: Some(article_language_aggregation.language.code.clone()),
+                    version: None,
+                })
+                .collect();
+
+            WatchService::notify(connection, email_transport, user_id, events).await;
+        }
+
+        Ok(article_language_aggregation)
     }
 
     async fn get_aggregation_with_relations(
@@ -340,6 +517,13 @@ impl ArticleLanguageService {
         )
         .expect(&FmtError::FailedToInsert("version_content").fmt());
 
+        ArticleLanguageRepository::upsert_search_content_raw(
+            connection,
+            article_language.id,
+            creation_dto.content.clone(),
+        )
+        .expect(&FmtError::FailedToUpdate("article_language_search").fmt());
+
         let article_version = ArticleVersionRepository::insert_raw(
             connection,
             ArticleVersionCreateDto {
@@ -348,10 +532,18 @@ impl ArticleLanguageService {
                 content_id: version_content.id,
                 user_id: creation_dto.user_id,
                 name: creation_dto.name,
+                source_version_id: None,
             },
         )
         .expect(&FmtError::FailedToInsert("article_version").fmt());
 
+        ImageRepository::insert_references_raw(
+            connection,
+            article_version.id,
+            creation_dto.content,
+        )
+        .expect(&FmtError::FailedToInsert("image_reference").fmt());
+
         (article_language, version_content, article_version)
     }
 }
@@ -1,22 +1,32 @@
 use diesel::Connection;
 use std::collections::HashMap;
+use std::sync::Arc;
 
 use super::authorization::PermissionsHandler;
 use super::diff_handler::DiffHandler;
-use super::dtm_common::QueryOptions;
+use super::dtm_common::{DiffGranularity, PageCursor, PaginationOptions, QueryOptions};
+use super::emailer::EmailTransport;
 use super::error::{ErrorWrapper, FmtError};
+use super::image_storage::ImageStorageHandler;
 
 use super::dtm::{
     article_language::dto::ArticleLanguagePatchDto,
     article_version::dto::{
         ArticleVersionCreateDto, ArticleVersionCreateRelationsDto, ArticleVersionPatchDto,
-        ArticleVersionsJoinSearchDto, LanguageSearchDto,
+        ArticleVersionSourceDto, ArticleVersionsJoinSearchDto, LanguageSearchDto,
     },
     version_content::dto::VersionContentDto,
+    watch::dto::WatchEventDto,
 };
 
 use super::aggregation::{
-    article_version::ArticleVersionAggregation, user_account::UserAccountAggregation,
+    article_version::{
+        ArticleVersionAggregation, ArticleVersionCompareAggregation,
+        ArticleVersionConflictAggregation, ArticleVersionOutdatedAggregation,
+    },
+    page::PageAggregation,
+    user_account::UserAccountAggregation,
+    version_diff::VersionDiffHunkAggregation,
 };
 
 use super::repository::{
@@ -25,13 +35,19 @@ use super::repository::{
         article_language::{ArticleLanguage, ArticleLanguageRepository},
         article_version::{ArticleVersion, ArticleVersionRepository},
         auth::UserAccount,
+        image::ImageRepository,
         version_content::{ContentType, VersionContent, VersionContentRepository},
+        watch::WatchEventType,
     },
     PgConnection,
 };
 
 use super::article_language::ArticleLanguageService;
-use super::version_content::VersionContentService;
+use super::version_content::{VersionContentService, VersionKeyframeInterval};
+use super::watch::WatchService;
+
+// merges with versions created concurrently before the edit is returned as a conflict
+const MAX_MERGE_RETRIES: i32 = 3;
 
 pub struct ArticleVersionService;
 
@@ -42,13 +58,17 @@ impl ArticleVersionService {
         language_search_dto: LanguageSearchDto,
         query_options: &QueryOptions,
     ) -> Result<ArticleVersionAggregation, ErrorWrapper> {
-        let (article_versions_relations, content_map) =
-            match Self::get_versions_with_content_map(connection, version, language_search_dto)
-                .await
-            {
-                Err(e) => return Err(e),
-                Ok(versions_with_content_map) => versions_with_content_map,
-            };
+        let (article_versions_relations, content_map) = match Self::get_versions_with_content_map(
+            connection,
+            version,
+            version,
+            language_search_dto,
+        )
+        .await
+        {
+            Err(e) => return Err(e),
+            Ok(versions_with_content_map) => versions_with_content_map,
+        };
 
         let requested_article_version_relations =
             match Self::get_requested_article_version_with_content(
@@ -78,6 +98,7 @@ impl ArticleVersionService {
         let (article_versions_relations, content_map) = match Self::get_versions_with_content_map(
             connection,
             version_ge_to_search,
+            None,
             language_search_dto,
         )
         .await
@@ -101,6 +122,232 @@ impl ArticleVersionService {
             .collect::<Vec<ArticleVersionAggregation>>())
     }
 
+    pub async fn get_aggregations_page(
+        connection: &PgConnection,
+        language_search_dto: LanguageSearchDto,
+        pagination: PaginationOptions,
+        query_options: &QueryOptions,
+    ) -> Result<PageAggregation<ArticleVersionAggregation>, ErrorWrapper> {
+        let article_languages_ids =
+            match Self::get_article_languages_ids(connection, language_search_dto).await {
+                Err(e) => return Err(e),
+                Ok(article_languages_ids) => article_languages_ids,
+            };
+
+        let article_versions = ArticleVersionRepository::get_page(
+            connection,
+            article_languages_ids.clone(),
+            pagination.clone(),
+            query_options,
+        )
+        .await;
+
+        let (article_versions, next_cursor) =
+            pagination.split_page(article_versions, |article_version| PageCursor {
+                created_at: article_version.created_at,
+                id: article_version.id,
+            });
+
+        let (version_ge, version_le) = match (
+            article_versions.iter().map(|version| version.version).min(),
+            article_versions.iter().map(|version| version.version).max(),
+        ) {
+            (Some(version_ge), Some(version_le)) => (version_ge, version_le),
+            _ => return Ok(PageAggregation::from_items(vec![], next_cursor)),
+        };
+
+        // content is reconstructed for the page range only (from the nearest keyframe above)
+        let (article_versions_relations, content_map) = match Self::get_versions_with_content_map(
+            connection,
+            Some(version_ge),
+            Some(version_le),
+            LanguageSearchDto {
+                article_languages_ids: Some(article_languages_ids),
+
+                language_code: None,
+                article_id: None,
+                article_language: None,
+                article_language_key: None,
+            },
+        )
+        .await
+        {
+            Err(e) => return Err(e),
+            Ok(versions_with_content_map) => versions_with_content_map,
+        };
+
+        let page_article_versions_relations = article_versions_relations
+            .into_iter()
+            .filter(|(article_version, _, _)| {
+                article_versions
+                    .iter()
+                    .any(|page_version| page_version.id == article_version.id)
+            })
+            .collect();
+
+        Ok(PageAggregation::from_items(
+            ArticleVersionAggregation::from_content_map(
+                page_article_versions_relations,
+                content_map,
+            ),
+            next_cursor,
+        ))
+    }
+
+    pub async fn get_compare_aggregation(
+        connection: &PgConnection,
+        version: i32,
+        compared_version: i32,
+        granularity: DiffGranularity,
+        language_search_dto: LanguageSearchDto,
+        query_options: &QueryOptions,
+    ) -> Result<ArticleVersionCompareAggregation, ErrorWrapper> {
+        let (article_versions_relations, content_map) = match Self::get_versions_with_content_map(
+            connection,
+            Some(std::cmp::min(version, compared_version)),
+            Some(std::cmp::max(version, compared_version)),
+            language_search_dto,
+        )
+        .await
+        {
+            Err(e) => return Err(e),
+            Ok(versions_with_content_map) => versions_with_content_map,
+        };
+
+        let requested_article_versions_relations = article_versions_relations
+            .into_iter()
+            .filter(|(article_version, _, _)| {
+                if query_options.is_actual && !article_version.enabled {
+                    return false;
+                }
+
+                article_version.version == version || article_version.version == compared_version
+            })
+            .collect();
+
+        let mut article_versions_aggregations = ArticleVersionAggregation::from_content_map(
+            requested_article_versions_relations,
+            content_map,
+        );
+
+        let actual_aggregation = match article_versions_aggregations
+            .iter()
+            .position(|aggregation| aggregation.version == version)
+        {
+            Some(index) => article_versions_aggregations.swap_remove(index),
+            None => return FmtError::NotFound("article_version").error(),
+        };
+
+        let compared_aggregation = if compared_version == version {
+            actual_aggregation.clone()
+        } else {
+            match article_versions_aggregations
+                .into_iter()
+                .find(|aggregation| aggregation.version == compared_version)
+            {
+                Some(aggregation) => aggregation,
+                None => return FmtError::NotFound("compared_article_version").error(),
+            }
+        };
+
+        let diff = DiffHandler::get_text_diff(
+            &actual_aggregation.content.content,
+            &compared_aggregation.content.content,
+            &granularity,
+        );
+
+        Ok(ArticleVersionCompareAggregation::from_aggregations(
+            actual_aggregation,
+            compared_aggregation,
+            VersionDiffHunkAggregation::from_hunks(diff),
+        ))
+    }
+
+    // actual translations whose source language got newer versions since they were translated
+    pub async fn get_outdated_aggregations(
+        connection: &PgConnection,
+        article_id: i32,
+        granularity: DiffGranularity,
+    ) -> Result<Vec<ArticleVersionOutdatedAggregation>, ErrorWrapper> {
+        if ArticleRepository::get_one(connection, article_id, &QueryOptions { is_actual: true })
+            .await
+            .is_none()
+        {
+            return FmtError::NotFound("article").error();
+        }
+
+        let article_languages = ArticleLanguageService::get_aggregations_map(
+            connection,
+            vec![article_id],
+            &QueryOptions { is_actual: true },
+        )
+        .await
+        .remove(&article_id)
+        .unwrap_or(vec![]);
+
+        let source_versions = ArticleVersionRepository::get_many_by_ids(
+            connection,
+            article_languages
+                .iter()
+                .filter_map(|article_language| article_language.version.source_version_id)
+                .collect(),
+        )
+        .await;
+
+        let mut outdated_aggregations = vec![];
+
+        for article_language in article_languages.iter() {
+            let source_version = match article_language
+                .version
+                .source_version_id
+                .and_then(|id| source_versions.iter().find(|version| version.id == id))
+            {
+                Some(source_version) => source_version,
+                None => continue,
+            };
+
+            // up to date unless the source language has a newer version; archived or disabled
+            // source languages are not listed, so they have nothing to catch up with either
+            let source_article_language = match article_languages.iter().find(|source| {
+                source.id == source_version.article_language_id
+                    && source.version.version > source_version.version
+            }) {
+                Some(source_article_language) => source_article_language,
+                None => continue,
+            };
+
+            let compare_aggregation = Self::get_compare_aggregation(
+                connection,
+                source_version.version,
+                source_article_language.version.version,
+                granularity,
+                LanguageSearchDto {
+                    article_languages_ids: Some(vec![source_article_language.id]),
+
+                    language_code: None,
+                    article_id: None,
+                    article_language: None,
+                    article_language_key: None,
+                },
+                &QueryOptions { is_actual: false },
+            )
+            .await?;
+
+            outdated_aggregations.push(ArticleVersionOutdatedAggregation {
+                language: article_language.language.code.clone(),
+                version: article_language.version.version,
+
+                source_language: source_article_language.language.code.clone(),
+                source_version: source_version.version,
+                source_actual_version: source_article_language.version.version,
+
+                diff: compare_aggregation.diff,
+            });
+        }
+
+        Ok(outdated_aggregations)
+    }
+
     pub async fn patch(
         connection: &PgConnection,
         version: i32,
@@ -128,6 +375,8 @@ impl ArticleVersionService {
             return FmtError::NotFound("article_version").error();
         }
 
+        Self::refresh_search_content(connection, article_language.id).await;
+
         return Self::get_aggregation(
             connection,
             Some(version),
@@ -146,10 +395,12 @@ impl ArticleVersionService {
 
     pub async fn insert(
         connection: &PgConnection,
+        email_transport: &Arc<dyn EmailTransport>,
         article_id: i32,
         language_code: String,
         creation_dto: ArticleVersionCreateRelationsDto,
         user_aggregation: &UserAccountAggregation,
+        keyframe_interval: VersionKeyframeInterval,
     ) -> Result<ArticleVersionAggregation, ErrorWrapper> {
         let article = match ArticleRepository::get_one(
             connection,
@@ -167,7 +418,7 @@ impl ArticleVersionService {
             _ => (),
         };
 
-        let article_language = match ArticleLanguageService::get_one_with_language(
+        let (article_language, language) = match ArticleLanguageService::get_one_with_language(
             connection,
             article_id,
             language_code,
@@ -176,19 +427,70 @@ impl ArticleVersionService {
         .await
         {
             Err(e) => return Err(e),
-            Ok((article_language, _)) => article_language,
+            Ok(article_language_with_language) => article_language_with_language,
         };
 
-        let article_versions_count =
-            ArticleVersionRepository::get_count(connection, article_language.id).await;
+        let source_version_id = match &creation_dto.source {
+            Some(source) => Some(
+                Self::get_source_version_id(connection, article_id, &language.code, source).await?,
+            ),
+            None => None,
+        };
 
-        let (article_version, version_content) = Self::create_relations_transaction(
-            connection,
-            creation_dto,
-            article_language,
-            article_versions_count,
-        )
-        .await;
+        let previous_name = article_language.name.clone();
+        let mut merged_dto = creation_dto.clone();
+        let mut merge_retries = 0;
+
+        // retried while the edit can be merged with newer versions, up to MAX_MERGE_RETRIES times;
+        // each merge starts from the edit as submitted, so a conflict covers all changes since its base
+        let (article_version, version_content) = loop {
+            match Self::create_relations_transaction(
+                connection,
+                merged_dto.clone(),
+                article_language.id,
+                source_version_id,
+                keyframe_interval,
+            )
+            .await
+            {
+                Ok(relations) => break relations,
+                Err(actual_version) => {
+                    merged_dto = Self::merge_with_actual_version(
+                        connection,
+                        article_language.id,
+                        actual_version,
+                        ArticleVersionCreateRelationsDto {
+                            merge: creation_dto.merge && merge_retries < MAX_MERGE_RETRIES,
+                            ..creation_dto.clone()
+                        },
+                    )
+                    .await?;
+
+                    merge_retries += 1;
+                }
+            }
+        };
+
+        let new_version_event = WatchEventDto {
+            article_id,
+            article_language_id: Some(article_version.article_language_id),
+            event_type: WatchEventType::NewVersion,
+            name: article_version.name.clone(),
+            language_code: Some(language.code),
+            version: Some(article_version.version),
+        };
+
+        let mut events = vec![new_version_event.clone()];
+
+        if article_version.name != previous_name {
+            events.push(WatchEventDto {
+                event_type: WatchEventType::Renamed,
+                version: None,
+                ..new_version_event
+            });
+        }
+
+        WatchService::notify(connection, email_transport, user_aggregation.id, events).await;
 
         Ok(ArticleVersionAggregation::from_related_models(
             vec![article_version],
@@ -197,41 +499,409 @@ impl ArticleVersionService {
         .swap_remove(0))
     }
 
-    async fn create_relations_transaction(
+    pub async fn delete(
         connection: &PgConnection,
-        creation_dto: ArticleVersionCreateRelationsDto,
-        article_language: ArticleLanguage,
-        article_versions_count: i32,
-    ) -> (ArticleVersion, VersionContent) {
+        version: i32,
+        article_id: i32,
+        language_code: String,
+        user_id: i32,
+    ) -> Result<(), ErrorWrapper> {
+        let article_language = match ArticleLanguageService::get_one_with_language(
+            connection,
+            article_id,
+            language_code,
+            &QueryOptions { is_actual: false },
+        )
+        .await
+        {
+            Err(e) => return Err(e),
+            Ok((article_language, _)) => article_language,
+        };
+
+        let article_language_id = article_language.id;
+
         connection
             .run(move |connection| {
-                return connection
-                    .transaction::<(ArticleVersion, VersionContent), diesel::result::Error, _>(
+                return connection.transaction::<(), ErrorWrapper, _>(|transaction_connection| {
+                    Self::delete_relations(
+                        transaction_connection,
+                        article_language,
+                        version,
+                        user_id,
+                    )
+                });
+            })
+            .await?;
+
+        Self::refresh_search_content(connection, article_language_id).await;
+
+        Ok(())
+    }
+
+    // the search index follows the latest enabled version, there is nothing to find without one
+    async fn refresh_search_content(connection: &PgConnection, article_language_id: i32) {
+        match Self::get_aggregation(
+            connection,
+            None,
+            LanguageSearchDto {
+                article_languages_ids: Some(vec![article_language_id]),
+
+                language_code: None,
+                article_id: None,
+                article_language: None,
+                article_language_key: None,
+            },
+            &QueryOptions { is_actual: true },
+        )
+        .await
+        {
+            Ok(article_version) => {
+                ArticleLanguageRepository::upsert_search_content(
+                    connection,
+                    article_language_id,
+                    article_version.content.content,
+                )
+                .await
+            }
+            Err(_) => {
+                ArticleLanguageRepository::delete_search_content(connection, article_language_id)
+                    .await
+            }
+        };
+    }
+
+    pub async fn rebuild_keyframes(
+        connection: &PgConnection,
+        article_id: Option<i32>,
+        keyframe_interval: VersionKeyframeInterval,
+    ) -> i32 {
+        let article_languages_ids =
+            ArticleVersionRepository::get_article_languages_ids(connection, article_id).await;
+
+        let mut keyframes_count = 0;
+
+        for article_language_id in article_languages_ids {
+            keyframes_count += connection
+                .run(move |connection| {
+                    return connection.transaction::<i32, diesel::result::Error, _>(
                         |transaction_connection| {
-                            Ok(Self::create_relations(
+                            Self::rebuild_language_keyframes(
                                 transaction_connection,
-                                creation_dto,
-                                article_language,
-                                article_versions_count,
-                            ))
+                                article_language_id,
+                                keyframe_interval,
+                            )
                         },
                     );
+                })
+                .await
+                .expect(&FmtError::FailedToUpdate("version_content").fmt());
+        }
+
+        keyframes_count
+    }
+
+    // re-links neighbouring versions' delta chain before removing the version itself
+    fn delete_relations(
+        connection: &mut diesel::PgConnection,
+        article_language: ArticleLanguage,
+        version: i32,
+        user_id: i32,
+    ) -> Result<(), ErrorWrapper> {
+        let article_versions_with_contents =
+            ArticleVersionRepository::get_many_with_content_raw(connection, article_language.id)?;
+
+        let deleted_index = match article_versions_with_contents
+            .iter()
+            .position(|(article_version, _)| article_version.version == version)
+        {
+            Some(deleted_index) => deleted_index,
+            None => return FmtError::NotFound("article_version").error(),
+        };
+
+        if article_versions_with_contents.len() == 1 {
+            return FmtError::FailedToProcess("the only article_version").error();
+        }
+
+        let contents_map = VersionContentService::get_full_contents_map(
+            &article_versions_with_contents
+                .iter()
+                .map(|(article_version, version_content)| (article_version, version_content))
+                .collect(),
+        );
+
+        let next_relations = match deleted_index {
+            0 => None,
+            _ => article_versions_with_contents.get(deleted_index - 1),
+        };
+
+        if let Some((previous_version, previous_content)) =
+            article_versions_with_contents.get(deleted_index + 1)
+        {
+            let previous_full_content = contents_map
+                .get(&previous_content.id)
+                .expect(&FmtError::NotFound("version_content").fmt());
+
+            match next_relations {
+                Some((_, next_content)) => {
+                    if matches!(previous_content.content_type, ContentType::Diff) {
+                        let next_full_content = contents_map
+                            .get(&next_content.id)
+                            .expect(&FmtError::NotFound("version_content").fmt());
+
+                        VersionContentRepository::patch_raw(
+                            connection,
+                            previous_content.id,
+                            VersionContentDto {
+                                content: DiffHandler::get_delta(
+                                    next_full_content,
+                                    previous_full_content.as_bytes().to_vec(),
+                                ),
+                                content_type: ContentType::Diff,
+                            },
+                        )?;
+                    }
+                }
+                None => {
+                    VersionContentRepository::patch_raw(
+                        connection,
+                        previous_content.id,
+                        VersionContentDto {
+                            content: previous_full_content.as_bytes().to_vec(),
+                            content_type: ContentType::Full,
+                        },
+                    )?;
+
+                    if previous_version.name != article_language.name {
+                        ArticleLanguageRepository::patch_raw(
+                            connection,
+                            article_language.id,
+                            ArticleLanguagePatchDto {
+                                name: Some(previous_version.name.clone()),
+                                user_id,
+                                enabled: None,
+                                archived: None,
+                            },
+                        )?;
+                    }
+                }
+            };
+        }
+
+        let (deleted_version, deleted_content) = &article_versions_with_contents[deleted_index];
+
+        ArticleVersionRepository::delete_raw(connection, deleted_version.id)?;
+        VersionContentRepository::delete_raw(connection, deleted_content.id)?;
+
+        Ok(())
+    }
+
+    fn rebuild_language_keyframes(
+        connection: &mut diesel::PgConnection,
+        article_language_id: i32,
+        keyframe_interval: VersionKeyframeInterval,
+    ) -> Result<i32, diesel::result::Error> {
+        let article_versions_with_contents =
+            ArticleVersionRepository::get_many_with_content_raw(connection, article_language_id)?;
+
+        let mut contents_map = HashMap::new();
+        VersionContentService::update_contents_map(
+            &mut contents_map,
+            &article_versions_with_contents
+                .iter()
+                .map(|(article_version, version_content)| (article_version, version_content))
+                .collect(),
+        );
+
+        let mut keyframes_count = 0;
+
+        for (article_version, version_content) in article_versions_with_contents.iter() {
+            if !matches!(version_content.content_type, ContentType::Diff)
+                || !keyframe_interval.is_keyframe(article_version.version)
+            {
+                continue;
+            }
+
+            let content = contents_map
+                .remove(&version_content.id)
+                .expect(&FmtError::NotFound("version_content").fmt());
+
+            VersionContentRepository::patch_raw(
+                connection,
+                version_content.id,
+                VersionContentDto {
+                    content: content.as_bytes().to_vec(),
+                    content_type: ContentType::Full,
+                },
+            )?;
+
+            keyframes_count += 1;
+        }
+
+        Ok(keyframes_count)
+    }
+
+    // a translation can be based on an enabled version of another language of the same article
+    async fn get_source_version_id(
+        connection: &PgConnection,
+        article_id: i32,
+        language_code: &String,
+        source: &ArticleVersionSourceDto,
+    ) -> Result<i32, ErrorWrapper> {
+        if &source.language == language_code {
+            return FmtError::FailedToProcess("source language").error();
+        }
+
+        let (source_article_language, _) = ArticleLanguageService::get_one_with_language(
+            connection,
+            article_id,
+            source.language.clone(),
+            &QueryOptions { is_actual: true },
+        )
+        .await?;
+
+        match ArticleVersionRepository::get_one_by_version(
+            connection,
+            source_article_language.id,
+            source.version,
+        )
+        .await
+        {
+            Some(source_version) if source_version.enabled => Ok(source_version.id),
+            _ => FmtError::NotFound("source_article_version").error(),
+        }
+    }
+
+    // the article language row is locked, so concurrent creations can't get the same version;
+    // Err -> actual version, when it is not the base one of the edit anymore
+    async fn create_relations_transaction(
+        connection: &PgConnection,
+        creation_dto: ArticleVersionCreateRelationsDto,
+        article_language_id: i32,
+        source_version_id: Option<i32>,
+        keyframe_interval: VersionKeyframeInterval,
+    ) -> Result<(ArticleVersion, VersionContent), i32> {
+        connection
+            .run(move |connection| {
+                return connection.transaction::<_, diesel::result::Error, _>(
+                    |transaction_connection| {
+                        let article_language = ArticleLanguageRepository::get_one_for_update_raw(
+                            transaction_connection,
+                            article_language_id,
+                        )?;
+                        let last_version = ArticleVersionRepository::get_last_version_raw(
+                            transaction_connection,
+                            article_language_id,
+                        )?;
+
+                        if let Some(base_version) = creation_dto.base_version {
+                            if base_version != last_version {
+                                return Ok(Err(last_version));
+                            }
+                        }
+
+                        Ok(Ok(Self::create_relations(
+                            transaction_connection,
+                            creation_dto,
+                            article_language,
+                            last_version,
+                            source_version_id,
+                            keyframe_interval,
+                        )))
+                    },
+                );
             })
             .await
             .expect(&FmtError::FailedToInsert("article_version_relations").fmt())
     }
 
+    // three-way merge of the edit with the actual version if requested and possible,
+    // otherwise a conflict with the versions created since the base one
+    async fn merge_with_actual_version(
+        connection: &PgConnection,
+        article_language_id: i32,
+        actual_version: i32,
+        creation_dto: ArticleVersionCreateRelationsDto,
+    ) -> Result<ArticleVersionCreateRelationsDto, ErrorWrapper> {
+        let base_version = creation_dto.base_version.unwrap_or(actual_version);
+
+        let (article_versions_relations, content_map) = Self::get_versions_with_content_map(
+            connection,
+            Some(base_version),
+            Some(actual_version),
+            LanguageSearchDto {
+                article_languages_ids: Some(vec![article_language_id]),
+
+                language_code: None,
+                article_id: None,
+                article_language: None,
+                article_language_key: None,
+            },
+        )
+        .await?;
+
+        let mut versions =
+            ArticleVersionAggregation::from_content_map(article_versions_relations, content_map);
+        versions.retain(|aggregation| aggregation.version <= actual_version);
+
+        let base_aggregation = match versions.pop() {
+            Some(aggregation) if aggregation.version == base_version => aggregation,
+            _ => return FmtError::NotFound("base_article_version").error(),
+        };
+
+        let actual_content = match versions.first() {
+            Some(aggregation) => aggregation.content.content.clone(),
+            None => return FmtError::NotFound("article_version").error(),
+        };
+
+        if creation_dto.merge {
+            if let Some(content) = DiffHandler::merge_three_way(
+                &base_aggregation.content.content,
+                &creation_dto.content,
+                &actual_content,
+            ) {
+                return Ok(ArticleVersionCreateRelationsDto {
+                    content,
+                    base_version: Some(actual_version),
+                    ..creation_dto
+                });
+            }
+        }
+
+        let diff = DiffHandler::get_text_diff(
+            &base_aggregation.content.content,
+            &actual_content,
+            &DiffGranularity::Line,
+        );
+
+        let conflict_aggregation = ArticleVersionConflictAggregation {
+            message: FmtError::Conflict("article_version").fmt(),
+            base_version,
+            actual_version,
+            versions,
+            diff: VersionDiffHunkAggregation::from_hunks(diff),
+        };
+
+        Err(
+            FmtError::Conflict("article_version").error_wrapper_enriched(
+                rocket::serde::json::to_string(&conflict_aggregation)
+                    .expect(&FmtError::FailedToProcess("article_version_conflict").fmt()),
+            ),
+        )
+    }
+
     fn create_relations(
         connection: &mut diesel::PgConnection,
         creation_dto: ArticleVersionCreateRelationsDto,
         article_language: ArticleLanguage,
-        article_versions_count: i32,
+        last_version: i32,
+        source_version_id: Option<i32>,
+        keyframe_interval: VersionKeyframeInterval,
     ) -> (ArticleVersion, VersionContent) {
-        if article_versions_count > 0 {
+        if last_version > 0 && !keyframe_interval.is_keyframe(last_version) {
             Self::update_previous_version_content(
                 connection,
                 article_language.id,
-                article_versions_count,
+                last_version,
                 &creation_dto,
             );
         }
@@ -266,31 +936,46 @@ impl ArticleVersionService {
         )
         .expect(&FmtError::FailedToInsert("version_content").fmt());
 
+        ArticleLanguageRepository::upsert_search_content_raw(
+            connection,
+            article_language.id,
+            creation_dto.content.clone(),
+        )
+        .expect(&FmtError::FailedToUpdate("article_language_search").fmt());
+
         let article_version = ArticleVersionRepository::insert_raw(
             connection,
             ArticleVersionCreateDto {
                 article_language_id: article_language.id,
-                version: article_versions_count + 1,
+                version: last_version + 1,
                 content_id: version_content.id,
                 user_id: creation_dto.user_id,
                 name: actual_language_name,
+                source_version_id,
             },
         )
         .expect(&FmtError::FailedToInsert("article_version").fmt());
 
+        ImageRepository::insert_references_raw(
+            connection,
+            article_version.id,
+            ImageStorageHandler::get_content_keys(&creation_dto.content),
+        )
+        .expect(&FmtError::FailedToInsert("image_reference").fmt());
+
         (article_version, version_content)
     }
 
     fn update_previous_version_content(
         connection: &mut diesel::PgConnection,
         article_language_id: i32,
-        article_versions_count: i32,
+        last_version: i32,
         creation_dto: &ArticleVersionCreateRelationsDto,
     ) {
         let article_version = ArticleVersionRepository::get_by_version_raw(
             connection,
             article_language_id,
-            article_versions_count,
+            last_version,
         )
         .expect(&FmtError::FailedToFetch("article_version").fmt())
         .expect(&FmtError::NotFound("article_version").fmt());
@@ -302,8 +987,15 @@ impl ArticleVersionService {
 
         let content_delta = DiffHandler::get_delta(&creation_dto.content, version_content.content);
 
-        VersionContentRepository::patch_raw(connection, article_version.content_id, content_delta)
-            .expect(&FmtError::FailedToUpdate("version_content").fmt());
+        VersionContentRepository::patch_raw(
+            connection,
+            article_version.content_id,
+            VersionContentDto {
+                content: content_delta,
+                content_type: ContentType::Diff,
+            },
+        )
+        .expect(&FmtError::FailedToUpdate("version_content").fmt());
     }
 
     fn get_requested_article_version_with_content(
@@ -327,9 +1019,11 @@ impl ArticleVersionService {
             })
     }
 
+    // keyframe_version -> loads versions only up to the nearest keyframe (full content) above it
     async fn get_versions_with_content_map(
         connection: &PgConnection,
         version: Option<i32>,
+        keyframe_version: Option<i32>,
         language_search_dto: LanguageSearchDto,
     ) -> Result<
         (
@@ -338,6 +1032,55 @@ impl ArticleVersionService {
         ),
         ErrorWrapper,
     > {
+        let article_languages_ids =
+            match Self::get_article_languages_ids(connection, language_search_dto).await {
+                Err(e) => return Err(e),
+                Ok(article_languages_ids) => article_languages_ids,
+            };
+
+        let version_le = match (keyframe_version, article_languages_ids.as_slice()) {
+            (Some(keyframe_version), [article_language_id]) => {
+                ArticleVersionRepository::get_nearest_keyframe_version(
+                    connection,
+                    *article_language_id,
+                    keyframe_version,
+                )
+                .await
+            }
+            _ => None,
+        };
+
+        let article_versions_relations = match version {
+            Some(version) => {
+                ArticleVersionRepository::get_many_with_content(
+                    connection,
+                    ArticleVersionsJoinSearchDto {
+                        article_languages_ids,
+                        version_ge: version,
+                        version_le,
+                    },
+                )
+                .await
+            }
+            None => {
+                ArticleVersionRepository::get_many_actuals_with_content(
+                    connection,
+                    article_languages_ids,
+                )
+                .await
+            }
+        };
+
+        let content_map =
+            VersionContentService::get_contents_map_by_ids(&article_versions_relations);
+
+        Ok((article_versions_relations, content_map))
+    }
+
+    async fn get_article_languages_ids(
+        connection: &PgConnection,
+        language_search_dto: LanguageSearchDto,
+    ) -> Result<Vec<i32>, ErrorWrapper> {
         let article_languages_ids = match language_search_dto.article_languages_ids {
             Some(article_languages_ids) => article_languages_ids,
             None => match language_search_dto.article_language {
@@ -380,29 +1123,6 @@ impl ArticleVersionService {
             },
         };
 
-        let article_versions_relations = match version {
-            Some(version) => {
-                ArticleVersionRepository::get_many_with_content(
-                    connection,
-                    ArticleVersionsJoinSearchDto {
-                        article_languages_ids,
-                        version_ge: version,
-                    },
-                )
-                .await
-            }
-            None => {
-                ArticleVersionRepository::get_many_actuals_with_content(
-                    connection,
-                    article_languages_ids,
-                )
-                .await
-            }
-        };
-
-        let content_map =
-            VersionContentService::get_contents_map_by_ids(&article_versions_relations);
-
-        Ok((article_versions_relations, content_map))
+        Ok(article_languages_ids)
     }
 }
@@ -1,32 +1,64 @@
 use diesel::Connection;
+use std::env;
+use std::sync::Arc;
 use uuid::Uuid;
 
 use super::authorization::PermissionsHandler;
-use super::dtm_common::{QueryOptions, TokenDto, UserRoleId};
-use super::emailer::Emailer;
+use super::dtm_common::{ChallengePurpose, QueryOptions, TokenDto, UserRoleId};
+use super::emailer::EmailTransport;
 use super::error::{ErrorWrapper, FmtError};
 use super::hasher::Hasher;
 use super::jwt_handler::JwtHandler;
 
 use super::dtm::auth::dto::{
-    UserAccountCreateDto, UserConfirmDto, UserConfirmPasswordResetDto, UserCreateRelationsDto,
-    UserLoginDto, UserOtpCreateDto, UserPasswordCreateDto, UserPatchDto, UserResetDto,
-    UserResetOTPsDto, UserSignupDto,
+    UserAccountCreateDto, UserChallengeDto, UserChallengeEnrollDto, UserConfirmDto,
+    UserConfirmPasswordResetDto, UserCreateRelationsDto, UserLanguageDto, UserLoginDto,
+    UserOidcCallbackDto, UserOtpCreateDto, UserPasswordCreateDto, UserPatchDto, UserRefreshDto,
+    UserResendDto, UserResetDto, UserResetOTPsDto, UserSessionCreateDto, UserSignupDto,
+    UserTotpCodeDto,
 };
 
 use super::aggregation::user_account::UserAccountAggregation;
 use super::aggregation::user_account_auth::{
-    UserAccountAuthAggregation, UserAccountPermissionsAggregation,
+    OidcAuthorizationAggregation, TotpEnrollmentAggregation, TotpRecoveryCodesAggregation,
+    UserAccountAuthAggregation, UserAccountEnrollmentAggregation, UserAccountLoginAggregation,
+    UserAccountPermissionsAggregation,
 };
 
 use super::repository::{
     entity::{
         article::ArticleRepository,
-        auth::{AuthRepository, OTPType, UserAccount, UserOtp, UserPassword},
+        auth::{AuthRepository, OTPType, UserAccount, UserOtp, UserPassword, UserSession},
+        language::LanguageRepository,
     },
     PgConnection,
 };
 
+use super::email_template::EmailTemplateService;
+use super::oidc::OidcService;
+use super::totp::TotpService;
+
+const OTP_REGISTER_LIVE_SEC_ENV: &str = "OTP_REGISTER_LIVE_SEC";
+const OTP_RESET_LIVE_SEC_ENV: &str = "OTP_RESET_LIVE_SEC";
+const OTP_MAX_ATTEMPTS_ENV: &str = "OTP_MAX_ATTEMPTS";
+const OTP_RESEND_COOLDOWN_SEC_ENV: &str = "OTP_RESEND_COOLDOWN_SEC";
+
+const LOGIN_MAX_FAILURES_ENV: &str = "LOGIN_MAX_FAILURES";
+const LOGIN_IP_MAX_FAILURES_ENV: &str = "LOGIN_IP_MAX_FAILURES";
+const LOGIN_LOCKOUT_SEC_ENV: &str = "LOGIN_LOCKOUT_SEC";
+const LOGIN_MAX_LOCKOUT_SEC_ENV: &str = "LOGIN_MAX_LOCKOUT_SEC";
+const LOGIN_FAILURE_WINDOW_SEC_ENV: &str = "LOGIN_FAILURE_WINDOW_SEC";
+
+const DEFAULT_OTP_REGISTER_LIVE_SEC: i32 = 60 * 60 * 24;
+const DEFAULT_OTP_RESET_LIVE_SEC: i32 = 60 * 60;
+const DEFAULT_OTP_MAX_ATTEMPTS: i32 = 5;
+const DEFAULT_OTP_RESEND_COOLDOWN_SEC: i32 = 60;
+const DEFAULT_LOGIN_MAX_FAILURES: i32 = 5;
+const DEFAULT_LOGIN_IP_MAX_FAILURES: i32 = 20;
+const DEFAULT_LOGIN_LOCKOUT_SEC: i32 = 60;
+const DEFAULT_LOGIN_MAX_LOCKOUT_SEC: i32 = 60 * 60;
+const DEFAULT_LOGIN_FAILURE_WINDOW_SEC: i32 = 60 * 60;
+
 pub struct AuthService;
 
 impl AuthService {
@@ -56,6 +88,35 @@ impl AuthService {
         UserAccountPermissionsAggregation::from_aggregation(user_account, permissions)
     }
 
+    async fn get_user(
+        connection: &PgConnection,
+        user_id: i32,
+    ) -> Result<UserAccount, ErrorWrapper> {
+        match AuthRepository::get_one_user(connection, user_id).await {
+            None => FmtError::NotFound("user_account").error(),
+            Some(user_account) => Ok(user_account),
+        }
+    }
+
+    async fn get_challenge_user(
+        connection: &PgConnection,
+        challenge_token: String,
+        purpose: ChallengePurpose,
+    ) -> Result<UserAccount, ErrorWrapper> {
+        let claims = match JwtHandler::decode_challenge_jwt(challenge_token) {
+            Ok(claims) if claims.purpose == purpose => claims,
+            _ => return FmtError::Unauthorized("invalid token").error(),
+        };
+
+        let user_account = Self::get_user(connection, claims.user_id).await?;
+
+        if !user_account.active || user_account.blocked {
+            return FmtError::PermissionDenied("not enough rights").error();
+        }
+
+        Ok(user_account)
+    }
+
     pub async fn get_aggregation(
         connection: &PgConnection,
         user_id: i32,
@@ -71,42 +132,246 @@ impl AuthService {
     pub async fn login(
         connection: &PgConnection,
         user_signup_dto: UserLoginDto,
-    ) -> Result<UserAccountAuthAggregation, ErrorWrapper> {
-        let (user_password, user_account) =
-            match AuthRepository::get_one_user_with_password(connection, user_signup_dto.email)
-                .await
-            {
-                Some((user_password, user_account)) => (user_password, user_account),
-                None => return FmtError::Unauthorized("invalid credentials").error(),
-            };
+        client_ip: Option<String>,
+    ) -> Result<UserAccountLoginAggregation, ErrorWrapper> {
+        let ip_subject = client_ip.map(|ip| format!("ip:{ip}"));
+        // unknown emails are locked the same way, so a lock doesn't reveal existing accounts
+        let email_subject = format!("email:{}", user_signup_dto.email);
+
+        let (user_password, user_account) = match AuthRepository::get_one_user_with_password(
+            connection,
+            user_signup_dto.email.clone(),
+        )
+        .await
+        {
+            Some((user_password, user_account)) => (user_password, user_account),
+            None => {
+                Self::check_login_lock(
+                    connection,
+                    [Some(email_subject.clone()), ip_subject.clone()]
+                        .into_iter()
+                        .flatten()
+                        .collect(),
+                )
+                .await?;
+                Hasher::verify_dummy(user_signup_dto.password);
+                Self::register_login_failures(connection, Some(email_subject), ip_subject).await;
+
+                return FmtError::Unauthorized("invalid credentials").error();
+            }
+        };
+
+        let user_subject = format!("user:{}", user_account.id);
+
+        Self::check_login_lock(
+            connection,
+            [Some(user_subject.clone()), ip_subject.clone()]
+                .into_iter()
+                .flatten()
+                .collect(),
+        )
+        .await?;
 
         if !user_account.active || user_account.blocked {
             return FmtError::PermissionDenied("not enough rights").error();
         }
 
-        match Hasher::verify_encoded(user_signup_dto.password, user_password.password) {
+        match Hasher::verify_encoded(
+            user_signup_dto.password.clone(),
+            user_password.password.clone(),
+        ) {
             Ok(is_correct) => match is_correct {
-                false => return FmtError::Unauthorized("invalid credentials").error(),
+                false => {
+                    Self::register_login_failures(connection, Some(user_subject), ip_subject).await;
+
+                    return FmtError::Unauthorized("invalid credentials").error();
+                }
                 _ => (),
             },
             Err(e) => return Err(e),
         };
 
-        let jwt_string = match JwtHandler::encode_jwt(user_account.id) {
-            Ok(jwt_string) => jwt_string,
-            Err(e) => return Err(e),
-        };
+        AuthRepository::delete_login_failure(connection, user_subject).await;
+
+        if Hasher::needs_rehash(&user_password.password) {
+            Self::rehash_password(connection, user_account.id, user_signup_dto.password).await;
+        }
+
+        Self::start_login(connection, user_account).await
+    }
+
+    pub async fn get_oidc_authorization(
+        provider: String,
+    ) -> Result<OidcAuthorizationAggregation, ErrorWrapper> {
+        OidcService::get_authorization(provider).await
+    }
+
+    pub async fn login_oidc(
+        connection: &PgConnection,
+        callback_dto: UserOidcCallbackDto,
+    ) -> Result<UserAccountLoginAggregation, ErrorWrapper> {
+        let user_account = OidcService::get_user(connection, callback_dto).await?;
+
+        if !user_account.active || user_account.blocked {
+            return FmtError::PermissionDenied("not enough rights").error();
+        }
+
+        Self::start_login(connection, user_account).await
+    }
+
+    pub async fn login_challenge(
+        connection: &PgConnection,
+        challenge_dto: UserChallengeDto,
+    ) -> Result<UserAccountAuthAggregation, ErrorWrapper> {
+        let user_account = Self::get_challenge_user(
+            connection,
+            challenge_dto.challenge_token,
+            ChallengePurpose::Totp,
+        )
+        .await?;
+
+        let user_subject = format!("user:{}", user_account.id);
+
+        Self::check_login_lock(connection, vec![user_subject.clone()]).await?;
+
+        if !TotpService::verify_code(connection, user_account.id, challenge_dto.code).await? {
+            Self::register_login_failures(connection, Some(user_subject), None).await;
+
+            return FmtError::Unauthorized("invalid code").error();
+        }
+
+        AuthRepository::delete_login_failure(connection, user_subject).await;
+
+        let token = Self::create_session(connection, user_account.id).await?;
 
-        Ok(UserAccountAuthAggregation::from_model(
+        Ok(UserAccountAuthAggregation::from_model(user_account, token))
+    }
+
+    pub async fn enroll_challenge(
+        connection: &PgConnection,
+        enroll_dto: UserChallengeEnrollDto,
+    ) -> Result<TotpEnrollmentAggregation, ErrorWrapper> {
+        let user_account = Self::get_challenge_user(
+            connection,
+            enroll_dto.challenge_token,
+            ChallengePurpose::Enrollment,
+        )
+        .await?;
+
+        TotpService::start_enrollment(connection, &user_account).await
+    }
+
+    pub async fn confirm_enroll_challenge(
+        connection: &PgConnection,
+        challenge_dto: UserChallengeDto,
+    ) -> Result<UserAccountEnrollmentAggregation, ErrorWrapper> {
+        let user_account = Self::get_challenge_user(
+            connection,
+            challenge_dto.challenge_token,
+            ChallengePurpose::Enrollment,
+        )
+        .await?;
+
+        let recovery_codes =
+            TotpService::confirm_enrollment(connection, user_account.id, challenge_dto.code)
+                .await?;
+
+        let token = Self::create_session(connection, user_account.id).await?;
+
+        Ok(UserAccountEnrollmentAggregation::from_model(
             user_account,
-            TokenDto { token: jwt_string },
+            token,
+            recovery_codes,
         ))
     }
 
+    pub async fn start_totp(
+        connection: &PgConnection,
+        user_id: i32,
+    ) -> Result<TotpEnrollmentAggregation, ErrorWrapper> {
+        let user_account = Self::get_user(connection, user_id).await?;
+
+        TotpService::start_enrollment(connection, &user_account).await
+    }
+
+    pub async fn confirm_totp(
+        connection: &PgConnection,
+        code_dto: UserTotpCodeDto,
+    ) -> Result<TotpRecoveryCodesAggregation, ErrorWrapper> {
+        let recovery_codes =
+            TotpService::confirm_enrollment(connection, code_dto.user_id, code_dto.code).await?;
+
+        Ok(TotpRecoveryCodesAggregation { recovery_codes })
+    }
+
+    pub async fn disable_totp(
+        connection: &PgConnection,
+        code_dto: UserTotpCodeDto,
+    ) -> Result<(), ErrorWrapper> {
+        let user_account = Self::get_user(connection, code_dto.user_id).await?;
+
+        TotpService::disable(connection, &user_account, code_dto.code).await
+    }
+
+    pub async fn refresh(
+        connection: &PgConnection,
+        refresh_dto: UserRefreshDto,
+    ) -> Result<UserAccountAuthAggregation, ErrorWrapper> {
+        let refresh_token_hash = Hasher::hash_token(&refresh_dto.refresh_token);
+
+        let (user_session, user_account) =
+            match AuthRepository::get_one_session_with_user(connection, refresh_token_hash).await {
+                Some((user_session, user_account)) => (user_session, user_account),
+                None => return FmtError::Unauthorized("invalid token").error(),
+            };
+
+        if user_session.revoked_at.is_some() {
+            // already rotated token is used again - treat the whole family as leaked
+            if user_session.replaced_by.is_some() {
+                AuthRepository::revoke_user_sessions(connection, user_account.id).await;
+            }
+
+            return FmtError::Unauthorized("revoked token").error();
+        }
+
+        if !user_account.active || user_account.blocked {
+            return FmtError::PermissionDenied("not enough rights").error();
+        }
+
+        let (new_session, refresh_token) =
+            Self::rotate_session_transaction(connection, user_session).await?;
+
+        let token = Self::get_token(new_session, refresh_token)?;
+
+        Ok(UserAccountAuthAggregation::from_model(user_account, token))
+    }
+
+    pub async fn unlock(
+        connection: &PgConnection,
+        user_id: i32,
+    ) -> Result<UserAccountAggregation, ErrorWrapper> {
+        let user_account_aggregation = Self::get_aggregation(connection, user_id).await?;
+
+        AuthRepository::delete_login_failure(connection, format!("user:{user_id}")).await;
+
+        Ok(user_account_aggregation)
+    }
+
+    pub async fn logout(connection: &PgConnection, session_id: i32) -> Result<(), ErrorWrapper> {
+        match AuthRepository::revoke_user_session(connection, session_id).await {
+            0 => FmtError::NotFound("user_session").error(),
+            _ => Ok(()),
+        }
+    }
+
+    pub async fn logout_all(connection: &PgConnection, user_id: i32) -> usize {
+        AuthRepository::revoke_user_sessions(connection, user_id).await
+    }
+
     pub async fn confirm_password_reset(
         connection: &PgConnection,
         confirm_reset_dto: UserConfirmPasswordResetDto,
-    ) -> Result<UserAccountAuthAggregation, ErrorWrapper> {
+    ) -> Result<UserAccountLoginAggregation, ErrorWrapper> {
         let (user_account, user_otp) = match AuthRepository::get_one_user_with_otp(
             connection,
             confirm_reset_dto.email,
@@ -118,9 +383,7 @@ impl AuthService {
             None => return FmtError::Unauthorized("invalid credentials").error(),
         };
 
-        if confirm_reset_dto.otp != user_otp.otp {
-            return FmtError::Unauthorized("invalid credentials").error();
-        }
+        Self::verify_otp(connection, &user_otp, &confirm_reset_dto.otp).await?;
 
         let password_hash = match Hasher::hash_password(confirm_reset_dto.password) {
             Ok(password_hash) => password_hash,
@@ -135,15 +398,8 @@ impl AuthService {
         )
         .await?;
 
-        let jwt_string = match JwtHandler::encode_jwt(user_account.id) {
-            Ok(jwt_string) => jwt_string,
-            Err(e) => return Err(e),
-        };
-
-        Ok(UserAccountAuthAggregation::from_model(
-            user_account,
-            TokenDto { token: jwt_string },
-        ))
+        // the reset replaces the password only, 2fa is still required
+        Self::start_login(connection, user_account).await
     }
 
     pub async fn confirm_user(
@@ -161,29 +417,27 @@ impl AuthService {
             None => return FmtError::Unauthorized("invalid credentials").error(),
         };
 
-        if user_confirm_dto.otp != user_otp.otp {
-            return FmtError::Unauthorized("invalid credentials").error();
-        }
+        Self::verify_otp(connection, &user_otp, &user_confirm_dto.otp).await?;
 
         let user_account =
             Self::confirm_user_transaction(connection, user_account.id, user_otp.id).await?;
 
-        let jwt_string = match JwtHandler::encode_jwt(user_account.id) {
-            Ok(jwt_string) => jwt_string,
-            Err(e) => return Err(e),
-        };
+        let token = Self::create_session(connection, user_account.id).await?;
 
-        Ok(UserAccountAuthAggregation::from_model(
-            user_account,
-            TokenDto { token: jwt_string },
-        ))
+        Ok(UserAccountAuthAggregation::from_model(user_account, token))
     }
 
     pub async fn create_user(
         connection: &PgConnection,
+        email_transport: &Arc<dyn EmailTransport>,
         user_signup_dto: UserSignupDto,
         redirect_to: Option<String>,
     ) -> Result<UserAccountAggregation, ErrorWrapper> {
+        let language_id = match user_signup_dto.language {
+            Some(language) => Some(Self::get_language_id(connection, language).await?),
+            None => None,
+        };
+
         let password_hash = match Hasher::hash_password(user_signup_dto.password) {
             Ok(password_hash) => password_hash,
             Err(e) => return Err(e),
@@ -198,18 +452,27 @@ impl AuthService {
                 email: user_signup_dto.email,
                 name: user_signup_dto.name,
                 role_id: UserRoleId::Common as i32,
-                otp: Some(otp.clone()),
+                otp_hash: Some(Hasher::hash_token(&otp)),
+                language_id,
             },
         )
         .await?;
 
-        Emailer::send_confirmation_email(&otp, &user_account.email, &redirect_to).await?;
+        EmailTemplateService::send_confirmation_email(
+            connection,
+            email_transport,
+            &user_account,
+            &otp,
+            &redirect_to,
+        )
+        .await?;
 
         Ok(UserAccountAggregation::from_model(user_account))
     }
 
     pub async fn reset_user_password(
         connection: &PgConnection,
+        email_transport: &Arc<dyn EmailTransport>,
         user_reset_dto: UserResetDto,
         redirect_to: Option<String>,
     ) -> Result<UserAccountAggregation, ErrorWrapper> {
@@ -230,19 +493,88 @@ impl AuthService {
             None => return FmtError::NotFound("invalid user").error(),
         };
 
+        Self::check_otp_cooldown(connection, user_account.id).await?;
+
         let otp = Uuid::new_v4().to_string();
 
-        Self::create_reset_otp_transaction(
+        Self::recreate_otp_transaction(
             connection,
             UserResetOTPsDto {
                 user_id: user_account.id,
                 existing_otp_ids,
-                otp: otp.clone(),
+                otp_hash: Hasher::hash_token(&otp),
+                otp_type: OTPType::Reset,
             },
         )
         .await?;
 
-        Emailer::send_reset_email(&otp, &user_account.email, &redirect_to).await?;
+        EmailTemplateService::send_reset_email(
+            connection,
+            email_transport,
+            &user_account,
+            &otp,
+            &redirect_to,
+        )
+        .await?;
+
+        Ok(UserAccountAggregation::from_model(user_account))
+    }
+
+    pub async fn resend_otp(
+        connection: &PgConnection,
+        email_transport: &Arc<dyn EmailTransport>,
+        resend_dto: UserResendDto,
+        redirect_to: Option<String>,
+    ) -> Result<UserAccountAggregation, ErrorWrapper> {
+        let (user_account, user_otp) =
+            match AuthRepository::get_user_with_optional_otp(connection, resend_dto.email, None)
+                .await
+            {
+                Some((user_account, Some(user_otp))) => (user_account, user_otp),
+                Some((_, None)) => return FmtError::NotFound("user_otp").error(),
+                None => return FmtError::NotFound("invalid user").error(),
+            };
+
+        Self::check_otp_cooldown(connection, user_account.id).await?;
+
+        let otp = Uuid::new_v4().to_string();
+        let otp_type = user_otp.otp_type;
+
+        let is_reset = matches!(otp_type, OTPType::Reset);
+
+        Self::recreate_otp_transaction(
+            connection,
+            UserResetOTPsDto {
+                otp_type,
+                user_id: user_account.id,
+                existing_otp_ids: vec![user_otp.id],
+                otp_hash: Hasher::hash_token(&otp),
+            },
+        )
+        .await?;
+
+        match is_reset {
+            true => {
+                EmailTemplateService::send_reset_email(
+                    connection,
+                    email_transport,
+                    &user_account,
+                    &otp,
+                    &redirect_to,
+                )
+                .await?
+            }
+            false => {
+                EmailTemplateService::send_confirmation_email(
+                    connection,
+                    email_transport,
+                    &user_account,
+                    &otp,
+                    &redirect_to,
+                )
+                .await?
+            }
+        };
 
         Ok(UserAccountAggregation::from_model(user_account))
     }
@@ -264,7 +596,8 @@ impl AuthService {
                 password_hash,
                 email: user_signup_dto.email,
                 name: user_signup_dto.name,
-                otp: None,
+                otp_hash: None,
+                language_id: None,
             },
         )
         .await?;
@@ -281,9 +614,18 @@ impl AuthService {
         )
         .await;
 
-        match JwtHandler::encode_jwt(user_account.id) {
-            Ok(jwt_string) => Ok(TokenDto { token: jwt_string }),
-            Err(e) => return Err(e),
+        Self::create_session(connection, user_account.id).await
+    }
+
+    pub async fn patch_language(
+        connection: &PgConnection,
+        language_dto: UserLanguageDto,
+    ) -> Result<(), ErrorWrapper> {
+        let language_id = Self::get_language_id(connection, language_dto.language).await?;
+
+        match AuthRepository::patch_language(connection, language_dto.user_id, language_id).await {
+            0 => FmtError::NotFound("user_account").error(),
+            _ => Ok(()),
         }
     }
 
@@ -301,6 +643,240 @@ impl AuthService {
         Self::get_aggregation(connection, user_id).await
     }
 
+    async fn get_language_id(
+        connection: &PgConnection,
+        language: String,
+    ) -> Result<i32, ErrorWrapper> {
+        match LanguageRepository::get_one(connection, language).await {
+            Some(language) => Ok(language.id),
+            None => FmtError::NotFound("language").error(),
+        }
+    }
+
+    fn get_env_config(env_name: &str, default: i32) -> i32 {
+        match env::var(env_name) {
+            Ok(value) => value
+                .parse::<i32>()
+                .expect(&FmtError::FailedToProcess(env_name).fmt()),
+            Err(_) => default,
+        }
+    }
+
+    fn get_otp_live_sec(otp_type: &OTPType) -> i32 {
+        match otp_type {
+            OTPType::Register => {
+                Self::get_env_config(OTP_REGISTER_LIVE_SEC_ENV, DEFAULT_OTP_REGISTER_LIVE_SEC)
+            }
+            OTPType::Reset => {
+                Self::get_env_config(OTP_RESET_LIVE_SEC_ENV, DEFAULT_OTP_RESET_LIVE_SEC)
+            }
+        }
+    }
+
+    async fn check_otp_cooldown(
+        connection: &PgConnection,
+        user_id: i32,
+    ) -> Result<(), ErrorWrapper> {
+        let cooldown_sec =
+            Self::get_env_config(OTP_RESEND_COOLDOWN_SEC_ENV, DEFAULT_OTP_RESEND_COOLDOWN_SEC);
+
+        match AuthRepository::has_recent_otp(connection, user_id, cooldown_sec).await {
+            true => FmtError::TooManyRequests("otp was sent recently").error(),
+            false => Ok(()),
+        }
+    }
+
+    async fn check_login_lock(
+        connection: &PgConnection,
+        subjects: Vec<String>,
+    ) -> Result<(), ErrorWrapper> {
+        if subjects.len() == 0 {
+            return Ok(());
+        }
+
+        match AuthRepository::get_login_lock(connection, subjects).await {
+            Some(login_lock) => Err(FmtError::AlreadyLocked("too many failed login attempts")
+                .error_wrapper_enriched(format!("retry after {} sec", login_lock.retry_after_sec))),
+            None => Ok(()),
+        }
+    }
+
+    async fn register_login_failures(
+        connection: &PgConnection,
+        user_subject: Option<String>,
+        ip_subject: Option<String>,
+    ) {
+        if let Some(user_subject) = user_subject {
+            let max_failures =
+                Self::get_env_config(LOGIN_MAX_FAILURES_ENV, DEFAULT_LOGIN_MAX_FAILURES);
+
+            Self::register_login_failure(connection, user_subject, max_failures).await;
+        }
+
+        if let Some(ip_subject) = ip_subject {
+            let max_failures =
+                Self::get_env_config(LOGIN_IP_MAX_FAILURES_ENV, DEFAULT_LOGIN_IP_MAX_FAILURES);
+
+            Self::register_login_failure(connection, ip_subject, max_failures).await;
+        }
+    }
+
+    // lockout doubles with each failure over the limit: LOGIN_LOCKOUT_SEC * 2^(failures - limit)
+    async fn register_login_failure(connection: &PgConnection, subject: String, max_failures: i32) {
+        let window_sec = Self::get_env_config(
+            LOGIN_FAILURE_WINDOW_SEC_ENV,
+            DEFAULT_LOGIN_FAILURE_WINDOW_SEC,
+        );
+
+        let login_failure =
+            AuthRepository::insert_login_failure(connection, subject, window_sec).await;
+
+        if login_failure.failed_count < max_failures {
+            return;
+        }
+
+        let lockout_sec = Self::get_env_config(LOGIN_LOCKOUT_SEC_ENV, DEFAULT_LOGIN_LOCKOUT_SEC);
+        let max_lockout_sec =
+            Self::get_env_config(LOGIN_MAX_LOCKOUT_SEC_ENV, DEFAULT_LOGIN_MAX_LOCKOUT_SEC);
+
+        let exponent = (login_failure.failed_count - max_failures).min(30) as u32;
+        let lock_sec = lockout_sec
+            .saturating_mul(2i32.saturating_pow(exponent))
+            .min(max_lockout_sec);
+
+        AuthRepository::lock_login_subject(connection, login_failure.id, lock_sec).await;
+    }
+
+    // each failed attempt is counted, the code is dropped once the limit is reached
+    async fn verify_otp(
+        connection: &PgConnection,
+        user_otp: &UserOtp,
+        otp: &String,
+    ) -> Result<(), ErrorWrapper> {
+        let max_attempts = Self::get_env_config(OTP_MAX_ATTEMPTS_ENV, DEFAULT_OTP_MAX_ATTEMPTS);
+
+        if user_otp.attempts < max_attempts && Hasher::verify_token(otp, &user_otp.otp_hash) {
+            return Ok(());
+        }
+
+        let attempts = AuthRepository::increment_otp_attempts(connection, user_otp.id).await;
+
+        if attempts >= max_attempts {
+            AuthRepository::delete_user_otps(connection, vec![user_otp.id]).await;
+        }
+
+        FmtError::Unauthorized("invalid credentials").error()
+    }
+
+    // hashes made with legacy or outdated parameters are upgraded on successful login
+    async fn rehash_password(connection: &PgConnection, user_id: i32, password: String) {
+        if let Ok(password_hash) = Hasher::hash_password(password) {
+            AuthRepository::update_user_password(
+                connection,
+                UserPasswordCreateDto {
+                    user_id,
+                    password_hash,
+                },
+            )
+            .await;
+        }
+    }
+
+    // 2fa applies regardless of how the first factor was passed
+    async fn start_login(
+        connection: &PgConnection,
+        user_account: UserAccount,
+    ) -> Result<UserAccountLoginAggregation, ErrorWrapper> {
+        if let Some(challenge) = TotpService::get_login_challenge(connection, &user_account).await?
+        {
+            return Ok(UserAccountLoginAggregation::from_challenge(
+                user_account,
+                challenge,
+            ));
+        }
+
+        let token = Self::create_session(connection, user_account.id).await?;
+
+        Ok(UserAccountLoginAggregation::from_token(user_account, token))
+    }
+
+    async fn create_session(
+        connection: &PgConnection,
+        user_id: i32,
+    ) -> Result<TokenDto, ErrorWrapper> {
+        let (user_session, refresh_token) = connection
+            .run(move |connection| Self::create_session_raw(connection, user_id))
+            .await
+            .expect(&FmtError::FailedToInsert("user_session").fmt());
+
+        Self::get_token(user_session, refresh_token)
+    }
+
+    fn create_session_raw(
+        connection: &mut diesel::PgConnection,
+        user_id: i32,
+    ) -> Result<(UserSession, String), diesel::result::Error> {
+        let refresh_token = JwtHandler::generate_refresh_token();
+
+        let user_session = AuthRepository::insert_user_session_raw(
+            connection,
+            UserSessionCreateDto {
+                user_id,
+                refresh_token_hash: Hasher::hash_token(&refresh_token),
+                live_sec: JwtHandler::get_refresh_live_sec(),
+            },
+        )?;
+
+        Ok((user_session, refresh_token))
+    }
+
+    fn get_token(
+        user_session: UserSession,
+        refresh_token: String,
+    ) -> Result<TokenDto, ErrorWrapper> {
+        let jwt_string = JwtHandler::encode_jwt(user_session.user_id, user_session.id)?;
+
+        Ok(TokenDto {
+            token: jwt_string,
+            refresh_token,
+        })
+    }
+
+    async fn rotate_session_transaction(
+        connection: &PgConnection,
+        user_session: UserSession,
+    ) -> Result<(UserSession, String), ErrorWrapper> {
+        connection
+            .run(move |connection| {
+                return connection.transaction::<(UserSession, String), ErrorWrapper, _>(
+                    |transaction_connection| {
+                        Self::rotate_session(transaction_connection, user_session)
+                    },
+                );
+            })
+            .await
+    }
+
+    fn rotate_session(
+        connection: &mut diesel::PgConnection,
+        user_session: UserSession,
+    ) -> Result<(UserSession, String), ErrorWrapper> {
+        let (new_session, refresh_token) =
+            Self::create_session_raw(connection, user_session.user_id)
+                .expect(&FmtError::FailedToInsert("user_session").fmt());
+
+        let replaced_count =
+            AuthRepository::replace_user_session_raw(connection, user_session.id, new_session.id)
+                .expect(&FmtError::FailedToUpdate("user_session").fmt());
+
+        // concurrent refresh with the same token has already rotated the session
+        if replaced_count == 0 {
+            return FmtError::Unauthorized("revoked token").error();
+        }
+
+        Ok((new_session, refresh_token))
+    }
+
     async fn confirm_password_reset_transaction(
         connection: &PgConnection,
         user_id: i32,
@@ -350,6 +926,9 @@ impl AuthService {
         )
         .expect(&FmtError::FailedToInsert("user_password").fmt());
 
+        AuthRepository::revoke_user_sessions_raw(connection, user_account.id)
+            .expect(&FmtError::FailedToUpdate("user_session").fmt());
+
         AuthRepository::delete_user_otps_raw(connection, vec![otp_id])
             .expect(&FmtError::FailedToUpdate("user_otp").fmt());
 
@@ -395,22 +974,20 @@ impl AuthService {
         Ok(user_account)
     }
 
-    async fn create_reset_otp_transaction(
+    async fn recreate_otp_transaction(
         connection: &PgConnection,
         reset_dto: UserResetOTPsDto,
     ) -> Result<UserOtp, ErrorWrapper> {
         connection
             .run(move |connection| {
                 return connection.transaction::<UserOtp, ErrorWrapper, _>(
-                    |transaction_connection| {
-                        Self::create_reset_otp(transaction_connection, reset_dto)
-                    },
+                    |transaction_connection| Self::recreate_otp(transaction_connection, reset_dto),
                 );
             })
             .await
     }
 
-    fn create_reset_otp(
+    fn recreate_otp(
         connection: &mut diesel::PgConnection,
         reset_dto: UserResetOTPsDto,
     ) -> Result<UserOtp, ErrorWrapper> {
@@ -419,12 +996,15 @@ impl AuthService {
                 .expect(&FmtError::FailedToUpdate("user_otp").fmt());
         }
 
+        let live_sec = Self::get_otp_live_sec(&reset_dto.otp_type);
+
         let otp = AuthRepository::insert_user_otp_raw(
             connection,
             UserOtpCreateDto {
-                otp: reset_dto.otp,
+                live_sec,
+                otp_hash: reset_dto.otp_hash,
                 user_id: reset_dto.user_id,
-                otp_type: OTPType::Reset,
+                otp_type: reset_dto.otp_type,
             },
         )
         .expect(&FmtError::FailedToInsert("user_otp").fmt());
@@ -458,6 +1038,7 @@ impl AuthService {
                 email: creation_dto.email,
                 name: creation_dto.name,
                 role_id: creation_dto.role_id,
+                language_id: creation_dto.language_id,
             },
         ) {
             Ok(user_account) => user_account,
@@ -478,15 +1059,16 @@ impl AuthService {
         )
         .expect(&FmtError::FailedToInsert("user_password").fmt());
 
-        let user_otp = match creation_dto.otp {
+        let user_otp = match creation_dto.otp_hash {
             None => None,
-            Some(otp) => {
+            Some(otp_hash) => {
                 let otp = AuthRepository::insert_user_otp_raw(
                     connection,
                     UserOtpCreateDto {
-                        otp,
+                        otp_hash,
                         user_id: user_account.id,
                         otp_type: OTPType::Register,
+                        live_sec: Self::get_otp_live_sec(&OTPType::Register),
                     },
                 )
                 .expect(&FmtError::FailedToInsert("user_otp").fmt());
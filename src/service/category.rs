@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+
+use super::dtm_common::QueryOptions;
+use super::error::{ErrorWrapper, FmtError};
+
+use super::dtm::category::dto::{ArticleCategoryCreateDto, CategoryCreateDto, CategoryPatchDto};
+
+use super::aggregation::{category::CategoryAggregation, user_account::UserAccountAggregation};
+use super::authorization::PermissionsHandler;
+
+use super::repository::{
+    entity::{article::ArticleRepository, category::CategoryRepository},
+    PgConnection,
+};
+
+pub struct CategoryService;
+
+impl CategoryService {
+    pub async fn get_aggregation(
+        connection: &PgConnection,
+        id: i32,
+    ) -> Result<CategoryAggregation, ErrorWrapper> {
+        match CategoryRepository::get_one(connection, id).await {
+            None => FmtError::NotFound("category").error(),
+            Some(category) => Ok(CategoryAggregation::from_model(category)),
+        }
+    }
+
+    pub async fn get_aggregations(connection: &PgConnection) -> Vec<CategoryAggregation> {
+        let categories = CategoryRepository::get_many(connection).await;
+
+        CategoryAggregation::from_model_list(categories)
+    }
+
+    pub async fn get_article_aggregations(
+        connection: &PgConnection,
+        article_id: i32,
+    ) -> Vec<CategoryAggregation> {
+        Self::get_aggregations_map(connection, vec![article_id])
+            .await
+            .remove(&article_id)
+            .unwrap_or(vec![])
+    }
+
+    pub async fn get_aggregations_map(
+        connection: &PgConnection,
+        article_ids: Vec<i32>,
+    ) -> HashMap<i32, Vec<CategoryAggregation>> {
+        let article_categories_relations =
+            CategoryRepository::get_many_by_articles(connection, article_ids).await;
+
+        CategoryAggregation::get_aggregations_map(article_categories_relations)
+    }
+
+    pub async fn insert(
+        connection: &PgConnection,
+        creation_dto: CategoryCreateDto,
+    ) -> Result<CategoryAggregation, ErrorWrapper> {
+        match CategoryRepository::insert(connection, creation_dto).await {
+            Ok(category) => Ok(CategoryAggregation::from_model(category)),
+            Err(e) => Err(ErrorWrapper::from_duplicated_key(
+                e,
+                FmtError::FailedToInsert("category").error_wrapper(),
+            )),
+        }
+    }
+
+    pub async fn patch(
+        connection: &PgConnection,
+        patch_dto: CategoryPatchDto,
+    ) -> Result<CategoryAggregation, ErrorWrapper> {
+        let id = patch_dto.id;
+
+        let updated_count = match CategoryRepository::patch(connection, patch_dto).await {
+            Ok(updated_count) => updated_count,
+            Err(e) => {
+                return Err(ErrorWrapper::from_duplicated_key(
+                    e,
+                    FmtError::FailedToUpdate("category").error_wrapper(),
+                ))
+            }
+        };
+
+        if updated_count == 0 {
+            return FmtError::NotFound("category").error();
+        }
+
+        Self::get_aggregation(connection, id).await
+    }
+
+    pub async fn delete(connection: &PgConnection, id: i32) -> Result<(), ErrorWrapper> {
+        let deleted_count = CategoryRepository::delete(connection, id).await;
+
+        if deleted_count == 0 {
+            return FmtError::NotFound("category").error();
+        }
+
+        Ok(())
+    }
+
+    pub async fn attach(
+        connection: &PgConnection,
+        creation_dto: ArticleCategoryCreateDto,
+        user_aggregation: &UserAccountAggregation,
+    ) -> Result<(), ErrorWrapper> {
+        match Self::check_article_permission(connection, creation_dto.article_id, user_aggregation)
+            .await
+        {
+            Err(e) => return Err(e),
+            Ok(_) => (),
+        };
+
+        if CategoryRepository::get_one(connection, creation_dto.category_id)
+            .await
+            .is_none()
+        {
+            return FmtError::NotFound("category").error();
+        }
+
+        match CategoryRepository::insert_article_category(connection, creation_dto).await {
+            Ok(_) => Ok(()),
+            Err(e) => Err(ErrorWrapper::from_duplicated_key(
+                e,
+                FmtError::FailedToInsert("article_category").error_wrapper(),
+            )),
+        }
+    }
+
+    pub async fn detach(
+        connection: &PgConnection,
+        article_id: i32,
+        category_id: i32,
+        user_aggregation: &UserAccountAggregation,
+    ) -> Result<(), ErrorWrapper> {
+        match Self::check_article_permission(connection, article_id, user_aggregation).await {
+            Err(e) => return Err(e),
+            Ok(_) => (),
+        };
+
+        let deleted_count =
+            CategoryRepository::delete_article_category(connection, article_id, category_id).await;
+
+        if deleted_count == 0 {
+            return FmtError::NotFound("article_category").error();
+        }
+
+        Ok(())
+    }
+
+    async fn check_article_permission(
+        connection: &PgConnection,
+        article_id: i32,
+        user_aggregation: &UserAccountAggregation,
+    ) -> Result<(), ErrorWrapper> {
+        let article = match ArticleRepository::get_one(
+            connection,
+            article_id,
+            &QueryOptions { is_actual: false },
+        )
+        .await
+        {
+            Some(article) => article,
+            None => return FmtError::NotFound("article").error(),
+        };
+
+        match PermissionsHandler::can_patch_article(&article, user_aggregation) {
+            false => FmtError::PermissionDenied("not enough rights").error(),
+            true => Ok(()),
+        }
+    }
+}
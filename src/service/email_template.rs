@@ -0,0 +1,314 @@
+use std::collections::HashMap;
+use std::env;
+use std::sync::Arc;
+
+use super::emailer::{EmailMessage, EmailParams, EmailTransport, Emailer};
+use super::error::{ErrorWrapper, FmtError};
+
+use super::dtm::email_template::dto::EmailTemplatePatchDto;
+
+use super::aggregation::email_template::{EmailPreviewAggregation, EmailTemplateAggregation};
+use super::aggregation::user_account::UserAccountAggregation;
+
+use super::repository::{
+    entity::{
+        auth::UserAccount,
+        email_template::{EmailTemplate, EmailTemplateRepository, EmailTemplateType},
+        language::{Language, LanguageRepository},
+    },
+    PgConnection,
+};
+
+const EMAIL_DEFAULT_LANGUAGE_ENV: &str = "EMAIL_DEFAULT_LANGUAGE";
+const DEFAULT_EMAIL_LANGUAGE: &str = "en";
+
+const SUBJECT_MAX_LENGTH: usize = 255;
+const PREVIEW_OTP: &str = "preview";
+const PREVIEW_DETAILS: &str = "Article [en]: new version 2";
+
+pub struct EmailTemplateService;
+
+impl EmailTemplateService {
+    pub async fn get_aggregations(connection: &PgConnection) -> Vec<EmailTemplateAggregation> {
+        let language_codes = LanguageRepository::get_many(connection)
+            .await
+            .into_iter()
+            .map(|language| (language.id, language.code))
+            .collect::<HashMap<i32, String>>();
+
+        EmailTemplateRepository::get_many(connection)
+            .await
+            .into_iter()
+            .map(|email_template| {
+                let language = language_codes
+                    .get(&email_template.language_id)
+                    .cloned()
+                    .unwrap_or_default();
+
+                EmailTemplateAggregation::from_model(email_template, language)
+            })
+            .collect()
+    }
+
+    pub async fn patch(
+        connection: &PgConnection,
+        patch_dto: EmailTemplatePatchDto,
+    ) -> Result<EmailTemplateAggregation, ErrorWrapper> {
+        if let Some(subject) = &patch_dto.subject {
+            if subject.trim().is_empty() {
+                return FmtError::EmptyValue("subject").error();
+            }
+
+            if subject.chars().count() > SUBJECT_MAX_LENGTH {
+                return FmtError::FailedToProcess("subject is too long").error();
+            }
+        }
+
+        let (email_template, language) = Self::get_template(
+            connection,
+            &patch_dto.template_type,
+            patch_dto.language.clone(),
+        )
+        .await?;
+
+        EmailTemplateRepository::patch(connection, email_template.id, patch_dto).await;
+
+        match EmailTemplateRepository::get_one(
+            connection,
+            email_template.template_type,
+            language.id,
+        )
+        .await
+        {
+            Some(email_template) => Ok(EmailTemplateAggregation::from_model(
+                email_template,
+                language.code,
+            )),
+            None => FmtError::NotFound("email_template").error(),
+        }
+    }
+
+    // renders the stored template with the requesting user's data and a placeholder key
+    pub async fn get_preview(
+        connection: &PgConnection,
+        template_type: String,
+        language: String,
+        user_aggregation: &UserAccountAggregation,
+    ) -> Result<EmailPreviewAggregation, ErrorWrapper> {
+        let (email_template, language) =
+            Self::get_template(connection, &template_type, language).await?;
+
+        let otp = String::from(PREVIEW_OTP);
+        let url = match email_template.template_type {
+            EmailTemplateType::Confirmation => {
+                Emailer::get_confirmation_url(&otp, &user_aggregation.email, &None)
+            }
+            EmailTemplateType::Reset => {
+                Emailer::get_reset_url(&otp, &user_aggregation.email, &None)
+            }
+            EmailTemplateType::Watch | EmailTemplateType::Digest => String::new(),
+        };
+
+        let message = Self::render(
+            &email_template,
+            &EmailParams {
+                url,
+                name: user_aggregation.name.clone(),
+                email: user_aggregation.email.clone(),
+                details: String::from(PREVIEW_DETAILS),
+                unsubscribe_url: Emailer::get_unsubscribe_url(&otp),
+            },
+        );
+
+        Ok(EmailPreviewAggregation {
+            template_type: email_template.template_type,
+            language: language.code,
+
+            to: message.to,
+            subject: message.subject,
+            html: message.html,
+            text: message.text,
+        })
+    }
+
+    pub async fn send_confirmation_email(
+        connection: &PgConnection,
+        email_transport: &Arc<dyn EmailTransport>,
+        user_account: &UserAccount,
+        otp: &String,
+        redirect_to: &Option<String>,
+    ) -> Result<(), ErrorWrapper> {
+        let url = Emailer::get_confirmation_url(otp, &user_account.email, redirect_to);
+
+        Self::send(
+            connection,
+            email_transport,
+            EmailTemplateType::Confirmation,
+            user_account,
+            url,
+            String::new(),
+            String::new(),
+        )
+        .await
+    }
+
+    pub async fn send_reset_email(
+        connection: &PgConnection,
+        email_transport: &Arc<dyn EmailTransport>,
+        user_account: &UserAccount,
+        otp: &String,
+        redirect_to: &Option<String>,
+    ) -> Result<(), ErrorWrapper> {
+        let url = Emailer::get_reset_url(otp, &user_account.email, redirect_to);
+
+        Self::send(
+            connection,
+            email_transport,
+            EmailTemplateType::Reset,
+            user_account,
+            url,
+            String::new(),
+            String::new(),
+        )
+        .await
+    }
+
+    // rendered within the request, the caller decides when to deliver it
+    pub async fn get_watch_email(
+        connection: &PgConnection,
+        user_account: &UserAccount,
+        details: String,
+        unsubscribe_key: &String,
+    ) -> Result<EmailMessage, ErrorWrapper> {
+        Self::get_message(
+            connection,
+            EmailTemplateType::Watch,
+            user_account,
+            String::new(),
+            details,
+            Emailer::get_unsubscribe_url(unsubscribe_key),
+        )
+        .await
+    }
+
+    pub async fn send_digest_email(
+        connection: &PgConnection,
+        email_transport: &Arc<dyn EmailTransport>,
+        user_account: &UserAccount,
+        details: String,
+    ) -> Result<(), ErrorWrapper> {
+        Self::send(
+            connection,
+            email_transport,
+            EmailTemplateType::Digest,
+            user_account,
+            String::new(),
+            details,
+            String::new(),
+        )
+        .await
+    }
+
+    async fn send(
+        connection: &PgConnection,
+        email_transport: &Arc<dyn EmailTransport>,
+        template_type: EmailTemplateType,
+        user_account: &UserAccount,
+        url: String,
+        details: String,
+        unsubscribe_url: String,
+    ) -> Result<(), ErrorWrapper> {
+        let message = Self::get_message(
+            connection,
+            template_type,
+            user_account,
+            url,
+            details,
+            unsubscribe_url,
+        )
+        .await?;
+
+        email_transport.send(&message).await
+    }
+
+    async fn get_message(
+        connection: &PgConnection,
+        template_type: EmailTemplateType,
+        user_account: &UserAccount,
+        url: String,
+        details: String,
+        unsubscribe_url: String,
+    ) -> Result<EmailMessage, ErrorWrapper> {
+        let email_template =
+            Self::get_user_template(connection, template_type, user_account.language_id).await?;
+
+        Ok(Self::render(
+            &email_template,
+            &EmailParams {
+                url,
+                details,
+                unsubscribe_url,
+                name: user_account.name.clone(),
+                email: user_account.email.clone(),
+            },
+        ))
+    }
+
+    // user's preferred language, otherwise EMAIL_DEFAULT_LANGUAGE
+    async fn get_user_template(
+        connection: &PgConnection,
+        template_type: EmailTemplateType,
+        language_id: Option<i32>,
+    ) -> Result<EmailTemplate, ErrorWrapper> {
+        if let Some(language_id) = language_id {
+            if let Some(email_template) =
+                EmailTemplateRepository::get_one(connection, template_type, language_id).await
+            {
+                return Ok(email_template);
+            }
+        }
+
+        let default_language =
+            env::var(EMAIL_DEFAULT_LANGUAGE_ENV).unwrap_or(String::from(DEFAULT_EMAIL_LANGUAGE));
+
+        let language = match LanguageRepository::get_one(connection, default_language).await {
+            Some(language) => language,
+            None => return FmtError::NotFound(EMAIL_DEFAULT_LANGUAGE_ENV).error(),
+        };
+
+        match EmailTemplateRepository::get_one(connection, template_type, language.id).await {
+            Some(email_template) => Ok(email_template),
+            None => FmtError::NotFound("email_template").error(),
+        }
+    }
+
+    async fn get_template(
+        connection: &PgConnection,
+        template_type: &String,
+        language: String,
+    ) -> Result<(EmailTemplate, Language), ErrorWrapper> {
+        let template_type = match EmailTemplateType::from_value(template_type) {
+            Some(template_type) => template_type,
+            None => return FmtError::NotFound("email_template type").error(),
+        };
+
+        let language = match LanguageRepository::get_one(connection, language).await {
+            Some(language) => language,
+            None => return FmtError::NotFound("language").error(),
+        };
+
+        match EmailTemplateRepository::get_one(connection, template_type, language.id).await {
+            Some(email_template) => Ok((email_template, language)),
+            None => FmtError::NotFound("email_template").error(),
+        }
+    }
+
+    fn render(email_template: &EmailTemplate, params: &EmailParams) -> EmailMessage {
+        Emailer::render(
+            &email_template.subject,
+            &email_template.html,
+            &email_template.text,
+            params,
+        )
+    }
+}
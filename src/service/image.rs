@@ -1,70 +1,523 @@
 use base64::{engine::general_purpose, Engine as _};
 use chrono::prelude::*;
-use cloud_storage::Object;
+use diesel::Connection;
 use futures::{stream, StreamExt};
+use rocket::fs::TempFile;
+use rocket::tokio::{self, io::AsyncReadExt};
+use std::collections::{hash_map::Entry, HashMap};
 use std::env;
+use std::future::Future;
+use std::path::PathBuf;
 use std::sync::Arc;
+use uuid::Uuid;
 
+use super::dtm_common::{PageCursor, PaginationOptions, QueryOptions};
 use super::error::{ErrorWrapper, FmtError};
+use super::image_processor::{ImageProcessor, ImageRendition as ImageRenditionContent};
+use super::image_storage::{
+    ImageStorage, ImageStorageHandler, ImageStorageType, LocalImageStorage,
+};
 
-use super::dtm::image::dto::ImageCreateDto;
+use super::dtm::image::dto::{
+    ImageCreateDto, ImageFileDto, ImageMetadataCreateDto, ImageRenditionCreateDto,
+};
 
-use super::aggregation::image::ImageAggregation;
+use super::aggregation::{
+    image::{
+        ImageAggregation, ImageRenditionAggregation, ImageUploadAggregation,
+        ImageUploadErrorAggregation, ImagesUploadAggregation,
+    },
+    page::PageAggregation,
+    user_account::UserAccountAggregation,
+};
+use super::authorization::PermissionsHandler;
 
-const IMAGE_BUCKET_ENV: &str = "IMAGE_BUCKET";
-const GOOGLE_API_URL_ENV: &str = "GOOGLE_API_URL";
+use super::repository::{
+    entity::{
+        article::ArticleRepository,
+        image::{Image, ImageRendition, ImageRepository},
+    },
+    PgConnection,
+};
+
+const IMAGE_ORPHAN_TTL_HOURS_ENV: &str = "IMAGE_ORPHAN_TTL_HOURS";
+const IMAGE_MAX_SIZE_ENV: &str = "IMAGE_MAX_SIZE";
+
+const DEFAULT_IMAGE_ORPHAN_TTL_HOURS: i32 = 24;
+const DEFAULT_IMAGE_MAX_SIZE: u64 = 10 * 1024 * 1024;
 
 pub struct ImageService;
 
 impl ImageService {
+    pub async fn get_user_aggregations_page(
+        connection: &PgConnection,
+        user_id: i32,
+        pagination: PaginationOptions,
+    ) -> PageAggregation<ImageAggregation> {
+        let images =
+            ImageRepository::get_page_by_user(connection, user_id, pagination.clone()).await;
+
+        let (images, next_cursor) = pagination.split_page(images, |image| PageCursor {
+            created_at: image.created_at,
+            id: image.id,
+        });
+
+        PageAggregation::from_items(
+            Self::get_aggregations(connection, images).await,
+            next_cursor,
+        )
+    }
+
+    pub async fn get_article_aggregations(
+        connection: &PgConnection,
+        article_id: i32,
+    ) -> Result<Vec<ImageAggregation>, ErrorWrapper> {
+        if ArticleRepository::get_one(connection, article_id, &QueryOptions { is_actual: true })
+            .await
+            .is_none()
+        {
+            return FmtError::NotFound("article").error();
+        }
+
+        let images = ImageRepository::get_many_by_article(connection, article_id).await;
+
+        Ok(Self::get_aggregations(connection, images).await)
+    }
+
     pub async fn upload_images(
+        connection: &PgConnection,
         creation_dtos: Vec<ImageCreateDto>,
-    ) -> Result<Vec<ImageAggregation>, ErrorWrapper> {
-        let google_api_url =
-            &env::var(GOOGLE_API_URL_ENV).expect(&FmtError::EmptyValue(GOOGLE_API_URL_ENV).fmt());
-        let image_bucket = Arc::new(
-            env::var(IMAGE_BUCKET_ENV).expect(&FmtError::EmptyValue(IMAGE_BUCKET_ENV).fmt()),
+        user_id: i32,
+    ) -> ImagesUploadAggregation {
+        let contents = creation_dtos
+            .into_iter()
+            .map(|image_dto| {
+                (image_dto.id, async move {
+                    match general_purpose::STANDARD.decode(image_dto.base64) {
+                        Ok(content) => Ok(content),
+                        Err(_) => FmtError::FailedToProcess("image base64").error(),
+                    }
+                })
+            })
+            .collect();
+
+        Self::store_images(connection, contents, user_id).await
+    }
+
+    // files are read only when they are processed, not all of them up front
+    pub async fn upload_files(
+        connection: &PgConnection,
+        file_dtos: Vec<ImageFileDto<'_>>,
+        user_id: i32,
+    ) -> ImagesUploadAggregation {
+        let max_size = Self::get_max_size();
+
+        let contents = file_dtos
+            .into_iter()
+            .map(|file_dto| (file_dto.id, Self::read_file(file_dto.file, max_size)))
+            .collect();
+
+        Self::store_images(connection, contents, user_id).await
+    }
+
+    pub async fn delete(
+        connection: &PgConnection,
+        id: i32,
+        user_aggregation: &UserAccountAggregation,
+    ) -> Result<(), ErrorWrapper> {
+        let image = match ImageRepository::get_one(connection, id).await {
+            None => return FmtError::NotFound("image").error(),
+            Some(image) => image,
+        };
+
+        if !PermissionsHandler::can_delete_image(&image, user_aggregation) {
+            return FmtError::PermissionDenied("not enough rights").error();
+        }
+
+        if ImageRepository::is_referenced(connection, id).await {
+            return FmtError::FailedToProcess("image is referenced by article versions").error();
+        }
+
+        let image_storage: Arc<dyn ImageStorage> = ImageStorageHandler::get_storage();
+
+        let storage_keys = ImageRepository::get_renditions_by_images(connection, vec![id])
+            .await
+            .into_iter()
+            .map(|image_rendition| image_rendition.storage_key)
+            .collect();
+
+        if let Err(e) = Self::delete_files(&image_storage, storage_keys).await {
+            return Err(e);
+        }
+
+        ImageRepository::delete(connection, vec![id]).await;
+
+        Ok(())
+    }
+
+    // removes images which no article version refers to and which are older than ttl
+    pub async fn delete_unreferenced(connection: &PgConnection) -> usize {
+        let ttl_hours = env::var(IMAGE_ORPHAN_TTL_HOURS_ENV)
+            .ok()
+            .and_then(|ttl_hours| ttl_hours.parse::<i32>().ok())
+            .unwrap_or(DEFAULT_IMAGE_ORPHAN_TTL_HOURS);
+
+        let images = ImageRepository::get_many_unreferenced(connection, ttl_hours).await;
+
+        let mut renditions_map = Self::get_renditions_map(
+            ImageRepository::get_renditions_by_images(
+                connection,
+                images.iter().map(|image| image.id).collect(),
+            )
+            .await,
         );
 
-        let responses = stream::iter(creation_dtos)
-            .map(move |image_dto| {
-                let image_bucket = Arc::clone(&image_bucket);
+        let image_storage: Arc<dyn ImageStorage> = ImageStorageHandler::get_storage();
+
+        let mut deleted_ids = vec![];
+
+        for image in images {
+            let storage_keys = match renditions_map.remove(&image.id) {
+                Some(image_renditions) => image_renditions
+                    .into_iter()
+                    .map(|image_rendition| image_rendition.storage_key)
+                    .collect(),
+                None => vec![image.storage_key],
+            };
+
+            if Self::delete_files(&image_storage, storage_keys)
+                .await
+                .is_ok()
+            {
+                deleted_ids.push(image.id);
+            }
+        }
+
+        match deleted_ids.len() {
+            0 => 0,
+            _ => ImageRepository::delete(connection, deleted_ids).await,
+        }
+    }
+
+    pub fn get_local_file_path(key: &String) -> Option<PathBuf> {
+        match ImageStorageHandler::get_storage_type() {
+            ImageStorageType::Local => LocalImageStorage::new().get_path(key),
+            _ => None,
+        }
+    }
+
+    async fn store_images<F>(
+        connection: &PgConnection,
+        contents: Vec<(i32, F)>,
+        user_id: i32,
+    ) -> ImagesUploadAggregation
+    where
+        F: Future<Output = Result<Vec<u8>, ErrorWrapper>>,
+    {
+        let image_storage: Arc<dyn ImageStorage> = ImageStorageHandler::get_storage();
+        let max_size = Self::get_max_size();
 
-                tokio::spawn(async move {
-                    let timestamp = Utc::now().timestamp_millis().to_string();
+        let upload_storage = Arc::clone(&image_storage);
 
-                    let filename = format!("{}_{}.{}", timestamp, image_dto.id, image_dto.format);
-                    let binary_str = general_purpose::STANDARD
-                        .decode(image_dto.base64)
-                        .expect(&FmtError::FailedToProcess("image base64").fmt());
+        let responses = stream::iter(contents)
+            .map(move |(id, content)| {
+                let image_storage = Arc::clone(&upload_storage);
 
-                    let object = Object::create(
-                        &image_bucket,
-                        binary_str,
-                        filename.as_str(),
-                        format!("image/{}", image_dto.format).as_str(),
-                    )
+                async move {
+                    let content = match content.await {
+                        Err(e) => return (id, Err(e)),
+                        Ok(content) => content,
+                    };
+
+                    let upload_result = tokio::spawn(Self::upload_content(
+                        image_storage,
+                        content,
+                        max_size,
+                        user_id,
+                    ))
                     .await;
 
-                    (object, image_dto.id)
-                })
+                    match upload_result {
+                        Ok(upload_result) => (id, upload_result),
+                        Err(_) => (id, FmtError::FailedToInsert("image").error()),
+                    }
+                }
             })
-            .buffer_unordered(10);
+            .buffer_unordered(10)
+            .collect::<Vec<_>>()
+            .await;
 
-        let images_objects = responses
-            .fold(vec![], |mut images_objects, response_dto| async move {
-                let (result, id) = response_dto.expect(&FmtError::FailedToInsert("image").fmt());
-                let upload_result = result.expect(&FmtError::FailedToInsert("image").fmt());
+        let mut images_upload_aggregation = ImagesUploadAggregation {
+            images: vec![],
+            errors: vec![],
+        };
 
-                images_objects.push(ImageAggregation {
-                    id,
-                    uri: format!("{}/{}", google_api_url, upload_result.name),
-                });
+        for (id, upload_result) in responses {
+            let upload_result = match upload_result {
+                Err(e) => Err(e),
+                Ok((metadata_dto, rendition_dtos)) => {
+                    let storage_keys = rendition_dtos
+                        .iter()
+                        .map(|rendition_dto| rendition_dto.storage_key.clone())
+                        .collect();
 
-                images_objects
+                    match Self::insert_transaction(connection, metadata_dto, rendition_dtos).await {
+                        Err(_) => {
+                            Self::delete_files(&image_storage, storage_keys).await.ok();
+
+                            FmtError::FailedToInsert("image").error()
+                        }
+                        Ok((image, image_renditions)) => {
+                            let image_aggregation =
+                                Self::get_aggregation(&image_storage, image, image_renditions);
+
+                            Ok(ImageUploadAggregation {
+                                id,
+                                uri: image_aggregation.uri.clone(),
+                                image: image_aggregation,
+                            })
+                        }
+                    }
+                }
+            };
+
+            match upload_result {
+                Ok(image_upload_aggregation) => images_upload_aggregation
+                    .images
+                    .push(image_upload_aggregation),
+                Err(e) => images_upload_aggregation
+                    .errors
+                    .push(ImageUploadErrorAggregation {
+                        id,
+                        error: e.message,
+                    }),
+            }
+        }
+
+        images_upload_aggregation
+            .images
+            .sort_by_key(|image_upload_aggregation| image_upload_aggregation.id);
+        images_upload_aggregation
+            .errors
+            .sort_by_key(|image_upload_error| image_upload_error.id);
+
+        images_upload_aggregation
+    }
+
+    async fn insert_transaction(
+        connection: &PgConnection,
+        metadata_dto: ImageMetadataCreateDto,
+        rendition_dtos: Vec<ImageRenditionCreateDto>,
+    ) -> Result<(Image, Vec<ImageRendition>), diesel::result::Error> {
+        connection
+            .run(move |connection| {
+                connection.transaction::<(Image, Vec<ImageRendition>), diesel::result::Error, _>(
+                    |transaction_connection| {
+                        let image =
+                            ImageRepository::insert_raw(transaction_connection, metadata_dto)?;
+
+                        let image_renditions = ImageRepository::insert_renditions_raw(
+                            transaction_connection,
+                            image.id,
+                            rendition_dtos,
+                        )?;
+
+                        Ok((image, image_renditions))
+                    },
+                )
             })
-            .await;
+            .await
+    }
+
+    async fn upload_content(
+        image_storage: Arc<dyn ImageStorage>,
+        content: Vec<u8>,
+        max_size: u64,
+        user_id: i32,
+    ) -> Result<(ImageMetadataCreateDto, Vec<ImageRenditionCreateDto>), ErrorWrapper> {
+        if content.len() as u64 > max_size {
+            return FmtError::FailedToProcess("image size").error();
+        }
+
+        if ImageProcessor::get_image_type(&content).is_none() {
+            return FmtError::FailedToProcess("image type").error();
+        }
+
+        let renditions = match tokio::task::spawn_blocking(move || {
+            ImageProcessor::get_renditions(content)
+        })
+        .await
+        {
+            Err(_) => return FmtError::FailedToProcess("image content").error(),
+            Ok(Err(e)) => return Err(e),
+            Ok(Ok(renditions)) => renditions,
+        };
+
+        let timestamp = Utc::now().timestamp_millis().to_string();
+
+        // keys are tracked in article content by substring, so they must be unique
+        let key_prefix = format!("{}_{}", timestamp, Uuid::new_v4());
+
+        let mut rendition_dtos: Vec<ImageRenditionCreateDto> = vec![];
+
+        for (index, rendition) in renditions.into_iter().enumerate() {
+            let storage_key = match index {
+                0 => format!("{}.{}", key_prefix, rendition.extension),
+                _ => format!("{}_{}.{}", key_prefix, rendition.name, rendition.extension),
+            };
+
+            let rendition_dto = Self::get_rendition_dto(&rendition, storage_key);
+
+            if let Err(e) = image_storage
+                .upload(
+                    &rendition_dto.storage_key,
+                    rendition.content,
+                    &rendition.mime_type,
+                )
+                .await
+            {
+                let uploaded_keys = rendition_dtos
+                    .into_iter()
+                    .map(|rendition_dto| rendition_dto.storage_key)
+                    .collect();
+
+                Self::delete_files(&image_storage, uploaded_keys).await.ok();
+
+                return Err(e);
+            }
+
+            rendition_dtos.push(rendition_dto);
+        }
+
+        // first rendition is the original one
+        let original_dto = &rendition_dtos[0];
+
+        let metadata_dto = ImageMetadataCreateDto {
+            storage_key: original_dto.storage_key.clone(),
+            mime_type: original_dto.mime_type.clone(),
+            size: original_dto.size,
+            width: original_dto.width,
+            height: original_dto.height,
+            user_id,
+        };
+
+        Ok((metadata_dto, rendition_dtos))
+    }
+
+    fn get_rendition_dto(
+        rendition: &ImageRenditionContent,
+        storage_key: String,
+    ) -> ImageRenditionCreateDto {
+        ImageRenditionCreateDto {
+            name: rendition.name.clone(),
+            storage_key,
+            mime_type: rendition.mime_type.clone(),
+            size: rendition.content.len() as i32,
+            width: Some(rendition.width),
+            height: Some(rendition.height),
+        }
+    }
+
+    async fn delete_files(
+        image_storage: &Arc<dyn ImageStorage>,
+        storage_keys: Vec<String>,
+    ) -> Result<(), ErrorWrapper> {
+        for storage_key in storage_keys {
+            if let Err(e) = image_storage.delete(&storage_key).await {
+                return Err(e);
+            }
+        }
+
+        Ok(())
+    }
+
+    // the read is bounded, a file growing past its reported length is still rejected
+    async fn read_file(file: TempFile<'_>, max_size: u64) -> Result<Vec<u8>, ErrorWrapper> {
+        if file.len() > max_size {
+            return FmtError::FailedToProcess("image size").error();
+        }
+
+        let reader = match file.open().await {
+            Err(_) => return FmtError::FailedToProcess("image file").error(),
+            Ok(reader) => reader,
+        };
+        tokio::pin!(reader);
+
+        let mut content = Vec::with_capacity(file.len() as usize);
+
+        match reader.take(max_size + 1).read_to_end(&mut content).await {
+            Err(_) => FmtError::FailedToProcess("image file").error(),
+            Ok(size) if size as u64 > max_size => FmtError::FailedToProcess("image size").error(),
+            Ok(_) => Ok(content),
+        }
+    }
+
+    async fn get_aggregations(
+        connection: &PgConnection,
+        images: Vec<Image>,
+    ) -> Vec<ImageAggregation> {
+        let mut renditions_map = Self::get_renditions_map(
+            ImageRepository::get_renditions_by_images(
+                connection,
+                images.iter().map(|image| image.id).collect(),
+            )
+            .await,
+        );
+
+        let image_storage: Arc<dyn ImageStorage> = ImageStorageHandler::get_storage();
+
+        images
+            .into_iter()
+            .map(|image| {
+                let image_renditions = renditions_map.remove(&image.id).unwrap_or(vec![]);
+
+                Self::get_aggregation(&image_storage, image, image_renditions)
+            })
+            .collect()
+    }
+
+    fn get_aggregation(
+        image_storage: &Arc<dyn ImageStorage>,
+        image: Image,
+        image_renditions: Vec<ImageRendition>,
+    ) -> ImageAggregation {
+        let uri = image_storage.get_uri(&image.storage_key);
+
+        let rendition_aggregations = image_renditions
+            .into_iter()
+            .map(|image_rendition| {
+                let rendition_uri = image_storage.get_uri(&image_rendition.storage_key);
+
+                ImageRenditionAggregation::from_model(image_rendition, rendition_uri)
+            })
+            .collect();
+
+        ImageAggregation::from_model(image, uri, rendition_aggregations)
+    }
+
+    fn get_renditions_map(
+        image_renditions: Vec<ImageRendition>,
+    ) -> HashMap<i32, Vec<ImageRendition>> {
+        image_renditions
+            .into_iter()
+            .fold(HashMap::new(), |mut acc, image_rendition| {
+                match acc.entry(image_rendition.image_id) {
+                    Entry::Vacant(acc) => {
+                        acc.insert(vec![image_rendition]);
+                    }
+                    Entry::Occupied(mut acc) => {
+                        acc.get_mut().push(image_rendition);
+                    }
+                };
+
+                acc
+            })
+    }
 
-        Ok(images_objects)
+    fn get_max_size() -> u64 {
+        env::var(IMAGE_MAX_SIZE_ENV)
+            .ok()
+            .and_then(|max_size| max_size.parse::<u64>().ok())
+            .unwrap_or(DEFAULT_IMAGE_MAX_SIZE)
     }
 }
@@ -1,17 +1,21 @@
+use std::collections::HashMap;
+
+use super::error::{ErrorWrapper, FmtError};
+
+use super::dtm::language::dto::{LanguageCreateDto, LanguagePatchDto};
+
 use super::aggregation::language::LanguageAggregation;
 
-use super::repository::{
-    entity::language::{Language, LanguageRepository},
-    PgConnection,
-};
+use super::repository::{entity::language::LanguageRepository, PgConnection};
+
+const CODE_MIN_LENGTH: usize = 2;
+const CODE_MAX_LENGTH: usize = 10;
+const NAME_MAX_LENGTH: usize = 100;
+const BCP47_MAX_LENGTH: usize = 35;
 
 pub struct LanguageService;
 
 impl LanguageService {
-    pub async fn get_one(connection: &PgConnection, code: String) -> Option<Language> {
-        LanguageRepository::get_one(connection, code).await
-    }
-
     pub async fn get_aggregation(
         connection: &PgConnection,
         code: String,
@@ -24,9 +28,214 @@ impl LanguageService {
         Some(LanguageAggregation::from_model(language))
     }
 
+    // new content can be created in enabled languages only
+    pub async fn get_enabled_aggregation(
+        connection: &PgConnection,
+        code: String,
+    ) -> Result<LanguageAggregation, ErrorWrapper> {
+        match Self::get_aggregation(connection, code).await {
+            None => FmtError::NotFound("language").error(),
+            Some(language) if !language.enabled => {
+                FmtError::FailedToProcess("language is disabled").error()
+            }
+            Some(language) => Ok(language),
+        }
+    }
+
     pub async fn get_aggregations(connection: &PgConnection) -> Vec<LanguageAggregation> {
         let languages = LanguageRepository::get_many(connection).await;
 
         LanguageAggregation::from_model_list(languages)
     }
+
+    pub async fn insert(
+        connection: &PgConnection,
+        creation_dto: LanguageCreateDto,
+    ) -> Result<LanguageAggregation, ErrorWrapper> {
+        Self::validate_code(&creation_dto.code)?;
+        Self::validate_name("name", &creation_dto.name)?;
+        Self::validate_name("native_name", &creation_dto.native_name)?;
+        Self::validate_bcp47(&creation_dto.bcp47)?;
+
+        let fallback_language_id = match &creation_dto.fallback {
+            Some(fallback) => Some(
+                Self::get_enabled_aggregation(connection, fallback.clone())
+                    .await?
+                    .id,
+            ),
+            None => None,
+        };
+
+        match LanguageRepository::insert(connection, creation_dto, fallback_language_id).await {
+            Ok(language) => Ok(LanguageAggregation::from_model(language)),
+            Err(e) => Err(ErrorWrapper::from_duplicated_key(
+                e,
+                FmtError::FailedToInsert("language").error_wrapper(),
+            )),
+        }
+    }
+
+    pub async fn patch(
+        connection: &PgConnection,
+        patch_dto: LanguagePatchDto,
+    ) -> Result<LanguageAggregation, ErrorWrapper> {
+        if let Some(name) = &patch_dto.name {
+            Self::validate_name("name", name)?;
+        }
+
+        if let Some(native_name) = &patch_dto.native_name {
+            Self::validate_name("native_name", native_name)?;
+        }
+
+        if let Some(bcp47) = &patch_dto.bcp47 {
+            Self::validate_bcp47(bcp47)?;
+        }
+
+        let languages = Self::get_aggregations(connection).await;
+
+        let language = match languages
+            .iter()
+            .find(|language| language.code == patch_dto.code)
+        {
+            Some(language) => language,
+            None => return FmtError::NotFound("language").error(),
+        };
+
+        let fallback_language_id = match patch_dto.fallback.as_deref() {
+            None => None,
+            Some("") => Some(None),
+            Some(fallback) => {
+                let fallback_language = match languages
+                    .iter()
+                    .find(|fallback_language| fallback_language.code == fallback)
+                {
+                    Some(fallback_language) if fallback_language.enabled => fallback_language,
+                    Some(_) => return FmtError::FailedToProcess("fallback is disabled").error(),
+                    None => return FmtError::NotFound("fallback language").error(),
+                };
+
+                if Self::is_fallback_cycle(&languages, language.id, fallback_language.id) {
+                    return FmtError::FailedToProcess("fallback chain is cyclic").error();
+                }
+
+                Some(Some(fallback_language.id))
+            }
+        };
+
+        // disabling would break the chain of languages which fall back to this one
+        if patch_dto.enabled == Some(false)
+            && languages.iter().any(|dependent_language| {
+                dependent_language.enabled
+                    && dependent_language.fallback_language_id == Some(language.id)
+            })
+        {
+            return FmtError::FailedToProcess("language is a fallback of enabled languages")
+                .error();
+        }
+
+        let (id, code) = (language.id, language.code.clone());
+
+        LanguageRepository::patch(connection, id, patch_dto, fallback_language_id).await;
+
+        match Self::get_aggregation(connection, code).await {
+            Some(language) => Ok(language),
+            None => FmtError::NotFound("language").error(),
+        }
+    }
+
+    // configured fallbacks of a language in order, stopping once a language repeats
+    pub fn get_fallback_chain(languages: &[LanguageAggregation], language_id: i32) -> Vec<i32> {
+        let fallbacks = languages
+            .iter()
+            .map(|language| (language.id, language.fallback_language_id))
+            .collect::<HashMap<i32, Option<i32>>>();
+
+        let mut chain = vec![];
+        let mut next_id = fallbacks.get(&language_id).cloned().flatten();
+
+        while let Some(id) = next_id {
+            if id == language_id || chain.contains(&id) {
+                break;
+            }
+
+            chain.push(id);
+            next_id = fallbacks.get(&id).cloned().flatten();
+        }
+
+        chain
+    }
+
+    // exact code or BCP-47 tag match first, then the primary subtag (e.g. `en-GB` -> `en`)
+    pub fn find_by_tag<'l>(
+        languages: &'l [LanguageAggregation],
+        tag: &str,
+    ) -> Option<&'l LanguageAggregation> {
+        let primary_subtag = tag.split('-').next().unwrap_or(tag);
+
+        languages
+            .iter()
+            .find(|language| language.code == tag || language.bcp47.to_lowercase() == tag)
+            .or_else(|| {
+                languages.iter().find(|language| {
+                    language.code == primary_subtag
+                        || language.bcp47.to_lowercase().split('-').next() == Some(primary_subtag)
+                })
+            })
+    }
+
+    fn is_fallback_cycle(
+        languages: &[LanguageAggregation],
+        language_id: i32,
+        fallback_language_id: i32,
+    ) -> bool {
+        fallback_language_id == language_id
+            || Self::get_fallback_chain(languages, fallback_language_id).contains(&language_id)
+    }
+
+    fn validate_code(code: &String) -> Result<(), ErrorWrapper> {
+        let is_valid = (CODE_MIN_LENGTH..=CODE_MAX_LENGTH).contains(&code.len())
+            && code
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+
+        match is_valid {
+            true => Ok(()),
+            false => FmtError::FailedToProcess("code").error(),
+        }
+    }
+
+    fn validate_name(field: &str, name: &String) -> Result<(), ErrorWrapper> {
+        if name.trim().is_empty() {
+            return FmtError::EmptyValue(field).error();
+        }
+
+        if name.chars().count() > NAME_MAX_LENGTH {
+            return FmtError::FailedToProcess(field).error();
+        }
+
+        Ok(())
+    }
+
+    // structural check of a BCP-47 tag: primary language subtag followed by alphanumeric subtags
+    fn validate_bcp47(bcp47: &String) -> Result<(), ErrorWrapper> {
+        let mut subtags = bcp47.split('-');
+
+        let is_primary_valid = subtags
+            .next()
+            .map(|subtag| {
+                (2..=8).contains(&subtag.len()) && subtag.chars().all(|c| c.is_ascii_alphabetic())
+            })
+            .unwrap_or(false);
+
+        let is_valid = bcp47.len() <= BCP47_MAX_LENGTH
+            && is_primary_valid
+            && subtags.all(|subtag| {
+                (1..=8).contains(&subtag.len()) && subtag.chars().all(|c| c.is_ascii_alphanumeric())
+            });
+
+        match is_valid {
+            true => Ok(()),
+            false => FmtError::FailedToProcess("bcp47").error(),
+        }
+    }
 }
@@ -6,13 +6,24 @@ use super::dtm_common;
 use super::emailer;
 use super::error;
 use super::hasher;
+use super::image_processor;
+use super::image_storage;
 use super::jwt_handler;
+use super::oidc_handler;
 use super::repository;
+use super::totp_handler;
 
+pub mod api_token;
 pub mod article;
+pub mod article_draft;
 pub mod article_language;
 pub mod article_version;
 pub mod auth;
+pub mod category;
+pub mod email_template;
 pub mod image;
 pub mod language;
+pub mod oidc;
+pub mod totp;
 pub mod version_content;
+pub mod watch;
@@ -0,0 +1,183 @@
+use diesel::Connection;
+use rand::Rng;
+
+use super::dtm_common::UserRoleId;
+use super::error::{ErrorWrapper, FmtError};
+use super::jwt_handler::JwtHandler;
+use super::oidc_handler::{OidcHandler, OidcUserInfo};
+
+use super::dtm::auth::dto::{
+    UserAccountCreateDto, UserIdentityCreateDto, UserOidcCallbackDto, UserPatchDto,
+};
+
+use super::aggregation::user_account_auth::OidcAuthorizationAggregation;
+
+use super::repository::{
+    entity::auth::{AuthRepository, UserAccount},
+    PgConnection,
+};
+
+const NAME_MAX_LENGTH: usize = 30;
+const NAME_SUFFIX_LENGTH: usize = 8;
+
+pub struct OidcService;
+
+impl OidcService {
+    pub async fn get_authorization(
+        provider_name: String,
+    ) -> Result<OidcAuthorizationAggregation, ErrorWrapper> {
+        let provider = OidcHandler::get_provider(&provider_name)?;
+        let state = JwtHandler::encode_oidc_state_jwt(provider.name.clone())?;
+
+        Ok(OidcAuthorizationAggregation {
+            authorization_url: OidcHandler::get_authorization_url(&provider, state).await?,
+        })
+    }
+
+    // returns the linked account, otherwise links one by verified email or provisions a new one
+    pub async fn get_user(
+        connection: &PgConnection,
+        callback_dto: UserOidcCallbackDto,
+    ) -> Result<UserAccount, ErrorWrapper> {
+        let provider = OidcHandler::get_provider(&callback_dto.provider)?;
+
+        match JwtHandler::decode_oidc_state_jwt(callback_dto.state) {
+            Ok(claims) if claims.provider == provider.name => (),
+            _ => return FmtError::Unauthorized("invalid state").error(),
+        };
+
+        let user_info = OidcHandler::get_user_info(&provider, callback_dto.code).await?;
+
+        if let Some((user_identity, user_account)) = AuthRepository::get_one_user_by_identity(
+            connection,
+            provider.name.clone(),
+            user_info.sub.clone(),
+        )
+        .await
+        {
+            if user_identity.email != user_info.email {
+                AuthRepository::update_user_identity_email(
+                    connection,
+                    user_identity.id,
+                    user_info.email,
+                )
+                .await;
+            }
+
+            return Ok(user_account);
+        }
+
+        let email = match (&user_info.email, user_info.email_verified) {
+            (Some(email), Some(true)) => email.clone(),
+            _ => return FmtError::PermissionDenied("email is not verified").error(),
+        };
+
+        let name = Self::get_available_name(connection, &user_info, &email).await;
+
+        connection
+            .run(move |connection| {
+                return connection.transaction::<UserAccount, ErrorWrapper, _>(
+                    |transaction_connection| {
+                        Self::link_identity(
+                            transaction_connection,
+                            provider.name,
+                            user_info.sub,
+                            email,
+                            name,
+                        )
+                    },
+                );
+            })
+            .await
+    }
+
+    fn link_identity(
+        connection: &mut diesel::PgConnection,
+        provider: String,
+        subject: String,
+        email: String,
+        name: String,
+    ) -> Result<UserAccount, ErrorWrapper> {
+        let user_account =
+            match AuthRepository::get_one_user_by_email_raw(connection, email.clone())? {
+                Some(user_account) => user_account,
+                None => {
+                    let user_account = AuthRepository::insert_user_account_raw(
+                        connection,
+                        UserAccountCreateDto {
+                            name,
+                            email: email.clone(),
+                            role_id: UserRoleId::Common as i32,
+                            language_id: None,
+                        },
+                    )
+                    .map_err(|e| {
+                        ErrorWrapper::from_duplicated_key(
+                            e,
+                            FmtError::FailedToInsert("user_account").error_wrapper(),
+                        )
+                    })?;
+
+                    // the provider has already verified the email, no otp confirmation needed
+                    AuthRepository::patch_raw(
+                        connection,
+                        UserPatchDto {
+                            user_id: user_account.id,
+                            active: Some(true),
+
+                            updated_by: None,
+                            blocked: None,
+                        },
+                    )?
+                }
+            };
+
+        AuthRepository::insert_user_identity_raw(
+            connection,
+            UserIdentityCreateDto {
+                provider,
+                subject,
+                user_id: user_account.id,
+                email: Some(email),
+            },
+        )
+        .map_err(|e| {
+            ErrorWrapper::from_duplicated_key(
+                e,
+                FmtError::FailedToInsert("user_identity").error_wrapper(),
+            )
+        })?;
+
+        Ok(user_account)
+    }
+
+    async fn get_available_name(
+        connection: &PgConnection,
+        user_info: &OidcUserInfo,
+        email: &String,
+    ) -> String {
+        let base_name = user_info
+            .preferred_username
+            .clone()
+            .or(user_info.name.clone())
+            .unwrap_or(email.split('@').next().unwrap_or_default().to_owned());
+
+        let name = base_name
+            .trim()
+            .chars()
+            .take(NAME_MAX_LENGTH)
+            .collect::<String>();
+
+        if !name.is_empty() && !AuthRepository::exists_user_name(connection, name.clone()).await {
+            return name;
+        }
+
+        let suffix = format!("{:016x}", rand::thread_rng().gen::<u64>());
+        let prefix = name
+            .chars()
+            .take(NAME_MAX_LENGTH - NAME_SUFFIX_LENGTH - 1)
+            .collect::<String>();
+
+        format!("{}_{}", prefix, &suffix[..NAME_SUFFIX_LENGTH])
+    }
+}
@@ -0,0 +1,194 @@
+use diesel::Connection;
+use rand::Rng;
+use std::env;
+
+use super::dtm_common::{ChallengePurpose, UserRoleId};
+use super::error::{ErrorWrapper, FmtError};
+use super::hasher::Hasher;
+use super::jwt_handler::JwtHandler;
+use super::totp_handler::TotpHandler;
+
+use super::aggregation::user_account_auth::{LoginChallengeAggregation, TotpEnrollmentAggregation};
+
+use super::repository::{
+    entity::auth::{AuthRepository, UserAccount},
+    PgConnection,
+};
+
+const TOTP_REQUIRED_FOR_ADMIN_ENV: &str = "TOTP_REQUIRED_FOR_ADMIN";
+
+const RECOVERY_CODES_COUNT: usize = 10;
+
+pub struct TotpService;
+
+impl TotpService {
+    pub fn is_required(role_id: i32) -> bool {
+        let is_admin = UserRoleId::from_i32(role_id) == Some(UserRoleId::Admin);
+
+        is_admin
+            && env::var(TOTP_REQUIRED_FOR_ADMIN_ENV)
+                .map(|is_required| is_required == "true")
+                .unwrap_or(false)
+    }
+
+    pub async fn get_login_challenge(
+        connection: &PgConnection,
+        user_account: &UserAccount,
+    ) -> Result<Option<LoginChallengeAggregation>, ErrorWrapper> {
+        let is_enabled = match AuthRepository::get_user_totp(connection, user_account.id).await {
+            Some(user_totp) => user_totp.confirmed,
+            None => false,
+        };
+
+        let purpose = match (is_enabled, Self::is_required(user_account.role_id)) {
+            (true, _) => ChallengePurpose::Totp,
+            (false, true) => ChallengePurpose::Enrollment,
+            (false, false) => return Ok(None),
+        };
+
+        Ok(Some(LoginChallengeAggregation {
+            purpose,
+            challenge_token: JwtHandler::encode_challenge_jwt(user_account.id, purpose)?,
+        }))
+    }
+
+    pub async fn start_enrollment(
+        connection: &PgConnection,
+        user_account: &UserAccount,
+    ) -> Result<TotpEnrollmentAggregation, ErrorWrapper> {
+        if let Some(user_totp) = AuthRepository::get_user_totp(connection, user_account.id).await {
+            if user_totp.confirmed {
+                return FmtError::AlreadyExists("user_totp").error();
+            }
+        }
+
+        let secret = TotpHandler::generate_secret();
+        let user_totp = AuthRepository::upsert_user_totp(connection, user_account.id, secret).await;
+
+        Ok(TotpEnrollmentAggregation {
+            provisioning_uri: TotpHandler::get_provisioning_uri(
+                &user_totp.secret,
+                &user_account.email,
+            ),
+            secret: user_totp.secret,
+        })
+    }
+
+    // returns recovery codes, they are shown only once
+    pub async fn confirm_enrollment(
+        connection: &PgConnection,
+        user_id: i32,
+        code: String,
+    ) -> Result<Vec<String>, ErrorWrapper> {
+        let user_totp = match AuthRepository::get_user_totp(connection, user_id).await {
+            Some(user_totp) if user_totp.confirmed => {
+                return FmtError::AlreadyExists("user_totp").error()
+            }
+            Some(user_totp) => user_totp,
+            None => return FmtError::NotFound("user_totp").error(),
+        };
+
+        let step = match TotpHandler::verify_code(&user_totp.secret, &code, None)? {
+            Some(step) => step,
+            None => return FmtError::Unauthorized("invalid code").error(),
+        };
+
+        let recovery_codes = Self::generate_recovery_codes();
+        let code_hashes = recovery_codes
+            .iter()
+            .map(|recovery_code| Hasher::hash_token(&Self::normalize_recovery_code(recovery_code)))
+            .collect::<Vec<String>>();
+
+        connection
+            .run(move |connection| {
+                return connection.transaction::<(), ErrorWrapper, _>(|transaction_connection| {
+                    let confirmed_count = AuthRepository::confirm_user_totp_raw(
+                        transaction_connection,
+                        user_totp.id,
+                        step,
+                    )?;
+
+                    if confirmed_count == 0 {
+                        return FmtError::AlreadyExists("user_totp").error();
+                    }
+
+                    AuthRepository::delete_recovery_codes_raw(transaction_connection, user_id)?;
+                    AuthRepository::insert_recovery_codes_raw(
+                        transaction_connection,
+                        user_id,
+                        code_hashes,
+                    )?;
+
+                    Ok(())
+                });
+            })
+            .await?;
+
+        Ok(recovery_codes)
+    }
+
+    pub async fn disable(
+        connection: &PgConnection,
+        user_account: &UserAccount,
+        code: String,
+    ) -> Result<(), ErrorWrapper> {
+        if Self::is_required(user_account.role_id) {
+            return FmtError::PermissionDenied("2fa is required").error();
+        }
+
+        if !Self::verify_code(connection, user_account.id, code).await? {
+            return FmtError::Unauthorized("invalid code").error();
+        }
+
+        let user_id = user_account.id;
+
+        connection
+            .run(move |connection| {
+                return connection.transaction::<(), ErrorWrapper, _>(|transaction_connection| {
+                    AuthRepository::delete_recovery_codes_raw(transaction_connection, user_id)?;
+                    AuthRepository::delete_user_totp_raw(transaction_connection, user_id)?;
+
+                    Ok(())
+                });
+            })
+            .await
+    }
+
+    // accepts either current TOTP code or an unused recovery code
+    pub async fn verify_code(
+        connection: &PgConnection,
+        user_id: i32,
+        code: String,
+    ) -> Result<bool, ErrorWrapper> {
+        let user_totp = match AuthRepository::get_user_totp(connection, user_id).await {
+            Some(user_totp) if user_totp.confirmed => user_totp,
+            _ => return FmtError::NotFound("user_totp").error(),
+        };
+
+        if let Some(step) =
+            TotpHandler::verify_code(&user_totp.secret, &code, user_totp.last_used_step)?
+        {
+            return Ok(AuthRepository::use_totp_step(connection, user_totp.id, step).await > 0);
+        }
+
+        let code_hash = Hasher::hash_token(&Self::normalize_recovery_code(&code));
+
+        Ok(AuthRepository::use_recovery_code(connection, user_id, code_hash).await > 0)
+    }
+
+    fn generate_recovery_codes() -> Vec<String> {
+        let mut rng = rand::thread_rng();
+
+        (0..RECOVERY_CODES_COUNT)
+            .map(|_| {
+                let code = format!("{:010x}", rng.gen::<u64>() & 0xff_ffff_ffff);
+
+                format!("{}-{}", &code[..5], &code[5..])
+            })
+            .collect()
+    }
+
+    fn normalize_recovery_code(code: &String) -> String {
+        code.trim().replace("-", "").to_lowercase()
+    }
+}
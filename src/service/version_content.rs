@@ -1,4 +1,5 @@
 use std::collections::{hash_map::Entry, HashMap};
+use std::env;
 
 use super::diff_handler::DiffHandler;
 use super::error::FmtError;
@@ -9,6 +10,48 @@ use super::repository::entity::{
     version_content::{ContentType, VersionContent},
 };
 
+const VERSION_KEYFRAME_INTERVAL_ENV: &str = "VERSION_KEYFRAME_INTERVAL";
+const DEFAULT_VERSION_KEYFRAME_INTERVAL: i32 = 50;
+
+// keyframe versions keep full content (bounds deltas replay on reconstruction);
+// managed by rocket, so the interval is parsed and validated once at launch
+#[derive(Clone, Copy)]
+pub struct VersionKeyframeInterval(i32);
+
+impl VersionKeyframeInterval {
+    pub fn new(interval: i32) -> Self {
+        if interval < 1 {
+            panic!(
+                "{}",
+                &FmtError::FailedToProcess(VERSION_KEYFRAME_INTERVAL_ENV).fmt()
+            );
+        }
+
+        Self(interval)
+    }
+
+    pub fn from_env() -> Self {
+        match env::var(VERSION_KEYFRAME_INTERVAL_ENV) {
+            Ok(interval) => Self::new(
+                interval
+                    .parse::<i32>()
+                    .expect(&FmtError::FailedToProcess(VERSION_KEYFRAME_INTERVAL_ENV).fmt()),
+            ),
+            Err(_) => Self::default(),
+        }
+    }
+
+    pub fn is_keyframe(&self, version: i32) -> bool {
+        version % self.0 == 0
+    }
+}
+
+impl Default for VersionKeyframeInterval {
+    fn default() -> Self {
+        Self(DEFAULT_VERSION_KEYFRAME_INTERVAL)
+    }
+}
+
 pub struct VersionContentService;
 
 impl VersionContentService {
@@ -40,7 +83,25 @@ impl VersionContentService {
         return contents_map;
     }
 
-    fn update_contents_map<'s>(
+    // same as contents map, but also includes the leading full content
+    pub fn get_full_contents_map(
+        article_versions_with_contents: &Vec<(&ArticleVersion, &VersionContent)>,
+    ) -> HashMap<i32, String> {
+        let mut contents_map: HashMap<i32, String> = HashMap::new();
+
+        Self::update_contents_map(&mut contents_map, article_versions_with_contents);
+
+        if let Some((_, version_content)) = article_versions_with_contents.get(0) {
+            contents_map.insert(
+                version_content.id,
+                DiffHandler::get_string_from_bytes(&version_content.content),
+            );
+        }
+
+        contents_map
+    }
+
+    pub fn update_contents_map<'s>(
         contents_map: &'s mut HashMap<i32, String>,
         article_versions_with_contents: &Vec<(&ArticleVersion, &VersionContent)>,
     ) -> &'s HashMap<i32, String> {
@@ -62,11 +123,16 @@ impl VersionContentService {
                     DiffHandler::get_string_from_bytes(&full_content.1.content),
                 ),
                 |(content_map, previous_full_content), version_content| {
-                    let content = DiffHandler::get_patch(
-                        &version_content.content,
-                        version_content.content_length,
-                        previous_full_content,
-                    );
+                    let content = match version_content.content_type {
+                        ContentType::Full => {
+                            DiffHandler::get_string_from_bytes(&version_content.content)
+                        }
+                        ContentType::Diff => DiffHandler::get_patch(
+                            &version_content.content,
+                            version_content.content_length,
+                            previous_full_content,
+                        ),
+                    };
 
                     content_map.insert(version_content.id, String::from(&content));
 
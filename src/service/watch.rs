@@ -0,0 +1,337 @@
+use rocket::tokio;
+use std::env;
+use std::sync::Arc;
+use std::time::Duration;
+use uuid::Uuid;
+
+use super::dtm_common::QueryOptions;
+use super::emailer::{EmailTransport, Emailer};
+use super::error::{ErrorWrapper, FmtError};
+
+use super::dtm::watch::dto::{
+    UserWatchCreateDto, WatchCreateDto, WatchEventDto, WatchNotificationCreateDto, WatchPatchDto,
+};
+
+use super::aggregation::watch::{WatchAggregation, WatchDigestAggregation};
+
+use super::repository::{
+    entity::{
+        article::ArticleRepository,
+        auth::UserAccount,
+        watch::{Watch, WatchDelivery, WatchEventType, WatchNotification, WatchRepository},
+    },
+    PgConnection,
+};
+
+use super::article_language::ArticleLanguageService;
+use super::email_template::EmailTemplateService;
+
+const WATCH_DIGEST_INTERVAL_ENV: &str = "WATCH_DIGEST_INTERVAL_SEC";
+const DEFAULT_WATCH_DIGEST_INTERVAL_SEC: u64 = 24 * 60 * 60;
+
+// how often digests are sent by the scheduled task, 0 disables it (POST /watches/digest only);
+// parsed once at launch
+#[derive(Clone, Copy)]
+pub struct WatchDigestInterval(u64);
+
+impl WatchDigestInterval {
+    pub fn from_env() -> Self {
+        match env::var(WATCH_DIGEST_INTERVAL_ENV) {
+            Ok(interval) => Self(
+                interval
+                    .parse::<u64>()
+                    .expect(&FmtError::FailedToProcess(WATCH_DIGEST_INTERVAL_ENV).fmt()),
+            ),
+            Err(_) => Self::default(),
+        }
+    }
+
+    pub fn get_duration(&self) -> Option<Duration> {
+        match self.0 {
+            0 => None,
+            interval => Some(Duration::from_secs(interval)),
+        }
+    }
+}
+
+impl Default for WatchDigestInterval {
+    fn default() -> Self {
+        Self(DEFAULT_WATCH_DIGEST_INTERVAL_SEC)
+    }
+}
+
+pub struct WatchService;
+
+impl WatchService {
+    pub async fn get_aggregations(
+        connection: &PgConnection,
+        user_id: i32,
+    ) -> Vec<WatchAggregation> {
+        WatchRepository::get_many(connection, user_id)
+            .await
+            .into_iter()
+            .map(|(watch, language)| WatchAggregation::from_model(watch, language))
+            .collect()
+    }
+
+    pub async fn create(
+        connection: &PgConnection,
+        creation_dto: WatchCreateDto,
+        query_options: &QueryOptions,
+    ) -> Result<WatchAggregation, ErrorWrapper> {
+        if ArticleRepository::get_one(connection, creation_dto.article_id, query_options)
+            .await
+            .is_none()
+        {
+            return FmtError::NotFound("article").error();
+        }
+
+        let article_language_id = match &creation_dto.language {
+            Some(language) => {
+                match ArticleLanguageService::get_one_with_language(
+                    connection,
+                    creation_dto.article_id,
+                    language.clone(),
+                    query_options,
+                )
+                .await
+                {
+                    Err(e) => return Err(e),
+                    Ok((article_language, _)) => Some(article_language.id),
+                }
+            }
+            None => None,
+        };
+
+        let watch = WatchRepository::insert(
+            connection,
+            UserWatchCreateDto {
+                article_language_id,
+                user_id: creation_dto.user_id,
+                article_id: creation_dto.article_id,
+                delivery: creation_dto.delivery,
+                unsubscribe_key: Uuid::new_v4().simple().to_string(),
+            },
+        )
+        .await
+        .map_err(|e| {
+            ErrorWrapper::from_duplicated_key(e, FmtError::FailedToInsert("watch").error_wrapper())
+        })?;
+
+        Ok(WatchAggregation::from_model(watch, creation_dto.language))
+    }
+
+    pub async fn patch(
+        connection: &PgConnection,
+        patch_dto: WatchPatchDto,
+    ) -> Result<WatchAggregation, ErrorWrapper> {
+        let (id, user_id) = (patch_dto.id, patch_dto.user_id);
+
+        if WatchRepository::patch(connection, patch_dto).await == 0 {
+            return FmtError::NotFound("watch").error();
+        }
+
+        match Self::get_aggregations(connection, user_id)
+            .await
+            .into_iter()
+            .find(|aggregation| aggregation.id == id)
+        {
+            Some(aggregation) => Ok(aggregation),
+            None => FmtError::NotFound("watch").error(),
+        }
+    }
+
+    pub async fn delete(
+        connection: &PgConnection,
+        id: i32,
+        user_id: i32,
+    ) -> Result<(), ErrorWrapper> {
+        match WatchRepository::delete(connection, id, user_id).await {
+            0 => FmtError::NotFound("watch").error(),
+            _ => Ok(()),
+        }
+    }
+
+    pub async fn unsubscribe(
+        connection: &PgConnection,
+        unsubscribe_key: String,
+    ) -> Result<(), ErrorWrapper> {
+        match WatchRepository::delete_by_key(connection, unsubscribe_key).await {
+            0 => FmtError::NotFound("watch").error(),
+            _ => Ok(()),
+        }
+    }
+
+    // immediate watchers are emailed in the background, digest ones are queued, the author is skipped;
+    // delivery failures are logged only, they must not fail the change itself
+    pub async fn notify(
+        connection: &PgConnection,
+        email_transport: &Arc<dyn EmailTransport>,
+        user_id: i32,
+        events: Vec<WatchEventDto>,
+    ) {
+        let mut immediate_watches: Vec<(Watch, UserAccount, Vec<String>)> = vec![];
+        let mut notification_dtos = vec![];
+
+        for event in events {
+            let watches = WatchRepository::get_many_by_target(
+                connection,
+                event.article_id,
+                event.article_language_id,
+                user_id,
+            )
+            .await;
+
+            for (watch, user_account) in watches {
+                match watch.delivery {
+                    WatchDelivery::Digest => notification_dtos.push(WatchNotificationCreateDto {
+                        watch_id: watch.id,
+                        event: event.clone(),
+                    }),
+                    WatchDelivery::Immediate => {
+                        let line = Self::get_event_line(
+                            event.event_type,
+                            &event.name,
+                            &event.language_code,
+                            event.version,
+                        );
+
+                        match immediate_watches
+                            .iter_mut()
+                            .find(|(immediate_watch, _, _)| immediate_watch.id == watch.id)
+                        {
+                            Some((_, _, lines)) => lines.push(line),
+                            None => immediate_watches.push((watch, user_account, vec![line])),
+                        }
+                    }
+                }
+            }
+        }
+
+        if !notification_dtos.is_empty() {
+            WatchRepository::insert_notifications(connection, notification_dtos).await;
+        }
+
+        let mut messages = vec![];
+
+        for (watch, user_account, lines) in immediate_watches {
+            match EmailTemplateService::get_watch_email(
+                connection,
+                &user_account,
+                lines.join("\n"),
+                &watch.unsubscribe_key,
+            )
+            .await
+            {
+                Ok(message) => messages.push(message),
+                Err(e) => eprintln!("{}", e.message),
+            }
+        }
+
+        if messages.is_empty() {
+            return;
+        }
+
+        // the response doesn't wait for the email provider
+        let email_transport = Arc::clone(email_transport);
+
+        tokio::spawn(async move {
+            for message in messages {
+                if let Err(e) = email_transport.send(&message).await {
+                    eprintln!("{}", e.message);
+                }
+            }
+        });
+    }
+
+    // one email per user with all pending events, unsent ones are kept for the next run
+    pub async fn send_digests(
+        connection: &PgConnection,
+        email_transport: &Arc<dyn EmailTransport>,
+    ) -> WatchDigestAggregation {
+        let mut users_notifications: Vec<(UserAccount, Vec<(WatchNotification, Watch)>)> = vec![];
+
+        for (notification, watch, user_account) in
+            WatchRepository::get_pending_notifications(connection).await
+        {
+            match users_notifications.last_mut() {
+                Some((last_user_account, notifications))
+                    if last_user_account.id == user_account.id =>
+                {
+                    notifications.push((notification, watch))
+                }
+                _ => users_notifications.push((user_account, vec![(notification, watch)])),
+            }
+        }
+
+        let mut sent_count = 0;
+
+        for (user_account, notifications) in users_notifications {
+            let ids = notifications
+                .iter()
+                .map(|(notification, _)| notification.id)
+                .collect::<Vec<i32>>();
+
+            if !user_account.active || user_account.blocked {
+                WatchRepository::mark_notifications_sent(connection, ids).await;
+                continue;
+            }
+
+            let details = notifications
+                .iter()
+                .map(|(notification, watch)| {
+                    format!(
+                        "{} ({})",
+                        Self::get_event_line(
+                            notification.event_type,
+                            &notification.name,
+                            &notification.language_code,
+                            notification.version,
+                        ),
+                        Emailer::get_unsubscribe_url(&watch.unsubscribe_key)
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join("\n");
+
+            match EmailTemplateService::send_digest_email(
+                connection,
+                email_transport,
+                &user_account,
+                details,
+            )
+            .await
+            {
+                Ok(_) => {
+                    WatchRepository::mark_notifications_sent(connection, ids).await;
+                    sent_count += 1;
+                }
+                Err(e) => eprintln!("{}", e.message),
+            }
+        }
+
+        WatchDigestAggregation { sent_count }
+    }
+
+    fn get_event_line(
+        event_type: WatchEventType,
+        name: &String,
+        language_code: &Option<String>,
+        version: Option<i32>,
+    ) -> String {
+        let target = match language_code {
+            Some(language_code) => format!("{name} [{language_code}]"),
+            None => name.clone(),
+        };
+
+        match (event_type, version) {
+            (WatchEventType::NewVersion, Some(version)) => {
+                format!("{target}: new version {version}")
+            }
+            (WatchEventType::NewVersion, None) => format!("{target}: new version"),
+            (WatchEventType::Archived, _) => format!("{target}: archived"),
+            (WatchEventType::Restored, _) => format!("{target}: restored"),
+            (WatchEventType::Renamed, _) => format!("{target}: renamed"),
+        }
+    }
+}
@@ -149,3 +149,45 @@ async fn get_deleted_articles() {
         ArticleAssertOptions { is_updated: true },
     );
 }
+
+#[tokio::test]
+async fn get_articles_paginated() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let admin_token = setup.user_handler.get_token(TestUser::Admin1).unwrap();
+
+    let mut created_articles_ids = vec![];
+
+    for index in 1..=3 {
+        let created_article = ArticleRequestHandler::create_article(
+            &setup,
+            &ArticleCreateRelationsBody {
+                name: format!("{}_article_{}", setup.test_id, index),
+                content: String::from("test content"),
+                language: String::from("ua"),
+                article_type: ArticleType::Public,
+            },
+            admin_token.clone(),
+        )
+        .await;
+
+        created_articles_ids.push(created_article.id);
+    }
+
+    let first_page = ArticleRequestHandler::get_articles_page(&setup, 2, None).await;
+
+    let first_page_ids: Vec<i32> = first_page.items.iter().map(|article| article.id).collect();
+    assert_eq!(
+        first_page_ids,
+        vec![created_articles_ids[2], created_articles_ids[1]]
+    );
+    assert!(first_page.next_cursor.is_some());
+
+    let second_page =
+        ArticleRequestHandler::get_articles_page(&setup, 2, first_page.next_cursor).await;
+
+    assert_eq!(second_page.items[0].id, created_articles_ids[0]);
+    assert!(second_page
+        .items
+        .iter()
+        .all(|article| !first_page_ids.contains(&article.id)));
+}
@@ -0,0 +1,245 @@
+use rocket::http::Status;
+
+use super::error::FmtError;
+
+use super::setup::{SetupOptions, TestSetup, TestUser};
+use super::test_handler::request_handler::{
+    article::ArticleRequestHandler,
+    article_draft::{ArticleDraftRequest, ArticleDraftRequestHandler},
+    article_language::ArticleLanguageRequestHandler,
+    article_version::ArticleVersionRequestHandler,
+};
+
+use super::dtm::{
+    article::request_body::ArticleCreateRelationsBody,
+    article_draft::request_body::ArticleDraftSaveBody,
+    article_version::request_body::ArticleVersionCreateRelationsBody,
+};
+use super::repository::entity::article::ArticleType;
+
+fn get_draft_body(content: &str, rebase: Option<bool>) -> ArticleDraftSaveBody {
+    ArticleDraftSaveBody {
+        name: None,
+        content: String::from(content),
+        rebase,
+    }
+}
+
+#[tokio::test]
+async fn publish_article_draft() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let admin_token = setup.user_handler.get_token(TestUser::Admin1).unwrap();
+    let user_token = setup.user_handler.get_token(TestUser::Common1).unwrap();
+
+    let language = String::from("ua");
+    let article = ArticleRequestHandler::create_article(
+        &setup,
+        &ArticleCreateRelationsBody {
+            name: format!("{}_draft_article", setup.test_id),
+            content: String::from("published content"),
+            language: language.clone(),
+            article_type: ArticleType::Public,
+        },
+        admin_token.clone(),
+    )
+    .await;
+
+    let draft = ArticleDraftRequestHandler::save_article_draft(
+        &setup,
+        article.id,
+        &language,
+        &get_draft_body("first draft content", None),
+        admin_token.clone(),
+    )
+    .await;
+
+    assert_eq!(draft.base_version, 1);
+    assert!(!draft.is_outdated);
+
+    // autosave keeps the base version and replaces the content
+    let draft = ArticleDraftRequestHandler::save_article_draft(
+        &setup,
+        article.id,
+        &language,
+        &get_draft_body("second draft content", None),
+        admin_token.clone(),
+    )
+    .await;
+
+    assert_eq!(draft.base_version, 1);
+    assert_eq!(draft.content, "second draft content");
+    assert!(draft.updated_at.is_some());
+
+    let foreign_response =
+        ArticleDraftRequest::get_article_draft(&setup, article.id, &language, user_token).await;
+
+    assert_eq!(foreign_response.status(), Status::NotFound);
+
+    let article_language =
+        ArticleLanguageRequestHandler::get_article_language(&setup, article.id, &language).await;
+
+    assert_eq!(article_language.version.version, 1);
+    assert_eq!(
+        article_language.version.content.content,
+        "published content"
+    );
+
+    let article_version = ArticleDraftRequestHandler::publish_article_draft(
+        &setup,
+        article.id,
+        &language,
+        admin_token.clone(),
+    )
+    .await;
+
+    assert_eq!(article_version.version, 2);
+    assert_eq!(article_version.content.content, "second draft content");
+
+    let published_draft_response =
+        ArticleDraftRequest::get_article_draft(&setup, article.id, &language, admin_token).await;
+
+    assert_eq!(published_draft_response.status(), Status::NotFound);
+}
+
+#[tokio::test]
+async fn publish_outdated_article_draft() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let admin_token = setup.user_handler.get_token(TestUser::Admin1).unwrap();
+
+    let language = String::from("ua");
+    let article = ArticleRequestHandler::create_article(
+        &setup,
+        &ArticleCreateRelationsBody {
+            name: format!("{}_outdated_draft_article", setup.test_id),
+            content: String::from("published content"),
+            language: language.clone(),
+            article_type: ArticleType::Public,
+        },
+        admin_token.clone(),
+    )
+    .await;
+
+    ArticleDraftRequestHandler::save_article_draft(
+        &setup,
+        article.id,
+        &language,
+        &get_draft_body("draft content", None),
+        admin_token.clone(),
+    )
+    .await;
+
+    ArticleVersionRequestHandler::create_article_version(
+        &setup,
+        &ArticleVersionCreateRelationsBody {
+            content: String::from("concurrent content"),
+            name: None,
+            source: None,
+            base_version: None,
+            merge: None,
+        },
+        article.id,
+        &language,
+        admin_token.clone(),
+    )
+    .await;
+
+    let draft = ArticleDraftRequestHandler::get_article_draft(
+        &setup,
+        article.id,
+        &language,
+        admin_token.clone(),
+    )
+    .await;
+
+    assert!(draft.is_outdated);
+    assert_eq!(draft.actual_version, 2);
+
+    let conflict_response = ArticleDraftRequest::publish_article_draft(
+        &setup,
+        article.id,
+        &language,
+        admin_token.clone(),
+    )
+    .await;
+
+    assert_eq!(conflict_response.status(), Status::Conflict);
+
+    let rebased_draft = ArticleDraftRequestHandler::save_article_draft(
+        &setup,
+        article.id,
+        &language,
+        &get_draft_body("draft content with concurrent content", Some(true)),
+        admin_token.clone(),
+    )
+    .await;
+
+    assert_eq!(rebased_draft.base_version, 2);
+    assert!(!rebased_draft.is_outdated);
+
+    let article_version = ArticleDraftRequestHandler::publish_article_draft(
+        &setup,
+        article.id,
+        &language,
+        admin_token,
+    )
+    .await;
+
+    assert_eq!(article_version.version, 3);
+}
+
+#[tokio::test]
+async fn delete_article_draft() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let admin_token = setup.user_handler.get_token(TestUser::Admin1).unwrap();
+
+    let language = String::from("ua");
+    let article = ArticleRequestHandler::create_article(
+        &setup,
+        &ArticleCreateRelationsBody {
+            name: format!("{}_deleted_draft_article", setup.test_id),
+            content: String::from("published content"),
+            language: language.clone(),
+            article_type: ArticleType::Public,
+        },
+        admin_token.clone(),
+    )
+    .await;
+
+    ArticleDraftRequestHandler::save_article_draft(
+        &setup,
+        article.id,
+        &language,
+        &get_draft_body("draft content", None),
+        admin_token.clone(),
+    )
+    .await;
+
+    let delete_response = ArticleDraftRequest::delete_article_draft(
+        &setup,
+        article.id,
+        &language,
+        admin_token.clone(),
+    )
+    .await;
+
+    assert_eq!(delete_response.status(), Status::Ok);
+
+    let repeated_delete_response = ArticleDraftRequest::delete_article_draft(
+        &setup,
+        article.id,
+        &language,
+        admin_token.clone(),
+    )
+    .await;
+
+    assert_eq!(repeated_delete_response.status(), Status::NotFound);
+
+    let error_message = repeated_delete_response.into_string().await.unwrap();
+    assert_eq!(error_message, FmtError::NotFound("article_draft").fmt());
+
+    let publish_response =
+        ArticleDraftRequest::publish_article_draft(&setup, article.id, &language, admin_token)
+            .await;
+
+    assert_eq!(publish_response.status(), Status::NotFound);
+}
@@ -0,0 +1,7 @@
+use super::dtm;
+use super::error;
+use super::repository;
+use super::setup;
+use super::test_handler;
+
+pub mod article_draft_test;
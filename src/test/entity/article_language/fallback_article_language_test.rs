@@ -0,0 +1,142 @@
+use rocket::http::Status;
+
+use super::error::FmtError;
+
+use super::setup::{SetupOptions, TestSetup, TestUser};
+use super::test_handler::request_handler::{
+    article::ArticleRequestHandler,
+    article_language::{ArticleLanguageRequest, ArticleLanguageRequestHandler},
+    language::LanguageRequestHandler,
+};
+
+use super::dtm::{
+    article::request_body::ArticleCreateRelationsBody,
+    article_language::request_body::ArticleLanguageCreateRelationsBody,
+    language::request_body::LanguageCreateBody,
+};
+use super::repository::entity::article::ArticleType;
+
+#[tokio::test]
+async fn get_article_language_fallback() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let admin_token = setup.user_handler.get_token(TestUser::Admin1).unwrap();
+
+    let original_language = String::from("ua");
+    let translated_language = String::from("en");
+    let article_creation_body = ArticleCreateRelationsBody {
+        name: format!("{}_fallback_article", setup.test_id),
+        content: String::from("original content"),
+        language: original_language.clone(),
+        article_type: ArticleType::Public,
+    };
+
+    let article =
+        ArticleRequestHandler::create_article(&setup, &article_creation_body, admin_token.clone())
+            .await;
+
+    // nothing but the original language exists yet
+    let original_fallback = ArticleLanguageRequestHandler::get_article_language_fallback(
+        &setup,
+        article.id,
+        &translated_language,
+        None,
+    )
+    .await;
+
+    assert!(original_fallback.is_fallback);
+    assert_eq!(original_fallback.requested_language, translated_language);
+    assert_eq!(original_fallback.served_language, original_language);
+    assert_eq!(
+        original_fallback.article_language.version.content.content,
+        article_creation_body.content
+    );
+
+    let strict_response = ArticleLanguageRequest::get_article_language_fallback(
+        &setup,
+        article.id,
+        &translated_language,
+        Some(false),
+        None,
+    )
+    .await;
+
+    assert_eq!(strict_response.status(), Status::NotFound);
+
+    let error_message = strict_response.into_string().await.unwrap();
+    assert_eq!(error_message, FmtError::NotFound("article_language").fmt());
+
+    ArticleLanguageRequestHandler::create_article_language(
+        &setup,
+        &ArticleLanguageCreateRelationsBody {
+            name: format!("{}_fallback_article_en", setup.test_id),
+            content: String::from("translated content"),
+        },
+        article.id,
+        &translated_language,
+        admin_token.clone(),
+    )
+    .await;
+
+    let served_language = ArticleLanguageRequestHandler::get_article_language_fallback(
+        &setup,
+        article.id,
+        &translated_language,
+        None,
+    )
+    .await;
+
+    assert!(!served_language.is_fallback);
+    assert_eq!(served_language.served_language, translated_language);
+
+    let code = format!("g{}", setup.test_id % 100_000_000);
+
+    LanguageRequestHandler::create_language(
+        &setup,
+        &LanguageCreateBody {
+            code: code.clone(),
+            name: format!("Language {code}"),
+            native_name: format!("Native {code}"),
+            direction: None,
+            bcp47: String::from("gd"),
+            fallback: Some(translated_language.clone()),
+        },
+        admin_token,
+    )
+    .await;
+
+    // the configured fallback wins over the original language
+    let configured_fallback = ArticleLanguageRequestHandler::get_article_language_fallback(
+        &setup, article.id, &code, None,
+    )
+    .await;
+
+    assert!(configured_fallback.is_fallback);
+    assert_eq!(configured_fallback.requested_language, code);
+    assert_eq!(configured_fallback.served_language, translated_language);
+
+    // and the header wins over the configured fallback, matched by the BCP-47 tag
+    let header_fallback = ArticleLanguageRequestHandler::get_article_language_fallback(
+        &setup,
+        article.id,
+        &code,
+        Some("de;q=0.9, uk-UA, *;q=0.1"),
+    )
+    .await;
+
+    assert!(header_fallback.is_fallback);
+    assert_eq!(header_fallback.served_language, original_language);
+
+    let unknown_response = ArticleLanguageRequest::get_article_language_fallback(
+        &setup,
+        article.id,
+        &String::from("incorrect"),
+        None,
+        None,
+    )
+    .await;
+
+    assert_eq!(unknown_response.status(), Status::NotFound);
+
+    let error_message = unknown_response.into_string().await.unwrap();
+    assert_eq!(error_message, FmtError::NotFound("language").fmt());
+}
@@ -179,3 +179,53 @@ async fn get_article_languages_disabled() {
         ArticleLanguageAssertOptions { is_updated: true },
     );
 }
+
+#[tokio::test]
+async fn get_article_languages_paginated() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let admin_token = setup.user_handler.get_token(TestUser::Admin1).unwrap();
+
+    let article = ArticleRequestHandler::create_article(
+        &setup,
+        &ArticleCreateRelationsBody {
+            name: format!("{}_article", setup.test_id),
+            content: String::from("test content"),
+            language: String::from("ua"),
+            article_type: ArticleType::Public,
+        },
+        admin_token.clone(),
+    )
+    .await;
+
+    let second_article_language = ArticleLanguageRequestHandler::create_article_language(
+        &setup,
+        &ArticleLanguageCreateRelationsBody {
+            name: format!("{}_article_lang", setup.test_id),
+            content: String::from("test content"),
+        },
+        article.id,
+        &String::from("en"),
+        admin_token,
+    )
+    .await;
+
+    let first_page =
+        ArticleLanguageRequestHandler::get_article_languages_page(&setup, article.id, 1, None)
+            .await;
+
+    assert_eq!(first_page.items.len(), 1);
+    assert_eq!(first_page.items[0].id, second_article_language.id);
+    assert!(first_page.next_cursor.is_some());
+
+    let second_page = ArticleLanguageRequestHandler::get_article_languages_page(
+        &setup,
+        article.id,
+        1,
+        first_page.next_cursor,
+    )
+    .await;
+
+    assert_eq!(second_page.items.len(), 1);
+    assert_eq!(second_page.items[0].id, article.languages[0].id);
+    assert!(second_page.next_cursor.is_none());
+}
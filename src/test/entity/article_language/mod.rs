@@ -6,6 +6,8 @@ use super::test_handler;
 
 pub mod create_article_language_test;
 pub mod delete_restore_article_language_test;
+pub mod fallback_article_language_test;
 pub mod get_article_language_test;
 pub mod get_article_languages_test;
 pub mod patch_article_language_test;
+pub mod search_article_languages_test;
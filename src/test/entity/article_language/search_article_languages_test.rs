@@ -0,0 +1,116 @@
+use super::setup::{SetupOptions, TestSetup, TestUser};
+use super::test_handler::request_handler::{
+    article::ArticleRequestHandler, article_language::ArticleLanguageRequestHandler,
+    article_version::ArticleVersionRequestHandler,
+};
+
+use super::dtm::{
+    article::request_body::ArticleCreateRelationsBody,
+    article_language::request_body::ArticleLanguageCreateRelationsBody,
+    article_version::request_body::ArticleVersionCreateRelationsBody,
+};
+use super::repository::entity::article::ArticleType;
+
+#[tokio::test]
+async fn search_article_languages_by_content() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let admin_token = setup.user_handler.get_token(TestUser::Admin1).unwrap();
+
+    let first_word = format!("first{}", setup.test_id);
+    let second_word = format!("second{}", setup.test_id);
+
+    let article = ArticleRequestHandler::create_article(
+        &setup,
+        &ArticleCreateRelationsBody {
+            name: format!("{}_article", setup.test_id),
+            content: format!("some content with {} inside", first_word),
+            language: String::from("ua"),
+            article_type: ArticleType::Public,
+        },
+        admin_token.clone(),
+    )
+    .await;
+
+    let search_results = ArticleLanguageRequestHandler::search_article_languages_by_content(
+        &setup,
+        &first_word,
+        None,
+    )
+    .await;
+
+    assert_eq!(search_results.len(), 1);
+    assert_eq!(search_results[0].article_id, article.id);
+    assert_eq!(search_results[0].language_code, "ua");
+    assert!(search_results[0].rank > 0.0);
+    assert!(search_results[0]
+        .snippet
+        .contains(&format!("<b>{}</b>", first_word)));
+
+    let search_results_wrong_language =
+        ArticleLanguageRequestHandler::search_article_languages_by_content(
+            &setup,
+            &first_word,
+            Some(String::from("en")),
+        )
+        .await;
+
+    assert!(search_results_wrong_language.is_empty());
+
+    ArticleVersionRequestHandler::create_article_version(
+        &setup,
+        &ArticleVersionCreateRelationsBody {
+            content: format!("updated content with {} inside", second_word),
+            name: None,
+            source: None,
+            base_version: None,
+            merge: None,
+        },
+        article.id,
+        &String::from("ua"),
+        admin_token.clone(),
+    )
+    .await;
+
+    let search_results_outdated =
+        ArticleLanguageRequestHandler::search_article_languages_by_content(
+            &setup,
+            &first_word,
+            None,
+        )
+        .await;
+
+    assert!(search_results_outdated.is_empty());
+
+    let search_results_updated =
+        ArticleLanguageRequestHandler::search_article_languages_by_content(
+            &setup,
+            &second_word,
+            None,
+        )
+        .await;
+
+    assert_eq!(search_results_updated.len(), 1);
+    assert_eq!(search_results_updated[0].article_id, article.id);
+
+    ArticleLanguageRequestHandler::create_article_language(
+        &setup,
+        &ArticleLanguageCreateRelationsBody {
+            name: format!("{}_article_lang", setup.test_id),
+            content: format!("english content about {}", second_word),
+        },
+        article.id,
+        &String::from("en"),
+        admin_token,
+    )
+    .await;
+
+    let search_results_en = ArticleLanguageRequestHandler::search_article_languages_by_content(
+        &setup,
+        &second_word,
+        Some(String::from("en")),
+    )
+    .await;
+
+    assert_eq!(search_results_en.len(), 1);
+    assert_eq!(search_results_en[0].language_code, "en");
+}
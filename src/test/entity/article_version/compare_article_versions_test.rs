@@ -0,0 +1,104 @@
+use rocket::http::Status;
+
+use super::error::FmtError;
+
+use super::setup::{SetupOptions, TestSetup, TestUser};
+use super::test_handler::request_handler::{
+    article::ArticleRequestHandler,
+    article_version::{ArticleVersionRequest, ArticleVersionRequestHandler},
+};
+
+use super::dtm::{
+    article::request_body::ArticleCreateRelationsBody,
+    article_version::request_body::ArticleVersionCreateRelationsBody,
+};
+use super::dtm_common::DiffOperation;
+use super::repository::entity::article::ArticleType;
+
+#[tokio::test]
+async fn compare_article_versions() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let admin_token = setup.user_handler.get_token(TestUser::Admin1).unwrap();
+
+    let language = String::from("ua");
+    let article = ArticleRequestHandler::create_article(
+        &setup,
+        &ArticleCreateRelationsBody {
+            name: format!("{}_article", setup.test_id),
+            content: String::from("first line\nsecond line\nthird line\n"),
+            language: language.clone(),
+            article_type: ArticleType::Public,
+        },
+        admin_token.clone(),
+    )
+    .await;
+
+    ArticleVersionRequestHandler::create_article_version(
+        &setup,
+        &ArticleVersionCreateRelationsBody {
+            content: String::from("first line\nupdated line\nthird line\nfourth line\n"),
+            name: None,
+            source: None,
+            base_version: None,
+            merge: None,
+        },
+        article.id,
+        &language,
+        admin_token,
+    )
+    .await;
+
+    let compare_aggregation =
+        ArticleVersionRequestHandler::compare_article_versions(&setup, article.id, &language, 1, 2)
+            .await;
+
+    assert_eq!(compare_aggregation.actual.version, 1);
+    assert_eq!(compare_aggregation.compared.version, 2);
+
+    let diff: Vec<(DiffOperation, &str)> = compare_aggregation
+        .diff
+        .iter()
+        .map(|hunk| (hunk.operation, hunk.content.as_str()))
+        .collect();
+
+    assert_eq!(
+        diff,
+        vec![
+            (DiffOperation::Equal, "first line\n"),
+            (DiffOperation::Delete, "second line\n"),
+            (DiffOperation::Insert, "updated line\n"),
+            (DiffOperation::Equal, "third line\n"),
+            (DiffOperation::Insert, "fourth line\n"),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn compare_article_versions_wrong_params() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let admin_token = setup.user_handler.get_token(TestUser::Admin1).unwrap();
+
+    let language = String::from("ua");
+    let article = ArticleRequestHandler::create_article(
+        &setup,
+        &ArticleCreateRelationsBody {
+            name: format!("{}_article", setup.test_id),
+            content: String::from("first version content"),
+            language: language.clone(),
+            article_type: ArticleType::Public,
+        },
+        admin_token,
+    )
+    .await;
+
+    let response_wrong_version =
+        ArticleVersionRequest::compare_article_versions(&setup, article.id, &language, 1, 2).await;
+
+    assert_eq!(response_wrong_version.status(), Status::NotFound);
+
+    let error_message = response_wrong_version.into_string().await.unwrap();
+    assert_eq!(
+        error_message,
+        FmtError::NotFound("compared_article_version").fmt()
+    );
+}
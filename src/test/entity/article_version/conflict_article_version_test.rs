@@ -0,0 +1,197 @@
+use futures::future::join_all;
+use rocket::http::Status;
+
+use super::setup::{SetupOptions, TestSetup, TestUser};
+use super::test_handler::request_handler::{
+    article::ArticleRequestHandler,
+    article_version::{ArticleVersionRequest, ArticleVersionRequestHandler},
+};
+
+use super::aggregation::article_version::ArticleVersionConflictAggregation;
+use super::dtm::{
+    article::request_body::ArticleCreateRelationsBody,
+    article_version::request_body::ArticleVersionCreateRelationsBody,
+};
+use super::repository::entity::article::ArticleType;
+
+fn get_version_body(
+    content: &str,
+    base_version: i32,
+    merge: Option<bool>,
+) -> ArticleVersionCreateRelationsBody {
+    ArticleVersionCreateRelationsBody {
+        content: String::from(content),
+        name: None,
+        source: None,
+        base_version: Some(base_version),
+        merge,
+    }
+}
+
+#[tokio::test]
+async fn create_article_version_conflict() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let admin_token = setup.user_handler.get_token(TestUser::Admin1).unwrap();
+
+    let language = String::from("ua");
+    let article = ArticleRequestHandler::create_article(
+        &setup,
+        &ArticleCreateRelationsBody {
+            name: format!("{}_article", setup.test_id),
+            content: String::from("a\nb\nc\nd\ne\n"),
+            language: language.clone(),
+            article_type: ArticleType::Public,
+        },
+        admin_token.clone(),
+    )
+    .await;
+
+    let second_version = ArticleVersionRequestHandler::create_article_version(
+        &setup,
+        &get_version_body("A\nb\nc\nd\ne\n", 1, None),
+        article.id,
+        &language,
+        admin_token.clone(),
+    )
+    .await;
+
+    assert_eq!(second_version.version, 2);
+
+    // the edit is still based on the first version
+    let conflict_aggregation = ArticleVersionRequestHandler::create_article_version_conflict(
+        &setup,
+        &get_version_body("a\nb\nc\nd\nE\n", 1, None),
+        article.id,
+        &language,
+        admin_token.clone(),
+    )
+    .await;
+
+    assert_eq!(conflict_aggregation.base_version, 1);
+    assert_eq!(conflict_aggregation.actual_version, 2);
+    assert_eq!(conflict_aggregation.versions.len(), 1);
+    assert_eq!(conflict_aggregation.versions[0].version, 2);
+    assert!(!conflict_aggregation.diff.is_empty());
+
+    let merged_version = ArticleVersionRequestHandler::create_article_version(
+        &setup,
+        &get_version_body("a\nb\nc\nd\nE\n", 1, Some(true)),
+        article.id,
+        &language,
+        admin_token.clone(),
+    )
+    .await;
+
+    assert_eq!(merged_version.version, 3);
+    assert_eq!(merged_version.content.content, "A\nb\nc\nd\nE\n");
+
+    // the first line was changed by both edits
+    let overlapping_aggregation = ArticleVersionRequestHandler::create_article_version_conflict(
+        &setup,
+        &get_version_body("X\nb\nc\nd\ne\n", 1, Some(true)),
+        article.id,
+        &language,
+        admin_token.clone(),
+    )
+    .await;
+
+    assert_eq!(overlapping_aggregation.actual_version, 3);
+    assert_eq!(
+        overlapping_aggregation
+            .versions
+            .iter()
+            .map(|aggregation| aggregation.version)
+            .collect::<Vec<i32>>(),
+        vec![3, 2]
+    );
+
+    let actual_version = ArticleVersionRequestHandler::create_article_version(
+        &setup,
+        &get_version_body("X\nb\nc\nd\nE\n", 3, None),
+        article.id,
+        &language,
+        admin_token.clone(),
+    )
+    .await;
+
+    assert_eq!(actual_version.version, 4);
+
+    let unknown_base_response = ArticleVersionRequest::create_article_version(
+        &setup,
+        &get_version_body("a\n", 10, Some(true)),
+        article.id,
+        &language,
+        admin_token,
+    )
+    .await;
+
+    assert_eq!(unknown_base_response.status(), Status::NotFound);
+}
+
+#[tokio::test]
+async fn create_article_version_concurrent_conflict() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let admin_token = setup.user_handler.get_token(TestUser::Admin1).unwrap();
+
+    let language = String::from("ua");
+    let content = "a\n-\nb\n-\nc\n-\nd\n-\ne\n";
+    let article = ArticleRequestHandler::create_article(
+        &setup,
+        &ArticleCreateRelationsBody {
+            name: format!("{}_concurrent_article", setup.test_id),
+            content: String::from(content),
+            language: language.clone(),
+            article_type: ArticleType::Public,
+        },
+        admin_token.clone(),
+    )
+    .await;
+
+    // every edit is based on the first version and changes its own line
+    let bodies = ["a", "b", "c", "d"]
+        .into_iter()
+        .map(|line| get_version_body(&content.replace(line, &line.to_uppercase()), 1, Some(true)))
+        .collect::<Vec<ArticleVersionCreateRelationsBody>>();
+
+    let responses = join_all(bodies.iter().map(|body| {
+        ArticleVersionRequest::create_article_version(
+            &setup,
+            body,
+            article.id,
+            &language,
+            admin_token.clone(),
+        )
+    }))
+    .await;
+
+    for response in responses {
+        match response.status() {
+            Status::Ok => (),
+            Status::Conflict => {
+                let conflict_aggregation = response
+                    .into_json::<ArticleVersionConflictAggregation>()
+                    .await
+                    .unwrap();
+
+                assert_eq!(conflict_aggregation.base_version, 1);
+            }
+            status => panic!("unexpected status {status}"),
+        }
+    }
+
+    // the overlapping edit is reported against the submitted base, not an intermediate merge
+    let conflict_aggregation = ArticleVersionRequestHandler::create_article_version_conflict(
+        &setup,
+        &get_version_body(&content.replace("a", "X"), 1, Some(true)),
+        article.id,
+        &language,
+        admin_token,
+    )
+    .await;
+
+    assert_eq!(conflict_aggregation.base_version, 1);
+    assert_eq!(
+        conflict_aggregation.versions.len() as i32,
+        conflict_aggregation.actual_version - 1
+    );
+}
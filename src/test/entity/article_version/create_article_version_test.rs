@@ -39,6 +39,9 @@ async fn create_article_version() {
     let first_av_creation_body = ArticleVersionCreateRelationsBody {
         content: String::from("second version content"),
         name: None,
+        source: None,
+        base_version: None,
+        merge: None,
     };
 
     let first_response_body = ArticleVersionRequestHandler::create_article_version(
@@ -61,6 +64,9 @@ async fn create_article_version() {
     let second_av_creation_body = ArticleVersionCreateRelationsBody {
         content: String::from("test create article version content"),
         name: None,
+        source: None,
+        base_version: None,
+        merge: None,
     };
 
     let second_response_body = ArticleVersionRequestHandler::create_article_version(
@@ -102,6 +108,9 @@ async fn create_article_version_wrong_params() {
     let first_creation_body = ArticleVersionCreateRelationsBody {
         content: String::from("second version content"),
         name: None,
+        source: None,
+        base_version: None,
+        merge: None,
     };
 
     let wrong_language = String::from("en");
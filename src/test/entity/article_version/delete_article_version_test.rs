@@ -0,0 +1,175 @@
+use rocket::http::Status;
+
+use super::error::FmtError;
+
+use super::setup::{SetupOptions, TestSetup, TestUser};
+use super::test_handler::request_handler::{
+    article::ArticleRequestHandler,
+    article_version::{ArticleVersionRequest, ArticleVersionRequestHandler},
+};
+
+use super::dtm::{
+    article::request_body::ArticleCreateRelationsBody,
+    article_version::request_body::ArticleVersionCreateRelationsBody,
+};
+use super::repository::entity::article::ArticleType;
+
+fn get_version_content(version: i32) -> String {
+    format!("version {} content\nshared line\n", version)
+}
+
+#[tokio::test]
+async fn delete_article_version() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let admin_token = setup.user_handler.get_token(TestUser::Admin1).unwrap();
+
+    let language = String::from("ua");
+    let article = ArticleRequestHandler::create_article(
+        &setup,
+        &ArticleCreateRelationsBody {
+            name: format!("{}_article", setup.test_id),
+            content: get_version_content(1),
+            language: language.clone(),
+            article_type: ArticleType::Public,
+        },
+        admin_token.clone(),
+    )
+    .await;
+
+    for version in 2..=5 {
+        ArticleVersionRequestHandler::create_article_version(
+            &setup,
+            &ArticleVersionCreateRelationsBody {
+                content: get_version_content(version),
+                name: None,
+                source: None,
+                base_version: None,
+                merge: None,
+            },
+            article.id,
+            &language,
+            admin_token.clone(),
+        )
+        .await;
+    }
+
+    ArticleVersionRequestHandler::delete_article_version(
+        &setup,
+        article.id,
+        &language,
+        3,
+        admin_token.clone(),
+    )
+    .await;
+
+    ArticleVersionRequestHandler::delete_article_version(
+        &setup,
+        article.id,
+        &language,
+        5,
+        admin_token.clone(),
+    )
+    .await;
+
+    let article_versions =
+        ArticleVersionRequestHandler::get_article_versions(&setup, article.id, &language).await;
+
+    assert_eq!(
+        article_versions
+            .iter()
+            .map(|article_version| article_version.version)
+            .collect::<Vec<i32>>(),
+        vec![4, 2, 1]
+    );
+
+    for article_version in article_versions {
+        assert_eq!(
+            article_version.content.content,
+            get_version_content(article_version.version)
+        );
+    }
+
+    let response_deleted_version =
+        ArticleVersionRequest::get_article_version(&setup, article.id, &language, 3).await;
+
+    assert_eq!(response_deleted_version.status(), Status::NotFound);
+
+    let created_article_version = ArticleVersionRequestHandler::create_article_version(
+        &setup,
+        &ArticleVersionCreateRelationsBody {
+            content: get_version_content(6),
+            name: None,
+            source: None,
+            base_version: None,
+            merge: None,
+        },
+        article.id,
+        &language,
+        admin_token,
+    )
+    .await;
+
+    assert_eq!(created_article_version.version, 5);
+
+    let article_version =
+        ArticleVersionRequestHandler::get_article_version(&setup, article.id, &language, 4).await;
+
+    assert_eq!(article_version.content.content, get_version_content(4));
+}
+
+#[tokio::test]
+async fn delete_article_version_wrong_params() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let admin_token = setup.user_handler.get_token(TestUser::Admin1).unwrap();
+    let user_token = setup.user_handler.get_token(TestUser::Common1).unwrap();
+
+    let language = String::from("ua");
+    let article = ArticleRequestHandler::create_article(
+        &setup,
+        &ArticleCreateRelationsBody {
+            name: format!("{}_article", setup.test_id),
+            content: get_version_content(1),
+            language: language.clone(),
+            article_type: ArticleType::Public,
+        },
+        admin_token.clone(),
+    )
+    .await;
+
+    let response_not_permitted =
+        ArticleVersionRequest::delete_article_version(&setup, article.id, &language, 1, user_token)
+            .await;
+
+    assert_eq!(response_not_permitted.status(), Status::Forbidden);
+
+    let response_wrong_version = ArticleVersionRequest::delete_article_version(
+        &setup,
+        article.id,
+        &language,
+        2,
+        admin_token.clone(),
+    )
+    .await;
+
+    assert_eq!(response_wrong_version.status(), Status::NotFound);
+
+    let error_message = response_wrong_version.into_string().await.unwrap();
+    assert_eq!(error_message, FmtError::NotFound("article_version").fmt());
+
+    let response_only_version = ArticleVersionRequest::delete_article_version(
+        &setup,
+        article.id,
+        &language,
+        1,
+        admin_token,
+    )
+    .await;
+
+    assert_eq!(response_only_version.status(), Status::NotAcceptable);
+
+    let error_message = response_only_version.into_string().await.unwrap();
+    assert_eq!(
+        error_message,
+        FmtError::FailedToProcess("the only article_version").fmt()
+    );
+}
@@ -38,6 +38,9 @@ async fn get_article_version() {
     let creation_body = ArticleVersionCreateRelationsBody {
         content: String::from("second version content"),
         name: None,
+        source: None,
+        base_version: None,
+        merge: None,
     };
 
     ArticleVersionRequestHandler::create_article_version(
@@ -62,6 +65,9 @@ async fn get_article_version() {
                 &ArticleVersionCreateRelationsBody {
                     content: article_creation_body.content,
                     name: None,
+                    source: None,
+                    base_version: None,
+                    merge: None,
                 },
                 1,
             ),
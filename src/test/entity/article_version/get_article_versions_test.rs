@@ -38,6 +38,9 @@ async fn get_article_versions() {
     let creation_body = ArticleVersionCreateRelationsBody {
         content: String::from("second version content"),
         name: None,
+        source: None,
+        base_version: None,
+        merge: None,
     };
 
     ArticleVersionRequestHandler::create_article_version(
@@ -62,6 +65,9 @@ async fn get_article_versions() {
                 &ArticleVersionCreateRelationsBody {
                     content: article_creation_body.content,
                     name: None,
+                    source: None,
+                    base_version: None,
+                    merge: None,
                 },
                 1,
             ),
@@ -124,6 +130,9 @@ async fn get_article_versions_disabled() {
     let creation_body = ArticleVersionCreateRelationsBody {
         content: String::from("second version content"),
         name: None,
+        source: None,
+        base_version: None,
+        merge: None,
     };
 
     ArticleVersionRequestHandler::create_article_version(
@@ -190,6 +199,9 @@ async fn get_article_versions_disabled() {
                 &ArticleVersionCreateRelationsBody {
                     content: article_creation_body.content,
                     name: None,
+                    source: None,
+                    base_version: None,
+                    merge: None,
                 },
                 1,
             ),
@@ -197,3 +209,106 @@ async fn get_article_versions_disabled() {
         ArticleVersionAssertOptions { is_updated: true },
     );
 }
+
+#[tokio::test]
+async fn get_article_versions_paginated() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let admin_token = setup.user_handler.get_token(TestUser::Admin1).unwrap();
+
+    let language = String::from("ua");
+    let get_version_content = |version: i32| format!("version {} content", version);
+
+    let article = ArticleRequestHandler::create_article(
+        &setup,
+        &ArticleCreateRelationsBody {
+            name: format!("{}_article", setup.test_id),
+            content: get_version_content(1),
+            language: language.clone(),
+            article_type: ArticleType::Public,
+        },
+        admin_token.clone(),
+    )
+    .await;
+
+    for version in 2..=5 {
+        ArticleVersionRequestHandler::create_article_version(
+            &setup,
+            &ArticleVersionCreateRelationsBody {
+                content: get_version_content(version),
+                name: None,
+                source: None,
+                base_version: None,
+                merge: None,
+            },
+            article.id,
+            &language,
+            admin_token.clone(),
+        )
+        .await;
+    }
+
+    let mut cursor = None;
+    let mut received_versions = vec![];
+
+    for expected_versions in [vec![5, 4], vec![3, 2], vec![1]] {
+        let page = ArticleVersionRequestHandler::get_article_versions_page(
+            &setup, article.id, &language, 2, cursor,
+        )
+        .await;
+
+        let page_versions: Vec<i32> = page.items.iter().map(|item| item.version).collect();
+        assert_eq!(page_versions, expected_versions);
+
+        for article_version in &page.items {
+            assert_eq!(
+                article_version.content.content,
+                get_version_content(article_version.version)
+            );
+        }
+
+        received_versions.extend(page_versions);
+        cursor = page.next_cursor;
+    }
+
+    assert!(cursor.is_none());
+    assert_eq!(received_versions, vec![5, 4, 3, 2, 1]);
+}
+
+#[tokio::test]
+async fn get_article_versions_paginated_wrong_params() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let admin_token = setup.user_handler.get_token(TestUser::Admin1).unwrap();
+
+    let language = String::from("ua");
+    let article = ArticleRequestHandler::create_article(
+        &setup,
+        &ArticleCreateRelationsBody {
+            name: format!("{}_article", setup.test_id),
+            content: String::from("first version content"),
+            language: language.clone(),
+            article_type: ArticleType::Public,
+        },
+        admin_token,
+    )
+    .await;
+
+    let response_wrong_cursor = ArticleVersionRequest::get_article_versions_page(
+        &setup,
+        article.id,
+        &language,
+        2,
+        Some(String::from("wrong_cursor")),
+    )
+    .await;
+
+    assert_eq!(response_wrong_cursor.status(), Status::NotAcceptable);
+
+    let error_message = response_wrong_cursor.into_string().await.unwrap();
+    assert_eq!(error_message, FmtError::FailedToProcess("cursor").fmt());
+
+    let response_wrong_limit =
+        ArticleVersionRequest::get_article_versions_page(&setup, article.id, &language, 0, None)
+            .await;
+
+    assert_eq!(response_wrong_limit.status(), Status::NotAcceptable);
+}
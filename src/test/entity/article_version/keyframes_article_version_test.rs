@@ -0,0 +1,185 @@
+use rocket::http::Status;
+
+use super::setup::{SetupOptions, TestSetup, TestUser};
+use super::test_handler::request_handler::{
+    article::ArticleRequestHandler,
+    article_version::{ArticleVersionRequest, ArticleVersionRequestHandler},
+};
+
+use super::dtm::{
+    article::request_body::ArticleCreateRelationsBody,
+    article_version::request_body::ArticleVersionCreateRelationsBody,
+};
+use super::repository::entity::{article::ArticleType, version_content::ContentType};
+
+fn get_version_content(version: i32) -> String {
+    format!("version {} content\nshared line\n", version)
+}
+
+async fn get_full_versions(setup: &TestSetup, article_id: i32, language: &String) -> Vec<i32> {
+    let mut full_versions: Vec<i32> =
+        ArticleVersionRequestHandler::get_article_versions(setup, article_id, language)
+            .await
+            .into_iter()
+            .filter(|article_version| article_version.content.content_type == ContentType::Full)
+            .map(|article_version| article_version.version)
+            .collect();
+
+    full_versions.sort();
+    full_versions
+}
+
+#[tokio::test]
+async fn get_article_versions_with_keyframes() {
+    let setup = TestSetup::new_with_keyframe_interval(SetupOptions { is_lock: true }, 2).await;
+    let admin_token = setup.user_handler.get_token(TestUser::Admin1).unwrap();
+
+    let language = String::from("ua");
+    let article = ArticleRequestHandler::create_article(
+        &setup,
+        &ArticleCreateRelationsBody {
+            name: format!("{}_article", setup.test_id),
+            content: get_version_content(1),
+            language: language.clone(),
+            article_type: ArticleType::Public,
+        },
+        admin_token.clone(),
+    )
+    .await;
+
+    for version in 2..=7 {
+        ArticleVersionRequestHandler::create_article_version(
+            &setup,
+            &ArticleVersionCreateRelationsBody {
+                content: get_version_content(version),
+                name: None,
+                source: None,
+                base_version: None,
+                merge: None,
+            },
+            article.id,
+            &language,
+            admin_token.clone(),
+        )
+        .await;
+    }
+
+    // keyframes and the actual version only
+    assert_eq!(
+        get_full_versions(&setup, article.id, &language).await,
+        vec![2, 4, 6, 7]
+    );
+
+    for version in 1..=7 {
+        let article_version = ArticleVersionRequestHandler::get_article_version(
+            &setup, article.id, &language, version,
+        )
+        .await;
+
+        assert_eq!(
+            article_version.content.content,
+            get_version_content(version)
+        );
+    }
+
+    let article_versions =
+        ArticleVersionRequestHandler::get_article_versions(&setup, article.id, &language).await;
+
+    assert_eq!(article_versions.len(), 7);
+
+    for article_version in article_versions {
+        assert_eq!(
+            article_version.content.content,
+            get_version_content(article_version.version)
+        );
+    }
+}
+
+#[tokio::test]
+async fn rebuild_version_keyframes() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let admin_token = setup.user_handler.get_token(TestUser::Admin1).unwrap();
+    let user_token = setup.user_handler.get_token(TestUser::Common1).unwrap();
+
+    let language = String::from("ua");
+    let article = ArticleRequestHandler::create_article(
+        &setup,
+        &ArticleCreateRelationsBody {
+            name: format!("{}_article", setup.test_id),
+            content: get_version_content(1),
+            language: language.clone(),
+            article_type: ArticleType::Public,
+        },
+        admin_token.clone(),
+    )
+    .await;
+
+    for version in 2..=5 {
+        ArticleVersionRequestHandler::create_article_version(
+            &setup,
+            &ArticleVersionCreateRelationsBody {
+                content: get_version_content(version),
+                name: None,
+                source: None,
+                base_version: None,
+                merge: None,
+            },
+            article.id,
+            &language,
+            admin_token.clone(),
+        )
+        .await;
+    }
+
+    // created with the default interval, so there are no keyframes yet
+    assert_eq!(
+        get_full_versions(&setup, article.id, &language).await,
+        vec![5]
+    );
+
+    let keyframe_setup =
+        TestSetup::new_with_keyframe_interval(SetupOptions { is_lock: false }, 2).await;
+
+    let response_not_permitted = ArticleVersionRequest::rebuild_version_keyframes(
+        &keyframe_setup,
+        Some(article.id),
+        user_token,
+    )
+    .await;
+
+    assert_eq!(response_not_permitted.status(), Status::Forbidden);
+
+    let keyframes_aggregation = ArticleVersionRequestHandler::rebuild_version_keyframes(
+        &keyframe_setup,
+        Some(article.id),
+        admin_token.clone(),
+    )
+    .await;
+
+    assert_eq!(keyframes_aggregation.keyframes_count, 2);
+    assert_eq!(
+        get_full_versions(&setup, article.id, &language).await,
+        vec![2, 4, 5]
+    );
+
+    let repeated_keyframes_aggregation = ArticleVersionRequestHandler::rebuild_version_keyframes(
+        &keyframe_setup,
+        Some(article.id),
+        admin_token,
+    )
+    .await;
+
+    assert_eq!(repeated_keyframes_aggregation.keyframes_count, 0);
+
+    for version in 1..=5 {
+        let article_version = ArticleVersionRequestHandler::get_article_version(
+            &setup, article.id, &language, version,
+        )
+        .await;
+
+        assert_eq!(
+            article_version.content.content,
+            get_version_content(version)
+        );
+    }
+}
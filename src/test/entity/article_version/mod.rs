@@ -1,10 +1,17 @@
+use super::aggregation;
 use super::dtm;
+use super::dtm_common;
 use super::error;
 use super::repository;
 use super::setup;
 use super::test_handler;
 
+pub mod compare_article_versions_test;
+pub mod conflict_article_version_test;
 pub mod create_article_version_test;
+pub mod delete_article_version_test;
 pub mod get_article_version_test;
 pub mod get_article_versions_test;
+pub mod keyframes_article_version_test;
 pub mod patch_article_version_test;
+pub mod translation_article_version_test;
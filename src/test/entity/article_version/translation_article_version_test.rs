@@ -0,0 +1,192 @@
+use rocket::http::Status;
+
+use super::dtm_common::DiffOperation;
+use super::error::FmtError;
+
+use super::setup::{SetupOptions, TestSetup, TestUser};
+use super::test_handler::request_handler::{
+    article::ArticleRequestHandler,
+    article_language::ArticleLanguageRequestHandler,
+    article_version::{ArticleVersionRequest, ArticleVersionRequestHandler},
+};
+
+use super::dtm::{
+    article::request_body::ArticleCreateRelationsBody,
+    article_language::request_body::ArticleLanguageCreateRelationsBody,
+    article_version::request_body::{ArticleVersionCreateRelationsBody, ArticleVersionSourceBody},
+};
+use super::repository::entity::article::ArticleType;
+
+fn get_version_body(
+    content: &str,
+    source: Option<(&str, i32)>,
+) -> ArticleVersionCreateRelationsBody {
+    ArticleVersionCreateRelationsBody {
+        content: String::from(content),
+        name: None,
+        source: source.map(|(language, version)| ArticleVersionSourceBody {
+            language: String::from(language),
+            version,
+        }),
+        base_version: None,
+        merge: None,
+    }
+}
+
+#[tokio::test]
+async fn outdated_translations() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let admin_token = setup.user_handler.get_token(TestUser::Admin1).unwrap();
+
+    let source_language = String::from("ua");
+    let translation_language = String::from("en");
+
+    let article = ArticleRequestHandler::create_article(
+        &setup,
+        &ArticleCreateRelationsBody {
+            name: format!("{}_translated_article", setup.test_id),
+            content: String::from("first line\nsecond line\n"),
+            language: source_language.clone(),
+            article_type: ArticleType::Public,
+        },
+        admin_token.clone(),
+    )
+    .await;
+
+    ArticleLanguageRequestHandler::create_article_language(
+        &setup,
+        &ArticleLanguageCreateRelationsBody {
+            name: format!("{}_translated_article_en", setup.test_id),
+            content: String::from("draft translation"),
+        },
+        article.id,
+        &translation_language,
+        admin_token.clone(),
+    )
+    .await;
+
+    let translation = ArticleVersionRequestHandler::create_article_version(
+        &setup,
+        &get_version_body("translated lines\n", Some(("ua", 1))),
+        article.id,
+        &translation_language,
+        admin_token.clone(),
+    )
+    .await;
+
+    assert_eq!(
+        translation.source_version_id,
+        Some(article.languages[0].version.id)
+    );
+    assert!(
+        ArticleVersionRequestHandler::get_outdated_translations(&setup, article.id)
+            .await
+            .is_empty()
+    );
+
+    ArticleVersionRequestHandler::create_article_version(
+        &setup,
+        &get_version_body("first line\nchanged line\n", None),
+        article.id,
+        &source_language,
+        admin_token.clone(),
+    )
+    .await;
+
+    let outdated_translations =
+        ArticleVersionRequestHandler::get_outdated_translations(&setup, article.id).await;
+
+    assert_eq!(outdated_translations.len(), 1);
+    assert_eq!(outdated_translations[0].language, translation_language);
+    assert_eq!(outdated_translations[0].version, 2);
+    assert_eq!(outdated_translations[0].source_language, source_language);
+    assert_eq!(outdated_translations[0].source_version, 1);
+    assert_eq!(outdated_translations[0].source_actual_version, 2);
+    assert!(outdated_translations[0]
+        .diff
+        .iter()
+        .any(|hunk| hunk.operation == DiffOperation::Delete && hunk.content == "second line\n"));
+    assert!(outdated_translations[0]
+        .diff
+        .iter()
+        .any(|hunk| hunk.operation == DiffOperation::Insert && hunk.content == "changed line\n"));
+
+    ArticleVersionRequestHandler::create_article_version(
+        &setup,
+        &get_version_body("translated lines again\n", Some(("ua", 2))),
+        article.id,
+        &translation_language,
+        admin_token,
+    )
+    .await;
+
+    assert!(
+        ArticleVersionRequestHandler::get_outdated_translations(&setup, article.id)
+            .await
+            .is_empty()
+    );
+}
+
+#[tokio::test]
+async fn translation_wrong_source() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let admin_token = setup.user_handler.get_token(TestUser::Admin1).unwrap();
+
+    let language = String::from("ua");
+    let article = ArticleRequestHandler::create_article(
+        &setup,
+        &ArticleCreateRelationsBody {
+            name: format!("{}_wrong_source_article", setup.test_id),
+            content: String::from("source content"),
+            language: language.clone(),
+            article_type: ArticleType::Public,
+        },
+        admin_token.clone(),
+    )
+    .await;
+
+    let same_language_response = ArticleVersionRequest::create_article_version(
+        &setup,
+        &get_version_body("translated content", Some(("ua", 1))),
+        article.id,
+        &language,
+        admin_token.clone(),
+    )
+    .await;
+
+    assert_eq!(same_language_response.status(), Status::NotAcceptable);
+
+    ArticleLanguageRequestHandler::create_article_language(
+        &setup,
+        &ArticleLanguageCreateRelationsBody {
+            name: format!("{}_wrong_source_article_en", setup.test_id),
+            content: String::from("translated content"),
+        },
+        article.id,
+        &String::from("en"),
+        admin_token.clone(),
+    )
+    .await;
+
+    let missing_version_response = ArticleVersionRequest::create_article_version(
+        &setup,
+        &get_version_body("translated content", Some(("ua", 99))),
+        article.id,
+        &String::from("en"),
+        admin_token,
+    )
+    .await;
+
+    assert_eq!(missing_version_response.status(), Status::NotFound);
+
+    let error_message = missing_version_response.into_string().await.unwrap();
+    assert_eq!(
+        error_message,
+        FmtError::NotFound("source_article_version").fmt()
+    );
+
+    let missing_article_response =
+        ArticleVersionRequest::get_outdated_translations(&setup, 0).await;
+
+    assert_eq!(missing_article_response.status(), Status::NotFound);
+}
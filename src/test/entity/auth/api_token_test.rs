@@ -0,0 +1,215 @@
+use rocket::http::Status;
+
+use super::setup::{SetupOptions, TestSetup};
+use super::test_handler::request_handler::{
+    article::ArticleRequest,
+    auth::{AuthRequest, AuthRequestHandler},
+    email_template::EmailTemplateRequest,
+    image::ImageRequest,
+};
+
+use super::dtm::article::request_body::ArticleCreateRelationsBody;
+use super::dtm::auth::request_body::{ApiTokenCreateBody, UserSignupBody};
+use super::dtm_common::{ApiTokenScope, UserRoleId, API_TOKEN_PREFIX};
+use super::repository::entity::article::ArticleType;
+
+async fn create_user(setup: &TestSetup, role_id: UserRoleId) -> String {
+    let role_id = role_id as i32;
+
+    AuthRequestHandler::signup_with_role(
+        &setup.client,
+        &UserSignupBody {
+            name: format!("api_token_{}_{}", role_id, setup.test_id),
+            email: format!("api_token_{}_{}@mail.com", role_id, setup.test_id),
+            password: String::from("password"),
+        },
+        role_id,
+    )
+    .await
+    .token
+}
+
+fn get_article_body(setup: &TestSetup, prefix: &str) -> ArticleCreateRelationsBody {
+    ArticleCreateRelationsBody {
+        name: format!("{}_{}_article", setup.test_id, prefix),
+        content: String::from("test api token content"),
+        language: String::from("ua"),
+        article_type: ArticleType::Public,
+    }
+}
+
+#[tokio::test]
+async fn api_token_scopes() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let jwt_token = create_user(&setup, UserRoleId::Common).await;
+
+    let read_token = AuthRequestHandler::create_api_token(
+        &setup,
+        &ApiTokenCreateBody {
+            name: String::from("reader"),
+            scopes: vec![ApiTokenScope::Read],
+            live_days: None,
+        },
+        jwt_token.clone(),
+    )
+    .await;
+
+    assert!(read_token.token.starts_with(API_TOKEN_PREFIX));
+    assert_eq!(read_token.api_token.scopes, vec![ApiTokenScope::Read]);
+    assert!(read_token.api_token.expires_at.is_none());
+    assert!(read_token.api_token.last_used_at.is_none());
+
+    let read_response = ImageRequest::get_images(&setup, read_token.token.clone()).await;
+
+    assert_eq!(read_response.status(), Status::Ok);
+
+    let write_response = ArticleRequest::create_article(
+        &setup,
+        &get_article_body(&setup, "read"),
+        read_token.token.clone(),
+    )
+    .await;
+
+    assert_eq!(write_response.status(), Status::Forbidden);
+
+    let edit_token = AuthRequestHandler::create_api_token(
+        &setup,
+        &ApiTokenCreateBody {
+            name: String::from("editor"),
+            scopes: vec![ApiTokenScope::EditArticles],
+            live_days: Some(30),
+        },
+        jwt_token.clone(),
+    )
+    .await;
+
+    assert!(edit_token.api_token.expires_at.is_some());
+
+    let write_response = ArticleRequest::create_article(
+        &setup,
+        &get_article_body(&setup, "edit"),
+        edit_token.token.clone(),
+    )
+    .await;
+
+    assert_eq!(write_response.status(), Status::Ok);
+
+    // write scopes include reading
+    let read_response = ImageRequest::get_images(&setup, edit_token.token.clone()).await;
+
+    assert_eq!(read_response.status(), Status::Ok);
+
+    let image_write_response = ImageRequest::cleanup_images(&setup, edit_token.token.clone()).await;
+
+    assert_eq!(image_write_response.status(), Status::Forbidden);
+}
+
+#[tokio::test]
+async fn api_token_management() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let jwt_token = create_user(&setup, UserRoleId::Common).await;
+
+    let empty_scopes_response = AuthRequest::create_api_token(
+        &setup,
+        &ApiTokenCreateBody {
+            name: String::from("empty"),
+            scopes: vec![],
+            live_days: None,
+        },
+        jwt_token.clone(),
+    )
+    .await;
+
+    assert_eq!(empty_scopes_response.status(), Status::NotAcceptable);
+
+    let api_token = AuthRequestHandler::create_api_token(
+        &setup,
+        &ApiTokenCreateBody {
+            name: String::from("ci bot"),
+            scopes: vec![ApiTokenScope::Read, ApiTokenScope::UploadImages],
+            live_days: None,
+        },
+        jwt_token.clone(),
+    )
+    .await;
+
+    // tokens can't manage tokens, sessions or 2fa
+    let session_only_response = AuthRequest::get_api_tokens(&setup, api_token.token.clone()).await;
+
+    assert_eq!(session_only_response.status(), Status::Forbidden);
+
+    let read_response = ImageRequest::get_images(&setup, api_token.token.clone()).await;
+
+    assert_eq!(read_response.status(), Status::Ok);
+
+    let api_tokens = AuthRequestHandler::get_api_tokens(&setup, jwt_token.clone()).await;
+
+    assert_eq!(api_tokens.len(), 1);
+    assert_eq!(api_tokens[0].id, api_token.api_token.id);
+    assert_eq!(api_tokens[0].name, "ci bot");
+    assert!(api_tokens[0].last_used_at.is_some());
+
+    AuthRequestHandler::revoke_api_token(&setup, api_token.api_token.id, jwt_token.clone()).await;
+
+    let revoked_response = ImageRequest::get_images(&setup, api_token.token.clone()).await;
+
+    assert_eq!(revoked_response.status(), Status::Unauthorized);
+
+    let revoked_again_response =
+        AuthRequest::revoke_api_token(&setup, api_token.api_token.id, jwt_token.clone()).await;
+
+    assert_eq!(revoked_again_response.status(), Status::NotFound);
+
+    let api_tokens = AuthRequestHandler::get_api_tokens(&setup, jwt_token).await;
+
+    assert_eq!(api_tokens.len(), 0);
+
+    let invalid_response =
+        ImageRequest::get_images(&setup, format!("{}invalid", API_TOKEN_PREFIX)).await;
+
+    assert_eq!(invalid_response.status(), Status::Unauthorized);
+}
+
+#[tokio::test]
+async fn api_token_admin_routes() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let jwt_token = create_user(&setup, UserRoleId::Admin).await;
+
+    let api_token = AuthRequestHandler::create_api_token(
+        &setup,
+        &ApiTokenCreateBody {
+            name: String::from("admin bot"),
+            scopes: vec![
+                ApiTokenScope::Read,
+                ApiTokenScope::EditArticles,
+                ApiTokenScope::UploadImages,
+            ],
+            live_days: None,
+        },
+        jwt_token.clone(),
+    )
+    .await;
+
+    let write_response = ArticleRequest::create_article(
+        &setup,
+        &get_article_body(&setup, "admin"),
+        api_token.token.clone(),
+    )
+    .await;
+
+    assert_eq!(write_response.status(), Status::Ok);
+
+    // admin routes stay session only whatever scopes the token has
+    let cleanup_response = ImageRequest::cleanup_images(&setup, api_token.token.clone()).await;
+
+    assert_eq!(cleanup_response.status(), Status::Forbidden);
+
+    let templates_response =
+        EmailTemplateRequest::get_email_templates(&setup, api_token.token.clone()).await;
+
+    assert_eq!(templates_response.status(), Status::Forbidden);
+
+    let session_response = EmailTemplateRequest::get_email_templates(&setup, jwt_token).await;
+
+    assert_eq!(session_response.status(), Status::Ok);
+}
@@ -0,0 +1,82 @@
+use std::env;
+
+use super::emailer::{EmailMessage, OutboxEmailTransport};
+use super::setup::{SetupOptions, TestSetup};
+use super::test_handler::request_handler::auth::AuthRequestHandler;
+
+use super::dtm::auth::request_body::{UserConfirmBody, UserResetBody, UserSignupBody};
+
+const CLIENT_URL_ENV: &str = "CLIENT_URL";
+const OTP_RESEND_COOLDOWN_SEC_ENV: &str = "OTP_RESEND_COOLDOWN_SEC";
+
+const CLIENT_URL: &str = "http://client.test";
+
+fn get_key(message: &EmailMessage) -> String {
+    let url = reqwest::Url::parse(message.text.lines().last().unwrap()).unwrap();
+
+    url.query_pairs()
+        .find(|(key, _)| key == "key")
+        .map(|(_, value)| value.to_string())
+        .unwrap()
+}
+
+#[tokio::test]
+async fn outbox_emails() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+
+    env::set_var(CLIENT_URL_ENV, CLIENT_URL);
+    env::set_var(OTP_RESEND_COOLDOWN_SEC_ENV, "0");
+
+    let email = format!("outbox_{}@mail.com", setup.test_id);
+
+    AuthRequestHandler::signup(
+        &setup,
+        &UserSignupBody {
+            email: email.clone(),
+            name: format!("outbox_{}", setup.test_id),
+            password: String::from("password"),
+        },
+        None,
+    )
+    .await;
+
+    let messages = OutboxEmailTransport::get_messages(&email);
+
+    assert_eq!(messages.len(), 1);
+    assert!(messages[0]
+        .text
+        .contains(&format!("{CLIENT_URL}/api/confirm?key=")));
+    assert!(messages[0]
+        .html
+        .contains(&format!("{CLIENT_URL}/api/confirm?key=")));
+
+    // the emailed key is the one accepted by confirmation
+    let user_auth = AuthRequestHandler::confirm(
+        &setup,
+        &UserConfirmBody {
+            email: email.clone(),
+            otp: get_key(&messages[0]),
+        },
+    )
+    .await;
+
+    assert_eq!(user_auth.user.email, email);
+
+    AuthRequestHandler::reset(
+        &setup,
+        &UserResetBody {
+            email: email.clone(),
+        },
+    )
+    .await;
+
+    let messages = OutboxEmailTransport::get_messages(&email);
+
+    assert_eq!(messages.len(), 2);
+    assert!(messages[1]
+        .text
+        .contains(&format!("{CLIENT_URL}/reset/confirm?key=")));
+    assert_ne!(get_key(&messages[1]), get_key(&messages[0]));
+
+    env::remove_var(OTP_RESEND_COOLDOWN_SEC_ENV);
+}
@@ -0,0 +1,134 @@
+use rocket::http::Status;
+use std::env;
+
+use super::jwt_handler::JwtHandler;
+use super::setup::{SetupOptions, TestSetup, TestUser, TEST_TRUSTED_PROXY};
+use super::test_handler::request_handler::auth::{AuthRequest, AuthRequestHandler};
+
+use super::dtm::auth::request_body::{UserLoginBody, UserSignupBody};
+use super::dtm_common::UserRoleId;
+
+const LOGIN_MAX_FAILURES_ENV: &str = "LOGIN_MAX_FAILURES";
+const LOGIN_IP_MAX_FAILURES_ENV: &str = "LOGIN_IP_MAX_FAILURES";
+
+fn get_login_body(setup: &TestSetup, prefix: &str, password: &str) -> UserLoginBody {
+    UserLoginBody {
+        email: format!("{}_{}@mail.com", prefix, setup.test_id),
+        password: String::from(password),
+    }
+}
+
+#[tokio::test]
+async fn account_lockout() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let admin_token = setup.user_handler.get_token(TestUser::Admin1).unwrap();
+
+    env::set_var(LOGIN_MAX_FAILURES_ENV, "3");
+
+    let login_body = get_login_body(&setup, "lock", "password");
+    let token = AuthRequestHandler::signup_with_role(
+        &setup.client,
+        &UserSignupBody {
+            name: format!("lock_{}", setup.test_id),
+            email: login_body.email.clone(),
+            password: login_body.password.clone(),
+        },
+        UserRoleId::Common as i32,
+    )
+    .await;
+    let user_id = JwtHandler::decode_jwt(token.token).unwrap().user_id;
+
+    let invalid_login_body = get_login_body(&setup, "lock", "invalid");
+
+    for _ in 0..3 {
+        let response = AuthRequest::login(&setup, &invalid_login_body).await;
+
+        assert_eq!(response.status(), Status::Unauthorized);
+    }
+
+    // valid credentials are rejected until the lock expires
+    let locked_response = AuthRequest::login(&setup, &login_body).await;
+
+    assert_eq!(locked_response.status(), Status::Locked);
+
+    let forbidden_response = AuthRequest::unlock_user(
+        &setup,
+        user_id,
+        setup.user_handler.get_token(TestUser::Common1).unwrap(),
+    )
+    .await;
+
+    assert_eq!(forbidden_response.status(), Status::Forbidden);
+
+    let unlock_response = AuthRequest::unlock_user(&setup, user_id, admin_token).await;
+
+    assert_eq!(unlock_response.status(), Status::Ok);
+
+    AuthRequestHandler::login(&setup, &login_body).await;
+
+    // unknown emails are locked the same way as existing accounts
+    let unknown_login_body = get_login_body(&setup, "lock_unknown", "invalid");
+
+    for _ in 0..3 {
+        let response = AuthRequest::login(&setup, &unknown_login_body).await;
+
+        assert_eq!(response.status(), Status::Unauthorized);
+    }
+
+    let unknown_locked_response = AuthRequest::login(&setup, &unknown_login_body).await;
+
+    assert_eq!(unknown_locked_response.status(), Status::Locked);
+
+    env::remove_var(LOGIN_MAX_FAILURES_ENV);
+}
+
+#[tokio::test]
+async fn ip_lockout() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+
+    env::set_var(LOGIN_IP_MAX_FAILURES_ENV, "3");
+
+    let octets = (setup.test_id % (1 << 24)) as u32;
+    let client_ip = format!(
+        "10.{}.{}.{}",
+        octets >> 16,
+        (octets >> 8) & 255,
+        octets & 255
+    );
+    let other_client_ip = format!(
+        "11.{}.{}.{}",
+        octets >> 16,
+        (octets >> 8) & 255,
+        octets & 255
+    );
+
+    for index in 0..3 {
+        let login_body = get_login_body(&setup, &format!("unknown{index}"), "password");
+        let response = AuthRequest::login_from(&setup, &login_body, &client_ip, None).await;
+
+        assert_eq!(response.status(), Status::Unauthorized);
+    }
+
+    let login_body = get_login_body(&setup, "unknown", "password");
+
+    let locked_response = AuthRequest::login_from(&setup, &login_body, &client_ip, None).await;
+
+    assert_eq!(locked_response.status(), Status::Locked);
+
+    let other_response = AuthRequest::login_from(&setup, &login_body, &other_client_ip, None).await;
+
+    assert_eq!(other_response.status(), Status::Unauthorized);
+
+    // the forwarded ip is ignored unless the request comes from a trusted proxy
+    let spoofed_response =
+        AuthRequest::login_from(&setup, &login_body, &other_client_ip, Some(&client_ip)).await;
+
+    assert_eq!(spoofed_response.status(), Status::Unauthorized);
+
+    let proxied_response =
+        AuthRequest::login_from(&setup, &login_body, TEST_TRUSTED_PROXY, Some(&client_ip)).await;
+
+    assert_eq!(proxied_response.status(), Status::Locked);
+
+    env::remove_var(LOGIN_IP_MAX_FAILURES_ENV);
+}
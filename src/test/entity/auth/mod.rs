@@ -0,0 +1,16 @@
+use super::dtm;
+use super::dtm_common;
+use super::emailer;
+use super::jwt_handler;
+use super::repository;
+use super::setup;
+use super::test_handler;
+use super::totp_handler;
+
+pub mod api_token_test;
+pub mod email_test;
+pub mod login_lock_test;
+pub mod oidc_test;
+pub mod password_test;
+pub mod session_test;
+pub mod totp_test;
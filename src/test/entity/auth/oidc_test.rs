@@ -0,0 +1,207 @@
+use reqwest::Url;
+use rocket::http::Status;
+use std::env;
+
+use super::jwt_handler::JwtHandler;
+use super::setup::{SetupOptions, TestIdentityProvider, TestSetup, TEST_IDP_CLIENT_SECRET};
+use super::test_handler::request_handler::auth::{AuthRequest, AuthRequestHandler};
+
+use super::dtm::auth::request_body::{UserOidcCallbackBody, UserSignupBody};
+use super::dtm_common::UserRoleId;
+
+const PROVIDER: &str = "test_idp";
+
+fn set_provider_env() {
+    env::set_var("OIDC_PROVIDERS", format!("other_idp, {PROVIDER}"));
+    env::set_var("OIDC_TEST_IDP_ISSUER", TestIdentityProvider::get_issuer());
+    env::set_var("OIDC_TEST_IDP_CLIENT_ID", "wiki");
+    env::set_var("OIDC_TEST_IDP_CLIENT_SECRET", TEST_IDP_CLIENT_SECRET);
+    env::set_var(
+        "OIDC_TEST_IDP_REDIRECT_URI",
+        "http://localhost/oidc/callback",
+    );
+}
+
+async fn get_state(setup: &TestSetup) -> String {
+    let authorization = AuthRequestHandler::oidc_authorize(setup, PROVIDER).await;
+    let url = Url::parse(&authorization.authorization_url).unwrap();
+
+    assert!(authorization.authorization_url.starts_with(&format!(
+        "{}/authorize?",
+        TestIdentityProvider::get_issuer()
+    )));
+    assert!(url
+        .query_pairs()
+        .any(|(key, value)| key == "client_id" && value == "wiki"));
+
+    url.query_pairs()
+        .find(|(key, _)| key == "state")
+        .map(|(_, value)| value.to_string())
+        .unwrap()
+}
+
+async fn login(setup: &TestSetup, code: String) -> i32 {
+    let state = get_state(setup).await;
+    let login_aggregation =
+        AuthRequestHandler::oidc_callback(setup, PROVIDER, &UserOidcCallbackBody { code, state })
+            .await;
+
+    JwtHandler::decode_jwt(login_aggregation.token.unwrap().token)
+        .unwrap()
+        .user_id
+}
+
+#[tokio::test]
+async fn oidc_provisioning() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+
+    set_provider_env();
+
+    let subject = format!("oidc_{}", setup.test_id);
+    let email = format!("oidc_{}@idp.mail.com", setup.test_id);
+
+    let state = get_state(&setup).await;
+    let login_aggregation = AuthRequestHandler::oidc_callback(
+        &setup,
+        PROVIDER,
+        &UserOidcCallbackBody {
+            state,
+            code: TestIdentityProvider::get_code(&subject, &email, true),
+        },
+    )
+    .await;
+
+    assert_eq!(login_aggregation.user.email, email);
+    assert_eq!(login_aggregation.user.role_id, UserRoleId::Common as i32);
+
+    let user_id = JwtHandler::decode_jwt(login_aggregation.token.unwrap().token)
+        .unwrap()
+        .user_id;
+
+    // identity is matched by subject, even if the email changes on provider side
+    let changed_email = format!("oidc_changed_{}@idp.mail.com", setup.test_id);
+    let relogin_user_id = login(
+        &setup,
+        TestIdentityProvider::get_code(&subject, &changed_email, true),
+    )
+    .await;
+
+    assert_eq!(relogin_user_id, user_id);
+
+    let unverified_response = AuthRequest::oidc_callback(
+        &setup,
+        PROVIDER,
+        &UserOidcCallbackBody {
+            state: get_state(&setup).await,
+            code: TestIdentityProvider::get_code(
+                &format!("oidc_unverified_{}", setup.test_id),
+                &format!("oidc_unverified_{}@idp.mail.com", setup.test_id),
+                false,
+            ),
+        },
+    )
+    .await;
+
+    assert_eq!(unverified_response.status(), Status::Forbidden);
+}
+
+#[tokio::test]
+async fn oidc_linking() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+
+    set_provider_env();
+
+    let email = format!("oidc_link_{}@mail.com", setup.test_id);
+    let token = AuthRequestHandler::signup_with_role(
+        &setup.client,
+        &UserSignupBody {
+            email: email.clone(),
+            name: format!("oidc_link_{}", setup.test_id),
+            password: String::from("password"),
+        },
+        UserRoleId::Common as i32,
+    )
+    .await;
+    let user_id = JwtHandler::decode_jwt(token.token).unwrap().user_id;
+
+    let subject = format!("oidc_link_{}", setup.test_id);
+    let linked_user_id = login(
+        &setup,
+        TestIdentityProvider::get_code(&subject, &email, true),
+    )
+    .await;
+
+    assert_eq!(linked_user_id, user_id);
+
+    // an already linked subject keeps its account
+    let other_email = format!("oidc_other_{}@mail.com", setup.test_id);
+    let relinked_user_id = login(
+        &setup,
+        TestIdentityProvider::get_code(&subject, &other_email, true),
+    )
+    .await;
+
+    assert_eq!(relinked_user_id, user_id);
+}
+
+#[tokio::test]
+async fn oidc_invalid_callback() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+
+    set_provider_env();
+
+    let code = TestIdentityProvider::get_code(
+        &format!("oidc_invalid_{}", setup.test_id),
+        &format!("oidc_invalid_{}@idp.mail.com", setup.test_id),
+        true,
+    );
+
+    let unknown_provider_response = AuthRequest::oidc_authorize(&setup, "unknown_idp").await;
+
+    assert_eq!(unknown_provider_response.status(), Status::NotFound);
+
+    let invalid_state_response = AuthRequest::oidc_callback(
+        &setup,
+        PROVIDER,
+        &UserOidcCallbackBody {
+            code: code.clone(),
+            state: String::from("invalid"),
+        },
+    )
+    .await;
+
+    assert_eq!(invalid_state_response.status(), Status::Unauthorized);
+
+    // state is bound to the provider it was issued for
+    env::set_var("OIDC_OTHER_IDP_ISSUER", TestIdentityProvider::get_issuer());
+    env::set_var("OIDC_OTHER_IDP_CLIENT_ID", "wiki");
+    env::set_var("OIDC_OTHER_IDP_CLIENT_SECRET", TEST_IDP_CLIENT_SECRET);
+    env::set_var(
+        "OIDC_OTHER_IDP_REDIRECT_URI",
+        "http://localhost/oidc/callback",
+    );
+
+    let other_provider_response = AuthRequest::oidc_callback(
+        &setup,
+        "other_idp",
+        &UserOidcCallbackBody {
+            code: code.clone(),
+            state: get_state(&setup).await,
+        },
+    )
+    .await;
+
+    assert_eq!(other_provider_response.status(), Status::Unauthorized);
+
+    let invalid_code_response = AuthRequest::oidc_callback(
+        &setup,
+        PROVIDER,
+        &UserOidcCallbackBody {
+            code: String::from("invalid"),
+            state: get_state(&setup).await,
+        },
+    )
+    .await;
+
+    assert_eq!(invalid_code_response.status(), Status::Unauthorized);
+}
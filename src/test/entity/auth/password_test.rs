@@ -0,0 +1,38 @@
+use std::env;
+
+use super::setup::{SetupOptions, TestSetup};
+use super::test_handler::request_handler::auth::AuthRequestHandler;
+
+use super::dtm::auth::request_body::{UserLoginBody, UserSignupBody};
+use super::dtm_common::UserRoleId;
+
+const PWD_MEMORY_COST_ENV: &str = "PWD_MEMORY_COST";
+
+#[tokio::test]
+async fn login_with_outdated_hash() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+
+    let signup_body = UserSignupBody {
+        name: format!("password_{}", setup.test_id),
+        email: format!("password_{}@mail.com", setup.test_id),
+        password: String::from("password"),
+    };
+    let login_body = UserLoginBody {
+        email: signup_body.email.clone(),
+        password: signup_body.password.clone(),
+    };
+
+    env::set_var(PWD_MEMORY_COST_ENV, "8192");
+
+    AuthRequestHandler::signup_with_role(&setup.client, &signup_body, UserRoleId::Common as i32)
+        .await;
+
+    env::remove_var(PWD_MEMORY_COST_ENV);
+
+    // first login rehashes the password with current parameters, second one verifies the new hash
+    for _ in 0..2 {
+        let user_auth = AuthRequestHandler::login(&setup, &login_body).await;
+
+        assert_eq!(user_auth.user.email, login_body.email);
+    }
+}
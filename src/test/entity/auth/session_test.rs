@@ -0,0 +1,126 @@
+use rocket::http::Status;
+
+use super::setup::{SetupOptions, TestSetup};
+use super::test_handler::request_handler::auth::{AuthRequest, AuthRequestHandler};
+
+use super::dtm::auth::request_body::{UserLoginBody, UserRefreshBody, UserSignupBody};
+use super::dtm_common::UserRoleId;
+
+async fn create_user(setup: &TestSetup) -> UserLoginBody {
+    let signup_body = UserSignupBody {
+        name: format!("session_{}", setup.test_id),
+        email: format!("session_{}@mail.com", setup.test_id),
+        password: String::from("password"),
+    };
+
+    AuthRequestHandler::signup_with_role(&setup.client, &signup_body, UserRoleId::Common as i32)
+        .await;
+
+    UserLoginBody {
+        email: signup_body.email,
+        password: signup_body.password,
+    }
+}
+
+async fn assert_user_status(setup: &TestSetup, jwt_token: &String, status: Status) {
+    let response = AuthRequest::get_user(setup, jwt_token.clone()).await;
+
+    assert_eq!(response.status(), status);
+}
+
+#[tokio::test]
+async fn refresh_token_rotation() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let login_body = create_user(&setup).await;
+
+    let login_token = AuthRequestHandler::login(&setup, &login_body)
+        .await
+        .token
+        .unwrap();
+
+    let refreshed_token = AuthRequestHandler::refresh(
+        &setup,
+        &UserRefreshBody {
+            refresh_token: login_token.refresh_token.clone(),
+        },
+    )
+    .await
+    .token;
+
+    assert_ne!(refreshed_token.refresh_token, login_token.refresh_token);
+
+    assert_user_status(&setup, &login_token.token, Status::Unauthorized).await;
+    assert_user_status(&setup, &refreshed_token.token, Status::Ok).await;
+
+    // reuse of rotated refresh token revokes the whole session family
+    let reused_response = AuthRequest::refresh(
+        &setup,
+        &UserRefreshBody {
+            refresh_token: login_token.refresh_token,
+        },
+    )
+    .await;
+
+    assert_eq!(reused_response.status(), Status::Unauthorized);
+
+    assert_user_status(&setup, &refreshed_token.token, Status::Unauthorized).await;
+
+    let invalid_response = AuthRequest::refresh(
+        &setup,
+        &UserRefreshBody {
+            refresh_token: String::from("invalid"),
+        },
+    )
+    .await;
+
+    assert_eq!(invalid_response.status(), Status::Unauthorized);
+}
+
+#[tokio::test]
+async fn logout_sessions() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let login_body = create_user(&setup).await;
+
+    let first_token = AuthRequestHandler::login(&setup, &login_body)
+        .await
+        .token
+        .unwrap();
+    let second_token = AuthRequestHandler::login(&setup, &login_body)
+        .await
+        .token
+        .unwrap();
+    let third_token = AuthRequestHandler::login(&setup, &login_body)
+        .await
+        .token
+        .unwrap();
+
+    AuthRequestHandler::logout(&setup, first_token.token.clone()).await;
+
+    assert_user_status(&setup, &first_token.token, Status::Unauthorized).await;
+    assert_user_status(&setup, &second_token.token, Status::Ok).await;
+
+    let logged_out_response = AuthRequest::refresh(
+        &setup,
+        &UserRefreshBody {
+            refresh_token: first_token.refresh_token,
+        },
+    )
+    .await;
+
+    assert_eq!(logged_out_response.status(), Status::Unauthorized);
+
+    AuthRequestHandler::logout_all(&setup, second_token.token.clone()).await;
+
+    assert_user_status(&setup, &second_token.token, Status::Unauthorized).await;
+    assert_user_status(&setup, &third_token.token, Status::Unauthorized).await;
+
+    let logged_out_response = AuthRequest::refresh(
+        &setup,
+        &UserRefreshBody {
+            refresh_token: third_token.refresh_token,
+        },
+    )
+    .await;
+
+    assert_eq!(logged_out_response.status(), Status::Unauthorized);
+}
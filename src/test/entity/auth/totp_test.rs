@@ -0,0 +1,280 @@
+use rocket::http::Status;
+use std::env;
+
+use super::emailer::OutboxEmailTransport;
+use super::setup::{SetupOptions, TestSetup};
+use super::test_handler::request_handler::auth::{AuthRequest, AuthRequestHandler};
+use super::totp_handler::TotpHandler;
+
+use super::dtm::auth::request_body::{
+    UserChallengeBody, UserChallengeEnrollBody, UserConfirmPasswordResetBody, UserLoginBody,
+    UserResetBody, UserSignupBody, UserTotpCodeBody,
+};
+use super::dtm_common::{ChallengePurpose, UserRoleId};
+
+const TOTP_REQUIRED_FOR_ADMIN_ENV: &str = "TOTP_REQUIRED_FOR_ADMIN";
+const CLIENT_URL_ENV: &str = "CLIENT_URL";
+
+const CLIENT_URL: &str = "http://client.test";
+
+async fn create_user(setup: &TestSetup, prefix: &str, role_id: UserRoleId) -> UserLoginBody {
+    let signup_body = UserSignupBody {
+        name: format!("{}_{}", prefix, setup.test_id),
+        email: format!("{}_{}@mail.com", prefix, setup.test_id),
+        password: String::from("password"),
+    };
+
+    AuthRequestHandler::signup_with_role(&setup.client, &signup_body, role_id as i32).await;
+
+    UserLoginBody {
+        email: signup_body.email,
+        password: signup_body.password,
+    }
+}
+
+fn get_code(secret: &String, step_offset: i64) -> String {
+    TotpHandler::generate_code(secret, TotpHandler::get_current_step() + step_offset).unwrap()
+}
+
+#[tokio::test]
+async fn totp_rfc_vectors() {
+    // RFC 6238 appendix B secret, codes are the last 6 digits of SHA1 ones
+    let secret = String::from("GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ");
+
+    assert_eq!(
+        TotpHandler::generate_code(&secret, 59 / 30).unwrap(),
+        "287082"
+    );
+    assert_eq!(
+        TotpHandler::generate_code(&secret, 1111111109 / 30).unwrap(),
+        "081804"
+    );
+    assert_eq!(
+        TotpHandler::generate_code(&secret, 2000000000 / 30).unwrap(),
+        "279037"
+    );
+}
+
+#[tokio::test]
+async fn totp_two_step_login() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let login_body = create_user(&setup, "totp", UserRoleId::Common).await;
+
+    let token = AuthRequestHandler::login(&setup, &login_body)
+        .await
+        .token
+        .unwrap()
+        .token;
+
+    let enrollment = AuthRequestHandler::start_totp(&setup, token.clone()).await;
+
+    assert!(enrollment.provisioning_uri.starts_with("otpauth://totp/"));
+    assert!(enrollment
+        .provisioning_uri
+        .contains(&format!("secret={}", enrollment.secret)));
+
+    let invalid_confirm_response = AuthRequest::confirm_totp(
+        &setup,
+        &UserTotpCodeBody {
+            code: String::from("000000x"),
+        },
+        token.clone(),
+    )
+    .await;
+
+    assert_eq!(invalid_confirm_response.status(), Status::Unauthorized);
+
+    let recovery_codes = AuthRequestHandler::confirm_totp(
+        &setup,
+        &UserTotpCodeBody {
+            code: get_code(&enrollment.secret, 0),
+        },
+        token.clone(),
+    )
+    .await
+    .recovery_codes;
+
+    assert_eq!(recovery_codes.len(), 10);
+
+    let login_aggregation = AuthRequestHandler::login(&setup, &login_body).await;
+
+    assert!(login_aggregation.token.is_none());
+
+    let challenge = login_aggregation.challenge.unwrap();
+
+    assert_eq!(challenge.purpose, ChallengePurpose::Totp);
+
+    // the code used for confirmation can't be replayed
+    let replayed_response = AuthRequest::login_totp(
+        &setup,
+        &UserChallengeBody {
+            challenge_token: challenge.challenge_token.clone(),
+            code: get_code(&enrollment.secret, -1),
+        },
+    )
+    .await;
+
+    assert_eq!(replayed_response.status(), Status::Unauthorized);
+
+    AuthRequestHandler::login_totp(
+        &setup,
+        &UserChallengeBody {
+            challenge_token: challenge.challenge_token.clone(),
+            code: recovery_codes[0].clone(),
+        },
+    )
+    .await;
+
+    let used_recovery_response = AuthRequest::login_totp(
+        &setup,
+        &UserChallengeBody {
+            challenge_token: challenge.challenge_token.clone(),
+            code: recovery_codes[0].clone(),
+        },
+    )
+    .await;
+
+    assert_eq!(used_recovery_response.status(), Status::Unauthorized);
+
+    let user_auth = AuthRequestHandler::login_totp(
+        &setup,
+        &UserChallengeBody {
+            challenge_token: challenge.challenge_token,
+            code: get_code(&enrollment.secret, 1),
+        },
+    )
+    .await;
+
+    let disable_response = AuthRequest::disable_totp(
+        &setup,
+        &UserTotpCodeBody {
+            code: recovery_codes[1].clone(),
+        },
+        user_auth.token.token,
+    )
+    .await;
+
+    assert_eq!(disable_response.status(), Status::Ok);
+
+    let login_aggregation = AuthRequestHandler::login(&setup, &login_body).await;
+
+    assert!(login_aggregation.token.is_some());
+}
+
+#[tokio::test]
+async fn totp_required_for_admin() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let login_body = create_user(&setup, "totp_admin", UserRoleId::Admin).await;
+
+    env::set_var(TOTP_REQUIRED_FOR_ADMIN_ENV, "true");
+
+    let login_aggregation = AuthRequestHandler::login(&setup, &login_body).await;
+
+    assert!(login_aggregation.token.is_none());
+
+    let challenge = login_aggregation.challenge.unwrap();
+
+    assert_eq!(challenge.purpose, ChallengePurpose::Enrollment);
+
+    // enrollment challenge can't be used to pass 2fa
+    let wrong_purpose_response = AuthRequest::login_totp(
+        &setup,
+        &UserChallengeBody {
+            challenge_token: challenge.challenge_token.clone(),
+            code: String::from("000000"),
+        },
+    )
+    .await;
+
+    assert_eq!(wrong_purpose_response.status(), Status::Unauthorized);
+
+    let enrollment = AuthRequestHandler::login_totp_enroll(
+        &setup,
+        &UserChallengeEnrollBody {
+            challenge_token: challenge.challenge_token.clone(),
+        },
+    )
+    .await;
+
+    let user_enrollment = AuthRequestHandler::login_totp_confirm(
+        &setup,
+        &UserChallengeBody {
+            challenge_token: challenge.challenge_token,
+            code: get_code(&enrollment.secret, 0),
+        },
+    )
+    .await;
+
+    assert_eq!(user_enrollment.recovery_codes.len(), 10);
+
+    let disable_response = AuthRequest::disable_totp(
+        &setup,
+        &UserTotpCodeBody {
+            code: user_enrollment.recovery_codes[0].clone(),
+        },
+        user_enrollment.token.token,
+    )
+    .await;
+
+    assert_eq!(disable_response.status(), Status::Forbidden);
+
+    env::remove_var(TOTP_REQUIRED_FOR_ADMIN_ENV);
+}
+
+#[tokio::test]
+async fn totp_password_reset() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let login_body = create_user(&setup, "totp_reset", UserRoleId::Common).await;
+
+    env::set_var(CLIENT_URL_ENV, CLIENT_URL);
+
+    let token = AuthRequestHandler::login(&setup, &login_body)
+        .await
+        .token
+        .unwrap()
+        .token;
+
+    let enrollment = AuthRequestHandler::start_totp(&setup, token.clone()).await;
+
+    AuthRequestHandler::confirm_totp(
+        &setup,
+        &UserTotpCodeBody {
+            code: get_code(&enrollment.secret, 0),
+        },
+        token,
+    )
+    .await;
+
+    AuthRequestHandler::reset(
+        &setup,
+        &UserResetBody {
+            email: login_body.email.clone(),
+        },
+    )
+    .await;
+
+    let messages = OutboxEmailTransport::get_messages(&login_body.email);
+    let url = reqwest::Url::parse(messages.last().unwrap().text.lines().last().unwrap()).unwrap();
+    let otp = url
+        .query_pairs()
+        .find(|(key, _)| key == "key")
+        .map(|(_, value)| value.to_string())
+        .unwrap();
+
+    // the emailed code replaces the password, not the second factor
+    let reset_aggregation = AuthRequestHandler::confirm_reset(
+        &setup,
+        &UserConfirmPasswordResetBody {
+            email: login_body.email,
+            otp,
+            password: String::from("new_password"),
+        },
+    )
+    .await;
+
+    assert!(reset_aggregation.token.is_none());
+    assert_eq!(
+        reset_aggregation.challenge.unwrap().purpose,
+        ChallengePurpose::Totp
+    );
+}
@@ -0,0 +1,143 @@
+use rocket::http::Status;
+
+use super::setup::{SetupOptions, TestSetup, TestUser};
+use super::test_handler::request_handler::{
+    article::{ArticleRequest, ArticleRequestHandler},
+    category::CategoryRequestHandler,
+};
+
+use super::dtm::{
+    article::request_body::ArticleCreateRelationsBody, category::request_body::CategoryCreateBody,
+};
+use super::repository::entity::article::ArticleType;
+
+#[tokio::test]
+async fn attach_detach_article_category() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let admin_token = setup.user_handler.get_token(TestUser::Admin1).unwrap();
+
+    let category = CategoryRequestHandler::create_category(
+        &setup,
+        &CategoryCreateBody {
+            name: format!("{}_category", setup.test_id),
+        },
+        admin_token.clone(),
+    )
+    .await;
+
+    let article = ArticleRequestHandler::create_article(
+        &setup,
+        &ArticleCreateRelationsBody {
+            name: format!("{}_article", setup.test_id),
+            content: String::from("test content"),
+            language: String::from("ua"),
+            article_type: ArticleType::Public,
+        },
+        admin_token.clone(),
+    )
+    .await;
+
+    let other_article = ArticleRequestHandler::create_article(
+        &setup,
+        &ArticleCreateRelationsBody {
+            name: format!("{}_other_article", setup.test_id),
+            content: String::from("test content"),
+            language: String::from("ua"),
+            article_type: ArticleType::Public,
+        },
+        admin_token.clone(),
+    )
+    .await;
+
+    ArticleRequestHandler::attach_article_category(
+        &setup,
+        article.id,
+        category.id,
+        admin_token.clone(),
+    )
+    .await;
+
+    let received_article = ArticleRequestHandler::get_article(&setup, article.id).await;
+
+    assert_eq!(received_article.categories.len(), 1);
+    assert_eq!(received_article.categories[0].id, category.id);
+
+    let filtered_articles =
+        ArticleRequestHandler::get_articles_by_category(&setup, category.id).await;
+
+    assert_eq!(filtered_articles.len(), 1);
+    assert_eq!(filtered_articles[0].id, article.id);
+    assert!(filtered_articles
+        .iter()
+        .all(|filtered_article| filtered_article.id != other_article.id));
+
+    let response_duplicated = ArticleRequest::attach_article_category(
+        &setup,
+        article.id,
+        category.id,
+        admin_token.clone(),
+    )
+    .await;
+
+    assert_eq!(response_duplicated.status(), Status::BadRequest);
+
+    ArticleRequestHandler::detach_article_category(
+        &setup,
+        article.id,
+        category.id,
+        admin_token.clone(),
+    )
+    .await;
+
+    let received_article = ArticleRequestHandler::get_article(&setup, article.id).await;
+
+    assert!(received_article.categories.is_empty());
+
+    let filtered_articles =
+        ArticleRequestHandler::get_articles_by_category(&setup, category.id).await;
+
+    assert!(filtered_articles.is_empty());
+}
+
+#[tokio::test]
+async fn attach_article_category_wrong() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let admin_token = setup.user_handler.get_token(TestUser::Admin1).unwrap();
+    let user_token = setup.user_handler.get_token(TestUser::Common1).unwrap();
+
+    let category = CategoryRequestHandler::create_category(
+        &setup,
+        &CategoryCreateBody {
+            name: format!("{}_category", setup.test_id),
+        },
+        admin_token.clone(),
+    )
+    .await;
+
+    let article = ArticleRequestHandler::create_article(
+        &setup,
+        &ArticleCreateRelationsBody {
+            name: format!("{}_article", setup.test_id),
+            content: String::from("test content"),
+            language: String::from("ua"),
+            article_type: ArticleType::Public,
+        },
+        admin_token.clone(),
+    )
+    .await;
+
+    let response_not_permitted =
+        ArticleRequest::attach_article_category(&setup, article.id, category.id, user_token).await;
+
+    assert_eq!(response_not_permitted.status(), Status::Forbidden);
+
+    let response_wrong_category =
+        ArticleRequest::attach_article_category(&setup, article.id, -1, admin_token.clone()).await;
+
+    assert_eq!(response_wrong_category.status(), Status::NotFound);
+
+    let response_not_attached =
+        ArticleRequest::detach_article_category(&setup, article.id, category.id, admin_token).await;
+
+    assert_eq!(response_not_attached.status(), Status::NotFound);
+}
@@ -0,0 +1,95 @@
+use rocket::http::Status;
+
+use super::setup::{SetupOptions, TestSetup, TestUser};
+use super::test_handler::request_handler::category::{CategoryRequest, CategoryRequestHandler};
+
+use super::dtm::category::request_body::{CategoryCreateBody, CategoryPatchBody};
+
+#[tokio::test]
+async fn create_get_category() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let admin_token = setup.user_handler.get_token(TestUser::Admin1).unwrap();
+
+    let creation_body = CategoryCreateBody {
+        name: format!("{}_category", setup.test_id),
+    };
+
+    let created_category =
+        CategoryRequestHandler::create_category(&setup, &creation_body, admin_token).await;
+
+    assert_eq!(created_category.name, creation_body.name);
+
+    let received_category = CategoryRequestHandler::get_category(&setup, created_category.id).await;
+
+    assert_eq!(received_category.name, creation_body.name);
+
+    let received_categories = CategoryRequestHandler::get_categories(&setup).await;
+
+    assert!(received_categories
+        .iter()
+        .any(|category| category.id == created_category.id));
+}
+
+#[tokio::test]
+async fn create_category_wrong() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let admin_token = setup.user_handler.get_token(TestUser::Admin1).unwrap();
+    let user_token = setup.user_handler.get_token(TestUser::Common1).unwrap();
+
+    let creation_body = CategoryCreateBody {
+        name: format!("{}_category", setup.test_id),
+    };
+
+    let response_not_permitted =
+        CategoryRequest::create_category(&setup, &creation_body, user_token).await;
+
+    assert_eq!(response_not_permitted.status(), Status::Forbidden);
+
+    CategoryRequestHandler::create_category(&setup, &creation_body, admin_token.clone()).await;
+
+    let response_duplicated =
+        CategoryRequest::create_category(&setup, &creation_body, admin_token).await;
+
+    assert_eq!(response_duplicated.status(), Status::BadRequest);
+}
+
+#[tokio::test]
+async fn patch_delete_category() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let admin_token = setup.user_handler.get_token(TestUser::Admin1).unwrap();
+
+    let created_category = CategoryRequestHandler::create_category(
+        &setup,
+        &CategoryCreateBody {
+            name: format!("{}_category", setup.test_id),
+        },
+        admin_token.clone(),
+    )
+    .await;
+
+    let patch_body = CategoryPatchBody {
+        name: Some(format!("{}_category_patched", setup.test_id)),
+    };
+
+    let patched_category = CategoryRequestHandler::patch_category(
+        &setup,
+        created_category.id,
+        &patch_body,
+        admin_token.clone(),
+    )
+    .await;
+
+    assert_eq!(Some(patched_category.name), patch_body.name);
+    assert!(patched_category.updated_at.is_some());
+
+    CategoryRequestHandler::delete_category(&setup, created_category.id, admin_token.clone()).await;
+
+    let response_deleted = CategoryRequest::get_category(&setup, created_category.id).await;
+
+    assert_eq!(response_deleted.status(), Status::NotFound);
+
+    let response_delete_again =
+        CategoryRequest::delete_category(&setup, created_category.id, admin_token).await;
+
+    assert_eq!(response_delete_again.status(), Status::NotFound);
+}
@@ -0,0 +1,7 @@
+use super::dtm;
+use super::repository;
+use super::setup;
+use super::test_handler;
+
+pub mod article_category_test;
+pub mod category_test;
@@ -0,0 +1,282 @@
+use rocket::http::Status;
+use std::env;
+
+use super::emailer::OutboxEmailTransport;
+use super::setup::{SetupOptions, TestSetup, TestUser};
+use super::test_handler::request_handler::{
+    auth::{AuthRequest, AuthRequestHandler},
+    email_template::{EmailTemplateRequest, EmailTemplateRequestHandler},
+};
+
+use super::dtm::auth::request_body::{UserLanguageBody, UserResetBody, UserSignupBody};
+use super::dtm::email_template::request_body::EmailTemplatePatchBody;
+use super::dtm_common::UserRoleId;
+use super::repository::entity::email_template::EmailTemplateType;
+
+const CLIENT_URL_ENV: &str = "CLIENT_URL";
+const OTP_RESEND_COOLDOWN_SEC_ENV: &str = "OTP_RESEND_COOLDOWN_SEC";
+
+const CLIENT_URL: &str = "http://client.test";
+
+fn get_signup_body(setup: &TestSetup, prefix: &str) -> UserSignupBody {
+    UserSignupBody {
+        email: format!("{}_{}@mail.com", prefix, setup.test_id),
+        name: format!("{}_{}", prefix, setup.test_id),
+        password: String::from("password"),
+    }
+}
+
+async fn get_subject(
+    setup: &TestSetup,
+    template_type: EmailTemplateType,
+    language: &str,
+    jwt_token: String,
+) -> String {
+    EmailTemplateRequestHandler::get_email_templates(setup, jwt_token)
+        .await
+        .into_iter()
+        .find(|email_template| {
+            email_template.template_type == template_type && email_template.language == language
+        })
+        .unwrap()
+        .subject
+}
+
+#[tokio::test]
+async fn email_template_preview() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let admin_token = setup.user_handler.get_token(TestUser::Admin1).unwrap();
+    let user_token = setup.user_handler.get_token(TestUser::Common1).unwrap();
+
+    env::set_var(CLIENT_URL_ENV, CLIENT_URL);
+
+    let ua_preview = EmailTemplateRequestHandler::preview_email_template(
+        &setup,
+        "confirmation",
+        "ua",
+        admin_token.clone(),
+    )
+    .await;
+    let en_preview = EmailTemplateRequestHandler::preview_email_template(
+        &setup,
+        "confirmation",
+        "en",
+        admin_token.clone(),
+    )
+    .await;
+
+    assert_eq!(ua_preview.template_type, EmailTemplateType::Confirmation);
+    assert_eq!(ua_preview.language, "ua");
+    assert_ne!(ua_preview.subject, en_preview.subject);
+
+    for preview in [&ua_preview, &en_preview] {
+        assert!(preview
+            .html
+            .contains(&format!("<a href=\"{CLIENT_URL}/api/confirm?key=preview")));
+        assert!(preview
+            .text
+            .contains(&format!("{CLIENT_URL}/api/confirm?key=preview")));
+        assert!(!preview.text.contains("{{"));
+    }
+
+    let reset_preview = EmailTemplateRequestHandler::preview_email_template(
+        &setup,
+        "reset",
+        "en",
+        admin_token.clone(),
+    )
+    .await;
+
+    assert!(reset_preview
+        .text
+        .contains(&format!("{CLIENT_URL}/reset/confirm?key=preview")));
+
+    let forbidden_response =
+        EmailTemplateRequest::preview_email_template(&setup, "reset", "en", user_token.clone())
+            .await;
+
+    assert_eq!(forbidden_response.status(), Status::Forbidden);
+
+    let forbidden_response = EmailTemplateRequest::get_email_templates(&setup, user_token).await;
+
+    assert_eq!(forbidden_response.status(), Status::Forbidden);
+
+    let unknown_type_response =
+        EmailTemplateRequest::preview_email_template(&setup, "unknown", "en", admin_token.clone())
+            .await;
+
+    assert_eq!(unknown_type_response.status(), Status::NotFound);
+
+    let unknown_language_response =
+        EmailTemplateRequest::preview_email_template(&setup, "reset", "xx", admin_token).await;
+
+    assert_eq!(unknown_language_response.status(), Status::NotFound);
+}
+
+#[tokio::test]
+async fn localized_emails() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let admin_token = setup.user_handler.get_token(TestUser::Admin1).unwrap();
+
+    env::set_var(CLIENT_URL_ENV, CLIENT_URL);
+    env::set_var(OTP_RESEND_COOLDOWN_SEC_ENV, "0");
+
+    let ua_subject = get_subject(
+        &setup,
+        EmailTemplateType::Confirmation,
+        "ua",
+        admin_token.clone(),
+    )
+    .await;
+    let en_subject = get_subject(
+        &setup,
+        EmailTemplateType::Confirmation,
+        "en",
+        admin_token.clone(),
+    )
+    .await;
+
+    let ua_body = get_signup_body(&setup, "email_ua");
+    AuthRequestHandler::signup(&setup, &ua_body, Some(String::from("ua"))).await;
+
+    let ua_messages = OutboxEmailTransport::get_messages(&ua_body.email);
+
+    assert_eq!(ua_messages.len(), 1);
+    assert_eq!(ua_messages[0].subject, ua_subject);
+    assert!(ua_messages[0].text.contains(&ua_body.name));
+
+    // placeholders inside substituted values are kept as is
+    let placeholder_body = UserSignupBody {
+        name: format!("{{{{url}}}}_{}", setup.test_id),
+        ..get_signup_body(&setup, "email_placeholder")
+    };
+    AuthRequestHandler::signup(&setup, &placeholder_body, None).await;
+
+    let placeholder_messages = OutboxEmailTransport::get_messages(&placeholder_body.email);
+
+    assert!(placeholder_messages[0]
+        .text
+        .contains(&placeholder_body.name));
+
+    // users without preferred language get the default one
+    let default_body = get_signup_body(&setup, "email_default");
+    AuthRequestHandler::signup(&setup, &default_body, None).await;
+
+    let default_messages = OutboxEmailTransport::get_messages(&default_body.email);
+
+    assert_eq!(default_messages[0].subject, en_subject);
+
+    let unknown_language_response = AuthRequest::signup(
+        &setup,
+        &get_signup_body(&setup, "email_unknown"),
+        Some(String::from("xx")),
+    )
+    .await;
+
+    assert_eq!(unknown_language_response.status(), Status::NotFound);
+
+    // edited template is used for the next emails
+    let patched_subject = format!("Password reset {}", setup.test_id);
+    let en_reset_template =
+        EmailTemplateRequestHandler::get_email_templates(&setup, admin_token.clone())
+            .await
+            .into_iter()
+            .find(|email_template| {
+                email_template.template_type == EmailTemplateType::Reset
+                    && email_template.language == "en"
+            })
+            .unwrap();
+
+    let patched_template = EmailTemplateRequestHandler::patch_email_template(
+        &setup,
+        "reset",
+        "en",
+        &EmailTemplatePatchBody {
+            subject: Some(patched_subject.clone()),
+            html: None,
+            text: None,
+        },
+        admin_token.clone(),
+    )
+    .await;
+
+    assert_eq!(patched_template.subject, patched_subject);
+    assert_eq!(patched_template.text, en_reset_template.text);
+
+    let empty_subject_response = EmailTemplateRequest::patch_email_template(
+        &setup,
+        "reset",
+        "en",
+        &EmailTemplatePatchBody {
+            subject: Some(String::from(" ")),
+            html: None,
+            text: None,
+        },
+        admin_token.clone(),
+    )
+    .await;
+
+    assert_eq!(empty_subject_response.status(), Status::NotAcceptable);
+
+    AuthRequestHandler::reset(
+        &setup,
+        &UserResetBody {
+            email: default_body.email.clone(),
+        },
+    )
+    .await;
+
+    let default_messages = OutboxEmailTransport::get_messages(&default_body.email);
+
+    assert_eq!(default_messages.len(), 2);
+    assert_eq!(default_messages[1].subject, patched_subject);
+
+    // preferred language can be changed later
+    let switch_body = get_signup_body(&setup, "email_switch");
+    let switch_token = AuthRequestHandler::signup_with_role(
+        &setup.client,
+        &switch_body,
+        UserRoleId::Common as i32,
+    )
+    .await;
+
+    AuthRequestHandler::patch_user_language(
+        &setup,
+        &UserLanguageBody {
+            language: String::from("ua"),
+        },
+        switch_token.token,
+    )
+    .await;
+
+    AuthRequestHandler::reset(
+        &setup,
+        &UserResetBody {
+            email: switch_body.email.clone(),
+        },
+    )
+    .await;
+
+    let switch_messages = OutboxEmailTransport::get_messages(&switch_body.email);
+
+    assert_eq!(switch_messages.len(), 1);
+    assert_eq!(
+        switch_messages[0].subject,
+        get_subject(&setup, EmailTemplateType::Reset, "ua", admin_token.clone()).await
+    );
+
+    EmailTemplateRequestHandler::patch_email_template(
+        &setup,
+        "reset",
+        "en",
+        &EmailTemplatePatchBody {
+            subject: Some(en_reset_template.subject),
+            html: None,
+            text: None,
+        },
+        admin_token,
+    )
+    .await;
+
+    env::remove_var(OTP_RESEND_COOLDOWN_SEC_ENV);
+}
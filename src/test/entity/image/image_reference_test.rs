@@ -0,0 +1,159 @@
+use rocket::http::Status;
+use std::env;
+
+use super::setup::{SetupOptions, TestSetup, TestUser};
+use super::test_handler::{
+    mock_handler::image::ImageMockHandler,
+    request_handler::{
+        article::ArticleRequestHandler,
+        image::{ImageRequest, ImageRequestHandler},
+    },
+};
+use super::upload_image_test::set_local_storage;
+
+use super::dtm::{
+    article::request_body::ArticleCreateRelationsBody, image::request_body::ImageCreateBody,
+};
+use super::repository::entity::article::ArticleType;
+
+const IMAGE_ORPHAN_TTL_HOURS_ENV: &str = "IMAGE_ORPHAN_TTL_HOURS";
+
+fn get_images_body() -> Vec<ImageCreateBody> {
+    vec![
+        ImageCreateBody {
+            id: 1,
+            base64: ImageMockHandler::get_png_base64(),
+            format: None,
+        },
+        ImageCreateBody {
+            id: 2,
+            base64: ImageMockHandler::get_png_base64(),
+            format: None,
+        },
+    ]
+}
+
+#[tokio::test]
+async fn image_metadata_and_references() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let user_token = setup.user_handler.get_token(TestUser::Common1).unwrap();
+
+    set_local_storage(&setup);
+
+    let created_images =
+        ImageRequestHandler::create_images(&setup, &get_images_body(), user_token.clone())
+            .await
+            .images;
+
+    let (referenced_image, unreferenced_image) = (&created_images[0], &created_images[1]);
+
+    assert_eq!(referenced_image.image.mime_type, "image/webp");
+    assert_eq!(referenced_image.image.width, Some(1));
+    assert_eq!(referenced_image.image.height, Some(1));
+    assert_eq!(
+        referenced_image.image.size,
+        referenced_image.image.renditions[0].size
+    );
+
+    let user_images = ImageRequestHandler::get_images(&setup, user_token.clone()).await;
+
+    for created_image in created_images.iter() {
+        assert!(user_images
+            .items
+            .iter()
+            .any(|image| image.id == created_image.image.id));
+    }
+
+    let article = ArticleRequestHandler::create_article(
+        &setup,
+        &ArticleCreateRelationsBody {
+            name: format!("{}_article", setup.test_id),
+            // thumbnail rendition refers to the same image
+            content: format!(
+                "test content ![image]({})",
+                referenced_image.image.renditions[1].uri
+            ),
+            language: String::from("ua"),
+            article_type: ArticleType::Public,
+        },
+        user_token.clone(),
+    )
+    .await;
+
+    let article_images = ImageRequestHandler::get_article_images(&setup, article.id).await;
+
+    assert_eq!(article_images.len(), 1);
+    assert_eq!(article_images[0].id, referenced_image.image.id);
+
+    let response_referenced =
+        ImageRequest::delete_image(&setup, referenced_image.image.id, user_token.clone()).await;
+
+    assert_eq!(response_referenced.status(), Status::NotAcceptable);
+
+    ImageRequestHandler::delete_image(&setup, unreferenced_image.image.id, user_token).await;
+
+    let response_deleted_file = ImageRequest::get_image_file(&setup, &unreferenced_image.uri).await;
+
+    assert_eq!(response_deleted_file.status(), Status::NotFound);
+
+    let response_missing_article = ImageRequest::get_article_images(&setup, i32::MAX).await;
+
+    assert_eq!(response_missing_article.status(), Status::NotFound);
+}
+
+#[tokio::test]
+async fn cleanup_unreferenced_images() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let admin_token = setup.user_handler.get_token(TestUser::Admin1).unwrap();
+    let user_token = setup.user_handler.get_token(TestUser::Common1).unwrap();
+
+    set_local_storage(&setup);
+    env::set_var(IMAGE_ORPHAN_TTL_HOURS_ENV, "0");
+
+    let created_images =
+        ImageRequestHandler::create_images(&setup, &get_images_body(), user_token.clone())
+            .await
+            .images;
+
+    let (referenced_image, unreferenced_image) = (&created_images[0], &created_images[1]);
+    let unreferenced_key = unreferenced_image.uri.rsplit('/').next().unwrap();
+
+    // a link outside of the storage doesn't reference an image with the same file name
+    ArticleRequestHandler::create_article(
+        &setup,
+        &ArticleCreateRelationsBody {
+            name: format!("{}_article", setup.test_id),
+            content: format!(
+                "test content ![image]({}) [link](https://example.com/{})",
+                referenced_image.uri, unreferenced_key
+            ),
+            language: String::from("ua"),
+            article_type: ArticleType::Public,
+        },
+        user_token.clone(),
+    )
+    .await;
+
+    let response_not_admin = ImageRequest::cleanup_images(&setup, user_token.clone()).await;
+
+    assert_eq!(response_not_admin.status(), Status::Forbidden);
+
+    let cleanup_result = ImageRequestHandler::cleanup_images(&setup, admin_token.clone()).await;
+
+    env::remove_var(IMAGE_ORPHAN_TTL_HOURS_ENV);
+
+    assert!(cleanup_result.deleted_count >= 1);
+
+    ImageRequestHandler::get_image_file(&setup, &referenced_image.uri).await;
+
+    for rendition in unreferenced_image.image.renditions.iter() {
+        let response_deleted_file = ImageRequest::get_image_file(&setup, &rendition.uri).await;
+
+        assert_eq!(response_deleted_file.status(), Status::NotFound);
+    }
+
+    let response_deleted_image =
+        ImageRequest::delete_image(&setup, unreferenced_image.image.id, admin_token).await;
+
+    assert_eq!(response_deleted_image.status(), Status::NotFound);
+}
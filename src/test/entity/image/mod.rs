@@ -0,0 +1,7 @@
+use super::dtm;
+use super::repository;
+use super::setup;
+use super::test_handler;
+
+pub mod image_reference_test;
+pub mod upload_image_test;
@@ -0,0 +1,201 @@
+use base64::{engine::general_purpose, Engine as _};
+use rocket::http::Status;
+use std::env;
+
+use super::setup::{SetupOptions, TestSetup, TestUser};
+use super::test_handler::{
+    mock_handler::image::ImageMockHandler,
+    request_handler::image::{ImageRequest, ImageRequestHandler},
+};
+
+use super::dtm::image::request_body::ImageCreateBody;
+
+const IMAGE_STORAGE_ENV: &str = "IMAGE_STORAGE";
+const IMAGE_STORAGE_PATH_ENV: &str = "IMAGE_STORAGE_PATH";
+const IMAGE_MAX_SIZE_ENV: &str = "IMAGE_MAX_SIZE";
+
+pub fn set_local_storage(setup: &TestSetup) {
+    env::set_var(IMAGE_STORAGE_ENV, "local");
+    env::set_var(
+        IMAGE_STORAGE_PATH_ENV,
+        env::temp_dir().join(format!("wiki_images_{}", setup.test_id)),
+    );
+}
+
+#[tokio::test]
+async fn upload_image_local_storage() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let user_token = setup.user_handler.get_token(TestUser::Common1).unwrap();
+
+    set_local_storage(&setup);
+
+    let created_images = ImageRequestHandler::create_images(
+        &setup,
+        &vec![ImageCreateBody {
+            id: 1,
+            base64: ImageMockHandler::get_png_base64(),
+            format: None,
+        }],
+        user_token,
+    )
+    .await;
+
+    assert_eq!(created_images.errors.len(), 0);
+    assert_eq!(created_images.images.len(), 1);
+    assert_eq!(created_images.images[0].id, 1);
+    assert!(created_images.images[0].uri.starts_with("/image/file/"));
+    assert!(created_images.images[0].uri.ends_with(".webp"));
+
+    assert_eq!(
+        created_images.images[0]
+            .image
+            .renditions
+            .iter()
+            .map(|rendition| rendition.name.clone())
+            .collect::<Vec<String>>(),
+        vec!["original", "thumbnail", "medium"]
+    );
+
+    for rendition in created_images.images[0].image.renditions.iter() {
+        let received_content = ImageRequestHandler::get_image_file(&setup, &rendition.uri).await;
+
+        assert_eq!(received_content.len() as i32, rendition.size);
+        assert_eq!(&received_content[..4], b"RIFF");
+    }
+
+    let response_missing_file =
+        ImageRequest::get_image_file(&setup, &String::from("/image/file/missing.png")).await;
+
+    assert_eq!(response_missing_file.status(), Status::NotFound);
+}
+
+#[tokio::test]
+async fn upload_image_local_storage_wrong() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let user_token = setup.user_handler.get_token(TestUser::Common1).unwrap();
+
+    set_local_storage(&setup);
+
+    let created_images = ImageRequestHandler::create_images(
+        &setup,
+        &vec![
+            ImageCreateBody {
+                id: 1,
+                base64: String::from("not base64!"),
+                format: None,
+            },
+            ImageCreateBody {
+                id: 2,
+                base64: general_purpose::STANDARD.encode(b"test image content"),
+                format: Some(String::from("png")),
+            },
+            ImageCreateBody {
+                id: 3,
+                base64: ImageMockHandler::get_png_base64(),
+                format: Some(String::from("/../jpg")),
+            },
+        ],
+        user_token,
+    )
+    .await;
+
+    assert_eq!(created_images.images.len(), 1);
+    assert_eq!(created_images.images[0].id, 3);
+    assert_eq!(created_images.images[0].image.mime_type, "image/webp");
+
+    assert_eq!(
+        created_images
+            .errors
+            .iter()
+            .map(|image_error| image_error.id)
+            .collect::<Vec<i32>>(),
+        vec![1, 2]
+    );
+
+    let response_wrong_key =
+        ImageRequest::get_image_file(&setup, &String::from("/image/file/..")).await;
+
+    assert_eq!(response_wrong_key.status(), Status::NotFound);
+}
+
+#[tokio::test]
+async fn upload_image_files_multipart() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let user_token = setup.user_handler.get_token(TestUser::Common1).unwrap();
+
+    set_local_storage(&setup);
+
+    let created_images = ImageRequestHandler::upload_image_files(
+        &setup,
+        &vec![
+            ImageMockHandler::get_png_content(),
+            b"test image content".to_vec(),
+        ],
+        user_token.clone(),
+    )
+    .await;
+
+    assert_eq!(created_images.images.len(), 1);
+    assert_eq!(created_images.images[0].id, 0);
+    assert_eq!(created_images.images[0].image.width, Some(1));
+    assert_eq!(created_images.errors.len(), 1);
+    assert_eq!(created_images.errors[0].id, 1);
+
+    env::set_var(IMAGE_MAX_SIZE_ENV, "10");
+
+    let too_large_images = ImageRequestHandler::upload_image_files(
+        &setup,
+        &vec![ImageMockHandler::get_png_content()],
+        user_token,
+    )
+    .await;
+
+    env::remove_var(IMAGE_MAX_SIZE_ENV);
+
+    assert_eq!(too_large_images.images.len(), 0);
+    assert_eq!(too_large_images.errors.len(), 1);
+    assert!(too_large_images.errors[0].error.contains("image size"));
+}
+
+#[tokio::test]
+async fn upload_image_renditions() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let user_token = setup.user_handler.get_token(TestUser::Common1).unwrap();
+
+    set_local_storage(&setup);
+
+    let created_images = ImageRequestHandler::upload_image_files(
+        &setup,
+        &vec![ImageMockHandler::get_jpeg_content(800, 400, 6)],
+        user_token,
+    )
+    .await;
+
+    assert_eq!(created_images.errors.len(), 0);
+
+    let image = &created_images.images[0].image;
+
+    assert_eq!(image.mime_type, "image/jpeg");
+    assert_eq!((image.width, image.height), (Some(400), Some(800)));
+
+    let renditions_sizes = image
+        .renditions
+        .iter()
+        .map(|rendition| (rendition.name.clone(), rendition.width, rendition.height))
+        .collect::<Vec<(String, Option<i32>, Option<i32>)>>();
+
+    assert_eq!(
+        renditions_sizes,
+        vec![
+            (String::from("original"), Some(400), Some(800)),
+            (String::from("thumbnail"), Some(160), Some(320)),
+            (String::from("medium"), Some(400), Some(800)),
+        ]
+    );
+
+    for rendition in image.renditions.iter() {
+        let received_content = ImageRequestHandler::get_image_file(&setup, &rendition.uri).await;
+
+        assert!(!received_content.windows(4).any(|window| window == b"Exif"));
+    }
+}
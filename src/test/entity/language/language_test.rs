@@ -0,0 +1,300 @@
+use rocket::http::Status;
+
+use super::setup::{SetupOptions, TestSetup, TestUser};
+use super::test_handler::request_handler::{
+    article::{ArticleRequest, ArticleRequestHandler},
+    article_language::ArticleLanguageRequest,
+    language::{LanguageRequest, LanguageRequestHandler},
+};
+
+use super::dtm::{
+    article::request_body::ArticleCreateRelationsBody,
+    article_language::request_body::ArticleLanguageCreateRelationsBody,
+    language::request_body::{LanguageCreateBody, LanguagePatchBody},
+};
+use super::repository::entity::{article::ArticleType, language::TextDirection};
+
+fn get_code(setup: &TestSetup, prefix: &str) -> String {
+    format!("{}{}", prefix, setup.test_id % 100_000_000)
+}
+
+fn get_creation_body(code: &String, fallback: Option<String>) -> LanguageCreateBody {
+    LanguageCreateBody {
+        code: code.clone(),
+        name: format!("Language {code}"),
+        native_name: format!("Native {code}"),
+        direction: None,
+        bcp47: String::from("fr-CA"),
+        fallback,
+    }
+}
+
+fn get_patch_body() -> LanguagePatchBody {
+    LanguagePatchBody {
+        name: None,
+        native_name: None,
+        direction: None,
+        bcp47: None,
+        fallback: None,
+        enabled: None,
+    }
+}
+
+fn get_article_body(setup: &TestSetup, language: &String) -> ArticleCreateRelationsBody {
+    ArticleCreateRelationsBody {
+        name: format!("{}_{}_article", setup.test_id, language),
+        content: String::from("language content"),
+        language: language.clone(),
+        article_type: ArticleType::Public,
+    }
+}
+
+#[tokio::test]
+async fn create_language() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let admin_token = setup.user_handler.get_token(TestUser::Admin1).unwrap();
+    let user_token = setup.user_handler.get_token(TestUser::Common1).unwrap();
+
+    let code = get_code(&setup, "a");
+    let en_language = LanguageRequestHandler::get_languages(&setup, None, None)
+        .await
+        .into_iter()
+        .find(|language| language.code == "en")
+        .unwrap();
+
+    let language = LanguageRequestHandler::create_language(
+        &setup,
+        &get_creation_body(&code, Some(String::from("en"))),
+        admin_token.clone(),
+    )
+    .await;
+
+    assert_eq!(language.code, code);
+    assert_eq!(language.native_name, format!("Native {code}"));
+    assert_eq!(language.direction, TextDirection::Ltr);
+    assert_eq!(language.bcp47, "fr-CA");
+    assert_eq!(language.fallback_language_id, Some(en_language.id));
+    assert!(language.enabled);
+
+    let duplicate_response = LanguageRequest::create_language(
+        &setup,
+        &get_creation_body(&code, None),
+        admin_token.clone(),
+    )
+    .await;
+
+    assert_eq!(duplicate_response.status(), Status::BadRequest);
+
+    let invalid_code_response = LanguageRequest::create_language(
+        &setup,
+        &get_creation_body(&String::from("Not A Code"), None),
+        admin_token.clone(),
+    )
+    .await;
+
+    assert_eq!(invalid_code_response.status(), Status::NotAcceptable);
+
+    let invalid_bcp47_response = LanguageRequest::create_language(
+        &setup,
+        &LanguageCreateBody {
+            bcp47: String::from("1x-"),
+            ..get_creation_body(&get_code(&setup, "b"), None)
+        },
+        admin_token.clone(),
+    )
+    .await;
+
+    assert_eq!(invalid_bcp47_response.status(), Status::NotAcceptable);
+
+    let forbidden_response = LanguageRequest::create_language(
+        &setup,
+        &get_creation_body(&get_code(&setup, "c"), None),
+        user_token,
+    )
+    .await;
+
+    assert_eq!(forbidden_response.status(), Status::Forbidden);
+
+    // new languages can be used for articles right away
+    let article = ArticleRequestHandler::create_article(
+        &setup,
+        &get_article_body(&setup, &code),
+        admin_token.clone(),
+    )
+    .await;
+
+    assert_eq!(article.languages[0].language.code, code);
+}
+
+#[tokio::test]
+async fn patch_language() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let admin_token = setup.user_handler.get_token(TestUser::Admin1).unwrap();
+
+    let code = get_code(&setup, "d");
+    let dependent_code = get_code(&setup, "e");
+
+    LanguageRequestHandler::create_language(
+        &setup,
+        &get_creation_body(&code, None),
+        admin_token.clone(),
+    )
+    .await;
+
+    let renamed_language = LanguageRequestHandler::patch_language(
+        &setup,
+        &code,
+        &LanguagePatchBody {
+            name: Some(String::from("Renamed")),
+            direction: Some(TextDirection::Rtl),
+            ..get_patch_body()
+        },
+        admin_token.clone(),
+    )
+    .await;
+
+    assert_eq!(renamed_language.name, "Renamed");
+    assert_eq!(renamed_language.direction, TextDirection::Rtl);
+    assert_eq!(renamed_language.native_name, format!("Native {code}"));
+
+    let dependent_language = LanguageRequestHandler::create_language(
+        &setup,
+        &get_creation_body(&dependent_code, Some(code.clone())),
+        admin_token.clone(),
+    )
+    .await;
+
+    assert_eq!(
+        dependent_language.fallback_language_id,
+        Some(renamed_language.id)
+    );
+
+    let cyclic_response = LanguageRequest::patch_language(
+        &setup,
+        &code,
+        &LanguagePatchBody {
+            fallback: Some(dependent_code.clone()),
+            ..get_patch_body()
+        },
+        admin_token.clone(),
+    )
+    .await;
+
+    assert_eq!(cyclic_response.status(), Status::NotAcceptable);
+
+    let fallback_disable_response = LanguageRequest::patch_language(
+        &setup,
+        &code,
+        &LanguagePatchBody {
+            enabled: Some(false),
+            ..get_patch_body()
+        },
+        admin_token.clone(),
+    )
+    .await;
+
+    assert_eq!(fallback_disable_response.status(), Status::NotAcceptable);
+
+    let detached_language = LanguageRequestHandler::patch_language(
+        &setup,
+        &dependent_code,
+        &LanguagePatchBody {
+            fallback: Some(String::new()),
+            ..get_patch_body()
+        },
+        admin_token.clone(),
+    )
+    .await;
+
+    assert_eq!(detached_language.fallback_language_id, None);
+
+    let disabled_language = LanguageRequestHandler::patch_language(
+        &setup,
+        &code,
+        &LanguagePatchBody {
+            enabled: Some(false),
+            ..get_patch_body()
+        },
+        admin_token.clone(),
+    )
+    .await;
+
+    assert!(!disabled_language.enabled);
+
+    let unknown_response =
+        LanguageRequest::patch_language(&setup, "xx", &get_patch_body(), admin_token.clone()).await;
+
+    assert_eq!(unknown_response.status(), Status::NotFound);
+}
+
+#[tokio::test]
+async fn disabled_language() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let admin_token = setup.user_handler.get_token(TestUser::Admin1).unwrap();
+
+    let code = get_code(&setup, "f");
+
+    LanguageRequestHandler::create_language(
+        &setup,
+        &get_creation_body(&code, None),
+        admin_token.clone(),
+    )
+    .await;
+
+    let article = ArticleRequestHandler::create_article(
+        &setup,
+        &get_article_body(&setup, &String::from("en")),
+        admin_token.clone(),
+    )
+    .await;
+
+    LanguageRequestHandler::patch_language(
+        &setup,
+        &code,
+        &LanguagePatchBody {
+            enabled: Some(false),
+            ..get_patch_body()
+        },
+        admin_token.clone(),
+    )
+    .await;
+
+    let public_languages = LanguageRequestHandler::get_languages(&setup, None, None).await;
+
+    assert!(public_languages.iter().all(|language| language.enabled));
+    assert!(!public_languages
+        .iter()
+        .any(|language| language.code == code));
+
+    let all_languages =
+        LanguageRequestHandler::get_languages(&setup, Some(true), Some(admin_token.clone())).await;
+
+    assert!(all_languages.iter().any(|language| language.code == code));
+
+    let anonymous_response = LanguageRequest::get_languages(&setup, Some(true), None).await;
+
+    assert_eq!(anonymous_response.status(), Status::Unauthorized);
+
+    let article_response = ArticleRequest::create_article(
+        &setup,
+        &get_article_body(&setup, &code),
+        admin_token.clone(),
+    )
+    .await;
+
+    assert_eq!(article_response.status(), Status::NotAcceptable);
+
+    let article_language_response = ArticleLanguageRequest::create_article_language(
+        &setup,
+        &ArticleLanguageCreateRelationsBody {
+            content: String::from("disabled language content"),
+            name: format!("{}_disabled_language", setup.test_id),
+        },
+        article.id,
+        &code,
+        admin_token,
+    )
+    .await;
+
+    assert_eq!(article_language_response.status(), Status::NotAcceptable);
+}
@@ -0,0 +1,6 @@
+use super::dtm;
+use super::repository;
+use super::setup;
+use super::test_handler;
+
+pub mod language_test;
@@ -1,9 +1,21 @@
+use super::aggregation;
 use super::dtm;
+use super::dtm_common;
+use super::emailer;
 use super::error;
+use super::jwt_handler;
 use super::repository;
 use super::setup;
 use super::test_handler;
+use super::totp_handler;
 
 pub mod article;
+pub mod article_draft;
 pub mod article_language;
 pub mod article_version;
+pub mod auth;
+pub mod category;
+pub mod email_template;
+pub mod image;
+pub mod language;
+pub mod watch;
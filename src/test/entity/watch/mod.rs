@@ -0,0 +1,8 @@
+use super::dtm;
+use super::dtm_common;
+use super::emailer;
+use super::repository;
+use super::setup;
+use super::test_handler;
+
+pub mod watch_test;
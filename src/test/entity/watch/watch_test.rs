@@ -0,0 +1,350 @@
+use rocket::http::Status;
+use std::env;
+use std::time::Duration;
+
+use super::emailer::{EmailMessage, OutboxEmailTransport};
+use super::setup::{SetupOptions, TestSetup, TestUser};
+use super::test_handler::request_handler::{
+    article::ArticleRequestHandler,
+    article_version::ArticleVersionRequestHandler,
+    auth::AuthRequestHandler,
+    watch::{WatchRequest, WatchRequestHandler},
+};
+
+use super::dtm::{
+    article::request_body::{ArticleCreateRelationsBody, ArticlePatchBody},
+    article_version::request_body::ArticleVersionCreateRelationsBody,
+    auth::request_body::UserSignupBody,
+    watch::request_body::{WatchCreateBody, WatchPatchBody, WatchUnsubscribeBody},
+};
+use super::dtm_common::UserRoleId;
+use super::repository::entity::{article::ArticleType, watch::WatchDelivery};
+
+const CLIENT_URL_ENV: &str = "CLIENT_URL";
+
+const CLIENT_URL: &str = "http://client.test";
+
+async fn create_watcher(setup: &TestSetup, prefix: &str) -> (String, String) {
+    let signup_body = UserSignupBody {
+        email: format!("{}_{}@mail.com", prefix, setup.test_id),
+        name: format!("{}_{}", prefix, setup.test_id),
+        password: String::from("password"),
+    };
+
+    let token = AuthRequestHandler::signup_with_role(
+        &setup.client,
+        &signup_body,
+        UserRoleId::Common as i32,
+    )
+    .await
+    .token;
+
+    (signup_body.email, token)
+}
+
+// immediate emails are delivered in the background
+async fn wait_for_messages(email: &String, count: usize) -> Vec<EmailMessage> {
+    for _ in 0..50 {
+        let messages = OutboxEmailTransport::get_messages(email);
+
+        if messages.len() >= count {
+            return messages;
+        }
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+
+    OutboxEmailTransport::get_messages(email)
+}
+
+fn get_unsubscribe_key(text: &String) -> String {
+    text.split("/api/unsubscribe?key=")
+        .nth(1)
+        .unwrap()
+        .split_whitespace()
+        .next()
+        .unwrap()
+        .to_owned()
+}
+
+#[tokio::test]
+async fn watch_notifications() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let admin_token = setup.user_handler.get_token(TestUser::Admin1).unwrap();
+
+    env::set_var(CLIENT_URL_ENV, CLIENT_URL);
+
+    let language = String::from("ua");
+    let article = ArticleRequestHandler::create_article(
+        &setup,
+        &ArticleCreateRelationsBody {
+            name: format!("{}_watched_article", setup.test_id),
+            content: String::from("watched content"),
+            language: language.clone(),
+            article_type: ArticleType::Public,
+        },
+        admin_token.clone(),
+    )
+    .await;
+
+    let (immediate_email, immediate_token) = create_watcher(&setup, "watch_immediate").await;
+    let (digest_email, digest_token) = create_watcher(&setup, "watch_digest").await;
+
+    let immediate_watch = WatchRequestHandler::create_watch(
+        &setup,
+        &WatchCreateBody {
+            article_id: article.id,
+            language: Some(language.clone()),
+            delivery: None,
+        },
+        immediate_token.clone(),
+    )
+    .await;
+
+    assert_eq!(immediate_watch.delivery, WatchDelivery::Immediate);
+    assert_eq!(immediate_watch.language, Some(language.clone()));
+
+    let duplicate_response = WatchRequest::create_watch(
+        &setup,
+        &WatchCreateBody {
+            article_id: article.id,
+            language: Some(language.clone()),
+            delivery: Some(WatchDelivery::Digest),
+        },
+        immediate_token.clone(),
+    )
+    .await;
+
+    assert_eq!(duplicate_response.status(), Status::BadRequest);
+
+    let digest_watch = WatchRequestHandler::create_watch(
+        &setup,
+        &WatchCreateBody {
+            article_id: article.id,
+            language: None,
+            delivery: Some(WatchDelivery::Digest),
+        },
+        digest_token.clone(),
+    )
+    .await;
+
+    let renamed = format!("{}_renamed_article", setup.test_id);
+    ArticleVersionRequestHandler::create_article_version(
+        &setup,
+        &ArticleVersionCreateRelationsBody {
+            content: String::from("watched content changed"),
+            name: Some(renamed.clone()),
+            source: None,
+            base_version: None,
+            merge: None,
+        },
+        article.id,
+        &language,
+        admin_token.clone(),
+    )
+    .await;
+
+    let immediate_messages = wait_for_messages(&immediate_email, 1).await;
+
+    assert_eq!(immediate_messages.len(), 1);
+    assert!(immediate_messages[0].text.contains("new version 2"));
+    assert!(immediate_messages[0].text.contains(&renamed));
+    assert!(immediate_messages[0].text.contains(": renamed"));
+    assert!(immediate_messages[0]
+        .text
+        .contains(&format!("{CLIENT_URL}/api/unsubscribe?key=")));
+
+    // digest watchers are emailed by the digest job only
+    assert_eq!(OutboxEmailTransport::get_messages(&digest_email).len(), 0);
+
+    ArticleRequestHandler::delete_article(&setup, article.id, admin_token.clone()).await;
+
+    let immediate_messages = wait_for_messages(&immediate_email, 2).await;
+
+    assert_eq!(immediate_messages.len(), 2);
+    assert!(immediate_messages[1].text.contains(": archived"));
+
+    let forbidden_response = WatchRequest::send_watch_digests(&setup, digest_token.clone()).await;
+
+    assert_eq!(forbidden_response.status(), Status::Forbidden);
+
+    let digest_aggregation =
+        WatchRequestHandler::send_watch_digests(&setup, admin_token.clone()).await;
+
+    assert!(digest_aggregation.sent_count >= 1);
+
+    let digest_messages = OutboxEmailTransport::get_messages(&digest_email);
+
+    assert_eq!(digest_messages.len(), 1);
+    assert!(digest_messages[0].text.contains("new version 2"));
+    assert!(digest_messages[0].text.contains(": archived"));
+
+    // sent notifications are not repeated
+    WatchRequestHandler::send_watch_digests(&setup, admin_token.clone()).await;
+
+    assert_eq!(OutboxEmailTransport::get_messages(&digest_email).len(), 1);
+
+    let patched_watch = WatchRequestHandler::patch_watch(
+        &setup,
+        digest_watch.id,
+        &WatchPatchBody {
+            delivery: WatchDelivery::Immediate,
+        },
+        digest_token.clone(),
+    )
+    .await;
+
+    assert_eq!(patched_watch.delivery, WatchDelivery::Immediate);
+    assert_eq!(patched_watch.language, None);
+
+    let foreign_patch_response = WatchRequest::patch_watch(
+        &setup,
+        digest_watch.id,
+        &WatchPatchBody {
+            delivery: WatchDelivery::Digest,
+        },
+        immediate_token.clone(),
+    )
+    .await;
+
+    assert_eq!(foreign_patch_response.status(), Status::NotFound);
+
+    let unsubscribe_body = WatchUnsubscribeBody {
+        key: get_unsubscribe_key(&immediate_messages[0].text),
+    };
+
+    WatchRequestHandler::unsubscribe_watch(&setup, &unsubscribe_body).await;
+
+    assert_eq!(
+        WatchRequestHandler::get_watches(&setup, immediate_token)
+            .await
+            .len(),
+        0
+    );
+
+    let repeated_response = WatchRequest::unsubscribe_watch(&setup, &unsubscribe_body).await;
+
+    assert_eq!(repeated_response.status(), Status::NotFound);
+}
+
+#[tokio::test]
+async fn watch_own_changes() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+
+    env::set_var(CLIENT_URL_ENV, CLIENT_URL);
+
+    let language = String::from("ua");
+    let (email, token) = create_watcher(&setup, "watch_author").await;
+
+    let article = ArticleRequestHandler::create_article(
+        &setup,
+        &ArticleCreateRelationsBody {
+            name: format!("{}_own_article", setup.test_id),
+            content: String::from("own content"),
+            language: language.clone(),
+            article_type: ArticleType::Public,
+        },
+        token.clone(),
+    )
+    .await;
+
+    WatchRequestHandler::create_watch(
+        &setup,
+        &WatchCreateBody {
+            article_id: article.id,
+            language: None,
+            delivery: None,
+        },
+        token.clone(),
+    )
+    .await;
+
+    ArticleVersionRequestHandler::create_article_version(
+        &setup,
+        &ArticleVersionCreateRelationsBody {
+            content: String::from("own content changed"),
+            name: None,
+            source: None,
+            base_version: None,
+            merge: None,
+        },
+        article.id,
+        &language,
+        token.clone(),
+    )
+    .await;
+
+    // authors are not notified of their own changes
+    assert_eq!(OutboxEmailTransport::get_messages(&email).len(), 0);
+
+    let unknown_article_response = WatchRequest::create_watch(
+        &setup,
+        &WatchCreateBody {
+            article_id: -1,
+            language: None,
+            delivery: None,
+        },
+        token.clone(),
+    )
+    .await;
+
+    assert_eq!(unknown_article_response.status(), Status::NotFound);
+
+    let unknown_language_response = WatchRequest::create_watch(
+        &setup,
+        &WatchCreateBody {
+            article_id: article.id,
+            language: Some(String::from("xx")),
+            delivery: None,
+        },
+        token,
+    )
+    .await;
+
+    assert_eq!(unknown_language_response.status(), Status::NotFound);
+}
+
+#[tokio::test]
+async fn watch_disabled_article() {
+    let setup = TestSetup::new(SetupOptions { is_lock: true }).await;
+    let admin_token = setup.user_handler.get_token(TestUser::Admin1).unwrap();
+
+    let (_, token) = create_watcher(&setup, "watch_disabled").await;
+
+    let article = ArticleRequestHandler::create_article(
+        &setup,
+        &ArticleCreateRelationsBody {
+            name: format!("{}_disabled_article", setup.test_id),
+            content: String::from("disabled content"),
+            language: String::from("ua"),
+            article_type: ArticleType::Public,
+        },
+        admin_token.clone(),
+    )
+    .await;
+
+    ArticleRequestHandler::patch_article(
+        &setup,
+        article.id,
+        &ArticlePatchBody {
+            enabled: Some(false),
+            article_type: None,
+        },
+        admin_token.clone(),
+    )
+    .await;
+
+    let creation_body = WatchCreateBody {
+        article_id: article.id,
+        language: None,
+        delivery: None,
+    };
+
+    let common_response = WatchRequest::create_watch(&setup, &creation_body, token).await;
+
+    assert_eq!(common_response.status(), Status::NotFound);
+
+    let admin_watch = WatchRequestHandler::create_watch(&setup, &creation_body, admin_token).await;
+
+    assert_eq!(admin_watch.article_id, article.id);
+}
@@ -1,9 +1,14 @@
 use super::aggregation;
+use super::authorization;
 use super::dtm;
 use super::dtm_common;
+use super::emailer;
 use super::error;
+use super::jwt_handler;
 use super::repository;
 use super::router;
+use super::service;
+use super::totp_handler;
 
 mod setup;
 mod test_handler;
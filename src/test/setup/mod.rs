@@ -1,11 +1,16 @@
+use super::authorization;
 use super::dtm;
 use super::dtm_common;
+use super::emailer;
 use super::repository;
 use super::router;
+use super::service;
 use super::test_handler;
 
+mod test_identity_provider;
 mod test_setup;
 mod test_user_handler;
 
-pub use test_setup::{SetupOptions, TestSetup};
+pub use test_identity_provider::{TestIdentityProvider, TEST_IDP_CLIENT_SECRET};
+pub use test_setup::{SetupOptions, TestSetup, TEST_TRUSTED_PROXY};
 pub use test_user_handler::TestUser;
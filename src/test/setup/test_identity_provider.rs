@@ -0,0 +1,126 @@
+use rocket::serde::json::{json, Value};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::OnceLock;
+use std::thread;
+
+pub const TEST_IDP_CLIENT_SECRET: &str = "test_idp_secret";
+
+static ISSUER: OnceLock<String> = OnceLock::new();
+
+// local stand-in for an oidc provider, authorization code is "<subject>|<email>[|unverified]",
+// the code itself is issued as access token
+pub struct TestIdentityProvider;
+
+impl TestIdentityProvider {
+    pub fn get_issuer() -> &'static String {
+        ISSUER.get_or_init(|| {
+            let listener = TcpListener::bind("127.0.0.1:0").expect("bind test identity provider");
+            let issuer = format!("http://{}", listener.local_addr().unwrap());
+
+            let server_issuer = issuer.clone();
+            thread::spawn(move || {
+                for stream in listener.incoming().flatten() {
+                    Self::handle(stream, &server_issuer);
+                }
+            });
+
+            issuer
+        })
+    }
+
+    pub fn get_code(subject: &String, email: &String, is_verified: bool) -> String {
+        match is_verified {
+            true => format!("{subject}|{email}"),
+            false => format!("{subject}|{email}|unverified"),
+        }
+    }
+
+    fn handle(mut stream: TcpStream, issuer: &String) {
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line).unwrap_or_default();
+
+        let mut content_length = 0;
+        let mut authorization = String::new();
+
+        loop {
+            let mut line = String::new();
+
+            if reader.read_line(&mut line).unwrap_or_default() == 0 || line == "\r\n" {
+                break;
+            }
+
+            if let Some((key, value)) = line.split_once(':') {
+                match key.to_lowercase().as_str() {
+                    "content-length" => content_length = value.trim().parse().unwrap_or_default(),
+                    "authorization" => authorization = value.trim().to_owned(),
+                    _ => (),
+                }
+            }
+        }
+
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body).unwrap_or_default();
+
+        let response = match request_line.split_whitespace().nth(1).unwrap_or_default() {
+            "/.well-known/openid-configuration" => Some(json!({
+                "issuer": issuer,
+                "authorization_endpoint": format!("{issuer}/authorize"),
+                "token_endpoint": format!("{issuer}/token"),
+                "userinfo_endpoint": format!("{issuer}/userinfo"),
+            })),
+            "/token" => Self::get_token(&String::from_utf8_lossy(&body)),
+            "/userinfo" => Self::get_user_info(&authorization),
+            _ => None,
+        };
+
+        let (status, body) = match response {
+            Some(body) => ("200 OK", body.to_string()),
+            None => ("401 Unauthorized", String::from("{}")),
+        };
+
+        write!(
+            stream,
+            "HTTP/1.1 {status}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+            body.len()
+        )
+        .unwrap_or_default();
+    }
+
+    fn get_token(form: &str) -> Option<Value> {
+        let url = reqwest::Url::parse(&format!("http://localhost/?{form}")).ok()?;
+        let param = |name: &str| {
+            url.query_pairs()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.to_string())
+        };
+
+        if param("client_secret")? != TEST_IDP_CLIENT_SECRET
+            || param("grant_type")? != "authorization_code"
+        {
+            return None;
+        }
+
+        Some(json!({
+            "access_token": param("code")?,
+            "token_type": "Bearer",
+        }))
+    }
+
+    fn get_user_info(authorization: &str) -> Option<Value> {
+        let access_token = authorization.strip_prefix("Bearer ")?;
+        let mut claims = access_token.split('|');
+
+        let subject = claims.next()?;
+        let email = claims.next()?;
+
+        Some(json!({
+            "sub": subject,
+            "email": email,
+            "email_verified": claims.next() != Some("unverified"),
+            "preferred_username": subject,
+        }))
+    }
+}
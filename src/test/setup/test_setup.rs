@@ -1,13 +1,23 @@
 use dotenv::dotenv;
 use parking_lot::MutexGuard;
 use rocket::{local::asynchronous::Client, Build, Rocket};
-use std::sync::Once;
+use std::net::IpAddr;
+use std::sync::{Arc, Once};
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use super::authorization::TrustedProxies;
+use super::emailer::{EmailTransport, OutboxEmailTransport};
 use super::repository;
-use super::router::{article, article_language, article_version, auth, catchers};
+use super::router::{
+    article, article_draft, article_language, article_version, auth, catchers, category,
+    email_template, image, language, watch,
+};
+use super::service::version_content::VersionKeyframeInterval;
 use super::test_user_handler::TestUsersHandler;
 
+// requests from this address may pass the client ip in the X-Real-IP header
+pub const TEST_TRUSTED_PROXY: &str = "127.0.0.2";
+
 pub struct SetupOptions {
     pub is_lock: bool,
 }
@@ -50,6 +60,21 @@ impl TestSetup {
         }
     }
 
+    // own client with the given keyframe interval, the shared one keeps the default
+    pub async fn new_with_keyframe_interval(options: SetupOptions, keyframe_interval: i32) -> Self {
+        let mut setup = Self::new(options).await;
+
+        let client = Client::tracked(Self::get_rocket(VersionKeyframeInterval::new(
+            keyframe_interval,
+        )))
+        .await
+        .expect("valid rocket instance");
+
+        setup.client = Box::leak(Box::new(client));
+
+        setup
+    }
+
     async fn get_test_user_handler(client: &Client) -> &'static TestUsersHandler {
         if !TEST_USER_HANDLER_ONCE.is_completed() {
             TEST_USER_HANDLER_ONCE.call_once(|| {});
@@ -69,7 +94,7 @@ impl TestSetup {
         if !CLIENT_ONCE.is_completed() {
             CLIENT_ONCE.call_once(|| {});
 
-            let client = Client::tracked(Self::get_rocket())
+            let client = Client::tracked(Self::get_rocket(VersionKeyframeInterval::default()))
                 .await
                 .expect("valid rocket instance");
 
@@ -81,15 +106,27 @@ impl TestSetup {
         unsafe { CLIENT.as_ref().unwrap() }
     }
 
-    fn get_rocket() -> Rocket<Build> {
+    fn get_rocket(keyframe_interval: VersionKeyframeInterval) -> Rocket<Build> {
         dotenv().ok();
 
         rocket::build()
             .attach(repository::PgConnection::fairing())
+            .manage(keyframe_interval)
+            // tests read sent emails from the outbox whatever EMAIL_TRANSPORT is
+            .manage(Arc::new(OutboxEmailTransport::new()) as Arc<dyn EmailTransport>)
+            .manage(TrustedProxies::new(vec![TEST_TRUSTED_PROXY
+                .parse::<IpAddr>()
+                .unwrap()]))
             .mount("/articles", article::routes())
             .mount("/articles", article_language::routes())
             .mount("/articles", article_version::routes())
+            .mount("/articles", article_draft::routes())
+            .mount("/languages", language::routes())
+            .mount("/categories", category::routes())
+            .mount("/image", image::routes())
             .mount("/auth", auth::test_routes())
+            .mount("/email-templates", email_template::routes())
+            .mount("/watches", watch::routes())
             .register("/", catchers::catchers())
     }
 }
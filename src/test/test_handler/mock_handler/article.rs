@@ -51,6 +51,7 @@ impl ArticleMockHandler {
                     },
                 ),
             ],
+            categories: vec![],
         }
     }
 }
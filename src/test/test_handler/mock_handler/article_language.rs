@@ -3,6 +3,7 @@ use chrono::Utc;
 use super::aggregation::{
     article_language::ArticleLanguageAggregation, language::LanguageAggregation,
 };
+use super::TextDirection;
 
 use super::dtm::article_language::request_body::ArticleLanguageCreateRelationsBody;
 
@@ -52,6 +53,12 @@ impl ArticleLanguageMockHandler {
             language: LanguageAggregation {
                 id: 0,
                 code: mock_options.language.clone(),
+                name: mock_options.language.clone(),
+                native_name: mock_options.language.clone(),
+                direction: TextDirection::Ltr,
+                bcp47: mock_options.language.clone(),
+                fallback_language_id: None,
+                enabled: true,
             },
             version: ArticleVersionMockHandler::get_article_version_aggregation(
                 &ArticleVersionMockOptions {
@@ -5,6 +5,7 @@ use super::aggregation::{
 };
 
 use super::dtm::article_version::request_body::ArticleVersionCreateRelationsBody;
+use super::ContentType;
 
 pub struct ArticleVersionMockOptions {
     pub content: String,
@@ -36,6 +37,7 @@ impl ArticleVersionMockHandler {
         ArticleVersionAggregation {
             id: 0,
             article_language_id: 0,
+            source_version_id: None,
             name: String::from(""),
             version: mock_options.version,
             enabled: mock_options.enabled,
@@ -44,6 +46,7 @@ impl ArticleVersionMockHandler {
             content: VersionContentAggregation {
                 id: 0,
                 content: mock_options.content.clone(),
+                content_type: ContentType::Full,
             },
             created_by: None,
         }
@@ -0,0 +1,62 @@
+use base64::{engine::general_purpose, Engine as _};
+use image::{DynamicImage, ImageOutputFormat, RgbImage};
+use std::io::Cursor;
+
+// 1x1 transparent png
+const PNG_BASE64: &str =
+    "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNkYPhfDwAChwGA60e6kgAAAABJRU5ErkJggg==";
+
+pub struct ImageMockHandler;
+impl ImageMockHandler {
+    pub fn get_png_base64() -> String {
+        String::from(PNG_BASE64)
+    }
+
+    pub fn get_png_content() -> Vec<u8> {
+        general_purpose::STANDARD.decode(PNG_BASE64).unwrap()
+    }
+
+    // jpeg with an EXIF segment containing only the orientation tag
+    pub fn get_jpeg_content(width: u32, height: u32, orientation: u8) -> Vec<u8> {
+        let mut content = Cursor::new(vec![]);
+
+        DynamicImage::ImageRgb8(RgbImage::new(width, height))
+            .write_to(&mut content, ImageOutputFormat::Jpeg(90))
+            .unwrap();
+
+        let content = content.into_inner();
+
+        let mut exif_segment = vec![0xFF, 0xE1, 0x00, 0x22];
+        exif_segment.extend_from_slice(b"Exif\0\0");
+        exif_segment.extend_from_slice(&[
+            0x49,
+            0x49,
+            0x2A,
+            0x00,
+            0x08,
+            0x00,
+            0x00,
+            0x00, // tiff header
+            0x01,
+            0x00, // entries count
+            0x12,
+            0x01,
+            0x03,
+            0x00,
+            0x01,
+            0x00,
+            0x00,
+            0x00,
+            orientation,
+            0x00,
+            0x00,
+            0x00,
+            0x00,
+            0x00,
+            0x00,
+            0x00, // next ifd offset
+        ]);
+
+        [&content[..2], &exif_segment[..], &content[2..]].concat()
+    }
+}
@@ -1,7 +1,10 @@
 use super::aggregation;
 use super::dtm;
-use super::repository::entity::article::ArticleType;
+use super::repository::entity::{
+    article::ArticleType, language::TextDirection, version_content::ContentType,
+};
 
 pub mod article;
 pub mod article_language;
 pub mod article_version;
+pub mod image;
@@ -4,7 +4,7 @@ use serde::Serialize;
 
 use super::router::article::*;
 
-use super::aggregation::article::ArticleAggregation;
+use super::aggregation::{article::ArticleAggregation, page::PageAggregation};
 
 use super::request_handler::RequestHandler;
 use super::setup::TestSetup;
@@ -40,9 +40,66 @@ impl ArticleRequestHandler {
         assert_eq!(response.status(), Status::Ok);
 
         response
-            .into_json::<Vec<ArticleAggregation>>()
+            .into_json::<PageAggregation<ArticleAggregation>>()
             .await
             .unwrap()
+            .items
+    }
+
+    pub async fn get_articles_page(
+        setup: &TestSetup,
+        limit: i64,
+        cursor: Option<String>,
+    ) -> PageAggregation<ArticleAggregation> {
+        let response = ArticleRequest::get_articles_page(setup, limit, cursor).await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response
+            .into_json::<PageAggregation<ArticleAggregation>>()
+            .await
+            .unwrap()
+    }
+
+    pub async fn get_articles_by_category(
+        setup: &TestSetup,
+        category_id: i32,
+    ) -> Vec<ArticleAggregation> {
+        let response = ArticleRequest::get_articles_by_category(setup, category_id).await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response
+            .into_json::<PageAggregation<ArticleAggregation>>()
+            .await
+            .unwrap()
+            .items
+    }
+
+    pub async fn attach_article_category(
+        setup: &TestSetup,
+        article_id: i32,
+        category_id: i32,
+        jwt_token: String,
+    ) {
+        let response =
+            ArticleRequest::attach_article_category(setup, article_id, category_id, jwt_token)
+                .await;
+
+        assert_eq!(response.status(), Status::Ok);
+    }
+
+    pub async fn detach_article_category(
+        setup: &TestSetup,
+        article_id: i32,
+        category_id: i32,
+        jwt_token: String,
+    ) {
+        let response =
+            ArticleRequest::detach_article_category(setup, article_id, category_id, jwt_token)
+                .await;
+
+        assert_eq!(response.status(), Status::Ok);
     }
 
     pub async fn delete_article(
@@ -161,7 +218,64 @@ impl ArticleRequest {
     pub async fn get_articles<'s>(setup: &'s TestSetup) -> LocalResponse<'s> {
         setup
             .client
-            .get(uri!("/articles", get_articles))
+            .get(uri!("/articles", get_articles(_, _, _)))
+            .dispatch()
+            .await
+    }
+
+    pub async fn get_articles_page<'s>(
+        setup: &'s TestSetup,
+        limit: i64,
+        cursor: Option<String>,
+    ) -> LocalResponse<'s> {
+        setup
+            .client
+            .get(uri!("/articles", get_articles(_, Some(limit), cursor)))
+            .dispatch()
+            .await
+    }
+
+    pub async fn get_articles_by_category<'s>(
+        setup: &'s TestSetup,
+        category_id: i32,
+    ) -> LocalResponse<'s> {
+        setup
+            .client
+            .get(uri!("/articles", get_articles(Some(category_id), _, _)))
+            .dispatch()
+            .await
+    }
+
+    pub async fn attach_article_category<'s>(
+        setup: &'s TestSetup,
+        article_id: i32,
+        category_id: i32,
+        jwt_token: String,
+    ) -> LocalResponse<'s> {
+        setup
+            .client
+            .post(uri!(
+                "/articles",
+                attach_article_category(article_id, category_id)
+            ))
+            .header(RequestHandler::get_auth_header(jwt_token))
+            .dispatch()
+            .await
+    }
+
+    pub async fn detach_article_category<'s>(
+        setup: &'s TestSetup,
+        article_id: i32,
+        category_id: i32,
+        jwt_token: String,
+    ) -> LocalResponse<'s> {
+        setup
+            .client
+            .delete(uri!(
+                "/articles",
+                detach_article_category(article_id, category_id)
+            ))
+            .header(RequestHandler::get_auth_header(jwt_token))
             .dispatch()
             .await
     }
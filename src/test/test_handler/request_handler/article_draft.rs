@@ -0,0 +1,154 @@
+use rocket::local::asynchronous::LocalResponse;
+use rocket::{http::Status, uri};
+use serde::Serialize;
+
+use super::router::article_draft::*;
+
+use super::aggregation::{
+    article_draft::ArticleDraftAggregation, article_version::ArticleVersionAggregation,
+};
+
+use super::request_handler::RequestHandler;
+use super::setup::TestSetup;
+
+pub struct ArticleDraftRequestHandler;
+impl ArticleDraftRequestHandler {
+    pub async fn get_article_draft(
+        setup: &TestSetup,
+        article_id: i32,
+        language_code: &String,
+        jwt_token: String,
+    ) -> ArticleDraftAggregation {
+        let response =
+            ArticleDraftRequest::get_article_draft(setup, article_id, language_code, jwt_token)
+                .await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response
+            .into_json::<ArticleDraftAggregation>()
+            .await
+            .unwrap()
+    }
+
+    pub async fn save_article_draft<T>(
+        setup: &TestSetup,
+        article_id: i32,
+        language_code: &String,
+        save_body: &T,
+        jwt_token: String,
+    ) -> ArticleDraftAggregation
+    where
+        T: Serialize,
+    {
+        let response = ArticleDraftRequest::save_article_draft(
+            setup,
+            article_id,
+            language_code,
+            save_body,
+            jwt_token,
+        )
+        .await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response
+            .into_json::<ArticleDraftAggregation>()
+            .await
+            .unwrap()
+    }
+
+    pub async fn publish_article_draft(
+        setup: &TestSetup,
+        article_id: i32,
+        language_code: &String,
+        jwt_token: String,
+    ) -> ArticleVersionAggregation {
+        let response =
+            ArticleDraftRequest::publish_article_draft(setup, article_id, language_code, jwt_token)
+                .await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response
+            .into_json::<ArticleVersionAggregation>()
+            .await
+            .unwrap()
+    }
+}
+
+pub struct ArticleDraftRequest;
+impl ArticleDraftRequest {
+    pub async fn get_article_draft<'s>(
+        setup: &'s TestSetup,
+        article_id: i32,
+        language_code: &String,
+        jwt_token: String,
+    ) -> LocalResponse<'s> {
+        setup
+            .client
+            .get(uri!(
+                "/articles",
+                get_article_draft(article_id, language_code)
+            ))
+            .header(RequestHandler::get_auth_header(jwt_token))
+            .dispatch()
+            .await
+    }
+
+    pub async fn save_article_draft<'s, T>(
+        setup: &'s TestSetup,
+        article_id: i32,
+        language_code: &String,
+        save_body: &T,
+        jwt_token: String,
+    ) -> LocalResponse<'s>
+    where
+        T: Serialize,
+    {
+        setup
+            .client
+            .put(uri!(
+                "/articles",
+                save_article_draft(article_id, language_code)
+            ))
+            .json::<T>(save_body)
+            .header(RequestHandler::get_auth_header(jwt_token))
+            .dispatch()
+            .await
+    }
+
+    pub async fn delete_article_draft<'s>(
+        setup: &'s TestSetup,
+        article_id: i32,
+        language_code: &String,
+        jwt_token: String,
+    ) -> LocalResponse<'s> {
+        setup
+            .client
+            .delete(uri!(
+                "/articles",
+                delete_article_draft(article_id, language_code)
+            ))
+            .header(RequestHandler::get_auth_header(jwt_token))
+            .dispatch()
+            .await
+    }
+
+    pub async fn publish_article_draft<'s>(
+        setup: &'s TestSetup,
+        article_id: i32,
+        language_code: &String,
+        jwt_token: String,
+    ) -> LocalResponse<'s> {
+        setup
+            .client
+            .post(uri!(
+                "/articles",
+                publish_article_draft(article_id, language_code)
+            ))
+            .header(RequestHandler::get_auth_header(jwt_token))
+            .dispatch()
+            .await
+    }
+}
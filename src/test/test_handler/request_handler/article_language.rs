@@ -1,9 +1,16 @@
 use rocket::local::asynchronous::LocalResponse;
-use rocket::{http::Status, uri};
+use rocket::{
+    http::{Header, Status},
+    uri,
+};
 
 use super::router::article_language::*;
 
-use super::aggregation::article_language::ArticleLanguageAggregation;
+use super::aggregation::{
+    article_language::{ArticleLanguageAggregation, ArticleLanguageFallbackAggregation},
+    article_search::ArticleSearchAggregation,
+    page::PageAggregation,
+};
 
 use super::request_handler::RequestHandler;
 
@@ -55,6 +62,29 @@ impl ArticleLanguageRequestHandler {
             .unwrap()
     }
 
+    pub async fn get_article_language_fallback<'s>(
+        setup: &'s TestSetup,
+        article_id: i32,
+        language_code: &String,
+        accept_language: Option<&str>,
+    ) -> ArticleLanguageFallbackAggregation {
+        let response = ArticleLanguageRequest::get_article_language_fallback(
+            setup,
+            article_id,
+            language_code,
+            None,
+            accept_language,
+        )
+        .await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response
+            .into_json::<ArticleLanguageFallbackAggregation>()
+            .await
+            .unwrap()
+    }
+
     pub async fn get_article_languages<'s>(
         setup: &'s TestSetup,
         article_id: i32,
@@ -64,7 +94,46 @@ impl ArticleLanguageRequestHandler {
         assert_eq!(response.status(), Status::Ok);
 
         response
-            .into_json::<Vec<ArticleLanguageAggregation>>()
+            .into_json::<PageAggregation<ArticleLanguageAggregation>>()
+            .await
+            .unwrap()
+            .items
+    }
+
+    pub async fn get_article_languages_page<'s>(
+        setup: &'s TestSetup,
+        article_id: i32,
+        limit: i64,
+        cursor: Option<String>,
+    ) -> PageAggregation<ArticleLanguageAggregation> {
+        let response =
+            ArticleLanguageRequest::get_article_languages_page(setup, article_id, limit, cursor)
+                .await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response
+            .into_json::<PageAggregation<ArticleLanguageAggregation>>()
+            .await
+            .unwrap()
+    }
+
+    pub async fn search_article_languages_by_content<'s>(
+        setup: &'s TestSetup,
+        query: &String,
+        language_code: Option<String>,
+    ) -> Vec<ArticleSearchAggregation> {
+        let response = ArticleLanguageRequest::search_article_languages_by_content(
+            setup,
+            query,
+            language_code,
+        )
+        .await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response
+            .into_json::<Vec<ArticleSearchAggregation>>()
             .await
             .unwrap()
     }
@@ -149,19 +218,57 @@ impl ArticleLanguageRequest {
             .client
             .get(uri!(
                 "/articles",
-                get_article_language(article_id, language_code)
+                get_article_language(article_id, language_code, _)
             ))
             .dispatch()
             .await
     }
 
+    pub async fn get_article_language_fallback<'s>(
+        setup: &'s TestSetup,
+        article_id: i32,
+        language_code: &String,
+        fallback: Option<bool>,
+        accept_language: Option<&str>,
+    ) -> LocalResponse<'s> {
+        let request = setup.client.get(uri!(
+            "/articles",
+            get_article_language(article_id, language_code, fallback)
+        ));
+
+        let request = match accept_language {
+            Some(accept_language) => {
+                request.header(Header::new("Accept-Language", accept_language.to_string()))
+            }
+            None => request,
+        };
+
+        request.dispatch().await
+    }
+
     pub async fn get_article_languages<'s>(
         setup: &'s TestSetup,
         article_id: i32,
     ) -> LocalResponse<'s> {
         setup
             .client
-            .get(uri!("/articles", get_article_languages(article_id)))
+            .get(uri!("/articles", get_article_languages(article_id, _, _)))
+            .dispatch()
+            .await
+    }
+
+    pub async fn get_article_languages_page<'s>(
+        setup: &'s TestSetup,
+        article_id: i32,
+        limit: i64,
+        cursor: Option<String>,
+    ) -> LocalResponse<'s> {
+        setup
+            .client
+            .get(uri!(
+                "/articles",
+                get_article_languages(article_id, Some(limit), cursor)
+            ))
             .dispatch()
             .await
     }
@@ -237,4 +344,19 @@ impl ArticleLanguageRequest {
             .dispatch()
             .await
     }
+
+    pub async fn search_article_languages_by_content<'s>(
+        setup: &'s TestSetup,
+        query: &String,
+        language_code: Option<String>,
+    ) -> LocalResponse<'s> {
+        setup
+            .client
+            .get(uri!(
+                "/articles",
+                search_article_languages_by_content(query, language_code, _)
+            ))
+            .dispatch()
+            .await
+    }
 }
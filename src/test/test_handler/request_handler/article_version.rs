@@ -4,7 +4,14 @@ use serde::Serialize;
 
 use super::setup::TestSetup;
 
-use super::aggregation::article_version::ArticleVersionAggregation;
+use super::aggregation::{
+    article_version::{
+        ArticleVersionAggregation, ArticleVersionCompareAggregation,
+        ArticleVersionConflictAggregation, ArticleVersionOutdatedAggregation,
+    },
+    page::PageAggregation,
+    version_content::VersionKeyframesAggregation,
+};
 
 use super::dtm::article_version::request_body::ArticleVersionPatchBody;
 
@@ -41,6 +48,33 @@ impl ArticleVersionRequestHandler {
             .unwrap()
     }
 
+    pub async fn create_article_version_conflict<T>(
+        setup: &TestSetup,
+        creation_body: &T,
+        article_id: i32,
+        language_code: &String,
+        jwt_token: String,
+    ) -> ArticleVersionConflictAggregation
+    where
+        T: Serialize,
+    {
+        let response = ArticleVersionRequest::create_article_version(
+            setup,
+            creation_body,
+            article_id,
+            language_code,
+            jwt_token,
+        )
+        .await;
+
+        assert_eq!(response.status(), Status::Conflict);
+
+        response
+            .into_json::<ArticleVersionConflictAggregation>()
+            .await
+            .unwrap()
+    }
+
     pub async fn get_article_version<'s>(
         setup: &'s TestSetup,
         article_id: i32,
@@ -70,7 +104,105 @@ impl ArticleVersionRequestHandler {
         assert_eq!(response.status(), Status::Ok);
 
         response
-            .into_json::<Vec<ArticleVersionAggregation>>()
+            .into_json::<PageAggregation<ArticleVersionAggregation>>()
+            .await
+            .unwrap()
+            .items
+    }
+
+    pub async fn get_article_versions_page<'s>(
+        setup: &'s TestSetup,
+        article_id: i32,
+        language_code: &String,
+        limit: i64,
+        cursor: Option<String>,
+    ) -> PageAggregation<ArticleVersionAggregation> {
+        let response = ArticleVersionRequest::get_article_versions_page(
+            setup,
+            article_id,
+            language_code,
+            limit,
+            cursor,
+        )
+        .await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response
+            .into_json::<PageAggregation<ArticleVersionAggregation>>()
+            .await
+            .unwrap()
+    }
+
+    pub async fn compare_article_versions<'s>(
+        setup: &'s TestSetup,
+        article_id: i32,
+        language_code: &String,
+        version: i32,
+        compared_version: i32,
+    ) -> ArticleVersionCompareAggregation {
+        let response = ArticleVersionRequest::compare_article_versions(
+            setup,
+            article_id,
+            language_code,
+            version,
+            compared_version,
+        )
+        .await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response
+            .into_json::<ArticleVersionCompareAggregation>()
+            .await
+            .unwrap()
+    }
+
+    pub async fn get_outdated_translations<'s>(
+        setup: &'s TestSetup,
+        article_id: i32,
+    ) -> Vec<ArticleVersionOutdatedAggregation> {
+        let response = ArticleVersionRequest::get_outdated_translations(setup, article_id).await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response
+            .into_json::<Vec<ArticleVersionOutdatedAggregation>>()
+            .await
+            .unwrap()
+    }
+
+    pub async fn delete_article_version<'s>(
+        setup: &'s TestSetup,
+        article_id: i32,
+        language_code: &String,
+        version: i32,
+        jwt_token: String,
+    ) -> () {
+        let response = ArticleVersionRequest::delete_article_version(
+            setup,
+            article_id,
+            language_code,
+            version,
+            jwt_token,
+        )
+        .await;
+
+        assert_eq!(response.status(), Status::Ok);
+    }
+
+    pub async fn rebuild_version_keyframes<'s>(
+        setup: &'s TestSetup,
+        article_id: Option<i32>,
+        jwt_token: String,
+    ) -> VersionKeyframesAggregation {
+        let response =
+            ArticleVersionRequest::rebuild_version_keyframes(setup, article_id, jwt_token).await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response
+            .into_json::<VersionKeyframesAggregation>()
             .await
             .unwrap()
     }
@@ -151,12 +283,88 @@ impl ArticleVersionRequest {
             .client
             .get(uri!(
                 "/articles",
-                get_article_versions(article_id, language_code,)
+                get_article_versions(article_id, language_code, _, _)
             ))
             .dispatch()
             .await
     }
 
+    pub async fn get_article_versions_page<'s>(
+        setup: &'s TestSetup,
+        article_id: i32,
+        language_code: &String,
+        limit: i64,
+        cursor: Option<String>,
+    ) -> LocalResponse<'s> {
+        setup
+            .client
+            .get(uri!(
+                "/articles",
+                get_article_versions(article_id, language_code, Some(limit), cursor)
+            ))
+            .dispatch()
+            .await
+    }
+
+    pub async fn compare_article_versions<'s>(
+        setup: &'s TestSetup,
+        article_id: i32,
+        language_code: &String,
+        version: i32,
+        compared_version: i32,
+    ) -> LocalResponse<'s> {
+        setup
+            .client
+            .get(uri!(
+                "/articles",
+                compare_article_versions(article_id, language_code, version, compared_version, _)
+            ))
+            .dispatch()
+            .await
+    }
+
+    pub async fn get_outdated_translations<'s>(
+        setup: &'s TestSetup,
+        article_id: i32,
+    ) -> LocalResponse<'s> {
+        setup
+            .client
+            .get(uri!("/articles", get_outdated_translations(article_id, _)))
+            .dispatch()
+            .await
+    }
+
+    pub async fn delete_article_version<'s>(
+        setup: &'s TestSetup,
+        article_id: i32,
+        language_code: &String,
+        version: i32,
+        jwt_token: String,
+    ) -> LocalResponse<'s> {
+        setup
+            .client
+            .delete(uri!(
+                "/articles",
+                delete_article_version(article_id, language_code, version)
+            ))
+            .header(RequestHandler::get_auth_header(jwt_token))
+            .dispatch()
+            .await
+    }
+
+    pub async fn rebuild_version_keyframes<'s>(
+        setup: &'s TestSetup,
+        article_id: Option<i32>,
+        jwt_token: String,
+    ) -> LocalResponse<'s> {
+        setup
+            .client
+            .post(uri!("/articles", rebuild_version_keyframes(article_id)))
+            .header(RequestHandler::get_auth_header(jwt_token))
+            .dispatch()
+            .await
+    }
+
     pub async fn patch_article_version<'s>(
         setup: &'s TestSetup,
         patch_body: &ArticleVersionPatchBody,
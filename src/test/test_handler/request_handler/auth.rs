@@ -1,12 +1,22 @@
 use rocket::local::asynchronous::Client;
 use rocket::local::asynchronous::LocalResponse;
-use rocket::{http::Status, uri};
+use rocket::{
+    http::{Header, Status},
+    uri,
+};
 use serde::Serialize;
+use std::net::SocketAddr;
 
 use super::router::auth::*;
 
+use super::aggregation::api_token::{ApiTokenAggregation, ApiTokenCreatedAggregation};
+use super::aggregation::user_account_auth::{
+    OidcAuthorizationAggregation, TotpEnrollmentAggregation, TotpRecoveryCodesAggregation,
+    UserAccountAuthAggregation, UserAccountEnrollmentAggregation, UserAccountLoginAggregation,
+};
 use super::dtm_common::TokenDto;
 
+use super::request_handler::RequestHandler;
 use super::setup::TestSetup;
 
 pub struct AuthRequestHandler;
@@ -22,19 +32,247 @@ impl AuthRequestHandler {
         response.into_json::<TokenDto>().await.unwrap()
     }
 
-    pub async fn _login<T>(setup: &TestSetup, login_body: &T) -> TokenDto
+    pub async fn signup<T>(setup: &TestSetup, signup_body: &T, language: Option<String>)
     where
         T: Serialize,
     {
-        let response = AuthRequest::_login(setup, login_body).await;
+        let response = AuthRequest::signup(setup, signup_body, language).await;
 
         assert_eq!(response.status(), Status::Ok);
+    }
 
-        response.into_json::<TokenDto>().await.unwrap()
+    pub async fn reset<T>(setup: &TestSetup, reset_body: &T)
+    where
+        T: Serialize,
+    {
+        let response = AuthRequest::reset(setup, reset_body).await;
+
+        assert_eq!(response.status(), Status::Ok);
+    }
+
+    pub async fn confirm<T>(setup: &TestSetup, confirm_body: &T) -> UserAccountAuthAggregation
+    where
+        T: Serialize,
+    {
+        let response = AuthRequest::confirm(setup, confirm_body).await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response
+            .into_json::<UserAccountAuthAggregation>()
+            .await
+            .unwrap()
+    }
+
+    pub async fn confirm_reset<T>(
+        setup: &TestSetup,
+        confirm_reset_body: &T,
+    ) -> UserAccountLoginAggregation
+    where
+        T: Serialize,
+    {
+        let response = AuthRequest::confirm_reset(setup, confirm_reset_body).await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response
+            .into_json::<UserAccountLoginAggregation>()
+            .await
+            .unwrap()
+    }
+
+    pub async fn login<T>(setup: &TestSetup, login_body: &T) -> UserAccountLoginAggregation
+    where
+        T: Serialize,
+    {
+        let response = AuthRequest::login(setup, login_body).await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response
+            .into_json::<UserAccountLoginAggregation>()
+            .await
+            .unwrap()
+    }
+
+    pub async fn login_totp<T>(setup: &TestSetup, challenge_body: &T) -> UserAccountAuthAggregation
+    where
+        T: Serialize,
+    {
+        let response = AuthRequest::login_totp(setup, challenge_body).await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response
+            .into_json::<UserAccountAuthAggregation>()
+            .await
+            .unwrap()
+    }
+
+    pub async fn login_totp_enroll<T>(
+        setup: &TestSetup,
+        enroll_body: &T,
+    ) -> TotpEnrollmentAggregation
+    where
+        T: Serialize,
+    {
+        let response = AuthRequest::login_totp_enroll(setup, enroll_body).await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response
+            .into_json::<TotpEnrollmentAggregation>()
+            .await
+            .unwrap()
+    }
+
+    pub async fn login_totp_confirm<T>(
+        setup: &TestSetup,
+        challenge_body: &T,
+    ) -> UserAccountEnrollmentAggregation
+    where
+        T: Serialize,
+    {
+        let response = AuthRequest::login_totp_confirm(setup, challenge_body).await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response
+            .into_json::<UserAccountEnrollmentAggregation>()
+            .await
+            .unwrap()
+    }
+
+    pub async fn oidc_authorize(setup: &TestSetup, provider: &str) -> OidcAuthorizationAggregation {
+        let response = AuthRequest::oidc_authorize(setup, provider).await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response
+            .into_json::<OidcAuthorizationAggregation>()
+            .await
+            .unwrap()
+    }
+
+    pub async fn oidc_callback<T>(
+        setup: &TestSetup,
+        provider: &str,
+        callback_body: &T,
+    ) -> UserAccountLoginAggregation
+    where
+        T: Serialize,
+    {
+        let response = AuthRequest::oidc_callback(setup, provider, callback_body).await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response
+            .into_json::<UserAccountLoginAggregation>()
+            .await
+            .unwrap()
+    }
+
+    pub async fn start_totp(setup: &TestSetup, jwt_token: String) -> TotpEnrollmentAggregation {
+        let response = AuthRequest::start_totp(setup, jwt_token).await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response
+            .into_json::<TotpEnrollmentAggregation>()
+            .await
+            .unwrap()
+    }
+
+    pub async fn confirm_totp<T>(
+        setup: &TestSetup,
+        code_body: &T,
+        jwt_token: String,
+    ) -> TotpRecoveryCodesAggregation
+    where
+        T: Serialize,
+    {
+        let response = AuthRequest::confirm_totp(setup, code_body, jwt_token).await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response
+            .into_json::<TotpRecoveryCodesAggregation>()
+            .await
+            .unwrap()
+    }
+
+    pub async fn create_api_token<T>(
+        setup: &TestSetup,
+        api_token_body: &T,
+        jwt_token: String,
+    ) -> ApiTokenCreatedAggregation
+    where
+        T: Serialize,
+    {
+        let response = AuthRequest::create_api_token(setup, api_token_body, jwt_token).await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response
+            .into_json::<ApiTokenCreatedAggregation>()
+            .await
+            .unwrap()
+    }
+
+    pub async fn get_api_tokens(setup: &TestSetup, jwt_token: String) -> Vec<ApiTokenAggregation> {
+        let response = AuthRequest::get_api_tokens(setup, jwt_token).await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response
+            .into_json::<Vec<ApiTokenAggregation>>()
+            .await
+            .unwrap()
+    }
+
+    pub async fn revoke_api_token(setup: &TestSetup, id: i32, jwt_token: String) {
+        let response = AuthRequest::revoke_api_token(setup, id, jwt_token).await;
+
+        assert_eq!(response.status(), Status::Ok);
+    }
+
+    pub async fn patch_user_language<T>(setup: &TestSetup, language_body: &T, jwt_token: String)
+    where
+        T: Serialize,
+    {
+        let response = AuthRequest::patch_user_language(setup, language_body, jwt_token).await;
+
+        assert_eq!(response.status(), Status::Ok);
+    }
+
+    pub async fn refresh<T>(setup: &TestSetup, refresh_body: &T) -> UserAccountAuthAggregation
+    where
+        T: Serialize,
+    {
+        let response = AuthRequest::refresh(setup, refresh_body).await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response
+            .into_json::<UserAccountAuthAggregation>()
+            .await
+            .unwrap()
+    }
+
+    pub async fn logout(setup: &TestSetup, jwt_token: String) {
+        let response = AuthRequest::logout(setup, jwt_token).await;
+
+        assert_eq!(response.status(), Status::Ok);
+    }
+
+    pub async fn logout_all(setup: &TestSetup, jwt_token: String) {
+        let response = AuthRequest::logout_all(setup, jwt_token).await;
+
+        assert_eq!(response.status(), Status::Ok);
     }
 }
 
-struct AuthRequest;
+pub struct AuthRequest;
 impl AuthRequest {
     pub async fn signup_with_role<'s, T>(
         client: &'s Client,
@@ -51,7 +289,62 @@ impl AuthRequest {
             .await
     }
 
-    pub async fn _login<'s, T>(setup: &'s TestSetup, login_body: &T) -> LocalResponse<'s>
+    pub async fn signup<'s, T>(
+        setup: &'s TestSetup,
+        signup_body: &T,
+        language: Option<String>,
+    ) -> LocalResponse<'s>
+    where
+        T: Serialize,
+    {
+        setup
+            .client
+            .post(uri!("/auth", signup(_, language)))
+            .json::<T>(signup_body)
+            .dispatch()
+            .await
+    }
+
+    pub async fn reset<'s, T>(setup: &'s TestSetup, reset_body: &T) -> LocalResponse<'s>
+    where
+        T: Serialize,
+    {
+        setup
+            .client
+            .post(uri!("/auth", reset(_)))
+            .json::<T>(reset_body)
+            .dispatch()
+            .await
+    }
+
+    pub async fn confirm<'s, T>(setup: &'s TestSetup, confirm_body: &T) -> LocalResponse<'s>
+    where
+        T: Serialize,
+    {
+        setup
+            .client
+            .post(uri!("/auth", confirm))
+            .json::<T>(confirm_body)
+            .dispatch()
+            .await
+    }
+
+    pub async fn confirm_reset<'s, T>(
+        setup: &'s TestSetup,
+        confirm_reset_body: &T,
+    ) -> LocalResponse<'s>
+    where
+        T: Serialize,
+    {
+        setup
+            .client
+            .post(uri!("/auth", confirm_reset))
+            .json::<T>(confirm_reset_body)
+            .dispatch()
+            .await
+    }
+
+    pub async fn login<'s, T>(setup: &'s TestSetup, login_body: &T) -> LocalResponse<'s>
     where
         T: Serialize,
     {
@@ -62,4 +355,237 @@ impl AuthRequest {
             .dispatch()
             .await
     }
+
+    pub async fn login_from<'s, T>(
+        setup: &'s TestSetup,
+        login_body: &T,
+        remote_ip: &str,
+        forwarded_ip: Option<&str>,
+    ) -> LocalResponse<'s>
+    where
+        T: Serialize,
+    {
+        let mut request = setup
+            .client
+            .post(uri!("/auth", login))
+            .remote(SocketAddr::new(remote_ip.parse().unwrap(), 8000))
+            .json::<T>(login_body);
+
+        if let Some(forwarded_ip) = forwarded_ip {
+            request = request.header(Header::new("X-Real-IP", forwarded_ip.to_owned()));
+        }
+
+        request.dispatch().await
+    }
+
+    pub async fn unlock_user<'s>(
+        setup: &'s TestSetup,
+        user_id: i32,
+        jwt_token: String,
+    ) -> LocalResponse<'s> {
+        setup
+            .client
+            .post(uri!("/auth", unlock_user(user_id)))
+            .header(RequestHandler::get_auth_header(jwt_token))
+            .dispatch()
+            .await
+    }
+
+    pub async fn login_totp<'s, T>(setup: &'s TestSetup, body: &T) -> LocalResponse<'s>
+    where
+        T: Serialize,
+    {
+        setup
+            .client
+            .post(uri!("/auth", login_totp))
+            .json::<T>(body)
+            .dispatch()
+            .await
+    }
+
+    pub async fn login_totp_enroll<'s, T>(setup: &'s TestSetup, body: &T) -> LocalResponse<'s>
+    where
+        T: Serialize,
+    {
+        setup
+            .client
+            .post(uri!("/auth", login_totp_enroll))
+            .json::<T>(body)
+            .dispatch()
+            .await
+    }
+
+    pub async fn login_totp_confirm<'s, T>(setup: &'s TestSetup, body: &T) -> LocalResponse<'s>
+    where
+        T: Serialize,
+    {
+        setup
+            .client
+            .post(uri!("/auth", login_totp_confirm))
+            .json::<T>(body)
+            .dispatch()
+            .await
+    }
+
+    pub async fn oidc_authorize<'s>(setup: &'s TestSetup, provider: &str) -> LocalResponse<'s> {
+        setup
+            .client
+            .get(uri!("/auth", oidc_authorize(provider)))
+            .dispatch()
+            .await
+    }
+
+    pub async fn oidc_callback<'s, T>(
+        setup: &'s TestSetup,
+        provider: &str,
+        callback_body: &T,
+    ) -> LocalResponse<'s>
+    where
+        T: Serialize,
+    {
+        setup
+            .client
+            .post(uri!("/auth", oidc_callback(provider)))
+            .json::<T>(callback_body)
+            .dispatch()
+            .await
+    }
+
+    pub async fn start_totp<'s>(setup: &'s TestSetup, jwt_token: String) -> LocalResponse<'s> {
+        setup
+            .client
+            .post(uri!("/auth", start_totp))
+            .header(RequestHandler::get_auth_header(jwt_token))
+            .dispatch()
+            .await
+    }
+
+    pub async fn confirm_totp<'s, T>(
+        setup: &'s TestSetup,
+        code_body: &T,
+        jwt_token: String,
+    ) -> LocalResponse<'s>
+    where
+        T: Serialize,
+    {
+        setup
+            .client
+            .post(uri!("/auth", confirm_totp))
+            .header(RequestHandler::get_auth_header(jwt_token))
+            .json::<T>(code_body)
+            .dispatch()
+            .await
+    }
+
+    pub async fn disable_totp<'s, T>(
+        setup: &'s TestSetup,
+        code_body: &T,
+        jwt_token: String,
+    ) -> LocalResponse<'s>
+    where
+        T: Serialize,
+    {
+        setup
+            .client
+            .post(uri!("/auth", disable_totp))
+            .header(RequestHandler::get_auth_header(jwt_token))
+            .json::<T>(code_body)
+            .dispatch()
+            .await
+    }
+
+    pub async fn create_api_token<'s, T>(
+        setup: &'s TestSetup,
+        api_token_body: &T,
+        jwt_token: String,
+    ) -> LocalResponse<'s>
+    where
+        T: Serialize,
+    {
+        setup
+            .client
+            .post(uri!("/auth", create_api_token))
+            .header(RequestHandler::get_auth_header(jwt_token))
+            .json::<T>(api_token_body)
+            .dispatch()
+            .await
+    }
+
+    pub async fn get_api_tokens<'s>(setup: &'s TestSetup, jwt_token: String) -> LocalResponse<'s> {
+        setup
+            .client
+            .get(uri!("/auth", get_api_tokens))
+            .header(RequestHandler::get_auth_header(jwt_token))
+            .dispatch()
+            .await
+    }
+
+    pub async fn revoke_api_token<'s>(
+        setup: &'s TestSetup,
+        id: i32,
+        jwt_token: String,
+    ) -> LocalResponse<'s> {
+        setup
+            .client
+            .delete(uri!("/auth", revoke_api_token(id)))
+            .header(RequestHandler::get_auth_header(jwt_token))
+            .dispatch()
+            .await
+    }
+
+    pub async fn patch_user_language<'s, T>(
+        setup: &'s TestSetup,
+        language_body: &T,
+        jwt_token: String,
+    ) -> LocalResponse<'s>
+    where
+        T: Serialize,
+    {
+        setup
+            .client
+            .patch(uri!("/auth", patch_user_language))
+            .json::<T>(language_body)
+            .header(RequestHandler::get_auth_header(jwt_token))
+            .dispatch()
+            .await
+    }
+
+    pub async fn refresh<'s, T>(setup: &'s TestSetup, refresh_body: &T) -> LocalResponse<'s>
+    where
+        T: Serialize,
+    {
+        setup
+            .client
+            .post(uri!("/auth", refresh))
+            .json::<T>(refresh_body)
+            .dispatch()
+            .await
+    }
+
+    pub async fn logout<'s>(setup: &'s TestSetup, jwt_token: String) -> LocalResponse<'s> {
+        setup
+            .client
+            .post(uri!("/auth", logout))
+            .header(RequestHandler::get_auth_header(jwt_token))
+            .dispatch()
+            .await
+    }
+
+    pub async fn logout_all<'s>(setup: &'s TestSetup, jwt_token: String) -> LocalResponse<'s> {
+        setup
+            .client
+            .post(uri!("/auth", logout_all))
+            .header(RequestHandler::get_auth_header(jwt_token))
+            .dispatch()
+            .await
+    }
+
+    pub async fn get_user<'s>(setup: &'s TestSetup, jwt_token: String) -> LocalResponse<'s> {
+        setup
+            .client
+            .get(uri!("/auth", get_user(_)))
+            .header(RequestHandler::get_auth_header(jwt_token))
+            .dispatch()
+            .await
+    }
 }
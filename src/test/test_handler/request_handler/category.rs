@@ -0,0 +1,137 @@
+use rocket::local::asynchronous::LocalResponse;
+use rocket::{http::Status, uri};
+use serde::Serialize;
+
+use super::router::category::*;
+
+use super::aggregation::category::CategoryAggregation;
+
+use super::request_handler::RequestHandler;
+use super::setup::TestSetup;
+
+pub struct CategoryRequestHandler;
+impl CategoryRequestHandler {
+    pub async fn create_category<T>(
+        setup: &TestSetup,
+        creation_body: &T,
+        jwt_token: String,
+    ) -> CategoryAggregation
+    where
+        T: Serialize,
+    {
+        let response = CategoryRequest::create_category(setup, creation_body, jwt_token).await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response.into_json::<CategoryAggregation>().await.unwrap()
+    }
+
+    pub async fn patch_category<T>(
+        setup: &TestSetup,
+        category_id: i32,
+        patch_body: &T,
+        jwt_token: String,
+    ) -> CategoryAggregation
+    where
+        T: Serialize,
+    {
+        let response =
+            CategoryRequest::patch_category(setup, category_id, patch_body, jwt_token).await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response.into_json::<CategoryAggregation>().await.unwrap()
+    }
+
+    pub async fn get_category(setup: &TestSetup, category_id: i32) -> CategoryAggregation {
+        let response = CategoryRequest::get_category(setup, category_id).await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response.into_json::<CategoryAggregation>().await.unwrap()
+    }
+
+    pub async fn get_categories(setup: &TestSetup) -> Vec<CategoryAggregation> {
+        let response = CategoryRequest::get_categories(setup).await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response
+            .into_json::<Vec<CategoryAggregation>>()
+            .await
+            .unwrap()
+    }
+
+    pub async fn delete_category(setup: &TestSetup, category_id: i32, jwt_token: String) {
+        let response = CategoryRequest::delete_category(setup, category_id, jwt_token).await;
+
+        assert_eq!(response.status(), Status::Ok);
+    }
+}
+
+pub struct CategoryRequest;
+impl CategoryRequest {
+    pub async fn create_category<'s, T>(
+        setup: &'s TestSetup,
+        creation_body: &T,
+        jwt_token: String,
+    ) -> LocalResponse<'s>
+    where
+        T: Serialize,
+    {
+        setup
+            .client
+            .post(uri!("/categories", create_category))
+            .json::<T>(creation_body)
+            .header(RequestHandler::get_auth_header(jwt_token))
+            .dispatch()
+            .await
+    }
+
+    pub async fn patch_category<'s, T>(
+        setup: &'s TestSetup,
+        category_id: i32,
+        patch_body: &T,
+        jwt_token: String,
+    ) -> LocalResponse<'s>
+    where
+        T: Serialize,
+    {
+        setup
+            .client
+            .patch(uri!("/categories", patch_category(category_id)))
+            .json::<T>(patch_body)
+            .header(RequestHandler::get_auth_header(jwt_token))
+            .dispatch()
+            .await
+    }
+
+    pub async fn get_category<'s>(setup: &'s TestSetup, category_id: i32) -> LocalResponse<'s> {
+        setup
+            .client
+            .get(uri!("/categories", get_category(category_id)))
+            .dispatch()
+            .await
+    }
+
+    pub async fn get_categories<'s>(setup: &'s TestSetup) -> LocalResponse<'s> {
+        setup
+            .client
+            .get(uri!("/categories", get_categories))
+            .dispatch()
+            .await
+    }
+
+    pub async fn delete_category<'s>(
+        setup: &'s TestSetup,
+        category_id: i32,
+        jwt_token: String,
+    ) -> LocalResponse<'s> {
+        setup
+            .client
+            .delete(uri!("/categories", delete_category(category_id)))
+            .header(RequestHandler::get_auth_header(jwt_token))
+            .dispatch()
+            .await
+    }
+}
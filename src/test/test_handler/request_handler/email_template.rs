@@ -0,0 +1,126 @@
+use rocket::local::asynchronous::LocalResponse;
+use rocket::{http::Status, uri};
+use serde::Serialize;
+
+use super::router::email_template::*;
+
+use super::aggregation::email_template::{EmailPreviewAggregation, EmailTemplateAggregation};
+
+use super::request_handler::RequestHandler;
+use super::setup::TestSetup;
+
+pub struct EmailTemplateRequestHandler;
+impl EmailTemplateRequestHandler {
+    pub async fn get_email_templates(
+        setup: &TestSetup,
+        jwt_token: String,
+    ) -> Vec<EmailTemplateAggregation> {
+        let response = EmailTemplateRequest::get_email_templates(setup, jwt_token).await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response
+            .into_json::<Vec<EmailTemplateAggregation>>()
+            .await
+            .unwrap()
+    }
+
+    pub async fn patch_email_template<T>(
+        setup: &TestSetup,
+        template_type: &str,
+        language: &str,
+        patch_body: &T,
+        jwt_token: String,
+    ) -> EmailTemplateAggregation
+    where
+        T: Serialize,
+    {
+        let response = EmailTemplateRequest::patch_email_template(
+            setup,
+            template_type,
+            language,
+            patch_body,
+            jwt_token,
+        )
+        .await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response
+            .into_json::<EmailTemplateAggregation>()
+            .await
+            .unwrap()
+    }
+
+    pub async fn preview_email_template(
+        setup: &TestSetup,
+        template_type: &str,
+        language: &str,
+        jwt_token: String,
+    ) -> EmailPreviewAggregation {
+        let response =
+            EmailTemplateRequest::preview_email_template(setup, template_type, language, jwt_token)
+                .await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response
+            .into_json::<EmailPreviewAggregation>()
+            .await
+            .unwrap()
+    }
+}
+
+pub struct EmailTemplateRequest;
+impl EmailTemplateRequest {
+    pub async fn get_email_templates<'s>(
+        setup: &'s TestSetup,
+        jwt_token: String,
+    ) -> LocalResponse<'s> {
+        setup
+            .client
+            .get(uri!("/email-templates", get_email_templates))
+            .header(RequestHandler::get_auth_header(jwt_token))
+            .dispatch()
+            .await
+    }
+
+    pub async fn patch_email_template<'s, T>(
+        setup: &'s TestSetup,
+        template_type: &str,
+        language: &str,
+        patch_body: &T,
+        jwt_token: String,
+    ) -> LocalResponse<'s>
+    where
+        T: Serialize,
+    {
+        setup
+            .client
+            .patch(uri!(
+                "/email-templates",
+                patch_email_template(template_type, language)
+            ))
+            .json::<T>(patch_body)
+            .header(RequestHandler::get_auth_header(jwt_token))
+            .dispatch()
+            .await
+    }
+
+    pub async fn preview_email_template<'s>(
+        setup: &'s TestSetup,
+        template_type: &str,
+        language: &str,
+        jwt_token: String,
+    ) -> LocalResponse<'s> {
+        setup
+            .client
+            .get(uri!(
+                "/email-templates",
+                preview_email_template(template_type, language)
+            ))
+            .header(RequestHandler::get_auth_header(jwt_token))
+            .dispatch()
+            .await
+    }
+}
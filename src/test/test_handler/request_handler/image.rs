@@ -0,0 +1,197 @@
+use rocket::local::asynchronous::LocalResponse;
+use rocket::{
+    http::{ContentType, Status},
+    uri,
+};
+use serde::Serialize;
+
+use super::router::image::*;
+
+use super::aggregation::{
+    image::{ImageAggregation, ImageCleanupAggregation, ImagesUploadAggregation},
+    page::PageAggregation,
+};
+
+use super::request_handler::RequestHandler;
+use super::setup::TestSetup;
+
+pub struct ImageRequestHandler;
+impl ImageRequestHandler {
+    pub async fn create_images<T>(
+        setup: &TestSetup,
+        creation_body: &T,
+        jwt_token: String,
+    ) -> ImagesUploadAggregation
+    where
+        T: Serialize,
+    {
+        let response = ImageRequest::create_images(setup, creation_body, jwt_token).await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response
+            .into_json::<ImagesUploadAggregation>()
+            .await
+            .unwrap()
+    }
+
+    pub async fn upload_image_files(
+        setup: &TestSetup,
+        files: &Vec<Vec<u8>>,
+        jwt_token: String,
+    ) -> ImagesUploadAggregation {
+        let response = ImageRequest::upload_image_files(setup, files, jwt_token).await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response
+            .into_json::<ImagesUploadAggregation>()
+            .await
+            .unwrap()
+    }
+
+    pub async fn get_images(
+        setup: &TestSetup,
+        jwt_token: String,
+    ) -> PageAggregation<ImageAggregation> {
+        let response = ImageRequest::get_images(setup, jwt_token).await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response
+            .into_json::<PageAggregation<ImageAggregation>>()
+            .await
+            .unwrap()
+    }
+
+    pub async fn get_article_images(setup: &TestSetup, article_id: i32) -> Vec<ImageAggregation> {
+        let response = ImageRequest::get_article_images(setup, article_id).await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response.into_json::<Vec<ImageAggregation>>().await.unwrap()
+    }
+
+    pub async fn delete_image(setup: &TestSetup, id: i32, jwt_token: String) {
+        let response = ImageRequest::delete_image(setup, id, jwt_token).await;
+
+        assert_eq!(response.status(), Status::Ok);
+    }
+
+    pub async fn cleanup_images(setup: &TestSetup, jwt_token: String) -> ImageCleanupAggregation {
+        let response = ImageRequest::cleanup_images(setup, jwt_token).await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response
+            .into_json::<ImageCleanupAggregation>()
+            .await
+            .unwrap()
+    }
+
+    pub async fn get_image_file(setup: &TestSetup, image_uri: &String) -> Vec<u8> {
+        let response = ImageRequest::get_image_file(setup, image_uri).await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response.into_bytes().await.unwrap()
+    }
+}
+
+pub struct ImageRequest;
+impl ImageRequest {
+    pub async fn create_images<'s, T>(
+        setup: &'s TestSetup,
+        creation_body: &T,
+        jwt_token: String,
+    ) -> LocalResponse<'s>
+    where
+        T: Serialize,
+    {
+        setup
+            .client
+            .post(uri!("/image", create_image))
+            .json::<T>(creation_body)
+            .header(RequestHandler::get_auth_header(jwt_token))
+            .dispatch()
+            .await
+    }
+
+    pub async fn upload_image_files<'s>(
+        setup: &'s TestSetup,
+        files: &Vec<Vec<u8>>,
+        jwt_token: String,
+    ) -> LocalResponse<'s> {
+        let boundary = "image_upload_boundary";
+
+        let mut body = vec![];
+
+        for (index, file) in files.iter().enumerate() {
+            let disposition = format!("form-data; name=\"files\"; filename=\"{}.bin\"", index);
+
+            body.extend_from_slice(format!("--{}\r\n", boundary).as_bytes());
+            body.extend_from_slice(
+                format!("Content-Disposition: {}\r\n\r\n", disposition).as_bytes(),
+            );
+            body.extend_from_slice(file);
+            body.extend_from_slice(b"\r\n");
+        }
+
+        body.extend_from_slice(format!("--{}--\r\n", boundary).as_bytes());
+
+        setup
+            .client
+            .post(uri!("/image", upload_image_files))
+            .header(ContentType::new("multipart", "form-data").with_params(("boundary", boundary)))
+            .body(body)
+            .header(RequestHandler::get_auth_header(jwt_token))
+            .dispatch()
+            .await
+    }
+
+    pub async fn get_images<'s>(setup: &'s TestSetup, jwt_token: String) -> LocalResponse<'s> {
+        setup
+            .client
+            .get(uri!("/image", get_images(_, _)))
+            .header(RequestHandler::get_auth_header(jwt_token))
+            .dispatch()
+            .await
+    }
+
+    pub async fn get_article_images<'s>(
+        setup: &'s TestSetup,
+        article_id: i32,
+    ) -> LocalResponse<'s> {
+        setup
+            .client
+            .get(uri!("/image", get_article_images(article_id)))
+            .dispatch()
+            .await
+    }
+
+    pub async fn delete_image<'s>(
+        setup: &'s TestSetup,
+        id: i32,
+        jwt_token: String,
+    ) -> LocalResponse<'s> {
+        setup
+            .client
+            .delete(uri!("/image", delete_image(id)))
+            .header(RequestHandler::get_auth_header(jwt_token))
+            .dispatch()
+            .await
+    }
+
+    pub async fn cleanup_images<'s>(setup: &'s TestSetup, jwt_token: String) -> LocalResponse<'s> {
+        setup
+            .client
+            .post(uri!("/image", cleanup_images))
+            .header(RequestHandler::get_auth_header(jwt_token))
+            .dispatch()
+            .await
+    }
+
+    pub async fn get_image_file<'s>(setup: &'s TestSetup, image_uri: &String) -> LocalResponse<'s> {
+        setup.client.get(image_uri.clone()).dispatch().await
+    }
+}
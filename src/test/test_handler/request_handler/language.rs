@@ -0,0 +1,114 @@
+use rocket::local::asynchronous::LocalResponse;
+use rocket::{http::Status, uri};
+use serde::Serialize;
+
+use super::router::language::*;
+
+use super::aggregation::language::LanguageAggregation;
+
+use super::request_handler::RequestHandler;
+use super::setup::TestSetup;
+
+pub struct LanguageRequestHandler;
+impl LanguageRequestHandler {
+    pub async fn get_languages(
+        setup: &TestSetup,
+        include_disabled: Option<bool>,
+        jwt_token: Option<String>,
+    ) -> Vec<LanguageAggregation> {
+        let response = LanguageRequest::get_languages(setup, include_disabled, jwt_token).await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response
+            .into_json::<Vec<LanguageAggregation>>()
+            .await
+            .unwrap()
+    }
+
+    pub async fn create_language<T>(
+        setup: &TestSetup,
+        creation_body: &T,
+        jwt_token: String,
+    ) -> LanguageAggregation
+    where
+        T: Serialize,
+    {
+        let response = LanguageRequest::create_language(setup, creation_body, jwt_token).await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response.into_json::<LanguageAggregation>().await.unwrap()
+    }
+
+    pub async fn patch_language<T>(
+        setup: &TestSetup,
+        code: &str,
+        patch_body: &T,
+        jwt_token: String,
+    ) -> LanguageAggregation
+    where
+        T: Serialize,
+    {
+        let response = LanguageRequest::patch_language(setup, code, patch_body, jwt_token).await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response.into_json::<LanguageAggregation>().await.unwrap()
+    }
+}
+
+pub struct LanguageRequest;
+impl LanguageRequest {
+    pub async fn get_languages<'s>(
+        setup: &'s TestSetup,
+        include_disabled: Option<bool>,
+        jwt_token: Option<String>,
+    ) -> LocalResponse<'s> {
+        let request = setup
+            .client
+            .get(uri!("/languages", get_languages(include_disabled)));
+
+        let request = match jwt_token {
+            Some(jwt_token) => request.header(RequestHandler::get_auth_header(jwt_token)),
+            None => request,
+        };
+
+        request.dispatch().await
+    }
+
+    pub async fn create_language<'s, T>(
+        setup: &'s TestSetup,
+        creation_body: &T,
+        jwt_token: String,
+    ) -> LocalResponse<'s>
+    where
+        T: Serialize,
+    {
+        setup
+            .client
+            .post(uri!("/languages", create_language))
+            .json::<T>(creation_body)
+            .header(RequestHandler::get_auth_header(jwt_token))
+            .dispatch()
+            .await
+    }
+
+    pub async fn patch_language<'s, T>(
+        setup: &'s TestSetup,
+        code: &str,
+        patch_body: &T,
+        jwt_token: String,
+    ) -> LocalResponse<'s>
+    where
+        T: Serialize,
+    {
+        setup
+            .client
+            .patch(uri!("/languages", patch_language(code)))
+            .json::<T>(patch_body)
+            .header(RequestHandler::get_auth_header(jwt_token))
+            .dispatch()
+            .await
+    }
+}
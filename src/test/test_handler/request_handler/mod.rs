@@ -5,7 +5,13 @@ use super::router;
 use super::setup;
 
 pub mod article;
+pub mod article_draft;
 pub mod article_language;
 pub mod article_version;
 pub mod auth;
+pub mod category;
+pub mod email_template;
+pub mod image;
+pub mod language;
 pub mod request_handler;
+pub mod watch;
@@ -0,0 +1,149 @@
+use rocket::local::asynchronous::LocalResponse;
+use rocket::{http::Status, uri};
+use serde::Serialize;
+
+use super::router::watch::*;
+
+use super::aggregation::watch::{WatchAggregation, WatchDigestAggregation};
+
+use super::request_handler::RequestHandler;
+use super::setup::TestSetup;
+
+pub struct WatchRequestHandler;
+impl WatchRequestHandler {
+    pub async fn get_watches(setup: &TestSetup, jwt_token: String) -> Vec<WatchAggregation> {
+        let response = WatchRequest::get_watches(setup, jwt_token).await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response.into_json::<Vec<WatchAggregation>>().await.unwrap()
+    }
+
+    pub async fn create_watch<T>(
+        setup: &TestSetup,
+        creation_body: &T,
+        jwt_token: String,
+    ) -> WatchAggregation
+    where
+        T: Serialize,
+    {
+        let response = WatchRequest::create_watch(setup, creation_body, jwt_token).await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response.into_json::<WatchAggregation>().await.unwrap()
+    }
+
+    pub async fn patch_watch<T>(
+        setup: &TestSetup,
+        id: i32,
+        patch_body: &T,
+        jwt_token: String,
+    ) -> WatchAggregation
+    where
+        T: Serialize,
+    {
+        let response = WatchRequest::patch_watch(setup, id, patch_body, jwt_token).await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response.into_json::<WatchAggregation>().await.unwrap()
+    }
+
+    pub async fn unsubscribe_watch<T>(setup: &TestSetup, unsubscribe_body: &T)
+    where
+        T: Serialize,
+    {
+        let response = WatchRequest::unsubscribe_watch(setup, unsubscribe_body).await;
+
+        assert_eq!(response.status(), Status::Ok);
+    }
+
+    pub async fn send_watch_digests(
+        setup: &TestSetup,
+        jwt_token: String,
+    ) -> WatchDigestAggregation {
+        let response = WatchRequest::send_watch_digests(setup, jwt_token).await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        response
+            .into_json::<WatchDigestAggregation>()
+            .await
+            .unwrap()
+    }
+}
+
+pub struct WatchRequest;
+impl WatchRequest {
+    pub async fn get_watches<'s>(setup: &'s TestSetup, jwt_token: String) -> LocalResponse<'s> {
+        setup
+            .client
+            .get(uri!("/watches", get_watches))
+            .header(RequestHandler::get_auth_header(jwt_token))
+            .dispatch()
+            .await
+    }
+
+    pub async fn create_watch<'s, T>(
+        setup: &'s TestSetup,
+        creation_body: &T,
+        jwt_token: String,
+    ) -> LocalResponse<'s>
+    where
+        T: Serialize,
+    {
+        setup
+            .client
+            .post(uri!("/watches", create_watch))
+            .json::<T>(creation_body)
+            .header(RequestHandler::get_auth_header(jwt_token))
+            .dispatch()
+            .await
+    }
+
+    pub async fn patch_watch<'s, T>(
+        setup: &'s TestSetup,
+        id: i32,
+        patch_body: &T,
+        jwt_token: String,
+    ) -> LocalResponse<'s>
+    where
+        T: Serialize,
+    {
+        setup
+            .client
+            .patch(uri!("/watches", patch_watch(id)))
+            .json::<T>(patch_body)
+            .header(RequestHandler::get_auth_header(jwt_token))
+            .dispatch()
+            .await
+    }
+
+    pub async fn unsubscribe_watch<'s, T>(
+        setup: &'s TestSetup,
+        unsubscribe_body: &T,
+    ) -> LocalResponse<'s>
+    where
+        T: Serialize,
+    {
+        setup
+            .client
+            .post(uri!("/watches", unsubscribe_watch))
+            .json::<T>(unsubscribe_body)
+            .dispatch()
+            .await
+    }
+
+    pub async fn send_watch_digests<'s>(
+        setup: &'s TestSetup,
+        jwt_token: String,
+    ) -> LocalResponse<'s> {
+        setup
+            .client
+            .post(uri!("/watches", send_watch_digests))
+            .header(RequestHandler::get_auth_header(jwt_token))
+            .dispatch()
+            .await
+    }
+}
@@ -0,0 +1,6 @@
+use super::error;
+use super::hasher;
+
+mod totp_handler;
+
+pub use totp_handler::TotpHandler;
@@ -0,0 +1,151 @@
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use rand::RngCore;
+use reqwest::Url;
+use sha1::Sha1;
+use std::env;
+
+use super::error::{ErrorWrapper, FmtError};
+use super::hasher::Hasher;
+
+const TOTP_ISSUER_ENV: &str = "TOTP_ISSUER";
+
+const DEFAULT_TOTP_ISSUER: &str = "wiki-engine";
+const SECRET_LENGTH: usize = 20;
+const STEP_SEC: i64 = 30;
+const DIGITS: u32 = 6;
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+pub struct TotpHandler;
+
+// RFC 6238: HMAC-SHA1, 6 digits, 30 sec steps
+impl TotpHandler {
+    fn get_issuer() -> String {
+        env::var(TOTP_ISSUER_ENV).unwrap_or(String::from(DEFAULT_TOTP_ISSUER))
+    }
+
+    pub fn generate_secret() -> String {
+        let mut secret = [0u8; SECRET_LENGTH];
+        rand::thread_rng().fill_bytes(&mut secret);
+
+        Self::encode_base32(&secret)
+    }
+
+    pub fn get_provisioning_uri(secret: &String, account_name: &String) -> String {
+        let issuer = Self::get_issuer();
+
+        let mut url =
+            Url::parse("otpauth://totp/").expect(&FmtError::FailedToProcess("otpauth").fmt());
+        url.set_path(&format!("{issuer}:{account_name}"));
+        url.query_pairs_mut()
+            .append_pair("secret", secret)
+            .append_pair("issuer", &issuer)
+            .append_pair("algorithm", "SHA1")
+            .append_pair("digits", &DIGITS.to_string())
+            .append_pair("period", &STEP_SEC.to_string());
+
+        url.to_string()
+    }
+
+    pub fn get_current_step() -> i64 {
+        Utc::now().timestamp() / STEP_SEC
+    }
+
+    pub fn generate_code(secret: &String, step: i64) -> Result<String, ErrorWrapper> {
+        let key = match Self::decode_base32(secret) {
+            Some(key) => key,
+            None => return FmtError::FailedToProcess("totp secret").error(),
+        };
+
+        let mut mac = match Hmac::<Sha1>::new_from_slice(&key) {
+            Ok(mac) => mac,
+            Err(_) => return FmtError::FailedToProcess("totp secret").error(),
+        };
+        mac.update(&step.to_be_bytes());
+        let hash = mac.finalize().into_bytes();
+
+        // dynamic truncation
+        let offset = (hash[hash.len() - 1] & 0x0f) as usize;
+        let binary = u32::from_be_bytes([
+            hash[offset] & 0x7f,
+            hash[offset + 1],
+            hash[offset + 2],
+            hash[offset + 3],
+        ]);
+
+        Ok(format!(
+            "{:0width$}",
+            binary % 10u32.pow(DIGITS),
+            width = DIGITS as usize
+        ))
+    }
+
+    // codes of the adjacent steps are accepted to tolerate clock drift,
+    // steps up to `last_used_step` are rejected so a code can't be replayed
+    pub fn verify_code(
+        secret: &String,
+        code: &String,
+        last_used_step: Option<i64>,
+    ) -> Result<Option<i64>, ErrorWrapper> {
+        let current_step = Self::get_current_step();
+        let code = code.trim();
+
+        for step in [current_step - 1, current_step, current_step + 1] {
+            if last_used_step.map_or(false, |last_used_step| step <= last_used_step) {
+                continue;
+            }
+
+            let expected_code = Self::generate_code(secret, step)?;
+
+            if Hasher::constant_time_eq(expected_code.as_bytes(), code.as_bytes()) {
+                return Ok(Some(step));
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn encode_base32(bytes: &[u8]) -> String {
+        let mut encoded = String::new();
+        let mut buffer: u32 = 0;
+        let mut bits = 0;
+
+        for byte in bytes {
+            buffer = (buffer << 8) | *byte as u32;
+            bits += 8;
+
+            while bits >= 5 {
+                bits -= 5;
+                encoded.push(BASE32_ALPHABET[((buffer >> bits) & 31) as usize] as char);
+            }
+        }
+
+        if bits > 0 {
+            encoded.push(BASE32_ALPHABET[((buffer << (5 - bits)) & 31) as usize] as char);
+        }
+
+        encoded
+    }
+
+    fn decode_base32(encoded: &String) -> Option<Vec<u8>> {
+        let mut decoded = vec![];
+        let mut buffer: u32 = 0;
+        let mut bits = 0;
+
+        for char in encoded.trim_end_matches('=').chars() {
+            let value = BASE32_ALPHABET
+                .iter()
+                .position(|alphabet_char| *alphabet_char as char == char.to_ascii_uppercase())?;
+
+            buffer = (buffer << 5) | value as u32;
+            bits += 5;
+
+            if bits >= 8 {
+                bits -= 8;
+                decoded.push((buffer >> bits) as u8);
+            }
+        }
+
+        Some(decoded)
+    }
+}